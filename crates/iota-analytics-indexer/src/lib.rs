@@ -41,7 +41,10 @@ use crate::{
         MovePackageEntry, ObjectEntry, ObjectStatus, OwnerType, TransactionEntry,
         TransactionObjectEntry, WrappedObjectEntry,
     },
-    writers::{AnalyticsWriter, csv_writer::CSVWriter, parquet_writer::ParquetWriter},
+    writers::{
+        AnalyticsWriter, arrow_ipc_writer::ArrowIpcWriter, csv_writer::CSVWriter,
+        jsonl_writer::JsonLinesWriter, parquet_writer::ParquetWriter,
+    },
 };
 
 pub mod analytics_metrics;
@@ -288,6 +291,8 @@ impl MaxCheckpointReader for NoOpCheckpointReader {
 pub enum FileFormat {
     CSV = 0,
     PARQUET = 1,
+    ARROW = 2,
+    JSONL = 3,
 }
 
 impl FileFormat {
@@ -295,6 +300,8 @@ impl FileFormat {
         match self {
             FileFormat::CSV => "csv",
             FileFormat::PARQUET => "parquet",
+            FileFormat::ARROW => "arrow",
+            FileFormat::JSONL => "jsonl",
         }
     }
 }
@@ -869,6 +876,16 @@ pub fn make_writer<S: Serialize + ParquetSchema>(
             file_type,
             starting_checkpoint_seq_num,
         )?),
+        FileFormat::ARROW => Box::new(ArrowIpcWriter::new(
+            &config.checkpoint_dir,
+            file_type,
+            starting_checkpoint_seq_num,
+        )?),
+        FileFormat::JSONL => Box::new(JsonLinesWriter::new(
+            &config.checkpoint_dir,
+            file_type,
+            starting_checkpoint_seq_num,
+        )?),
     })
 }
 
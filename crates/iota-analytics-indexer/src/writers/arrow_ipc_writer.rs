@@ -0,0 +1,81 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use anyhow::Result;
+use arrow::ipc::writer::FileWriter;
+use iota_types::base_types::EpochId;
+use serde::Serialize;
+
+use crate::{
+    AnalyticsWriter, FileFormat, FileType, ParquetSchema, ParquetValue,
+    writers::{TableFile, to_record_batch},
+};
+
+// Save table entries to Arrow IPC (Feather v2) files.
+pub(crate) struct ArrowIpcWriter {
+    file: TableFile,
+    data: Vec<Vec<ParquetValue>>,
+}
+
+impl ArrowIpcWriter {
+    pub(crate) fn new(
+        root_dir_path: &Path,
+        file_type: FileType,
+        start_checkpoint_seq_num: u64,
+    ) -> Result<Self> {
+        Ok(Self {
+            file: TableFile::new(
+                root_dir_path,
+                file_type,
+                FileFormat::ARROW,
+                start_checkpoint_seq_num,
+            ),
+            data: vec![],
+        })
+    }
+}
+
+impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for ArrowIpcWriter {
+    fn file_format(&self) -> Result<FileFormat> {
+        Ok(FileFormat::ARROW)
+    }
+
+    fn write(&mut self, rows: &[S]) -> Result<()> {
+        for row in rows {
+            for col_idx in 0..S::schema().len() {
+                if col_idx == self.data.len() {
+                    self.data.push(vec![]);
+                }
+                self.data[col_idx].push(row.get_column(col_idx));
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, end_checkpoint_seq_num: u64) -> Result<bool> {
+        if self.data.is_empty() {
+            return Ok(false);
+        }
+        self.file.end(end_checkpoint_seq_num);
+        let batch = to_record_batch::<S>(std::mem::take(&mut self.data))?;
+
+        let mut writer = FileWriter::try_new(self.file.create()?, &batch.schema())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(true)
+    }
+
+    fn reset(&mut self, epoch_num: EpochId, start_checkpoint_seq_num: u64) -> Result<()> {
+        self.file.reset(epoch_num, start_checkpoint_seq_num);
+        self.data = vec![];
+        Ok(())
+    }
+
+    fn file_size(&self) -> Result<Option<u64>> {
+        // like the parquet writer, records are only serialized into a file
+        // when flush is invoked
+        Ok(None)
+    }
+}
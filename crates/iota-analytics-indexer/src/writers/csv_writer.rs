@@ -4,28 +4,22 @@
 
 #![allow(dead_code)]
 
-use std::{
-    fs,
-    fs::{File, create_dir_all, remove_file},
-    ops::Range,
-    path::{Path, PathBuf},
-};
+use std::{fs::File, path::Path};
 
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use csv::{Writer, WriterBuilder};
-use iota_storage::object_store::util::path_to_filesystem;
 use iota_types::base_types::EpochId;
 use serde::Serialize;
 
-use crate::{FileFormat, FileType, ParquetSchema, writers::AnalyticsWriter};
+use crate::{
+    FileFormat, FileType, ParquetSchema,
+    writers::{AnalyticsWriter, TableFile},
+};
 
 // Save table entries to csv files.
 pub(crate) struct CSVWriter {
-    root_dir_path: PathBuf,
-    file_type: FileType,
+    file: TableFile,
     writer: Writer<File>,
-    epoch: EpochId,
-    checkpoint_range: Range<u64>,
 }
 
 impl CSVWriter {
@@ -34,49 +28,23 @@ impl CSVWriter {
         file_type: FileType,
         start_checkpoint_seq_num: u64,
     ) -> Result<Self> {
-        let checkpoint_range = start_checkpoint_seq_num..u64::MAX;
-        let writer = Self::make_writer(
-            root_dir_path.to_path_buf(),
-            file_type,
-            0,
-            checkpoint_range.clone(),
-        )?;
-        Ok(CSVWriter {
-            root_dir_path: root_dir_path.to_path_buf(),
+        let file = TableFile::new(
+            root_dir_path,
             file_type,
-            writer,
-            epoch: 0,
-            checkpoint_range,
-        })
+            FileFormat::CSV,
+            start_checkpoint_seq_num,
+        );
+        let writer = Self::make_writer(&file)?;
+        Ok(CSVWriter { file, writer })
     }
 
-    fn make_writer(
-        root_dir_path: PathBuf,
-        file_type: FileType,
-        epoch_num: EpochId,
-        checkpoint_range: Range<u64>,
-    ) -> Result<Writer<File>> {
-        let file_path = path_to_filesystem(
-            root_dir_path,
-            &file_type.file_path(FileFormat::CSV, epoch_num, checkpoint_range),
-        )?;
-        create_dir_all(file_path.parent().ok_or(anyhow!("Bad directory path"))?)?;
-        if file_path.exists() {
-            remove_file(&file_path)?;
-        }
+    fn make_writer(file: &TableFile) -> Result<Writer<File>> {
         let writer = WriterBuilder::new()
             .has_headers(false)
             .delimiter(b'|')
-            .from_path(file_path)?;
+            .from_writer(file.create()?);
         Ok(writer)
     }
-
-    fn file_path(&self, epoch: EpochId, range: Range<u64>) -> Result<PathBuf> {
-        path_to_filesystem(
-            self.root_dir_path.clone(),
-            &self.file_type.file_path(FileFormat::CSV, epoch, range),
-        )
-    }
 }
 
 impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for CSVWriter {
@@ -93,31 +61,17 @@ impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for CSVWriter {
 
     fn flush(&mut self, end_checkpoint_seq_num: u64) -> Result<bool> {
         self.writer.flush()?;
-        let old_file_path = self.file_path(self.epoch, self.checkpoint_range.clone())?;
-        let new_file_path = self.file_path(
-            self.epoch,
-            self.checkpoint_range.start..end_checkpoint_seq_num,
-        )?;
-        fs::rename(old_file_path, new_file_path)?;
+        self.file.end_staged(end_checkpoint_seq_num)?;
         Ok(true)
     }
 
     fn reset(&mut self, epoch_num: EpochId, start_checkpoint_seq_num: u64) -> Result<()> {
-        self.checkpoint_range.start = start_checkpoint_seq_num;
-        self.checkpoint_range.end = u64::MAX;
-        self.epoch = epoch_num;
-        self.writer = CSVWriter::make_writer(
-            self.root_dir_path.clone(),
-            self.file_type,
-            self.epoch,
-            self.checkpoint_range.clone(),
-        )?;
+        self.file.reset(epoch_num, start_checkpoint_seq_num);
+        self.writer = CSVWriter::make_writer(&self.file)?;
         Ok(())
     }
 
     fn file_size(&self) -> Result<Option<u64>> {
-        let file_path = self.file_path(self.epoch, self.checkpoint_range.clone())?;
-        let len = fs::metadata(file_path)?.len();
-        Ok(Some(len))
+        Ok(Some(self.file.size()?))
     }
 }
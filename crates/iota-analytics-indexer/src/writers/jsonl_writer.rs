@@ -0,0 +1,70 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::Result;
+use iota_types::base_types::EpochId;
+use serde::Serialize;
+
+use crate::{
+    FileFormat, FileType, ParquetSchema,
+    writers::{AnalyticsWriter, TableFile},
+};
+
+// Save table entries to newline-delimited JSON files.
+pub(crate) struct JsonLinesWriter {
+    file: TableFile,
+    writer: BufWriter<File>,
+}
+
+impl JsonLinesWriter {
+    pub(crate) fn new(
+        root_dir_path: &Path,
+        file_type: FileType,
+        start_checkpoint_seq_num: u64,
+    ) -> Result<Self> {
+        let file = TableFile::new(
+            root_dir_path,
+            file_type,
+            FileFormat::JSONL,
+            start_checkpoint_seq_num,
+        );
+        let writer = BufWriter::new(file.create()?);
+        Ok(JsonLinesWriter { file, writer })
+    }
+}
+
+impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for JsonLinesWriter {
+    fn file_format(&self) -> Result<FileFormat> {
+        Ok(FileFormat::JSONL)
+    }
+
+    fn write(&mut self, rows: &[S]) -> Result<()> {
+        for row in rows {
+            serde_json::to_writer(&mut self.writer, row)?;
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, end_checkpoint_seq_num: u64) -> Result<bool> {
+        self.writer.flush()?;
+        self.file.end_staged(end_checkpoint_seq_num)?;
+        Ok(true)
+    }
+
+    fn reset(&mut self, epoch_num: EpochId, start_checkpoint_seq_num: u64) -> Result<()> {
+        self.file.reset(epoch_num, start_checkpoint_seq_num);
+        self.writer = BufWriter::new(self.file.create()?);
+        Ok(())
+    }
+
+    fn file_size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.file.size()?))
+    }
+}
@@ -2,13 +2,25 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use std::{
+    fs,
+    fs::{File, create_dir_all, remove_file},
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Result, anyhow};
+use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, StringArray, UInt64Array};
+use iota_storage::object_store::util::path_to_filesystem;
 use iota_types::base_types::EpochId;
 use serde::Serialize;
 
-use crate::{FileFormat, ParquetSchema};
+use crate::{FileFormat, FileType, ParquetSchema, ParquetValue};
 
+pub mod arrow_ipc_writer;
 pub mod csv_writer;
+pub mod jsonl_writer;
 pub mod parquet_writer;
 
 pub trait AnalyticsWriter<S: Serialize + ParquetSchema>: Send + Sync + 'static {
@@ -23,3 +35,263 @@ pub trait AnalyticsWriter<S: Serialize + ParquetSchema>: Send + Sync + 'static {
     /// Approx size in bytes of the current staging file if available
     fn file_size(&self) -> Result<Option<u64>>;
 }
+
+/// The local file a writer saves the entries of a table to, named after the
+/// epoch and the range of checkpoints of the entries.
+///
+/// Writers streaming entries to the file while checkpoints are processed
+/// stage it with an open-ended range, and rename it once the range ends.
+pub(crate) struct TableFile {
+    root_dir_path: PathBuf,
+    file_type: FileType,
+    file_format: FileFormat,
+    epoch: EpochId,
+    checkpoint_range: Range<u64>,
+}
+
+impl TableFile {
+    pub(crate) fn new(
+        root_dir_path: &Path,
+        file_type: FileType,
+        file_format: FileFormat,
+        start_checkpoint_seq_num: u64,
+    ) -> Self {
+        Self {
+            root_dir_path: root_dir_path.to_path_buf(),
+            file_type,
+            file_format,
+            epoch: 0,
+            checkpoint_range: start_checkpoint_seq_num..u64::MAX,
+        }
+    }
+
+    /// Path of the file for the current epoch and checkpoint range.
+    pub(crate) fn path(&self) -> Result<PathBuf> {
+        path_to_filesystem(
+            self.root_dir_path.clone(),
+            &self
+                .file_type
+                .file_path(self.file_format, self.epoch, self.checkpoint_range.clone()),
+        )
+    }
+
+    /// Creates the file, replacing any file previously written at its path.
+    pub(crate) fn create(&self) -> Result<File> {
+        let file_path = self.path()?;
+        create_dir_all(file_path.parent().ok_or(anyhow!("Bad directory path"))?)?;
+        if file_path.exists() {
+            remove_file(&file_path)?;
+        }
+        Ok(File::create(&file_path)?)
+    }
+
+    /// Ends the checkpoint range of the file.
+    pub(crate) fn end(&mut self, end_checkpoint_seq_num: u64) {
+        self.checkpoint_range.end = end_checkpoint_seq_num;
+    }
+
+    /// Ends the checkpoint range of the staged file, renaming it accordingly.
+    pub(crate) fn end_staged(&mut self, end_checkpoint_seq_num: u64) -> Result<()> {
+        let staged_file_path = self.path()?;
+        self.end(end_checkpoint_seq_num);
+        fs::rename(staged_file_path, self.path()?)?;
+        Ok(())
+    }
+
+    /// Starts an open-ended checkpoint range in the given epoch.
+    pub(crate) fn reset(&mut self, epoch_num: EpochId, start_checkpoint_seq_num: u64) {
+        self.epoch = epoch_num;
+        self.checkpoint_range = start_checkpoint_seq_num..u64::MAX;
+    }
+
+    /// Size in bytes of the file.
+    pub(crate) fn size(&self) -> Result<u64> {
+        Ok(fs::metadata(self.path()?)?.len())
+    }
+}
+
+macro_rules! convert_to_arrow_array {
+    ($column:ident, $target_vector:ident, $($variant:path => $types:ty),*) => {
+        match &$column[0] {
+            $(
+                $variant(_) => {
+                    let array = <$types>::from(
+                        $column
+                            .into_iter()
+                            .flat_map(|value| match value {
+                                $variant(value) => Some(value),
+                                _ => None,
+                            })
+                            .collect::<Vec<_>>(),
+                    );
+                    $target_vector.push(Arc::new(array) as ArrayRef);
+                }
+            )*
+        }
+    };
+}
+
+/// Convert the buffered columns of a table into an arrow record batch.
+pub(crate) fn to_record_batch<S: ParquetSchema>(
+    data: Vec<Vec<ParquetValue>>,
+) -> Result<RecordBatch> {
+    let mut batch_data = vec![];
+    for column in data {
+        convert_to_arrow_array!(column, batch_data,
+            ParquetValue::U64 => UInt64Array, ParquetValue::Str => StringArray, ParquetValue::OptionU64 => UInt64Array, ParquetValue::OptionStr => StringArray, ParquetValue::Bool => BooleanArray, ParquetValue::I64 => Int64Array
+        );
+    }
+    Ok(RecordBatch::try_from_iter(
+        S::schema().iter().zip(batch_data.into_iter()),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{File, read_to_string};
+
+    use arrow::ipc::reader::FileReader;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::writers::{
+        arrow_ipc_writer::ArrowIpcWriter, csv_writer::CSVWriter, jsonl_writer::JsonLinesWriter,
+        parquet_writer::ParquetWriter,
+    };
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct TestEntry {
+        checkpoint: u64,
+        name: String,
+        owner: Option<String>,
+        deleted: bool,
+    }
+
+    impl ParquetSchema for TestEntry {
+        fn schema() -> Vec<String> {
+            ["checkpoint", "name", "owner", "deleted"]
+                .map(String::from)
+                .to_vec()
+        }
+
+        fn get_column(&self, idx: usize) -> ParquetValue {
+            match idx {
+                0 => ParquetValue::U64(self.checkpoint),
+                1 => ParquetValue::Str(self.name.clone()),
+                2 => ParquetValue::OptionStr(self.owner.clone()),
+                3 => ParquetValue::Bool(self.deleted),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    fn entries(checkpoints: Range<u64>) -> Vec<TestEntry> {
+        checkpoints
+            .map(|checkpoint| TestEntry {
+                checkpoint,
+                // separators and quotes which need escaping
+                name: format!("entry | \"{checkpoint}\",\n"),
+                owner: (checkpoint % 2 == 0).then(|| format!("0x{checkpoint}")),
+                deleted: checkpoint % 3 == 0,
+            })
+            .collect()
+    }
+
+    fn record_batch(entries: &[TestEntry]) -> Result<RecordBatch> {
+        let columns = (0..TestEntry::schema().len())
+            .map(|idx| entries.iter().map(|entry| entry.get_column(idx)).collect())
+            .collect();
+        to_record_batch::<TestEntry>(columns)
+    }
+
+    /// Writes the entries of two consecutive checkpoint ranges of an epoch,
+    /// returning the paths of the files of both ranges.
+    fn write_files(
+        mut writer: impl AnalyticsWriter<TestEntry>,
+        root_dir_path: &Path,
+    ) -> Result<[PathBuf; 2]> {
+        let file_format = writer.file_format()?;
+        let file_path = |range| {
+            path_to_filesystem(
+                root_dir_path.to_path_buf(),
+                &FileType::Object.file_path(file_format, 1, range),
+            )
+        };
+
+        writer.reset(1, 10)?;
+        writer.write(&entries(10..12))?;
+        assert!(writer.flush(12)?);
+        writer.reset(1, 12)?;
+        writer.write(&entries(12..15))?;
+        assert!(writer.flush(15)?);
+        Ok([file_path(10..12)?, file_path(12..15)?])
+    }
+
+    #[test]
+    fn test_csv_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let writer = CSVWriter::new(dir.path(), FileType::Object, 0)?;
+        let files = write_files(writer, dir.path())?;
+
+        for (path, checkpoints) in files.iter().zip([10..12, 12..15]) {
+            let read = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .delimiter(b'|')
+                .from_path(path)?
+                .deserialize()
+                .collect::<Result<Vec<TestEntry>, _>>()?;
+            assert_eq!(read, entries(checkpoints));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsonl_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let writer = JsonLinesWriter::new(dir.path(), FileType::Object, 0)?;
+        let files = write_files(writer, dir.path())?;
+
+        for (path, checkpoints) in files.iter().zip([10..12, 12..15]) {
+            let read = read_to_string(path)?
+                .lines()
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<TestEntry>, _>>()?;
+            assert_eq!(read, entries(checkpoints));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arrow_ipc_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let writer = ArrowIpcWriter::new(dir.path(), FileType::Object, 0)?;
+        let files = write_files(writer, dir.path())?;
+
+        for (path, checkpoints) in files.iter().zip([10..12, 12..15]) {
+            let read =
+                FileReader::try_new(File::open(path)?, None)?.collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(read, vec![record_batch(&entries(checkpoints))?]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let writer = ParquetWriter::new(dir.path(), FileType::Object, 0)?;
+        let files = write_files(writer, dir.path())?;
+
+        for (path, checkpoints) in files.iter().zip([10..12, 12..15]) {
+            let read = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?
+                .build()?
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(read.len(), 1);
+            assert_eq!(
+                read[0].columns(),
+                record_batch(&entries(checkpoints))?.columns()
+            );
+        }
+        Ok(())
+    }
+}
@@ -2,28 +2,21 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{
-    fs::{File, create_dir_all, remove_file},
-    ops::Range,
-    path::{Path, PathBuf},
-    sync::Arc,
-};
+use std::path::Path;
 
-use anyhow::{Result, anyhow};
-use arrow_array::{ArrayRef, BooleanArray, Int64Array, RecordBatch, StringArray, UInt64Array};
-use iota_storage::object_store::util::path_to_filesystem;
+use anyhow::Result;
 use iota_types::base_types::EpochId;
 use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
 use serde::Serialize;
 
-use crate::{AnalyticsWriter, FileFormat, FileType, ParquetSchema, ParquetValue};
+use crate::{
+    AnalyticsWriter, FileFormat, FileType, ParquetSchema, ParquetValue,
+    writers::{TableFile, to_record_batch},
+};
 
 // Save table entries to parquet files.
 pub(crate) struct ParquetWriter {
-    root_dir_path: PathBuf,
-    file_type: FileType,
-    epoch: EpochId,
-    checkpoint_range: Range<u64>,
+    file: TableFile,
     data: Vec<Vec<ParquetValue>>,
 }
 
@@ -33,54 +26,18 @@ impl ParquetWriter {
         file_type: FileType,
         start_checkpoint_seq_num: u64,
     ) -> Result<Self> {
-        let checkpoint_range = start_checkpoint_seq_num..u64::MAX;
         Ok(Self {
-            root_dir_path: root_dir_path.to_path_buf(),
-            file_type,
-            epoch: 0,
-            checkpoint_range,
-            data: vec![],
-        })
-    }
-
-    fn file(&self) -> Result<File> {
-        let file_path = path_to_filesystem(
-            self.root_dir_path.clone(),
-            &self.file_type.file_path(
+            file: TableFile::new(
+                root_dir_path,
+                file_type,
                 FileFormat::PARQUET,
-                self.epoch,
-                self.checkpoint_range.clone(),
+                start_checkpoint_seq_num,
             ),
-        )?;
-        create_dir_all(file_path.parent().ok_or(anyhow!("Bad directory path"))?)?;
-        if file_path.exists() {
-            remove_file(&file_path)?;
-        }
-        Ok(File::create(&file_path)?)
+            data: vec![],
+        })
     }
 }
 
-macro_rules! convert_to_arrow_array {
-    ($column:ident, $target_vector:ident, $($variant:path => $types:ty),*) => {
-        match &$column[0] {
-            $(
-                $variant(_) => {
-                    let array = <$types>::from(
-                        $column
-                            .into_iter()
-                            .flat_map(|value| match value {
-                                $variant(value) => Some(value),
-                                _ => None,
-                            })
-                            .collect::<Vec<_>>(),
-                    );
-                    $target_vector.push(Arc::new(array) as ArrayRef);
-                }
-            )*
-        }
-    };
-}
-
 impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for ParquetWriter {
     fn file_format(&self) -> Result<FileFormat> {
         Ok(FileFormat::PARQUET)
@@ -102,29 +59,22 @@ impl<S: Serialize + ParquetSchema> AnalyticsWriter<S> for ParquetWriter {
         if self.data.is_empty() {
             return Ok(false);
         }
-        self.checkpoint_range.end = end_checkpoint_seq_num;
-        let mut batch_data = vec![];
-        for column in std::mem::take(&mut self.data) {
-            convert_to_arrow_array!(column, batch_data,
-                ParquetValue::U64 => UInt64Array, ParquetValue::Str => StringArray, ParquetValue::OptionU64 => UInt64Array, ParquetValue::OptionStr => StringArray, ParquetValue::Bool => BooleanArray, ParquetValue::I64 => Int64Array
-            );
-        }
-        let batch = RecordBatch::try_from_iter(S::schema().iter().zip(batch_data.into_iter()))?;
+        self.file.end(end_checkpoint_seq_num);
+        let batch = to_record_batch::<S>(std::mem::take(&mut self.data))?;
 
         let properties = WriterProperties::builder()
             .set_compression(Compression::SNAPPY)
             .build();
 
-        let mut writer = ArrowWriter::try_new(self.file()?, batch.schema(), Some(properties))?;
+        let mut writer =
+            ArrowWriter::try_new(self.file.create()?, batch.schema(), Some(properties))?;
         writer.write(&batch)?;
         writer.close()?;
         Ok(true)
     }
 
     fn reset(&mut self, epoch_num: EpochId, start_checkpoint_seq_num: u64) -> Result<()> {
-        self.checkpoint_range.start = start_checkpoint_seq_num;
-        self.checkpoint_range.end = u64::MAX;
-        self.epoch = epoch_num;
+        self.file.reset(epoch_num, start_checkpoint_seq_num);
         self.data = vec![];
         Ok(())
     }
@@ -794,6 +794,11 @@ pub struct StateSnapshotConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub object_store_config: Option<ObjectStoreConfig>,
     pub concurrency: usize,
+    /// If set, a full state snapshot is only written every
+    /// `full_snapshot_interval` epochs. Snapshots of the epochs in between
+    /// are written as deltas against the snapshot of the previous epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_snapshot_interval: Option<u64>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Serialize)]
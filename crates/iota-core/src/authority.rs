@@ -67,6 +67,7 @@ use iota_types::{
     error::{ExecutionError, IotaError, IotaResult, UserInputError},
     event::{Event, EventID, SystemEpochInfoEventV1},
     executable_transaction::VerifiedExecutableTransaction,
+    execution::ExecutionResult,
     execution_config_utils::to_binary_config,
     execution_status::ExecutionStatus,
     fp_ensure,
@@ -101,6 +102,7 @@ use iota_types::{
     },
     supported_protocol_versions::{ProtocolConfig, SupportedProtocolVersions},
     transaction::*,
+    transaction_executor::SimulateTransactionResult,
 };
 use itertools::Itertools;
 use move_binary_format::{CompiledModule, binary_config::BinaryConfig};
@@ -1702,6 +1704,116 @@ impl AuthorityState {
         TransactionEffects,
         Option<ObjectID>,
     )> {
+        let (inner_temp_store, effects, mock_gas) =
+            self.dry_run_transaction(epoch_store, &transaction, transaction_digest)?;
+        let tx_digest = *effects.transaction_digest();
+
+        let module_cache =
+            TemporaryModuleResolver::new(&inner_temp_store, epoch_store.module_cache().clone());
+
+        let mut layout_resolver =
+            epoch_store
+                .executor()
+                .type_layout_resolver(Box::new(PackageStoreWithFallback::new(
+                    &inner_temp_store,
+                    self.get_backing_package_store(),
+                )));
+        // Returning empty vector here because we recalculate changes in the rpc layer.
+        let object_changes = Vec::new();
+
+        // Returning empty vector here because we recalculate changes in the rpc layer.
+        let balance_changes = Vec::new();
+
+        let written_with_kind = effects
+            .created()
+            .into_iter()
+            .map(|(oref, _)| (oref, WriteKind::Create))
+            .chain(
+                effects
+                    .unwrapped()
+                    .into_iter()
+                    .map(|(oref, _)| (oref, WriteKind::Unwrap)),
+            )
+            .chain(
+                effects
+                    .mutated()
+                    .into_iter()
+                    .map(|(oref, _)| (oref, WriteKind::Mutate)),
+            )
+            .map(|(oref, kind)| {
+                let obj = inner_temp_store.written.get(&oref.0).unwrap();
+                // TODO: Avoid clones.
+                (oref.0, (oref, obj.clone(), kind))
+            })
+            .collect();
+
+        Ok((
+            DryRunTransactionBlockResponse {
+                input: IotaTransactionBlockData::try_from(transaction, &module_cache, tx_digest)
+                    .map_err(|e| IotaError::TransactionSerialization {
+                        error: format!(
+                            "Failed to convert transaction to IotaTransactionBlockData: {e}",
+                        ),
+                    })?, // TODO: replace the underlying try_from to IotaError. This one goes deep
+                effects: effects.clone().try_into()?,
+                events: IotaTransactionBlockEvents::try_from(
+                    inner_temp_store.events.clone(),
+                    tx_digest,
+                    None,
+                    layout_resolver.as_mut(),
+                )?,
+                object_changes,
+                balance_changes,
+            },
+            written_with_kind,
+            effects,
+            mock_gas,
+        ))
+    }
+
+    /// Simulates the execution of a transaction on top of the latest state,
+    /// without committing its results. If the transaction does not provide a
+    /// gas payment, a mock gas coin is used and its ID is returned.
+    pub async fn simulate_transaction(
+        &self,
+        transaction: TransactionData,
+    ) -> IotaResult<SimulateTransactionResult> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+        if !self.is_fullnode(&epoch_store) {
+            return Err(IotaError::UnsupportedFeature {
+                error: "simulate is only supported on fullnodes".to_string(),
+            });
+        }
+
+        if transaction.kind().is_system_tx() {
+            return Err(IotaError::UnsupportedFeature {
+                error: "simulate does not support system transactions".to_string(),
+            });
+        }
+
+        let transaction_digest = transaction.digest();
+        let (inner_temp_store, effects, mock_gas_id) =
+            self.dry_run_transaction(&epoch_store, &transaction, transaction_digest)?;
+
+        Ok(SimulateTransactionResult {
+            input_objects: inner_temp_store.input_objects,
+            output_objects: inner_temp_store.written,
+            events: effects.events_digest().map(|_| inner_temp_store.events),
+            effects,
+            mock_gas_id,
+            execution_results: None,
+        })
+    }
+
+    /// Executes a transaction without committing its results, returning the
+    /// temporary store holding its outputs, its effects and the ID of the mock
+    /// gas coin that was used if the transaction did not provide one.
+    fn dry_run_transaction(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+        transaction: &TransactionData,
+        transaction_digest: TransactionDigest,
+    ) -> IotaResult<(InnerTemporaryStore, TransactionEffects, Option<ObjectID>)> {
         // Cheap validity checks for a transaction, including input size limits.
         transaction.validity_check_no_gas_check(epoch_store.protocol_config())?;
 
@@ -1709,7 +1821,7 @@ impl AuthorityState {
         let receiving_object_refs = transaction.receiving_objects();
 
         iota_transaction_checks::deny::check_transaction_for_signing(
-            &transaction,
+            transaction,
             &[],
             &input_object_kinds,
             &receiving_object_refs,
@@ -1745,7 +1857,7 @@ impl AuthorityState {
                 iota_transaction_checks::check_transaction_input_with_given_gas(
                     epoch_store.protocol_config(),
                     epoch_store.reference_gas_price(),
-                    &transaction,
+                    transaction,
                     input_objects,
                     receiving_objects,
                     gas_object,
@@ -1758,7 +1870,7 @@ impl AuthorityState {
                 iota_transaction_checks::check_transaction_input(
                     epoch_store.protocol_config(),
                     epoch_store.reference_gas_price(),
-                    &transaction,
+                    transaction,
                     input_objects,
                     &receiving_objects,
                     &self.metrics.bytecode_verifier_metrics,
@@ -1794,73 +1906,11 @@ impl AuthorityState {
                 signer,
                 transaction_digest,
             );
-        let tx_digest = *effects.transaction_digest();
-
-        let module_cache =
-            TemporaryModuleResolver::new(&inner_temp_store, epoch_store.module_cache().clone());
-
-        let mut layout_resolver =
-            epoch_store
-                .executor()
-                .type_layout_resolver(Box::new(PackageStoreWithFallback::new(
-                    &inner_temp_store,
-                    self.get_backing_package_store(),
-                )));
-        // Returning empty vector here because we recalculate changes in the rpc layer.
-        let object_changes = Vec::new();
-
-        // Returning empty vector here because we recalculate changes in the rpc layer.
-        let balance_changes = Vec::new();
-
-        let written_with_kind = effects
-            .created()
-            .into_iter()
-            .map(|(oref, _)| (oref, WriteKind::Create))
-            .chain(
-                effects
-                    .unwrapped()
-                    .into_iter()
-                    .map(|(oref, _)| (oref, WriteKind::Unwrap)),
-            )
-            .chain(
-                effects
-                    .mutated()
-                    .into_iter()
-                    .map(|(oref, _)| (oref, WriteKind::Mutate)),
-            )
-            .map(|(oref, kind)| {
-                let obj = inner_temp_store.written.get(&oref.0).unwrap();
-                // TODO: Avoid clones.
-                (oref.0, (oref, obj.clone(), kind))
-            })
-            .collect();
 
-        Ok((
-            DryRunTransactionBlockResponse {
-                input: IotaTransactionBlockData::try_from(transaction, &module_cache, tx_digest)
-                    .map_err(|e| IotaError::TransactionSerialization {
-                        error: format!(
-                            "Failed to convert transaction to IotaTransactionBlockData: {e}",
-                        ),
-                    })?, // TODO: replace the underlying try_from to IotaError. This one goes deep
-                effects: effects.clone().try_into()?,
-                events: IotaTransactionBlockEvents::try_from(
-                    inner_temp_store.events.clone(),
-                    tx_digest,
-                    None,
-                    layout_resolver.as_mut(),
-                )?,
-                object_changes,
-                balance_changes,
-            },
-            written_with_kind,
-            effects,
-            mock_gas,
-        ))
+        Ok((inner_temp_store, effects, mock_gas))
     }
 
     /// The object ID for gas can be any object ID, even for an uncreated object
-    #[allow(clippy::collapsible_else_if)]
     pub async fn dev_inspect_transaction_block(
         &self,
         sender: IotaAddress,
@@ -1899,7 +1949,7 @@ impl AuthorityState {
         // after reading all the input objects.
         let payment = gas_objects.unwrap_or_default();
         let transaction = TransactionData::V1(TransactionDataV1 {
-            kind: transaction_kind.clone(),
+            kind: transaction_kind,
             sender,
             gas_data: GasData {
                 payment,
@@ -1918,6 +1968,87 @@ impl AuthorityState {
             vec![]
         };
 
+        let (inner_temp_store, effects, execution_result, _) =
+            self.dev_inspect_transaction_impl(&epoch_store, transaction, skip_checks)?;
+
+        let raw_effects = if show_raw_txn_data_and_effects {
+            bcs::to_bytes(&effects).map_err(|_| IotaError::TransactionSerialization {
+                error: "Failed to serialize transaction effects during dev inspect".to_string(),
+            })?
+        } else {
+            vec![]
+        };
+
+        let mut layout_resolver =
+            epoch_store
+                .executor()
+                .type_layout_resolver(Box::new(PackageStoreWithFallback::new(
+                    &inner_temp_store,
+                    self.get_backing_package_store(),
+                )));
+
+        DevInspectResults::new(
+            effects,
+            inner_temp_store.events.clone(),
+            execution_result,
+            raw_txn_data,
+            raw_effects,
+            layout_resolver.as_mut(),
+        )
+    }
+
+    /// Executes a transaction in dev-inspect mode on top of the latest state,
+    /// without committing its results. Unlike a simulation, this also reports
+    /// the return values and mutated references of each command.
+    pub async fn dev_inspect_transaction(
+        &self,
+        transaction: TransactionData,
+        skip_checks: bool,
+    ) -> IotaResult<SimulateTransactionResult> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+
+        if !self.is_fullnode(&epoch_store) {
+            return Err(IotaError::UnsupportedFeature {
+                error: "dev-inspect is only supported on fullnodes".to_string(),
+            });
+        }
+
+        if transaction.kind().is_system_tx() {
+            return Err(IotaError::UnsupportedFeature {
+                error: "system transactions are not supported".to_string(),
+            });
+        }
+
+        let (inner_temp_store, effects, execution_result, mock_gas_id) =
+            self.dev_inspect_transaction_impl(&epoch_store, transaction, skip_checks)?;
+
+        Ok(SimulateTransactionResult {
+            input_objects: inner_temp_store.input_objects,
+            output_objects: inner_temp_store.written,
+            events: effects.events_digest().map(|_| inner_temp_store.events),
+            effects,
+            mock_gas_id,
+            execution_results: execution_result.ok(),
+        })
+    }
+
+    #[allow(clippy::collapsible_else_if)]
+    fn dev_inspect_transaction_impl(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+        transaction: TransactionData,
+        skip_checks: bool,
+    ) -> IotaResult<(
+        InnerTemporaryStore,
+        TransactionEffects,
+        Result<Vec<ExecutionResult>, ExecutionError>,
+        Option<ObjectID>,
+    )> {
+        let reference_gas_price = epoch_store.reference_gas_price();
+        let protocol_config = epoch_store.protocol_config();
+        let max_tx_gas = protocol_config.max_tx_gas();
+        let (transaction_kind, sender, _) = transaction.execution_parts();
+
         transaction.validity_check_no_gas_check(protocol_config)?;
 
         let input_object_kinds = transaction.input_objects()?;
@@ -1945,6 +2076,7 @@ impl AuthorityState {
             DEV_INSPECT_GAS_COIN_VALUE,
             transaction.gas_owner(),
         );
+        let mock_gas_id = transaction.gas().is_empty().then(|| dummy_gas_object.id());
 
         let gas_objects = if transaction.gas().is_empty() {
             let gas_object_ref = dummy_gas_object.compute_object_reference();
@@ -2036,30 +2168,7 @@ impl AuthorityState {
             skip_checks,
         );
 
-        let raw_effects = if show_raw_txn_data_and_effects {
-            bcs::to_bytes(&effects).map_err(|_| IotaError::TransactionSerialization {
-                error: "Failed to serialize transaction effects during dev inspect".to_string(),
-            })?
-        } else {
-            vec![]
-        };
-
-        let mut layout_resolver =
-            epoch_store
-                .executor()
-                .type_layout_resolver(Box::new(PackageStoreWithFallback::new(
-                    &inner_temp_store,
-                    self.get_backing_package_store(),
-                )));
-
-        DevInspectResults::new(
-            effects,
-            inner_temp_store.events.clone(),
-            execution_result,
-            raw_txn_data,
-            raw_effects,
-            layout_resolver.as_mut(),
-        )
+        Ok((inner_temp_store, effects, execution_result, mock_gas_id))
     }
 
     // Only used for testing because of how epoch store is loaded.
@@ -820,6 +820,24 @@ impl AuthorityStore {
         Ok(())
     }
 
+    /// Removes the given object versions, and their live object markers, from
+    /// the store. Used when applying a delta state snapshot on top of a
+    /// restored live object set.
+    pub fn bulk_remove_live_objects(
+        perpetual_db: &AuthorityPerpetualTables,
+        object_refs: impl Iterator<Item = ObjectRef>,
+    ) -> IotaResult<()> {
+        let object_refs: Vec<ObjectRef> = object_refs.collect();
+        let mut batch = perpetual_db.objects.batch();
+        batch.delete_batch(
+            &perpetual_db.objects,
+            object_refs.iter().map(ObjectKey::from),
+        )?;
+        batch.delete_batch(&perpetual_db.live_owned_object_markers, object_refs.iter())?;
+        batch.write()?;
+        Ok(())
+    }
+
     pub fn set_epoch_start_configuration(
         &self,
         epoch_start_configuration: &EpochStartConfiguration,
@@ -1009,6 +1009,7 @@ impl ValidatorService {
     fn handle_traffic_resp<T>(
         &self,
         client: Option<IpAddr>,
+        method: &str,
         wrapped_response: WrappedServiceResponse<T>,
     ) -> Result<tonic::Response<T>, tonic::Status> {
         let (error, spam_weight, unwrapped_response) = match wrapped_response {
@@ -1026,6 +1027,7 @@ impl ValidatorService {
                 through_fullnode: None,
                 error_weight: error.map(normalize).unwrap_or(Weight::zero()),
                 spam_weight,
+                method: Some(method.to_string()),
                 timestamp: SystemTime::now(),
             })
         }
@@ -1076,7 +1078,8 @@ macro_rules! handle_with_decoration {
 
         // handle traffic tallying
         let wrapped_response = $self.$func_name($request).await;
-        $self.handle_traffic_resp(client, wrapped_response)
+        let method = stringify!($func_name).trim_end_matches("_impl");
+        $self.handle_traffic_resp(client, method, wrapped_response)
     }};
 }
 
@@ -10,7 +10,7 @@ use std::{
 };
 
 use iota_types::{
-    base_types::{IotaAddress, MoveObjectType, ObjectID, SequenceNumber},
+    base_types::{IotaAddress, MoveObjectType, ObjectDigest, ObjectID, SequenceNumber},
     digests::TransactionDigest,
     dynamic_field::{DynamicFieldInfo, DynamicFieldType},
     full_checkpoint_content::CheckpointData,
@@ -21,7 +21,7 @@ use iota_types::{
         BackingPackageStore, DynamicFieldIndexInfo, DynamicFieldKey, error::Error as StorageError,
     },
 };
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{StructTag, TypeTag};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
@@ -39,7 +39,7 @@ use crate::{
     checkpoints::CheckpointStore,
 };
 
-const CURRENT_DB_VERSION: u64 = 0;
+const CURRENT_DB_VERSION: u64 = 1;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 struct MetadataInfo {
@@ -75,6 +75,35 @@ impl OwnerIndexInfo {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct CoinOwnerIndexKey {
+    pub owner: IotaAddress,
+    /// The canonical representation of the coin type, e.g. `0x2::iota::IOTA`
+    /// in its long form. A string is used rather than a `StructTag` so that
+    /// the empty string can be used as a lower bound when iterating over all
+    /// of the coins of an owner.
+    pub coin_type: String,
+    pub object_id: ObjectID,
+}
+
+impl CoinOwnerIndexKey {
+    fn new(owner: IotaAddress, coin_type: &StructTag, object_id: ObjectID) -> Self {
+        Self {
+            owner,
+            coin_type: coin_type.to_canonical_string(true),
+            object_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoinOwnerIndexInfo {
+    // object_id and coin type of the coin are a part of the Key
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+    pub balance: u64,
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Debug)]
 pub struct TransactionInfo {
     pub checkpoint: u64,
@@ -134,6 +163,13 @@ struct IndexStoreTables {
     /// specific user account.
     owner: DBMap<OwnerIndexKey, OwnerIndexInfo>,
 
+    /// An index of coin ownership, grouped by coin type.
+    ///
+    /// Allows an efficient iterator to list all coins, or only the coins of a
+    /// given type, currently owned by a specific user account, as well as to
+    /// derive its balances.
+    coin_owner: DBMap<CoinOwnerIndexKey, CoinOwnerIndexInfo>,
+
     /// An index of dynamic fields (children objects).
     ///
     /// Allows an efficient iterator to list all of the dynamic fields owned by
@@ -313,6 +349,11 @@ impl IndexStoreTables {
                     let owner_key = OwnerIndexKey::new(owner, object.id());
                     let owner_info = OwnerIndexInfo::new(&object);
                     batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+
+                    // Coin Owner Index
+                    if let Some((key, info)) = try_create_coin_owner_index_info(&object) {
+                        batch.insert_batch(&self.coin_owner, [(key, info)])?;
+                    }
                 }
 
                 // Dynamic Field Index
@@ -412,6 +453,11 @@ impl IndexStoreTables {
                         Owner::AddressOwner(address) => {
                             let owner_key = OwnerIndexKey::new(*address, removed_object.id());
                             batch.delete_batch(&self.owner, [owner_key])?;
+
+                            if let Some((key, _)) = try_create_coin_owner_index_info(removed_object)
+                            {
+                                batch.delete_batch(&self.coin_owner, [key])?;
+                            }
                         }
                         Owner::ObjectOwner(object_id) => {
                             batch.delete_batch(&self.dynamic_field, [DynamicFieldKey::new(
@@ -431,6 +477,12 @@ impl IndexStoreTables {
                                 Owner::AddressOwner(address) => {
                                     let owner_key = OwnerIndexKey::new(*address, old_object.id());
                                     batch.delete_batch(&self.owner, [owner_key])?;
+
+                                    if let Some((key, _)) =
+                                        try_create_coin_owner_index_info(old_object)
+                                    {
+                                        batch.delete_batch(&self.coin_owner, [key])?;
+                                    }
                                 }
 
                                 Owner::ObjectOwner(object_id) => {
@@ -449,6 +501,10 @@ impl IndexStoreTables {
                             let owner_key = OwnerIndexKey::new(*owner, object.id());
                             let owner_info = OwnerIndexInfo::new(object);
                             batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+
+                            if let Some((key, info)) = try_create_coin_owner_index_info(object) {
+                                batch.insert_batch(&self.coin_owner, [(key, info)])?;
+                            }
                         }
                         Owner::ObjectOwner(parent) => {
                             if let Some(field_info) =
@@ -523,6 +579,43 @@ impl IndexStoreTables {
         Ok(iter)
     }
 
+    fn coin_owner_iter(
+        &self,
+        owner: IotaAddress,
+        coin_type: Option<&StructTag>,
+        cursor: Option<(&StructTag, ObjectID)>,
+    ) -> Result<impl Iterator<Item = (CoinOwnerIndexKey, CoinOwnerIndexInfo)> + '_, TypedStoreError>
+    {
+        let only_coin_type = coin_type.map(|coin_type| coin_type.to_canonical_string(true));
+        let start = match (cursor, &only_coin_type) {
+            (Some((coin_type, object_id)), _) => {
+                CoinOwnerIndexKey::new(owner, coin_type, object_id)
+            }
+            (None, Some(coin_type)) => CoinOwnerIndexKey {
+                owner,
+                coin_type: coin_type.clone(),
+                object_id: ObjectID::ZERO,
+            },
+            // The empty string is lower than any coin type
+            (None, None) => CoinOwnerIndexKey {
+                owner,
+                coin_type: String::new(),
+                object_id: ObjectID::ZERO,
+            },
+        };
+
+        Ok(self
+            .coin_owner
+            .unbounded_iter()
+            .skip_to(&start)?
+            .take_while(move |(key, _)| {
+                key.owner == owner
+                    && only_coin_type
+                        .as_ref()
+                        .map_or(true, |coin_type| &key.coin_type == coin_type)
+            }))
+    }
+
     fn dynamic_field_iter(
         &self,
         parent: ObjectID,
@@ -633,6 +726,19 @@ impl RestIndexStore {
         self.tables.owner_iter(owner, cursor)
     }
 
+    /// Iterates over the coins owned by `owner`, ordered by coin type and then
+    /// by object ID, optionally restricted to the coins of `coin_type`, and
+    /// starting at the coin identified by `cursor`.
+    pub fn coin_owner_iter(
+        &self,
+        owner: IotaAddress,
+        coin_type: Option<&StructTag>,
+        cursor: Option<(&StructTag, ObjectID)>,
+    ) -> Result<impl Iterator<Item = (CoinOwnerIndexKey, CoinOwnerIndexInfo)> + '_, TypedStoreError>
+    {
+        self.tables.coin_owner_iter(owner, coin_type, cursor)
+    }
+
     pub fn dynamic_field_iter(
         &self,
         parent: ObjectID,
@@ -739,3 +845,24 @@ fn try_create_coin_index_info(object: &Object) -> Option<(CoinIndexKey, CoinInde
                 })
         })
 }
+
+fn try_create_coin_owner_index_info(
+    object: &Object,
+) -> Option<(CoinOwnerIndexKey, CoinOwnerIndexInfo)> {
+    let Owner::AddressOwner(owner) = object.owner() else {
+        return None;
+    };
+    let TypeTag::Struct(coin_type) = object.coin_type_maybe()? else {
+        return None;
+    };
+    let coin = object.as_coin_maybe()?;
+
+    Some((
+        CoinOwnerIndexKey::new(*owner, &coin_type, object.id()),
+        CoinOwnerIndexInfo {
+            version: object.version(),
+            digest: object.digest(),
+            balance: coin.value(),
+        },
+    ))
+}
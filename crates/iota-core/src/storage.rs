@@ -16,8 +16,8 @@ use iota_types::{
     },
     object::Object,
     storage::{
-        AccountOwnedObjectInfo, CoinInfo, DynamicFieldIndexInfo, DynamicFieldKey, ObjectKey,
-        ObjectStore, ReadStore, RestStateReader, WriteStore,
+        AccountCoinInfo, AccountOwnedObjectInfo, CoinInfo, DynamicFieldIndexInfo, DynamicFieldKey,
+        ObjectKey, ObjectStore, ReadStore, RestStateReader, WriteStore,
         error::{Error as StorageError, Result},
     },
     transaction::VerifiedTransaction,
@@ -31,7 +31,10 @@ use crate::{
     checkpoints::CheckpointStore,
     epoch::committee_store::CommitteeStore,
     execution_cache::ExecutionCacheTraitPointers,
-    rest_index::{CoinIndexInfo, OwnerIndexInfo, OwnerIndexKey, RestIndexStore},
+    rest_index::{
+        CoinIndexInfo, CoinOwnerIndexInfo, CoinOwnerIndexKey, OwnerIndexInfo, OwnerIndexKey,
+        RestIndexStore,
+    },
 };
 
 #[derive(Clone)]
@@ -550,6 +553,45 @@ impl RestStateReader for RestReadStore {
         Ok(Box::new(iter) as _)
     }
 
+    fn account_coins_iter(
+        &self,
+        owner: IotaAddress,
+        coin_type: Option<&StructTag>,
+        cursor: Option<(&StructTag, ObjectID)>,
+    ) -> Result<Box<dyn Iterator<Item = AccountCoinInfo> + '_>> {
+        let iter = self
+            .index()?
+            .coin_owner_iter(owner, coin_type, cursor)?
+            .map(
+                |(
+                    CoinOwnerIndexKey {
+                        owner,
+                        coin_type,
+                        object_id,
+                    },
+                    CoinOwnerIndexInfo {
+                        version,
+                        digest,
+                        balance,
+                    },
+                )| {
+                    AccountCoinInfo {
+                        owner,
+                        // SAFETY: coin types are indexed from their canonical representation
+                        coin_type: coin_type
+                            .parse()
+                            .expect("indexed coin type should be a valid StructTag"),
+                        object_id,
+                        version,
+                        digest,
+                        balance,
+                    }
+                },
+            );
+
+        Ok(Box::new(iter) as _)
+    }
+
     fn dynamic_field_iter(
         &self,
         parent: ObjectID,
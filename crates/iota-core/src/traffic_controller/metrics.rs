@@ -24,6 +24,9 @@ pub struct TrafficControllerMetrics {
     pub highest_proxied_spam_rate: IntGauge,
     pub highest_direct_error_rate: IntGauge,
     pub highest_proxied_error_rate: IntGauge,
+    pub num_allowlisted_requests: IntCounter,
+    pub token_bucket_blocked_clients: IntCounter,
+    pub token_bucket_tracked_clients: IntGauge,
 }
 
 impl TrafficControllerMetrics {
@@ -122,6 +125,24 @@ impl TrafficControllerMetrics {
                 registry
             )
             .unwrap(),
+            num_allowlisted_requests: register_int_counter_with_registry!(
+                "traffic_control_num_allowlisted_requests",
+                "Number of requests from allowlisted clients, which bypass traffic control",
+                registry
+            )
+            .unwrap(),
+            token_bucket_blocked_clients: register_int_counter_with_registry!(
+                "traffic_control_token_bucket_blocked_clients",
+                "Number of times a client was blocked for running out of tokens",
+                registry
+            )
+            .unwrap(),
+            token_bucket_tracked_clients: register_int_gauge_with_registry!(
+                "traffic_control_token_bucket_tracked_clients",
+                "Number of clients whose token bucket is tracked",
+                registry
+            )
+            .unwrap(),
         }
     }
 
@@ -19,7 +19,7 @@ use std::{
 use dashmap::DashMap;
 use fs::File;
 use iota_metrics::spawn_monitored_task;
-use iota_types::traffic_control::{PolicyConfig, RemoteFirewallConfig, Weight};
+use iota_types::traffic_control::{IpCidr, PolicyConfig, RemoteFirewallConfig, Weight};
 use prometheus::IntGauge;
 use rand::Rng;
 use tokio::{
@@ -31,7 +31,9 @@ use tracing::{debug, error, info, trace, warn};
 use self::metrics::TrafficControllerMetrics;
 use crate::traffic_controller::{
     nodefw_client::{BlockAddress, BlockAddresses, NodeFWClient},
-    policies::{Policy, PolicyResponse, TrafficControlPolicy, TrafficTally},
+    policies::{
+        NoOpPolicy, Policy, PolicyResponse, TokenBucketPolicy, TrafficControlPolicy, TrafficTally,
+    },
 };
 
 pub const METRICS_INTERVAL_SECS: u64 = 2;
@@ -51,6 +53,7 @@ pub struct TrafficController {
     blocklists: Blocklists,
     metrics: Arc<TrafficControllerMetrics>,
     dry_run_mode: bool,
+    allowlist: Arc<Vec<IpCidr>>,
 }
 
 impl Debug for TrafficController {
@@ -101,6 +104,7 @@ impl TrafficController {
             },
             metrics: metrics.clone(),
             dry_run_mode: policy_config.dry_run,
+            allowlist: Arc::new(policy_config.allowlist.clone()),
         };
         let tally_loop_blocklists = ret.blocklists.clone();
         let clear_loop_blocklists = ret.blocklists.clone();
@@ -130,6 +134,11 @@ impl TrafficController {
     }
 
     pub fn tally(&self, tally: TrafficTally) {
+        // Requests from allowlisted clients can never lead to a block, so
+        // there is no need to tally them
+        if self.is_allowlisted(&tally.direct, &tally.through_fullnode) {
+            return;
+        }
         // Use try_send rather than send mainly to avoid creating backpressure
         // on the caller if the channel is full, which may slow down the critical
         // path. Dropping the tally on the floor should be ok, as in this case
@@ -152,6 +161,10 @@ impl TrafficController {
 
     /// Handle check with dry-run mode considered
     pub async fn check(&self, client: &Option<IpAddr>, proxied_client: &Option<IpAddr>) -> bool {
+        if self.is_allowlisted(client, proxied_client) {
+            self.metrics.num_allowlisted_requests.inc();
+            return true;
+        }
         match (
             self.check_impl(client, proxied_client).await,
             self.dry_run_mode(),
@@ -197,6 +210,18 @@ impl TrafficController {
         self.dry_run_mode
     }
 
+    /// Returns true if at least one of the given clients is known, and all
+    /// known clients belong to the allowlist.
+    fn is_allowlisted(&self, client: &Option<IpAddr>, proxied_client: &Option<IpAddr>) -> bool {
+        if self.allowlist.is_empty() || (client.is_none() && proxied_client.is_none()) {
+            return false;
+        }
+        [client, proxied_client]
+            .into_iter()
+            .flatten()
+            .all(|ip| self.allowlist.iter().any(|cidr| cidr.contains(ip)))
+    }
+
     async fn check_and_clear_blocklist(
         &self,
         client: &Option<IpAddr>,
@@ -256,8 +281,20 @@ async fn run_tally_loop(
     metrics: Arc<TrafficControllerMetrics>,
     mut mem_drainfile_present: bool,
 ) {
-    let mut spam_policy = TrafficControlPolicy::from_spam_config(policy_config.clone()).await;
-    let mut error_policy = TrafficControlPolicy::from_error_config(policy_config.clone()).await;
+    // An invalid policy only disables itself rather than the node, which keeps
+    // serving requests without that part of traffic control
+    let mut spam_policy = TrafficControlPolicy::from_spam_config(policy_config.clone())
+        .await
+        .unwrap_or_else(|e| {
+            error!("Invalid spam policy, traffic control will not tally spam: {e}");
+            TrafficControlPolicy::NoOp(NoOpPolicy::new(policy_config.clone()))
+        });
+    let mut error_policy = TrafficControlPolicy::from_error_config(policy_config.clone())
+        .await
+        .unwrap_or_else(|e| {
+            error!("Invalid error policy, traffic control will not tally errors: {e}");
+            TrafficControlPolicy::NoOp(NoOpPolicy::new(policy_config.clone()))
+        });
     let spam_blocklists = Arc::new(blocklists.clone());
     let error_blocklists = Arc::new(blocklists);
     let node_fw_client = fw_config
@@ -348,6 +385,18 @@ async fn run_tally_loop(
                     );
                 }
             }
+            let mut token_bucket_tracked_clients = None;
+            for policy in [&mut spam_policy, &mut error_policy] {
+                if let TrafficControlPolicy::TokenBucket(policy) = policy {
+                    report_token_bucket_metrics(policy, &metrics);
+                    *token_bucket_tracked_clients.get_or_insert(0) += policy.num_tracked_clients();
+                }
+            }
+            if let Some(tracked_clients) = token_bucket_tracked_clients {
+                metrics
+                    .token_bucket_tracked_clients
+                    .set(tracked_clients as i64);
+            }
             if let TrafficControlPolicy::FreqThreshold(error_policy) = &error_policy {
                 if let Some(highest_direct_rate) = error_policy.highest_direct_rate() {
                     metrics
@@ -373,6 +422,16 @@ async fn run_tally_loop(
     }
 }
 
+fn report_token_bucket_metrics(policy: &mut TokenBucketPolicy, metrics: &TrafficControllerMetrics) {
+    let blocked = policy.take_num_blocked();
+    metrics.token_bucket_blocked_clients.inc_by(blocked);
+    trace!(
+        "Token bucket policy blocked {} clients recently, tracking {} clients",
+        blocked,
+        policy.num_tracked_clients()
+    );
+}
+
 async fn handle_error_tally(
     policy: &mut TrafficControlPolicy,
     policy_config: &PolicyConfig,
@@ -13,9 +13,12 @@ use std::{
     time::{Duration, Instant, SystemTime},
 };
 
+use anyhow::ensure;
 use count_min_sketch::CountMinSketch32;
 use iota_metrics::spawn_monitored_task;
-use iota_types::traffic_control::{FreqThresholdConfig, PolicyConfig, PolicyType, Weight};
+use iota_types::traffic_control::{
+    FreqThresholdConfig, PolicyConfig, PolicyType, TokenBucketConfig, Weight,
+};
 use parking_lot::RwLock;
 use tracing::info;
 
@@ -234,6 +237,9 @@ pub struct TrafficTally {
     pub through_fullnode: Option<IpAddr>,
     pub error_weight: Weight,
     pub spam_weight: Weight,
+    /// The RPC method that was called, if known. Used by policies that
+    /// weigh requests by the cost of serving them.
+    pub method: Option<String>,
     pub timestamp: SystemTime,
 }
 
@@ -249,9 +255,15 @@ impl TrafficTally {
             through_fullnode,
             error_weight,
             spam_weight,
+            method: None,
             timestamp: SystemTime::now(),
         }
     }
+
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -271,6 +283,7 @@ pub trait Policy {
 // not object safe, so we can't use a trait object instead
 pub enum TrafficControlPolicy {
     FreqThreshold(FreqThresholdPolicy),
+    TokenBucket(TokenBucketPolicy),
     NoOp(NoOpPolicy),
     // Test policies below this point
     TestNConnIP(TestNConnIPPolicy),
@@ -282,6 +295,7 @@ impl Policy for TrafficControlPolicy {
         match self {
             TrafficControlPolicy::NoOp(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::FreqThreshold(policy) => policy.handle_tally(tally),
+            TrafficControlPolicy::TokenBucket(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::TestNConnIP(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::TestPanicOnInvocation(policy) => policy.handle_tally(tally),
         }
@@ -291,6 +305,7 @@ impl Policy for TrafficControlPolicy {
         match self {
             TrafficControlPolicy::NoOp(policy) => policy.policy_config(),
             TrafficControlPolicy::FreqThreshold(policy) => policy.policy_config(),
+            TrafficControlPolicy::TokenBucket(policy) => policy.policy_config(),
             TrafficControlPolicy::TestNConnIP(policy) => policy.policy_config(),
             TrafficControlPolicy::TestPanicOnInvocation(policy) => policy.policy_config(),
         }
@@ -298,25 +313,31 @@ impl Policy for TrafficControlPolicy {
 }
 
 impl TrafficControlPolicy {
-    pub async fn from_spam_config(policy_config: PolicyConfig) -> Self {
+    pub async fn from_spam_config(policy_config: PolicyConfig) -> anyhow::Result<Self> {
         Self::from_config(policy_config.clone().spam_policy_type, policy_config).await
     }
-    pub async fn from_error_config(policy_config: PolicyConfig) -> Self {
+    pub async fn from_error_config(policy_config: PolicyConfig) -> anyhow::Result<Self> {
         Self::from_config(policy_config.clone().error_policy_type, policy_config).await
     }
-    pub async fn from_config(policy_type: PolicyType, policy_config: PolicyConfig) -> Self {
-        match policy_type {
+    pub async fn from_config(
+        policy_type: PolicyType,
+        policy_config: PolicyConfig,
+    ) -> anyhow::Result<Self> {
+        Ok(match policy_type {
             PolicyType::NoOp => Self::NoOp(NoOpPolicy::new(policy_config)),
             PolicyType::FreqThreshold(freq_threshold_config) => Self::FreqThreshold(
                 FreqThresholdPolicy::new(policy_config, freq_threshold_config),
             ),
+            PolicyType::TokenBucket(token_bucket_config) => {
+                Self::TokenBucket(TokenBucketPolicy::new(policy_config, token_bucket_config)?)
+            }
             PolicyType::TestNConnIP(n) => {
                 Self::TestNConnIP(TestNConnIPPolicy::new(policy_config, n).await)
             }
             PolicyType::TestPanicOnInvocation => {
                 Self::TestPanicOnInvocation(TestPanicOnInvocationPolicy::new(policy_config))
             }
-        }
+        })
     }
 }
 
@@ -400,6 +421,180 @@ impl FreqThresholdPolicy {
     }
 }
 
+/// The token bucket of a single client.
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn full(capacity: f64, now: Instant) -> Self {
+        Self {
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, capacity: f64, refill_rate: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * refill_rate).min(capacity);
+        self.last_refill = now;
+    }
+}
+
+struct TokenBuckets {
+    buckets: HashMap<IpAddr, TokenBucket>,
+    capacity: f64,
+    refill_rate: f64,
+}
+
+impl TokenBuckets {
+    fn new(capacity: f64, refill_rate: f64) -> Self {
+        Self {
+            buckets: HashMap::new(),
+            capacity,
+            refill_rate,
+        }
+    }
+
+    /// Takes `cost` tokens from the bucket of `client`, returning false if
+    /// the bucket does not hold enough tokens, in which case it is left
+    /// untouched.
+    fn try_take(&mut self, client: IpAddr, cost: f64, now: Instant) -> bool {
+        let bucket = self
+            .buckets
+            .entry(client)
+            .or_insert_with(|| TokenBucket::full(self.capacity, now));
+        bucket.refill(self.capacity, self.refill_rate, now);
+        if bucket.tokens >= cost {
+            bucket.tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stops tracking clients whose bucket has been refilled to capacity,
+    /// since they are indistinguishable from clients that were never seen.
+    fn prune(&mut self, now: Instant) {
+        let (capacity, refill_rate) = (self.capacity, self.refill_rate);
+        self.buckets.retain(|_, bucket| {
+            bucket.refill(capacity, refill_rate, now);
+            bucket.tokens < capacity
+        });
+    }
+
+    fn len(&self) -> usize {
+        self.buckets.len()
+    }
+}
+
+pub struct TokenBucketPolicy {
+    config: PolicyConfig,
+    token_bucket_config: TokenBucketConfig,
+    clients: TokenBuckets,
+    proxied_clients: TokenBuckets,
+    /// Number of clients that ran out of tokens since the last call to
+    /// `take_num_blocked`.
+    num_blocked: u64,
+}
+
+impl TokenBucketPolicy {
+    /// Creates the policy, failing if a capacity, refill rate or method cost
+    /// of the config is not finite and positive. Buckets would otherwise
+    /// never be refilled, or be drained by free requests.
+    pub fn new(
+        config: PolicyConfig,
+        token_bucket_config: TokenBucketConfig,
+    ) -> anyhow::Result<Self> {
+        let TokenBucketConfig {
+            client_capacity,
+            client_refill_rate,
+            proxied_client_capacity,
+            proxied_client_refill_rate,
+            default_method_cost,
+            method_costs,
+            ..
+        } = &token_bucket_config;
+        let values = [
+            ("client-capacity", client_capacity),
+            ("client-refill-rate", client_refill_rate),
+            ("proxied-client-capacity", proxied_client_capacity),
+            ("proxied-client-refill-rate", proxied_client_refill_rate),
+            ("default-method-cost", default_method_cost),
+        ];
+        for (name, value) in values {
+            ensure!(
+                value.is_finite() && *value > 0.0,
+                "Token bucket {name} must be finite and positive, got {value}"
+            );
+        }
+        for (method, cost) in method_costs {
+            ensure!(
+                cost.is_finite() && *cost > 0.0,
+                "Token bucket cost of method {method} must be finite and positive, got {cost}"
+            );
+        }
+
+        let clients = TokenBuckets::new(
+            token_bucket_config.client_capacity,
+            token_bucket_config.client_refill_rate,
+        );
+        let proxied_clients = TokenBuckets::new(
+            token_bucket_config.proxied_client_capacity,
+            token_bucket_config.proxied_client_refill_rate,
+        );
+        Ok(Self {
+            config,
+            token_bucket_config,
+            clients,
+            proxied_clients,
+            num_blocked: 0,
+        })
+    }
+
+    /// Returns the number of clients currently tracked, direct and proxied.
+    pub fn num_tracked_clients(&self) -> usize {
+        self.clients.len() + self.proxied_clients.len()
+    }
+
+    /// Returns and resets the number of blocked clients.
+    pub fn take_num_blocked(&mut self) -> u64 {
+        std::mem::take(&mut self.num_blocked)
+    }
+
+    pub fn handle_tally(&mut self, tally: TrafficTally) -> PolicyResponse {
+        let now = Instant::now();
+        let cost = self
+            .token_bucket_config
+            .method_cost(tally.method.as_deref());
+        let block_client = tally
+            .direct
+            .filter(|client| !self.clients.try_take(*client, cost, now));
+        let block_proxied_client = tally
+            .through_fullnode
+            .filter(|client| !self.proxied_clients.try_take(*client, cost, now));
+
+        let max_tracked_clients = self.token_bucket_config.max_tracked_clients;
+        if self.clients.len() > max_tracked_clients {
+            self.clients.prune(now);
+        }
+        if self.proxied_clients.len() > max_tracked_clients {
+            self.proxied_clients.prune(now);
+        }
+
+        self.num_blocked += block_client.is_some() as u64 + block_proxied_client.is_some() as u64;
+        PolicyResponse {
+            block_client,
+            block_proxied_client,
+        }
+    }
+
+    fn policy_config(&self) -> &PolicyConfig {
+        &self.config
+    }
+}
+
 ////////////// *** Test policies below this point *** //////////////
 
 #[derive(Clone)]
@@ -526,6 +721,7 @@ mod tests {
             through_fullnode: Some(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4))),
             error_weight: Weight::zero(),
             spam_weight: Weight::one(),
+            method: None,
             timestamp: SystemTime::now(),
         };
         let bob = TrafficTally {
@@ -533,6 +729,7 @@ mod tests {
             through_fullnode: Some(IpAddr::V4(Ipv4Addr::new(4, 3, 2, 1))),
             error_weight: Weight::zero(),
             spam_weight: Weight::one(),
+            method: None,
             timestamp: SystemTime::now(),
         };
         let charlie = TrafficTally {
@@ -540,6 +737,7 @@ mod tests {
             through_fullnode: Some(IpAddr::V4(Ipv4Addr::new(5, 6, 7, 8))),
             error_weight: Weight::zero(),
             spam_weight: Weight::one(),
+            method: None,
             timestamp: SystemTime::now(),
         };
 
@@ -657,6 +855,146 @@ mod tests {
         assert_eq!(proxied_rate, 1);
     }
 
+    #[sim_test]
+    async fn test_token_bucket_policy() {
+        // Buckets hold up to 4 tokens and are refilled at 2 tokens per second,
+        // and `expensive` requests cost 3 tokens.
+        let mut policy = TokenBucketPolicy::new(PolicyConfig::default(), TokenBucketConfig {
+            client_capacity: 4.0,
+            client_refill_rate: 2.0,
+            proxied_client_capacity: 4.0,
+            proxied_client_refill_rate: 2.0,
+            method_costs: HashMap::from([("expensive".to_string(), 3.0)]),
+            ..Default::default()
+        })
+        .unwrap();
+        let alice = TrafficTally::new(
+            Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5))),
+            Some(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4))),
+            Weight::zero(),
+            Weight::one(),
+        );
+        let bob = TrafficTally::new(
+            Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5))),
+            Some(IpAddr::V4(Ipv4Addr::new(4, 3, 2, 1))),
+            Weight::zero(),
+            Weight::one(),
+        );
+
+        // alice can burst up to the capacity of her bucket
+        for i in 0..4 {
+            let response = policy.handle_tally(alice.clone());
+            assert_eq!(response.block_client, None, "Blocked at i = {}", i);
+            assert_eq!(response.block_proxied_client, None);
+        }
+        // but is blocked once it is empty, and so is the connection IP,
+        // which shares the same bucket capacity
+        let response = policy.handle_tally(alice.clone());
+        assert_eq!(response.block_client, alice.direct);
+        assert_eq!(response.block_proxied_client, alice.through_fullnode);
+        assert_eq!(policy.take_num_blocked(), 2);
+
+        // bob has his own bucket, but the connection IP is still out of tokens
+        let response = policy.handle_tally(bob.clone().with_method("expensive"));
+        assert_eq!(response.block_client, bob.direct);
+        assert_eq!(response.block_proxied_client, None);
+        // his bucket is left with a single token, which is not enough for
+        // another expensive request
+        let response = policy.handle_tally(bob.clone().with_method("expensive"));
+        assert_eq!(response.block_proxied_client, bob.through_fullnode);
+        assert_eq!(policy.num_tracked_clients(), 3);
+
+        // after a second, 2 tokens are refilled
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        for _ in 0..2 {
+            let response = policy.handle_tally(alice.clone());
+            assert_eq!(response.block_client, None);
+            assert_eq!(response.block_proxied_client, None);
+        }
+        let response = policy.handle_tally(alice.clone());
+        assert_eq!(response.block_proxied_client, alice.through_fullnode);
+        let response = policy.handle_tally(bob.clone().with_method("expensive"));
+        assert_eq!(response.block_proxied_client, None);
+    }
+
+    #[sim_test]
+    async fn test_token_bucket_policy_validation() {
+        let valid = TokenBucketConfig {
+            method_costs: HashMap::from([("expensive".to_string(), 3.0)]),
+            ..Default::default()
+        };
+        TokenBucketPolicy::new(PolicyConfig::default(), valid.clone()).unwrap();
+
+        let invalid_configs = [
+            TokenBucketConfig {
+                client_refill_rate: 0.0,
+                ..valid.clone()
+            },
+            TokenBucketConfig {
+                proxied_client_refill_rate: f64::NAN,
+                ..valid.clone()
+            },
+            TokenBucketConfig {
+                client_capacity: -1.0,
+                ..valid.clone()
+            },
+            TokenBucketConfig {
+                proxied_client_capacity: f64::INFINITY,
+                ..valid.clone()
+            },
+            TokenBucketConfig {
+                default_method_cost: 0.0,
+                ..valid.clone()
+            },
+            TokenBucketConfig {
+                method_costs: HashMap::from([("free".to_string(), 0.0)]),
+                ..valid.clone()
+            },
+        ];
+        for config in invalid_configs {
+            let err = TokenBucketPolicy::new(PolicyConfig::default(), config.clone())
+                .err()
+                .unwrap_or_else(|| panic!("Invalid config was accepted: {config:?}"));
+            assert!(
+                err.to_string().contains("must be finite and positive"),
+                "{err}"
+            );
+        }
+    }
+
+    #[sim_test]
+    async fn test_token_bucket_policy_pruning() {
+        let mut policy = TokenBucketPolicy::new(PolicyConfig::default(), TokenBucketConfig {
+            client_capacity: 1.0,
+            client_refill_rate: 10.0,
+            max_tracked_clients: 2,
+            ..Default::default()
+        })
+        .unwrap();
+        let tally = |i| {
+            TrafficTally::new(
+                Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, i))),
+                None,
+                Weight::zero(),
+                Weight::one(),
+            )
+        };
+
+        for _ in 0..3 {
+            let _ = policy.handle_tally(tally(1));
+        }
+        assert_eq!(policy.take_num_blocked(), 2);
+        assert_eq!(policy.take_num_blocked(), 0);
+
+        let _ = policy.handle_tally(tally(2));
+        assert_eq!(policy.num_tracked_clients(), 2);
+        // once buckets are full again, they are pruned when exceeding the
+        // maximum number of tracked clients
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let _ = policy.handle_tally(tally(3));
+        assert_eq!(policy.num_tracked_clients(), 1);
+    }
+
     #[sim_test]
     async fn test_traffic_sketch_mem_estimate() {
         // Test for getting a rough estimate of memory usage for the traffic sketch
@@ -30,7 +30,8 @@ use iota_types::{
         FinalizedEffects, IsTransactionExecutedLocally, QuorumDriverEffectsQueueResult,
         QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
     },
-    transaction::VerifiedTransaction,
+    transaction::{TransactionData, VerifiedTransaction},
+    transaction_executor::SimulateTransactionResult,
 };
 use prometheus::{
     Registry,
@@ -740,4 +741,21 @@ where
     ) -> Result<ExecuteTransactionResponseV1, QuorumDriverError> {
         self.execute_transaction_v1(request, client_addr).await
     }
+
+    async fn simulate_transaction(
+        &self,
+        transaction: TransactionData,
+    ) -> Result<SimulateTransactionResult, IotaError> {
+        self.validator_state.simulate_transaction(transaction).await
+    }
+
+    async fn dev_inspect_transaction(
+        &self,
+        transaction: TransactionData,
+        skip_checks: bool,
+    ) -> Result<SimulateTransactionResult, IotaError> {
+        self.validator_state
+            .dev_inspect_transaction(transaction, skip_checks)
+            .await
+    }
 }
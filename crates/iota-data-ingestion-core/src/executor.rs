@@ -85,13 +85,21 @@ impl<P: ProgressStore> IndexerExecutor<P> {
             tokio::select! {
                 _ = &mut exit_receiver => break,
                 Some((task_name, sequence_number)) = self.pool_progress_receiver.recv() => {
-                    self.progress_store.save(task_name.clone(), sequence_number).await?;
+                    // Save the progress reported by all the pools in a single update, so
+                    // that stores supporting it can write the watermarks atomically.
+                    let mut progress = ExecutorProgress::from([(task_name, sequence_number)]);
+                    while let Ok((task_name, sequence_number)) = self.pool_progress_receiver.try_recv() {
+                        progress.insert(task_name, sequence_number);
+                    }
+                    self.progress_store.save_all(progress.clone()).await?;
                     let seq_number = self.progress_store.min_watermark()?;
                     if seq_number > reader_checkpoint_number {
                         gc_sender.send(seq_number).await?;
                         reader_checkpoint_number = seq_number;
                     }
-                    self.metrics.data_ingestion_checkpoint.with_label_values(&[&task_name]).set(sequence_number as i64);
+                    for (task_name, sequence_number) in progress {
+                        self.metrics.data_ingestion_checkpoint.with_label_values(&[&task_name]).set(sequence_number as i64);
+                    }
                 }
                 Some(checkpoint) = checkpoint_recv.recv() => {
                     for sender in &self.pool_senders {
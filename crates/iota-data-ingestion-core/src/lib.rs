@@ -18,7 +18,10 @@ use iota_types::{
     full_checkpoint_content::CheckpointData, messages_checkpoint::CheckpointSequenceNumber,
 };
 pub use metrics::DataIngestionMetrics;
-pub use progress_store::{FileProgressStore, ProgressStore, ShimProgressStore};
+pub use progress_store::{
+    ExecutorProgress, FileProgressStore, ProgressStore, RocksDBProgressStore, ShimProgressStore,
+    WatermarkRecord,
+};
 pub use reader::ReaderOptions;
 pub use util::create_remote_store_client;
 pub use worker_pool::WorkerPool;
@@ -8,7 +8,9 @@ use anyhow::Result;
 use async_trait::async_trait;
 use iota_types::messages_checkpoint::CheckpointSequenceNumber;
 mod file;
+mod rocksdb;
 pub use file::FileProgressStore;
+pub use rocksdb::{RocksDBProgressStore, WatermarkRecord};
 
 pub type ExecutorProgress = HashMap<String, CheckpointSequenceNumber>;
 
@@ -20,6 +22,16 @@ pub trait ProgressStore: Send {
         task_name: String,
         checkpoint_number: CheckpointSequenceNumber,
     ) -> Result<()>;
+
+    /// Saves the watermarks of several tasks at once. Stores which can update
+    /// them atomically should override the default implementation, which
+    /// saves them one at a time.
+    async fn save_all(&mut self, progress: ExecutorProgress) -> Result<()> {
+        for (task_name, checkpoint_number) in progress {
+            self.save(task_name, checkpoint_number).await?;
+        }
+        Ok(())
+    }
 }
 
 pub struct ProgressStoreWrapper<P> {
@@ -46,6 +58,12 @@ impl<P: ProgressStore> ProgressStore for ProgressStoreWrapper<P> {
         self.pending_state.insert(task_name, checkpoint_number);
         Ok(())
     }
+
+    async fn save_all(&mut self, progress: ExecutorProgress) -> Result<()> {
+        self.progress_store.save_all(progress.clone()).await?;
+        self.pending_state.extend(progress);
+        Ok(())
+    }
 }
 
 impl<P: ProgressStore> ProgressStoreWrapper<P> {
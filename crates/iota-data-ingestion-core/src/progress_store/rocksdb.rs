@@ -0,0 +1,279 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use iota_types::messages_checkpoint::CheckpointSequenceNumber;
+use typed_store::{
+    DBMapUtils, Map,
+    rocks::{DBMap, MetricConf},
+    traits::{TableSummary, TypedStoreDebug},
+};
+
+use crate::progress_store::{ExecutorProgress, ProgressStore};
+
+#[derive(DBMapUtils)]
+struct RocksDBProgressStoreTables {
+    /// The latest watermark saved for each task.
+    watermarks: DBMap<String, CheckpointSequenceNumber>,
+    /// The watermarks saved for each task, keyed by task name and checkpoint
+    /// number, along with the time (in milliseconds since the unix epoch)
+    /// they were saved at.
+    history: DBMap<(String, CheckpointSequenceNumber), u64>,
+}
+
+/// A watermark saved for a task, as recorded in the history of a
+/// [`RocksDBProgressStore`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WatermarkRecord {
+    pub checkpoint_number: CheckpointSequenceNumber,
+    pub timestamp_ms: u64,
+}
+
+/// Progress store backed by an embedded RocksDB database.
+///
+/// Watermarks are saved along with a history of the previously saved ones,
+/// and the watermarks of several tasks can be updated atomically.
+pub struct RocksDBProgressStore {
+    tables: RocksDBProgressStoreTables,
+    history_retention: Option<u64>,
+}
+
+impl RocksDBProgressStore {
+    /// Opens the progress store located at `path`, creating it if it does not
+    /// exist yet.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            tables: RocksDBProgressStoreTables::open_tables_read_write(
+                path.to_path_buf(),
+                MetricConf::new("ingestion_progress"),
+                None,
+                None,
+            ),
+            history_retention: None,
+        }
+    }
+
+    /// Only keep the history of the last `checkpoints` checkpoints below the
+    /// watermark of each task. The whole history is kept by default.
+    pub fn with_history_retention(mut self, checkpoints: u64) -> Self {
+        self.history_retention = Some(checkpoints);
+        self
+    }
+
+    /// Returns the latest watermark of every task in the store.
+    pub fn watermarks(&self) -> Result<ExecutorProgress> {
+        Ok(self
+            .tables
+            .watermarks
+            .safe_iter()
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Returns the watermarks saved for `task_name`, in increasing checkpoint
+    /// order.
+    pub fn history(&self, task_name: &str) -> Result<Vec<WatermarkRecord>> {
+        let range = (task_name.to_string(), CheckpointSequenceNumber::MIN)
+            ..=(task_name.to_string(), CheckpointSequenceNumber::MAX);
+        Ok(self
+            .tables
+            .history
+            .safe_range_iter(range)
+            .map(|entry| {
+                entry.map(|((_, checkpoint_number), timestamp_ms)| WatermarkRecord {
+                    checkpoint_number,
+                    timestamp_ms,
+                })
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Moves the watermark of `task_name` back to `checkpoint_number`, so that
+    /// the task processes again the checkpoints that follow it. The history
+    /// saved past the new watermark is discarded.
+    pub fn rewind(
+        &mut self,
+        task_name: &str,
+        checkpoint_number: CheckpointSequenceNumber,
+    ) -> Result<()> {
+        let task_name = task_name.to_string();
+        let Some(watermark) = self.tables.watermarks.get(&task_name)? else {
+            bail!("no watermark saved for task {task_name}");
+        };
+        if checkpoint_number > watermark {
+            bail!(
+                "can't rewind task {task_name} to checkpoint {checkpoint_number}, past its watermark {watermark}"
+            );
+        }
+
+        let discarded = self
+            .tables
+            .history
+            .safe_range_iter(
+                (task_name.clone(), checkpoint_number + 1)
+                    ..=(task_name.clone(), CheckpointSequenceNumber::MAX),
+            )
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut batch = self.tables.watermarks.batch();
+        batch.delete_batch(&self.tables.history, discarded)?;
+        batch.insert_batch(
+            &self.tables.watermarks,
+            std::iter::once((&task_name, checkpoint_number)),
+        )?;
+        batch.insert_batch(
+            &self.tables.history,
+            std::iter::once(((task_name.clone(), checkpoint_number), now_ms())),
+        )?;
+        batch.write()?;
+        Ok(())
+    }
+
+    /// Saves the watermarks and records them in the history in a single write
+    /// batch, pruning the history past its retention.
+    fn write(&self, progress: &ExecutorProgress) -> Result<()> {
+        let timestamp_ms = now_ms();
+        let mut batch = self.tables.watermarks.batch();
+        batch.insert_batch(&self.tables.watermarks, progress.iter())?;
+        batch.insert_batch(
+            &self.tables.history,
+            progress.iter().map(|(task_name, checkpoint_number)| {
+                ((task_name.clone(), *checkpoint_number), timestamp_ms)
+            }),
+        )?;
+
+        if let Some(retention) = self.history_retention {
+            for (task_name, checkpoint_number) in progress {
+                let pruned = self
+                    .tables
+                    .history
+                    .safe_range_iter(
+                        (task_name.clone(), CheckpointSequenceNumber::MIN)
+                            ..(
+                                task_name.clone(),
+                                checkpoint_number.saturating_sub(retention),
+                            ),
+                    )
+                    .map(|entry| entry.map(|(key, _)| key))
+                    .collect::<Result<Vec<_>, _>>()?;
+                batch.delete_batch(&self.tables.history, pruned)?;
+            }
+        }
+
+        batch.write()?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProgressStore for RocksDBProgressStore {
+    async fn load(&mut self, task_name: String) -> Result<CheckpointSequenceNumber> {
+        Ok(self.tables.watermarks.get(&task_name)?.unwrap_or_default())
+    }
+
+    async fn save(
+        &mut self,
+        task_name: String,
+        checkpoint_number: CheckpointSequenceNumber,
+    ) -> Result<()> {
+        self.write(&ExecutorProgress::from([(task_name, checkpoint_number)]))
+    }
+
+    async fn save_all(&mut self, progress: ExecutorProgress) -> Result<()> {
+        self.write(&progress)
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = RocksDBProgressStore::new(dir.path());
+
+        assert_eq!(store.load("a".to_string()).await.unwrap(), 0);
+        store.save("a".to_string(), 3).await.unwrap();
+        store
+            .save_all(ExecutorProgress::from([
+                ("a".to_string(), 5),
+                ("b".to_string(), 4),
+            ]))
+            .await
+            .unwrap();
+        drop(store);
+
+        let mut store = RocksDBProgressStore::new(dir.path());
+        assert_eq!(store.load("a".to_string()).await.unwrap(), 5);
+        assert_eq!(
+            store.watermarks().unwrap(),
+            ExecutorProgress::from([("a".to_string(), 5), ("b".to_string(), 4)])
+        );
+        let history = store
+            .history("a")
+            .unwrap()
+            .into_iter()
+            .map(|record| record.checkpoint_number)
+            .collect::<Vec<_>>();
+        assert_eq!(history, [3, 5]);
+    }
+
+    #[tokio::test]
+    async fn history_retention() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = RocksDBProgressStore::new(dir.path()).with_history_retention(2);
+
+        for checkpoint_number in 0..10 {
+            store
+                .save("a".to_string(), checkpoint_number)
+                .await
+                .unwrap();
+        }
+        let history = store
+            .history("a")
+            .unwrap()
+            .into_iter()
+            .map(|record| record.checkpoint_number)
+            .collect::<Vec<_>>();
+        assert_eq!(history, [7, 8, 9]);
+    }
+
+    #[tokio::test]
+    async fn rewind() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = RocksDBProgressStore::new(dir.path());
+
+        for checkpoint_number in [2, 4, 6] {
+            store
+                .save("a".to_string(), checkpoint_number)
+                .await
+                .unwrap();
+        }
+        assert!(store.rewind("a", 7).is_err());
+        assert!(store.rewind("b", 1).is_err());
+
+        store.rewind("a", 3).unwrap();
+        assert_eq!(store.load("a".to_string()).await.unwrap(), 3);
+        let history = store
+            .history("a")
+            .unwrap()
+            .into_iter()
+            .map(|record| record.checkpoint_number)
+            .collect::<Vec<_>>();
+        assert_eq!(history, [2, 3]);
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use iota_data_ingestion_core::RocksDBProgressStore;
+use iota_types::messages_checkpoint::CheckpointSequenceNumber;
+
+/// Inspect and edit the watermarks of a RocksDB progress store. The ingestion
+/// service using the store must be stopped.
+#[derive(Parser)]
+#[command(name = "ingestion-progress")]
+struct Args {
+    /// Path of the RocksDB progress store.
+    #[arg(long)]
+    path: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the watermark of every task.
+    List,
+    /// Show the watermarks saved for a task, most recent first.
+    History {
+        task_name: String,
+        /// Maximum number of watermarks to show.
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Move the watermark of a task back to the given checkpoint, so that the
+    /// checkpoints following it are processed again.
+    Rewind {
+        task_name: String,
+        checkpoint_number: CheckpointSequenceNumber,
+    },
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mut progress_store = RocksDBProgressStore::new(&args.path);
+
+    match args.command {
+        Command::List => {
+            let mut watermarks = progress_store.watermarks()?.into_iter().collect::<Vec<_>>();
+            watermarks.sort();
+            for (task_name, checkpoint_number) in watermarks {
+                println!("{task_name}: {checkpoint_number}");
+            }
+        }
+        Command::History { task_name, limit } => {
+            for record in progress_store.history(&task_name)?.iter().rev().take(limit) {
+                println!(
+                    "{}: saved at {} ms",
+                    record.checkpoint_number, record.timestamp_ms
+                );
+            }
+        }
+        Command::Rewind {
+            task_name,
+            checkpoint_number,
+        } => {
+            progress_store.rewind(&task_name, checkpoint_number)?;
+            println!("Rewound {task_name} to checkpoint {checkpoint_number}");
+        }
+    }
+    Ok(())
+}
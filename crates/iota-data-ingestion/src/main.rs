@@ -9,7 +9,10 @@ use iota_data_ingestion::{
     ArchivalConfig, ArchivalWorker, BlobTaskConfig, BlobWorker, DynamoDBProgressStore,
     KVStoreTaskConfig, KVStoreWorker,
 };
-use iota_data_ingestion_core::{DataIngestionMetrics, IndexerExecutor, ReaderOptions, WorkerPool};
+use iota_data_ingestion_core::{
+    DataIngestionMetrics, IndexerExecutor, ProgressStore, ReaderOptions, RocksDBProgressStore,
+    WorkerPool,
+};
 use prometheus::Registry;
 use serde::{Deserialize, Serialize};
 use tokio::{signal, sync::oneshot};
@@ -30,15 +33,34 @@ struct TaskConfig {
     concurrency: usize,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+enum ProgressStoreConfig {
+    DynamoDB(DynamoDBProgressStoreConfig),
+    RocksDB(RocksDBProgressStoreConfig),
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
-struct ProgressStoreConfig {
+struct DynamoDBProgressStoreConfig {
     pub aws_access_key_id: String,
     pub aws_secret_access_key: String,
     pub aws_region: String,
     pub table_name: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+struct RocksDBProgressStoreConfig {
+    /// Path of the RocksDB database holding the watermarks.
+    pub path: PathBuf,
+    /// Number of checkpoints below each task's watermark for which the saved
+    /// watermarks are kept in the history. The whole history is kept if not
+    /// set.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub history_retention: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct IndexerConfig {
     path: PathBuf,
@@ -106,13 +128,33 @@ async fn main() -> Result<()> {
     iota_metrics::init_metrics(&registry);
     let metrics = DataIngestionMetrics::new(&registry);
 
-    let progress_store = DynamoDBProgressStore::new(
-        &config.progress_store.aws_access_key_id,
-        &config.progress_store.aws_secret_access_key,
-        config.progress_store.aws_region,
-        config.progress_store.table_name,
-    )
-    .await;
+    match config.progress_store.clone() {
+        ProgressStoreConfig::DynamoDB(progress_store_config) => {
+            let progress_store = DynamoDBProgressStore::new(
+                &progress_store_config.aws_access_key_id,
+                &progress_store_config.aws_secret_access_key,
+                progress_store_config.aws_region,
+                progress_store_config.table_name,
+            )
+            .await;
+            run(config, progress_store, metrics, exit_receiver).await
+        }
+        ProgressStoreConfig::RocksDB(progress_store_config) => {
+            let mut progress_store = RocksDBProgressStore::new(&progress_store_config.path);
+            if let Some(history_retention) = progress_store_config.history_retention {
+                progress_store = progress_store.with_history_retention(history_retention);
+            }
+            run(config, progress_store, metrics, exit_receiver).await
+        }
+    }
+}
+
+async fn run<P: ProgressStore>(
+    config: IndexerConfig,
+    progress_store: P,
+    metrics: DataIngestionMetrics,
+    exit_receiver: oneshot::Receiver<()>,
+) -> Result<()> {
     let mut executor = IndexerExecutor::new(progress_store, config.tasks.len(), metrics);
     for task_config in config.tasks {
         match task_config.task {
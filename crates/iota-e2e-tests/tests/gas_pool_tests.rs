@@ -0,0 +1,117 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, time::Duration};
+
+use futures::future::join_all;
+use iota_json_rpc_types::{IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponseOptions};
+use iota_keys::keystore::{AccountKeystore, InMemKeystore, Keystore};
+use iota_macros::*;
+use iota_sdk::gas_pool::{GasPool, GasPoolConfig, GasPoolSigner};
+use iota_types::{
+    base_types::IotaAddress, gas_coin::NANOS_PER_IOTA,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    quorum_driver_types::ExecuteTransactionRequestType, transaction::TransactionData,
+};
+use test_cluster::TestClusterBuilder;
+
+const GAS_BUDGET: u64 = 10_000_000;
+
+#[sim_test]
+async fn gas_pool_parallel_leases_and_recovery() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let client = test_cluster.iota_client().clone();
+    let rgp = test_cluster.get_reference_gas_price().await;
+    let signer = Keystore::from(InMemKeystore::new_insecure_for_tests(1));
+    let address = signer.addresses()[0];
+    test_cluster
+        .fund_address_and_return_gas(rgp, Some(10 * NANOS_PER_IOTA), address)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = GasPoolConfig {
+        size: 4,
+        coin_balance: NANOS_PER_IOTA,
+        dust_threshold: NANOS_PER_IOTA / 10,
+        lease_timeout: Duration::ZERO,
+        lease_file: Some(dir.path().join("leases.json")),
+        ..Default::default()
+    };
+    let pool = GasPool::new(
+        client.clone(),
+        Keystore::from(InMemKeystore::new_insecure_for_tests(1)),
+        address,
+        config.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(pool.available_coins().len(), 4);
+
+    // send transactions in parallel, each paid with its own leased coin
+    let leases = join_all((0..4).map(|_| pool.acquire(GAS_BUDGET)))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let leased_coins = leases
+        .iter()
+        .map(|lease| lease.object_ref().0)
+        .collect::<HashSet<_>>();
+    assert_eq!(leased_coins.len(), 4);
+    assert_eq!(pool.num_leased(), 4);
+
+    let responses = join_all(leases.iter().map(|lease| {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder
+            .pay_iota(vec![IotaAddress::random_for_testing_only()], vec![0])
+            .unwrap();
+        let data = TransactionData::new_programmable(
+            address,
+            vec![lease.object_ref()],
+            builder.finish(),
+            GAS_BUDGET,
+            rgp,
+        );
+        client.quorum_driver_api().execute_transaction_block(
+            signer.sign(data).unwrap(),
+            IotaTransactionBlockResponseOptions::new().with_effects(),
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+    }))
+    .await;
+    for (lease, response) in leases.into_iter().zip(responses) {
+        let effects = response.unwrap().effects.unwrap();
+        let balance = lease.balance();
+        lease.release(&effects).unwrap();
+        assert!(
+            pool.available_coins()
+                .iter()
+                .any(|(object_ref, b)| *object_ref
+                    == effects.gas_object().reference.to_object_ref()
+                    && *b < balance)
+        );
+    }
+    assert_eq!(pool.num_leased(), 0);
+
+    // leave a lease outstanding, as if the process crashed while using it
+    std::mem::forget(pool.acquire(GAS_BUDGET).await.unwrap());
+    drop(pool);
+
+    // the outstanding coin is recovered, and the coins below the new dust
+    // threshold are merged back together
+    let config = GasPoolConfig {
+        size: 2,
+        coin_balance: 3 * NANOS_PER_IOTA,
+        dust_threshold: 2 * NANOS_PER_IOTA,
+        ..config
+    };
+    let pool = GasPool::new(client, signer, address, config).await.unwrap();
+    let coins = pool.available_coins();
+    assert_eq!(coins.len(), 2);
+    assert!(
+        coins
+            .iter()
+            .all(|(_, balance)| *balance >= 2 * NANOS_PER_IOTA)
+    );
+    assert_eq!(pool.num_leased(), 0);
+}
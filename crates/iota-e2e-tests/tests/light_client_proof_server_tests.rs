@@ -0,0 +1,119 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{net::SocketAddr, time::Duration};
+
+use iota_config::local_ip_utils::new_local_tcp_socket_for_testing;
+use iota_json_rpc_types::IotaTransactionBlockEffectsAPI;
+use iota_light_client::{
+    proof::{Proof, verify_proof},
+    proof_server,
+    utils::Config,
+};
+use iota_macros::sim_test;
+use iota_test_transaction_builder::{make_staking_transaction, make_transfer_iota_transaction};
+use iota_types::{
+    IOTA_SYSTEM_ADDRESS, base_types::ObjectID, effects::TransactionEffectsAPI, event::EventID,
+};
+use reqwest::StatusCode;
+use test_cluster::{TestCluster, TestClusterBuilder};
+
+/// Starts a proof server trusting the genesis committee of the cluster.
+fn start_proof_server(test_cluster: &TestCluster, dir: &tempfile::TempDir) -> SocketAddr {
+    test_cluster
+        .get_genesis()
+        .save(dir.path().join("genesis.blob"))
+        .unwrap();
+    std::fs::write(dir.path().join("checkpoints.yaml"), "checkpoints: []\n").unwrap();
+    let config: Config = serde_json::from_value(serde_json::json!({
+        "full_node_url": test_cluster.rpc_url(),
+        "checkpoint_summary_dir": dir.path(),
+        "genesis_filename": "genesis.blob",
+    }))
+    .unwrap();
+
+    let address = new_local_tcp_socket_for_testing();
+    tokio::spawn(proof_server::run(config, address, Duration::from_secs(60)));
+    address
+}
+
+/// Queries the proof server until it serves the proof, which happens once the
+/// server is up and the transaction is checkpointed.
+async fn get_proof(url: &str) -> Proof {
+    tokio::time::timeout(Duration::from_secs(60), async {
+        loop {
+            if let Ok(response) = reqwest::get(url).await {
+                if response.status().is_success() {
+                    return response.json().await.unwrap();
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    })
+    .await
+    .expect("Timeout waiting for the proof")
+}
+
+#[sim_test]
+async fn proof_server_serves_verified_proofs() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let dir = tempfile::tempdir().unwrap();
+    let address = start_proof_server(&test_cluster, &dir);
+    let committee = test_cluster.get_genesis().committee().unwrap();
+
+    let txn = make_transfer_iota_transaction(&test_cluster.wallet, None, Some(9)).await;
+    let response = test_cluster.execute_transaction(txn).await;
+    let tx_digest = response.digest;
+    let effects = response.effects.unwrap();
+    let created = effects.created()[0].reference.object_id;
+
+    let proof = get_proof(&format!("http://{address}/proof/tx/{tx_digest}")).await;
+    verify_proof(&committee, &proof).unwrap();
+    let contents_proof = proof.contents_proof.unwrap();
+    assert_eq!(*contents_proof.effects.transaction_digest(), tx_digest);
+
+    let proof = get_proof(&format!("http://{address}/proof/object/{created}")).await;
+    verify_proof(&committee, &proof).unwrap();
+    assert_eq!(proof.targets.objects.len(), 1);
+    assert_eq!(proof.targets.objects[0].0.0, created);
+
+    let response = reqwest::get(format!(
+        "http://{address}/proof/object/{}",
+        ObjectID::random()
+    ))
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[sim_test]
+async fn proof_server_serves_event_proofs() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let dir = tempfile::tempdir().unwrap();
+    let address = start_proof_server(&test_cluster, &dir);
+    let committee = test_cluster.get_genesis().committee().unwrap();
+
+    // Staking emits a single `StakingRequestEvent`
+    let validator_address = test_cluster
+        .swarm
+        .active_validators()
+        .next()
+        .unwrap()
+        .config()
+        .iota_address();
+    let txn = make_staking_transaction(&test_cluster.wallet, validator_address).await;
+    let tx_digest = test_cluster.execute_transaction(txn).await.digest;
+
+    let proof = get_proof(&format!("http://{address}/proof/event/{tx_digest}/0")).await;
+    verify_proof(&committee, &proof).unwrap();
+    assert_eq!(proof.targets.events.len(), 1);
+    let (event_id, event) = &proof.targets.events[0];
+    assert_eq!(*event_id, EventID::from((tx_digest, 0)));
+    assert_eq!(event.type_.address, IOTA_SYSTEM_ADDRESS);
+    assert_eq!(event.type_.name.as_str(), "StakingRequestEvent");
+
+    let response = reqwest::get(format!("http://{address}/proof/event/{tx_digest}/1"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
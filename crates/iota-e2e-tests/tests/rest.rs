@@ -2,13 +2,24 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use iota_macros::sim_test;
-use iota_rest_api::{Client, ExecuteTransactionQueryParameters, client::BalanceChange};
-use iota_test_transaction_builder::make_transfer_iota_transaction;
+use iota_rest_api::{
+    Client, ExecuteTransactionQueryParameters,
+    accounts::{ListAccountBalancesQueryParameters, ListAccountCoinsQueryParameters},
+    client::BalanceChange,
+    transactions::{InspectTransactionQueryParameters, SimulateTransactionQueryParameters},
+};
+use iota_test_transaction_builder::{make_staking_transaction, make_transfer_iota_transaction};
 use iota_types::{
-    base_types::IotaAddress, effects::TransactionEffectsAPI, transaction::TransactionDataAPI,
+    base_types::IotaAddress,
+    effects::{TransactionEffects, TransactionEffectsAPI},
+    iota_sdk2_conversions::type_tag_sdk_to_core,
+    transaction::TransactionDataAPI,
 };
 use test_cluster::TestClusterBuilder;
+use url::Url;
 
 #[sim_test]
 async fn execute_transaction_transfer() {
@@ -52,3 +63,284 @@ async fn execute_transaction_transfer() {
 
     assert_eq!(actual, expected);
 }
+
+#[sim_test]
+async fn simulate_transaction_transfer() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+
+    let client = Client::new(test_cluster.rpc_url());
+    let address = IotaAddress::random_for_testing_only();
+    let amount = 9;
+
+    let txn =
+        make_transfer_iota_transaction(&test_cluster.wallet, Some(address), Some(amount)).await;
+    let sender = txn.transaction_data().sender();
+
+    let request = SimulateTransactionQueryParameters {
+        events: false,
+        balance_changes: true,
+        input_objects: true,
+        output_objects: true,
+    };
+
+    let response = client
+        .simulate_transaction(&request, txn.transaction_data())
+        .await
+        .unwrap();
+
+    let effects = TransactionEffects::from(response.effects);
+    assert!(effects.status().is_ok());
+    assert!(response.command_results.is_none());
+    let gas = effects.gas_cost_summary().net_gas_usage();
+
+    let mut expected = vec![
+        BalanceChange {
+            address: sender,
+            coin_type: iota_types::gas_coin::GAS::type_tag(),
+            amount: -(amount as i128 + gas as i128),
+        },
+        BalanceChange {
+            address,
+            coin_type: iota_types::gas_coin::GAS::type_tag(),
+            amount: amount as i128,
+        },
+    ];
+    expected.sort_by_key(|e| e.address);
+
+    let mut actual = response
+        .balance_changes
+        .unwrap()
+        .into_iter()
+        .map(|change| BalanceChange {
+            address: change.address.into(),
+            coin_type: type_tag_sdk_to_core(change.coin_type),
+            amount: change.amount,
+        })
+        .collect::<Vec<_>>();
+    actual.sort_by_key(|e| e.address);
+
+    assert_eq!(actual, expected);
+
+    // Simulating does not commit the transaction, so it can still be executed.
+    let request = ExecuteTransactionQueryParameters {
+        events: false,
+        balance_changes: false,
+        input_objects: false,
+        output_objects: false,
+    };
+    let executed = client.execute_transaction(&request, &txn).await.unwrap();
+    assert_eq!(
+        executed.effects.gas_cost_summary().net_gas_usage(),
+        gas,
+        "simulated gas usage should match the executed one"
+    );
+}
+
+#[sim_test]
+async fn inspect_transaction_transfer() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+
+    let client = Client::new(test_cluster.rpc_url());
+    let address = IotaAddress::random_for_testing_only();
+
+    let txn = make_transfer_iota_transaction(&test_cluster.wallet, Some(address), Some(9)).await;
+
+    let request = InspectTransactionQueryParameters {
+        events: false,
+        balance_changes: false,
+        input_objects: false,
+        output_objects: false,
+        full_checks: false,
+    };
+
+    let response = client
+        .inspect_transaction(&request, txn.transaction_data())
+        .await
+        .unwrap();
+
+    assert!(TransactionEffects::from(response.effects).status().is_ok());
+    assert!(response.balance_changes.is_none());
+    let command_results = response.command_results.unwrap();
+    assert_eq!(
+        command_results.len(),
+        txn.transaction_data().kind().iter_commands().count()
+    );
+}
+
+#[sim_test]
+async fn list_account_coins_and_balances() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+
+    let client = iota_rest_api::client::sdk::Client::new(&test_cluster.rpc_url()).unwrap();
+    let address = IotaAddress::random_for_testing_only();
+
+    for amount in [9, 10] {
+        let txn =
+            make_transfer_iota_transaction(&test_cluster.wallet, Some(address), Some(amount)).await;
+        test_cluster.execute_transaction(txn).await;
+    }
+
+    // The coin index is updated as checkpoints are executed by the fullnode.
+    let balances_parameters = ListAccountBalancesQueryParameters {
+        limit: None,
+        start: None,
+    };
+    let balances = tokio::time::timeout(Duration::from_secs(60), async {
+        loop {
+            let balances = client
+                .list_account_balances(address.into(), &balances_parameters)
+                .await
+                .unwrap()
+                .into_inner();
+            if balances.first().is_some_and(|b| b.coin_object_count == 2) {
+                break balances;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("timed out waiting for the coins to be indexed");
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances[0].total_balance, 19);
+
+    let parameters = ListAccountCoinsQueryParameters {
+        coin_type: None,
+        limit: Some(1),
+        start: None,
+    };
+    let (coins, parts) = client
+        .list_account_coins(address.into(), &parameters)
+        .await
+        .unwrap()
+        .into_parts();
+    assert_eq!(coins.len(), 1);
+    let cursor = parts.cursor.expect("a second page of coins should exist");
+
+    let parameters = ListAccountCoinsQueryParameters {
+        start: Some(cursor.parse().unwrap()),
+        ..parameters
+    };
+    let (more_coins, parts) = client
+        .list_account_coins(address.into(), &parameters)
+        .await
+        .unwrap()
+        .into_parts();
+    assert_eq!(more_coins.len(), 1);
+    assert!(parts.cursor.is_none());
+
+    let mut amounts = coins
+        .iter()
+        .chain(&more_coins)
+        .map(|coin| coin.balance)
+        .collect::<Vec<_>>();
+    amounts.sort();
+    assert_eq!(amounts, [9, 10]);
+}
+
+/// Reads the next message of a Server-Sent Events stream, returning its id and
+/// data.
+async fn next_stream_message(
+    response: &mut reqwest::Response,
+    buffer: &mut Vec<u8>,
+) -> (String, serde_json::Value) {
+    loop {
+        if let Some(end) = buffer.windows(2).position(|window| window == b"\n\n") {
+            let message = String::from_utf8(buffer.drain(..end + 2).collect()).unwrap();
+            let mut id = None;
+            let mut data = String::new();
+            for line in message.lines() {
+                if let Some(value) = line.strip_prefix("id:") {
+                    id = Some(value.trim().to_owned());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data.push_str(value.trim());
+                }
+            }
+            // Keep alive messages are comments without an id
+            if let Some(id) = id {
+                return (id, serde_json::from_str(&data).unwrap());
+            }
+            continue;
+        }
+
+        let chunk = tokio::time::timeout(Duration::from_secs(30), response.chunk())
+            .await
+            .expect("timed out waiting for a stream message")
+            .unwrap()
+            .expect("the stream ended");
+        buffer.extend_from_slice(&chunk);
+    }
+}
+
+#[sim_test]
+async fn stream_events_resume() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let validator_address = test_cluster
+        .swarm
+        .active_validators()
+        .next()
+        .unwrap()
+        .config()
+        .iota_address();
+
+    let first = make_staking_transaction(&test_cluster.wallet, validator_address).await;
+    let staker = first.transaction_data().sender();
+    // Only stream the staking requests of the staker, which requires decoding
+    // the events.
+    let filter = serde_json::json!({
+        "All": [
+            { "MoveEventType": "0x3::validator::StakingRequestEvent" },
+            { "MoveEventField": { "path": "/staker_address", "value": staker.to_string() } },
+        ]
+    });
+    let mut url = Url::parse(test_cluster.rpc_url())
+        .unwrap()
+        .join("api/v1/events/stream")
+        .unwrap();
+    url.query_pairs_mut()
+        .append_pair("filter", &filter.to_string());
+    let client = reqwest::Client::new();
+
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    let mut buffer = vec![];
+
+    let first_digest = test_cluster.execute_transaction(first).await.digest;
+    let second = make_staking_transaction(&test_cluster.wallet, validator_address).await;
+    let second_digest = test_cluster.execute_transaction(second).await.digest;
+
+    let (first_id, first_event) = next_stream_message(&mut response, &mut buffer).await;
+    assert_eq!(first_event["transaction_digest"], first_digest.to_string());
+    let (second_id, second_event) = next_stream_message(&mut response, &mut buffer).await;
+    assert_eq!(
+        second_event["transaction_digest"],
+        second_digest.to_string()
+    );
+
+    // Resuming after the first event streams the second one again.
+    let mut response = client
+        .get(url)
+        .header("Last-Event-ID", &first_id)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    let mut buffer = vec![];
+    let (id, event) = next_stream_message(&mut response, &mut buffer).await;
+    assert_eq!(id, second_id);
+    assert_eq!(event, second_event);
+
+    // Invalid filters are rejected.
+    let mut url = Url::parse(test_cluster.rpc_url())
+        .unwrap()
+        .join("api/v1/events/stream")
+        .unwrap();
+    url.query_pairs_mut().append_pair("filter", "{}");
+    let response = client.get(url).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+}
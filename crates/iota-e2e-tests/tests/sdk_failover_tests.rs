@@ -0,0 +1,81 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_json_rpc_types::IotaObjectDataOptions;
+use iota_macros::*;
+use iota_sdk::{IotaClientBuilder, http_client::RpcHttpClient};
+use test_cluster::TestClusterBuilder;
+
+// an endpoint nothing listens on
+const UNREACHABLE_URL: &str = "http://127.0.0.1:1";
+
+#[sim_test]
+async fn failover_to_healthy_endpoint() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let gas = test_cluster
+        .wallet
+        .get_one_gas_object()
+        .await
+        .unwrap()
+        .unwrap()
+        .1;
+
+    let client = IotaClientBuilder::default()
+        .request_timeout(Duration::from_secs(5))
+        .build_with_failover([UNREACHABLE_URL, test_cluster.rpc_url()])
+        .await
+        .unwrap();
+
+    // every request succeeds, whichever endpoint it is sent to first
+    for _ in 0..4 {
+        let object = client
+            .read_api()
+            .get_object_with_options(gas.0, IotaObjectDataOptions::new())
+            .await
+            .unwrap();
+        assert_eq!(object.object_id().unwrap(), gas.0);
+    }
+
+    let RpcHttpClient::Failover(http) = client.rpc_http() else {
+        panic!("expected a failover client");
+    };
+    assert_eq!(http.endpoints(), vec![
+        (UNREACHABLE_URL, false),
+        (test_cluster.rpc_url(), true)
+    ]);
+}
+
+#[sim_test]
+async fn quorum_reads() {
+    let test_cluster = TestClusterBuilder::new().build().await;
+    let gas = test_cluster
+        .wallet
+        .get_one_gas_object()
+        .await
+        .unwrap()
+        .unwrap()
+        .1;
+
+    let client = IotaClientBuilder::default()
+        .quorum_reads(2)
+        .build_with_failover([test_cluster.rpc_url(), test_cluster.rpc_url()])
+        .await
+        .unwrap();
+    let object = client
+        .read_api()
+        .get_object_with_options(gas.0, IotaObjectDataOptions::new())
+        .await
+        .unwrap();
+    assert_eq!(object.object_ref_if_exists(), Some(gas));
+
+    // quorum reads can't involve more endpoints than the client has
+    assert!(
+        IotaClientBuilder::default()
+            .quorum_reads(3)
+            .build_with_failover([test_cluster.rpc_url(), test_cluster.rpc_url()])
+            .await
+            .is_err()
+    );
+}
@@ -25,7 +25,8 @@ use iota_test_transaction_builder::batch_make_transfer_transactions;
 use iota_types::{
     quorum_driver_types::ExecuteTransactionRequestType,
     traffic_control::{
-        FreqThresholdConfig, PolicyConfig, PolicyType, RemoteFirewallConfig, Weight,
+        FreqThresholdConfig, PolicyConfig, PolicyType, RemoteFirewallConfig, TokenBucketConfig,
+        Weight,
     },
 };
 use jsonrpsee::{core::client::ClientT, rpc_params};
@@ -729,6 +730,70 @@ async fn test_traffic_sketch_with_sampled_spam() {
     assert!(metrics.num_blocked > (expected_requests / 5) - 100);
 }
 
+#[sim_test]
+async fn test_traffic_token_bucket_with_blocks() {
+    let token_bucket_config = TokenBucketConfig {
+        client_capacity: 500.0,
+        client_refill_rate: 500.0,
+        ..Default::default()
+    };
+    let policy = PolicyConfig {
+        connection_blocklist_ttl_sec: 1,
+        proxy_blocklist_ttl_sec: 1,
+        spam_policy_type: PolicyType::TokenBucket(token_bucket_config),
+        spam_sample_rate: Weight::one(),
+        channel_capacity: 100,
+        dry_run: false,
+        ..Default::default()
+    };
+    let metrics = TrafficSim::run(
+        policy,
+        1,    // num_clients
+        1000, // per_client_tps
+        Duration::from_secs(10),
+        true, // report
+    )
+    .await;
+
+    let expected_requests = 1000 * 10;
+    assert!(metrics.num_requests > expected_requests - 100);
+    // the client bursts through a full bucket in half a second, and is then
+    // blocked for a second, during which its bucket is refilled
+    assert!(metrics.abs_time_to_first_block.unwrap() < Duration::from_secs(2));
+    assert!(metrics.num_blocklist_adds >= 4);
+    assert!(metrics.num_blocked > expected_requests / 2);
+}
+
+#[sim_test]
+async fn test_traffic_token_bucket_allowlisted_no_blocks() {
+    let token_bucket_config = TokenBucketConfig {
+        client_capacity: 500.0,
+        client_refill_rate: 500.0,
+        ..Default::default()
+    };
+    let policy = PolicyConfig {
+        connection_blocklist_ttl_sec: 1,
+        proxy_blocklist_ttl_sec: 1,
+        spam_policy_type: PolicyType::TokenBucket(token_bucket_config),
+        spam_sample_rate: Weight::one(),
+        channel_capacity: 100,
+        dry_run: false,
+        allowlist: vec!["127.0.0.0/24".parse().unwrap()],
+        ..Default::default()
+    };
+    let metrics = TrafficSim::run(
+        policy,
+        2,    // num_clients
+        1000, // per_client_tps
+        Duration::from_secs(5),
+        true, // report
+    )
+    .await;
+
+    assert_eq!(metrics.num_blocked, 0);
+    assert_eq!(metrics.num_blocklist_adds, 0);
+}
+
 async fn assert_traffic_control_ok(mut test_cluster: TestCluster) -> Result<(), anyhow::Error> {
     let context = &mut test_cluster.wallet;
     let jsonrpc_client = &test_cluster.fullnode_handle.rpc_client;
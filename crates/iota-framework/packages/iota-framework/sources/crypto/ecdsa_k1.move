@@ -31,6 +31,10 @@ module iota::ecdsa_k1 {
     /// Error if the seed is invalid.
     const EInvalidSeed: u64 = 5;
 
+    #[allow(unused_const)]
+    /// Error if Schnorr signature verification is not enabled on the network.
+    const ENotSupported: u64 = 6;
+
     #[allow(unused_const)]
     /// Hash function name that are valid for ecrecover and secp256k1_verify.
     const KECCAK256: u8 = 0;
@@ -66,6 +70,15 @@ module iota::ecdsa_k1 {
     /// If the signature is valid to the pubkey and hashed message, return true. Else false.
     public native fun secp256k1_verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>, hash: u8): bool;
 
+    /// @param signature: A 64-bytes BIP-340 Schnorr signature in form (r, s).
+    /// @param public_key: A 32-bytes x-only public key as defined in BIP-340.
+    /// @param msg: The 32-bytes message that the signature is signed against. BIP-340 does not hash the message,
+    /// so this is usually a (tagged) hash of the actual data.
+    ///
+    /// If the signature is a valid BIP-340 signature of the message and public key, return true. Else false.
+    /// Aborts with `ENotSupported` if Schnorr signatures are not enabled on the network.
+    public native fun schnorr_verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;
+
     #[test_only]
     /// @param private_key: A 32-bytes private key that is used to sign the message.
     /// @param msg: The message to sign, this is raw message without hashing.
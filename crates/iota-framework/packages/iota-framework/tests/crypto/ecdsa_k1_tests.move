@@ -85,6 +85,43 @@ module iota::ecdsa_k1_tests {
         assert!(verify == false);
     }
 
+    #[test]
+    fun test_schnorr_verify() {
+        // test vector 0 from https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pk = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        assert!(ecdsa_k1::schnorr_verify(&sig, &pk, &msg));
+
+        // signature over the sha256 hash of a message
+        let msg = std::hash::sha2_256(b"Hello, world!");
+        let pk = x"1a5f3b61b70ad994f9f19b767064da0efa49c1ba1c6a5abdcad7fc66cab19484";
+        let sig = x"cb8221fdc751eff5d503d7279b1b1dd2906d2d5e0c19ef2edc0e2dccb8106c40ba3409431b22b3f96092e05533d301525090c8216164d878dd2d90c354618a5d";
+        assert!(ecdsa_k1::schnorr_verify(&sig, &pk, &msg));
+    }
+
+    #[test]
+    fun test_schnorr_verify_invalid() {
+        let msg = std::hash::sha2_256(b"Hello, world!");
+        let pk = x"1a5f3b61b70ad994f9f19b767064da0efa49c1ba1c6a5abdcad7fc66cab19484";
+        let sig = x"cb8221fdc751eff5d503d7279b1b1dd2906d2d5e0c19ef2edc0e2dccb8106c40ba3409431b22b3f96092e05533d301525090c8216164d878dd2d90c354618a5d";
+
+        // wrong message
+        let other_msg = std::hash::sha2_256(b"Hello, world?");
+        assert!(!ecdsa_k1::schnorr_verify(&sig, &pk, &other_msg));
+
+        // message that is not 32 bytes
+        assert!(!ecdsa_k1::schnorr_verify(&sig, &pk, &b"Hello, world!"));
+
+        // compressed instead of x-only public key
+        let compressed_pk = x"021a5f3b61b70ad994f9f19b767064da0efa49c1ba1c6a5abdcad7fc66cab19484";
+        assert!(!ecdsa_k1::schnorr_verify(&sig, &compressed_pk, &msg));
+
+        // truncated signature
+        let short_sig = x"cb8221fdc751eff5d503d7279b1b1dd2906d2d5e0c19ef2edc0e2dccb8106c40ba3409431b22b3f96092e05533d301525090c8216164d878dd2d90c354618a";
+        assert!(!ecdsa_k1::schnorr_verify(&short_sig, &pk, &msg));
+    }
+
     #[test]
     fun test_ecrecover_eth_address() {
         // Test case from https://stackoverflow.com/questions/67278243/how-to-verify-the-signature-made-by-metamask-for-ethereum
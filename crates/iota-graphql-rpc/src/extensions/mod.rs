@@ -7,3 +7,4 @@ pub(crate) mod feature_gate;
 pub(crate) mod logger;
 pub(crate) mod query_limits_checker;
 pub(crate) mod timeout;
+pub(crate) mod watermark;
@@ -0,0 +1,46 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_graphql::{
+    Data, Response,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+};
+use async_trait::async_trait;
+
+use crate::server::watermark_task::{Watermark, WatermarkLock};
+
+/// Extension factory for `WatermarkRefresh`, which provides each item emitted
+/// by a subscription with the latest watermark.
+///
+/// A subscription lives as long as its websocket connection, so a watermark
+/// captured when the connection is opened would become stale. Instead, the
+/// connection data holds the `WatermarkLock`, which is read whenever a
+/// subscription item is executed. Requests over HTTP are stamped with their
+/// watermark by the handler and are left untouched.
+pub(crate) struct WatermarkRefresh;
+
+impl ExtensionFactory for WatermarkRefresh {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(WatermarkRefresh)
+    }
+}
+
+#[async_trait]
+impl Extension for WatermarkRefresh {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let Some(watermark_lock) = ctx.data_opt::<WatermarkLock>() else {
+            return next.run(ctx, operation_name).await;
+        };
+
+        let mut data = Data::default();
+        data.insert(Watermark::new(watermark_lock.clone()).await);
+        next.run_with_data(ctx, operation_name, data).await
+    }
+}
@@ -112,10 +112,10 @@ pub(crate) fn functional_group(type_: &str, field: &str) -> Option<FunctionalGro
 mod tests {
     use std::collections::BTreeSet;
 
-    use async_graphql::{OutputType, registry::Registry};
+    use async_graphql::{OutputType, SubscriptionType, registry::Registry};
 
     use super::*;
-    use crate::types::query::Query;
+    use crate::{subscription::Subscription, types::query::Query};
 
     #[test]
     /// Makes sure all the functional groups correspond to real elements of the
@@ -125,14 +125,13 @@ mod tests {
     fn test_groups_match_schema() {
         let mut registry = Registry::default();
         Query::create_type_info(&mut registry);
+        Subscription::create_type_info(&mut registry);
 
         let unimplemented = BTreeSet::from_iter([
             ("Checkpoint", "addressMetrics"),
             ("Epoch", "protocolConfig"),
             ("Query", "moveCallMetrics"),
             ("Query", "networkMetrics"),
-            ("Subscription", "events"),
-            ("Subscription", "transactions"),
         ]);
 
         for (type_, field) in &unimplemented {
@@ -11,17 +11,18 @@ use std::{
 };
 
 use async_graphql::{
-    EmptySubscription, Schema, SchemaBuilder,
+    Data, Schema, SchemaBuilder,
     extensions::{ApolloTracing, ExtensionFactory, Tracing},
+    http::ALL_WEBSOCKET_PROTOCOLS,
 };
-use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};
 use axum::{
     Extension, Router,
     body::Body,
-    extract::{ConnectInfo, FromRef, Query as AxumQuery, State},
+    extract::{ConnectInfo, FromRef, Query as AxumQuery, State, ws::WebSocketUpgrade},
     http::{HeaderMap, StatusCode},
     middleware::{self},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::{MethodRouter, Route, get, post},
 };
 use chrono::Utc;
@@ -55,6 +56,7 @@ use crate::{
         logger::Logger,
         query_limits_checker::{QueryLimitsChecker, ShowUsage},
         timeout::Timeout,
+        watermark::WatermarkRefresh,
     },
     metrics::Metrics,
     mutation::Mutation,
@@ -65,6 +67,7 @@ use crate::{
         version::{check_version_middleware, set_version_middleware},
         watermark_task::{Watermark, WatermarkLock, WatermarkTask},
     },
+    subscription::{CheckpointReceiver, Subscription},
     types::{
         datatype::IMoveDatatype,
         move_object::IMoveObject,
@@ -163,7 +166,7 @@ impl Server {
 
 pub(crate) struct ServerBuilder {
     state: AppState,
-    schema: SchemaBuilder<Query, Mutation, EmptySubscription>,
+    schema: SchemaBuilder<Query, Mutation, Subscription>,
     router: Option<Router>,
     db_reader: Option<Db>,
     resolver: Option<PackageResolver>,
@@ -236,7 +239,7 @@ impl ServerBuilder {
         self
     }
 
-    fn build_schema(self) -> Schema<Query, Mutation, EmptySubscription> {
+    fn build_schema(self) -> Schema<Query, Mutation, Subscription> {
         self.schema.finish()
     }
 
@@ -246,7 +249,7 @@ impl ServerBuilder {
         self,
     ) -> (
         String,
-        Schema<Query, Mutation, EmptySubscription>,
+        Schema<Query, Mutation, Subscription>,
         Db,
         PackageResolver,
         Router,
@@ -275,6 +278,8 @@ impl ServerBuilder {
                 .route("/:version", post(graphql_handler))
                 .route("/graphql", post(graphql_handler))
                 .route("/graphql/:version", post(graphql_handler))
+                .route("/subscriptions", get(subscription_handler))
+                .route("/graphql/subscriptions", get(subscription_handler))
                 .route("/health", get(health_check))
                 .route("/graphql/health", get(health_check))
                 .route("/graphql/:version/health", get(health_check))
@@ -368,6 +373,9 @@ impl ServerBuilder {
             ))
             .layer(axum::extract::Extension(schema))
             .layer(axum::extract::Extension(watermark_task.lock()))
+            .layer(axum::extract::Extension(
+                watermark_task.checkpoint_receiver(),
+            ))
             .layer(Self::cors()?);
 
         Ok(Server {
@@ -484,7 +492,8 @@ impl ServerBuilder {
             .context_data(iota_sdk_client)
             .context_data(zklogin_config)
             .context_data(metrics.clone())
-            .context_data(config.clone());
+            .context_data(config.clone())
+            .extension(WatermarkRefresh);
 
         if config.internal_features.feature_gate {
             builder = builder.extension(FeatureGate);
@@ -521,8 +530,8 @@ impl ServerBuilder {
     }
 }
 
-fn schema_builder() -> SchemaBuilder<Query, Mutation, EmptySubscription> {
-    async_graphql::Schema::build(Query, Mutation, EmptySubscription)
+fn schema_builder() -> SchemaBuilder<Query, Mutation, Subscription> {
+    async_graphql::Schema::build(Query, Mutation, Subscription)
         .register_output_type::<IMoveObject>()
         .register_output_type::<IObject>()
         .register_output_type::<IOwner>()
@@ -567,6 +576,34 @@ async fn graphql_handler(
     (extensions, result.into())
 }
 
+/// Entry point for graphql subscriptions, served over websockets. Each
+/// connection is stamped with a unique ID and the client's address, like
+/// requests, and receives the checkpoint watermark updates that drive its
+/// subscriptions. The watermark lock is passed along rather than a snapshot of
+/// the watermark, so that each subscription item reads the latest one.
+async fn subscription_handler(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(schema): Extension<IotaGraphQLSchema>,
+    Extension(watermark_lock): Extension<WatermarkLock>,
+    Extension(checkpoint_receiver): Extension<CheckpointReceiver>,
+    protocol: GraphQLProtocol,
+    websocket: WebSocketUpgrade,
+) -> Response {
+    let mut data = Data::default();
+    data.insert(Uuid::new_v4());
+    data.insert(addr);
+    data.insert(watermark_lock);
+    data.insert(checkpoint_receiver);
+
+    websocket
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            GraphQLWebSocket::new(stream, schema, protocol)
+                .with_data(data)
+                .serve()
+        })
+}
+
 #[derive(Clone)]
 struct MetricsMakeCallbackHandler {
     metrics: Metrics,
@@ -0,0 +1,450 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+
+use async_graphql::{connection::CursorType, *};
+use futures::{Stream, StreamExt, stream};
+use tokio::sync::watch;
+
+use crate::{
+    config::ServiceConfig,
+    data::Db,
+    error::Error,
+    types::{
+        cursor::Page,
+        event::{self, Event, EventFilter, EventKey},
+        transaction_block::{self, TransactionBlock, TransactionBlockFilter},
+    },
+};
+
+/// Receiver of the checkpoint high watermark, sent by the watermark task each
+/// time the indexer makes a new checkpoint available.
+pub(crate) type CheckpointReceiver = watch::Receiver<u64>;
+
+pub struct Subscription;
+
+/// Subscriptions are used to stream data from the Iota network as the indexer
+/// makes it available. They are served over websockets.
+#[Subscription]
+impl Subscription {
+    /// Stream events as they are emitted in the network, starting from the
+    /// latest checkpoint known when the subscription is created.
+    ///
+    /// We currently do not support filtering by emitting module and event type
+    /// at the same time so if both are provided in one filter, the
+    /// subscription will error.
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<EventFilter>,
+    ) -> Result<impl Stream<Item = Result<Event>>> {
+        let db: Db = ctx.data_unchecked::<Db>().clone();
+        let config: ServiceConfig = ctx.data_unchecked::<ServiceConfig>().clone();
+        let mut receiver = checkpoint_receiver(ctx)?;
+        let filter = filter.unwrap_or_default();
+
+        // Only stream events emitted after the latest one that is already
+        // available when subscribing.
+        let checkpoint = *receiver.borrow_and_update();
+        let page = Page::from_params(&config, None, None, Some(1), None)?;
+        let last = Event::paginate(&db, page, filter.clone(), checkpoint)
+            .await
+            .extend()?
+            .edges
+            .pop()
+            .map(|edge| event::Cursor::decode_cursor(&edge.cursor))
+            .transpose()
+            .map_err(|e| Error::Internal(format!("Failed to decode event cursor: {e}")).extend())?
+            .map(|cursor| (cursor.tx, cursor.e));
+
+        let events = poll_checkpoints(receiver, last, move |mut last, checkpoint| {
+            let db = db.clone();
+            let config = config.clone();
+            let filter = filter.clone();
+            async move {
+                let batch = events_after(&db, &config, &filter, &mut last, checkpoint).await;
+                (batch, last)
+            }
+        });
+
+        Ok(events)
+    }
+
+    /// Stream transaction blocks as they are included in checkpoints, starting
+    /// from the latest checkpoint known when the subscription is created.
+    ///
+    /// Filters that would require a `scanLimit` when querying transaction
+    /// blocks are applied with the service's maximum scan limit on each new
+    /// range of checkpoints.
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TransactionBlockFilter>,
+    ) -> Result<impl Stream<Item = Result<TransactionBlock>>> {
+        let db: Db = ctx.data_unchecked::<Db>().clone();
+        let config: ServiceConfig = ctx.data_unchecked::<ServiceConfig>().clone();
+        let mut receiver = checkpoint_receiver(ctx)?;
+        let filter = filter.unwrap_or_default();
+
+        let lo = *receiver.borrow_and_update();
+        let range = CheckpointRange {
+            lo,
+            hi: lo,
+            after: None,
+        };
+        let transactions = poll_checkpoints(receiver, range, move |range, checkpoint| {
+            let db = db.clone();
+            let config = config.clone();
+            let filter = filter.clone();
+            async move { transactions_between(&db, &config, filter, range, checkpoint).await }
+        });
+
+        Ok(transactions)
+    }
+}
+
+/// The checkpoint receiver is provided per websocket connection, so it is
+/// missing if the subscription was not requested over a websocket.
+fn checkpoint_receiver(ctx: &Context<'_>) -> Result<CheckpointReceiver> {
+    ctx.data_opt::<CheckpointReceiver>()
+        .cloned()
+        .ok_or_else(|| {
+            Error::Client("Subscriptions are only supported over websockets".to_string()).extend()
+        })
+}
+
+/// The maximum number of pages fetched for a subscription each time it is
+/// polled. A subscriber which fell behind catches up over several polls rather
+/// than scanning an unbounded range at once.
+const MAX_PAGES_PER_POLL: usize = 10;
+
+/// A range of checkpoints whose transaction blocks are being streamed: the
+/// ones after `lo`, up to and including `hi`, continuing `after` a cursor if
+/// the range could not be fetched in one poll.
+struct CheckpointRange {
+    lo: u64,
+    hi: u64,
+    after: Option<transaction_block::Cursor>,
+}
+
+/// Fetch the events matching `filter` that were emitted after the event
+/// identified by `last` (a transaction and event sequence number pair), up to
+/// and including `checkpoint`. `last` is advanced to the last event fetched.
+///
+/// Returns the events, and whether more of them remain to be fetched.
+async fn events_after(
+    db: &Db,
+    config: &ServiceConfig,
+    filter: &EventFilter,
+    last: &mut Option<(u64, u64)>,
+    checkpoint: u64,
+) -> Result<(Vec<Event>, bool)> {
+    let (events, cursor, pending) = fetch_pages(*last, MAX_PAGES_PER_POLL, |last| async move {
+        // Cursors are re-issued at the new checkpoint, so that pagination is
+        // consistent with it rather than with the checkpoint the previous event
+        // was read at.
+        let after = last.map(|(tx, e)| {
+            event::Cursor::new(EventKey {
+                tx,
+                e,
+                checkpoint_viewed_at: checkpoint,
+            })
+        });
+        let page = Page::from_params(
+            config,
+            Some(config.limits.max_page_size as u64),
+            after,
+            None,
+            None,
+        )?;
+        let connection = Event::paginate(db, page, filter.clone(), checkpoint)
+            .await
+            .extend()?;
+
+        let mut events = vec![];
+        let mut last = None;
+        for edge in connection.edges {
+            let cursor = event::Cursor::decode_cursor(&edge.cursor).map_err(|e| {
+                Error::Internal(format!("Failed to decode event cursor: {e}")).extend()
+            })?;
+            last = Some((cursor.tx, cursor.e));
+            events.push(edge.node);
+        }
+        Ok::<_, async_graphql::Error>((events, last, connection.has_next_page))
+    })
+    .await?;
+
+    *last = cursor;
+    Ok((events, pending))
+}
+
+/// Fetch the transaction blocks matching `filter` in the checkpoints of
+/// `range`, or, once the previous range has been fetched entirely, in the
+/// checkpoints after it, up to and including `checkpoint`.
+///
+/// Returns the transaction blocks, and the range to fetch on the next poll,
+/// along with whether the range still has transaction blocks to fetch.
+async fn transactions_between(
+    db: &Db,
+    config: &ServiceConfig,
+    filter: TransactionBlockFilter,
+    range: CheckpointRange,
+    checkpoint: u64,
+) -> (Result<(Vec<TransactionBlock>, bool)>, CheckpointRange) {
+    let range = match range.after {
+        Some(_) => range,
+        None => CheckpointRange {
+            lo: range.hi,
+            hi: checkpoint,
+            after: None,
+        },
+    };
+
+    let bounds = TransactionBlockFilter {
+        after_checkpoint: Some(range.lo.into()),
+        before_checkpoint: Some((range.hi + 1).into()),
+        ..Default::default()
+    };
+    let Some(filter) = filter.intersect(bounds) else {
+        return (Ok((vec![], false)), CheckpointRange {
+            after: None,
+            ..range
+        });
+    };
+
+    let scan_limit = filter
+        .requires_scan_limit()
+        .then_some(config.limits.max_scan_limit as u64);
+
+    let hi = range.hi;
+    let result = fetch_pages(range.after.clone(), MAX_PAGES_PER_POLL, |after| {
+        let filter = filter.clone();
+        async move {
+            let page = Page::from_params(
+                config,
+                Some(config.limits.max_page_size as u64),
+                after,
+                None,
+                None,
+            )?;
+            let connection =
+                TransactionBlock::paginate_with(db, &config.limits, page, filter, hi, scan_limit)
+                    .await
+                    .extend()?;
+
+            let transactions = connection
+                .edges
+                .into_iter()
+                .map(|edge| edge.node)
+                .collect::<Vec<_>>();
+
+            // With a scan limit, the end cursor may not point at a transaction in
+            // this page, so it is used (rather than the last edge) to continue.
+            let (true, Some(end_cursor)) = (connection.has_next_page, connection.end_cursor) else {
+                return Ok((transactions, None, false));
+            };
+            let cursor = transaction_block::Cursor::decode_cursor(&end_cursor).map_err(|e| {
+                Error::Internal(format!("Failed to decode transaction cursor: {e}")).extend()
+            })?;
+            Ok::<_, async_graphql::Error>((transactions, Some(cursor), true))
+        }
+    })
+    .await;
+
+    match result {
+        Ok((transactions, after, true)) => {
+            (Ok((transactions, true)), CheckpointRange { after, ..range })
+        }
+        Ok((transactions, _, false)) => (Ok((transactions, false)), CheckpointRange {
+            after: None,
+            ..range
+        }),
+        // the range is fetched again on the next poll, from where it was left
+        Err(e) if range.after.is_some() => (Err(e), range),
+        Err(e) => (Err(e), CheckpointRange {
+            hi: range.lo,
+            ..range
+        }),
+    }
+}
+
+/// Fetch up to `max_pages` pages of items with `fetch`, starting after the
+/// cursor `after`. `fetch` returns the items of a page, the cursor to continue
+/// from (if the page is not empty), and whether there is a next page.
+///
+/// Returns the items, the cursor to continue from, and whether more pages
+/// remain to be fetched.
+async fn fetch_pages<T, C, F, Fut>(
+    mut after: Option<C>,
+    max_pages: usize,
+    mut fetch: F,
+) -> Result<(Vec<T>, Option<C>, bool)>
+where
+    C: Clone,
+    F: FnMut(Option<C>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<C>, bool)>>,
+{
+    let mut items = vec![];
+    for _ in 0..max_pages {
+        let (page, cursor, has_next_page) = fetch(after.clone()).await?;
+        items.extend(page);
+        if cursor.is_some() {
+            after = cursor;
+        }
+        if !has_next_page {
+            return Ok((items, after, false));
+        }
+    }
+    Ok((items, after, true))
+}
+
+/// Stream the items returned by `poll` as new checkpoints become available.
+///
+/// `poll` is called with its state and the latest checkpoint, and returns a
+/// batch of items along with whether more of them are pending. It is called
+/// again right away while items are pending, and otherwise on the next
+/// checkpoint. A failure is surfaced to the subscriber without ending the
+/// subscription.
+fn poll_checkpoints<S, T, F, Fut>(
+    receiver: CheckpointReceiver,
+    state: S,
+    poll: F,
+) -> impl Stream<Item = Result<T>>
+where
+    F: FnMut(S, u64) -> Fut,
+    Fut: Future<Output = (Result<(Vec<T>, bool)>, S)>,
+{
+    stream::unfold(
+        (receiver, state, poll, false),
+        |(mut receiver, state, mut poll, pending)| async move {
+            if !pending {
+                receiver.changed().await.ok()?;
+            }
+            let checkpoint = *receiver.borrow_and_update();
+            let (batch, state) = poll(state, checkpoint).await;
+            let (items, pending) = match batch {
+                Ok((items, pending)) => (items.into_iter().map(Ok).collect(), pending),
+                Err(e) => (vec![Err(e)], false),
+            };
+            Some((stream::iter(items), (receiver, state, poll, pending)))
+        },
+    )
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Fetches pages of two items out of `items`, a cursor being the index of
+    /// the last item fetched.
+    async fn fetch(items: &[u64], after: Option<usize>) -> Result<(Vec<u64>, Option<usize>, bool)> {
+        let start = after.map_or(0, |i| i + 1);
+        let end = (start + 2).min(items.len());
+        let page = items[start..end].to_vec();
+        let cursor = (end > start).then_some(end - 1);
+        Ok((page, cursor, end < items.len()))
+    }
+
+    #[tokio::test]
+    async fn fetch_pages_is_capped() {
+        let items = (0..10).collect::<Vec<u64>>();
+
+        let (fetched, after, pending) = fetch_pages(None, 3, |after| fetch(&items, after))
+            .await
+            .unwrap();
+        assert_eq!(fetched, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(after, Some(5));
+        assert!(pending);
+
+        // resuming from the cursor fetches the rest
+        let (fetched, after, pending) = fetch_pages(after, 3, |after| fetch(&items, after))
+            .await
+            .unwrap();
+        assert_eq!(fetched, vec![6, 7, 8, 9]);
+        assert_eq!(after, Some(9));
+        assert!(!pending);
+
+        // once caught up, the cursor is kept
+        let (fetched, after, pending) = fetch_pages(after, 3, |after| fetch(&items, after))
+            .await
+            .unwrap();
+        assert!(fetched.is_empty());
+        assert_eq!(after, Some(9));
+        assert!(!pending);
+    }
+
+    #[tokio::test]
+    async fn fetch_pages_fails() {
+        let result = fetch_pages(None::<usize>, 3, |_| async {
+            Err::<(Vec<u64>, Option<usize>, bool), _>(Error::Internal("boom".to_string()).extend())
+        })
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn poll_checkpoints_catches_up_over_several_polls() {
+        let (sender, receiver) = watch::channel(0);
+        let polls = Arc::new(Mutex::new(vec![]));
+
+        // each poll returns at most 2 of the items of the new checkpoints, an
+        // item per checkpoint
+        let stream = poll_checkpoints(receiver, 0u64, {
+            let polls = polls.clone();
+            move |last, checkpoint| {
+                polls.lock().unwrap().push(checkpoint);
+                async move {
+                    let items = (last + 1..=checkpoint).take(2).collect::<Vec<_>>();
+                    let last = items.last().copied().unwrap_or(last);
+                    (
+                        Ok::<_, async_graphql::Error>((items, last < checkpoint)),
+                        last,
+                    )
+                }
+            }
+        });
+        tokio::pin!(stream);
+
+        sender.send(5).unwrap();
+        let mut items = vec![];
+        for _ in 0..5 {
+            items.push(stream.next().await.unwrap().unwrap());
+        }
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        // the pending items are fetched without waiting for a new checkpoint
+        assert_eq!(*polls.lock().unwrap(), vec![5, 5, 5]);
+
+        sender.send(6).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 6);
+        assert_eq!(*polls.lock().unwrap(), vec![5, 5, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn poll_checkpoints_surfaces_errors() {
+        let (sender, receiver) = watch::channel(0);
+        let stream = poll_checkpoints(receiver, (), |(), checkpoint| async move {
+            let batch = if checkpoint == 1 {
+                Err(Error::Internal("boom".to_string()).extend())
+            } else {
+                Ok((vec![checkpoint], false))
+            };
+            (batch, ())
+        });
+        tokio::pin!(stream);
+
+        sender.send(1).unwrap();
+        assert!(stream.next().await.unwrap().is_err());
+
+        // the subscription goes on after the failure
+        sender.send(2).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+
+        // and ends with the watermark task
+        drop(sender);
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -26,7 +26,7 @@ use iota_types::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    config::ServiceConfig,
+    config::{Limits, ServiceConfig},
     connection::ScanConnection,
     data::{self, DataLoader, Db, DbConnection, QueryExecutor},
     error::Error,
@@ -304,8 +304,28 @@ impl TransactionBlock {
         checkpoint_viewed_at: u64,
         scan_limit: Option<u64>,
     ) -> Result<ScanConnection<String, TransactionBlock>, Error> {
-        let limits = &ctx.data_unchecked::<ServiceConfig>().limits;
+        Self::paginate_with(
+            ctx.data_unchecked(),
+            &ctx.data_unchecked::<ServiceConfig>().limits,
+            page,
+            filter,
+            checkpoint_viewed_at,
+            scan_limit,
+        )
+        .await
+    }
 
+    /// Like `paginate`, but with the `db` and `limits` passed explicitly rather
+    /// than read from the request context, for callers that outlive it (like
+    /// subscriptions).
+    pub(crate) async fn paginate_with(
+        db: &Db,
+        limits: &Limits,
+        page: Page<Cursor>,
+        filter: TransactionBlockFilter,
+        checkpoint_viewed_at: u64,
+        scan_limit: Option<u64>,
+    ) -> Result<ScanConnection<String, TransactionBlock>, Error> {
         // If the caller has provided some arbitrary combination of `function`, `kind`,
         // `recvAddress`, `inputObject`, or `changedObject`, we require setting a
         // `scanLimit`.
@@ -339,7 +359,6 @@ impl TransactionBlock {
 
         let cursor_viewed_at = page.validate_cursor_consistency()?;
         let checkpoint_viewed_at = cursor_viewed_at.unwrap_or(checkpoint_viewed_at);
-        let db: &Db = ctx.data_unchecked();
         let is_from_front = page.is_from_front();
 
         use transactions::dsl as tx;
@@ -7,6 +7,7 @@ mod tests {
     use std::{sync::Arc, time::Duration};
 
     use fastcrypto::encoding::{Base64, Encoding};
+    use futures::{SinkExt, StreamExt};
     use iota_graphql_rpc::{
         client::{ClientError, simple_client::GraphqlQueryVariable},
         config::ConnectionConfig,
@@ -23,7 +24,14 @@ mod tests {
     use serial_test::serial;
     use simulacrum::Simulacrum;
     use tempfile::tempdir;
-    use tokio::time::sleep;
+    use tokio::{
+        net::TcpStream,
+        time::{sleep, timeout},
+    };
+    use tokio_tungstenite::{
+        MaybeTlsStream, WebSocketStream,
+        tungstenite::{Message, client::IntoClientRequest, http::HeaderValue},
+    };
 
     async fn prep_executor_cluster() -> (ConnectionConfig, ExecutorCluster) {
         let rng = StdRng::from_seed([12; 32]);
@@ -413,6 +421,116 @@ mod tests {
         cluster.cleanup_resources().await
     }
 
+    /// Reads messages from a websocket until the next one of the GraphQL over
+    /// websocket protocol, and parses it.
+    async fn next_protocol_message(
+        websocket: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
+    ) -> serde_json::Value {
+        loop {
+            let message = timeout(Duration::from_secs(60), websocket.next())
+                .await
+                .expect("Timed out waiting for a websocket message")
+                .expect("Websocket closed")
+                .unwrap();
+            if let Message::Text(text) = message {
+                return serde_json::from_str(&text).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_transactions_subscription() {
+        let _guard = telemetry_subscribers::TelemetryConfig::new()
+            .with_env()
+            .init();
+
+        let connection_config = ConnectionConfig::default();
+        let cluster =
+            iota_graphql_rpc::test_infra::cluster::start_cluster(connection_config.clone(), None)
+                .await;
+        cluster
+            .wait_for_checkpoint_catchup(0, Duration::from_secs(10))
+            .await;
+
+        let mut request = format!("ws://{}/subscriptions", connection_config.server_address())
+            .into_client_request()
+            .unwrap();
+        request.headers_mut().insert(
+            "Sec-WebSocket-Protocol",
+            HeaderValue::from_static("graphql-transport-ws"),
+        );
+        let (mut websocket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+
+        websocket
+            .send(Message::Text(
+                json!({"type": "connection_init"}).to_string(),
+            ))
+            .await
+            .unwrap();
+        let ack = next_protocol_message(&mut websocket).await;
+        assert_eq!(ack["type"], "connection_ack");
+
+        // The epoch's total checkpoints are counted up to the watermark, which
+        // has to be the latest one rather than the one the connection was
+        // opened at
+        let subscription = r#"
+            subscription {
+                transactions {
+                    digest
+                    effects {
+                        checkpoint { sequenceNumber }
+                        epoch { totalCheckpoints }
+                    }
+                }
+            }
+        "#;
+        websocket
+            .send(Message::Text(
+                json!({"id": "1", "type": "subscribe", "payload": {"query": subscription}})
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+
+        let recipient = cluster.validator_fullnode_handle.wallet.get_addresses()[1];
+        let tx = cluster
+            .validator_fullnode_handle
+            .test_transaction_builder()
+            .await
+            .transfer_iota(Some(1_000), recipient)
+            .build();
+        let digest = cluster
+            .validator_fullnode_handle
+            .sign_and_execute_transaction(&tx)
+            .await
+            .digest
+            .to_string();
+
+        // Other transactions, like consensus commit prologues, are streamed as
+        // well until the executed one shows up
+        loop {
+            let message = next_protocol_message(&mut websocket).await;
+            assert_eq!(message["type"], "next", "Unexpected message: {message}");
+            assert_eq!(message["id"], "1");
+            let transaction = &message["payload"]["data"]["transactions"];
+            let effects = &transaction["effects"];
+            let checkpoint = effects["checkpoint"]["sequenceNumber"].as_u64().unwrap();
+            let total_checkpoints = effects["epoch"]["totalCheckpoints"].as_u64().unwrap();
+            assert!(
+                total_checkpoints >= checkpoint,
+                "Stale watermark: {total_checkpoints} checkpoints in the epoch, \
+                 but transaction is in checkpoint {checkpoint}"
+            );
+            if transaction["digest"] == digest.as_str() {
+                break;
+            }
+        }
+
+        websocket.close(None).await.unwrap();
+        cluster.cleanup_resources().await
+    }
+
     #[tokio::test]
     #[serial]
     #[ignore = "https://github.com/iotaledger/iota/issues/1777"]
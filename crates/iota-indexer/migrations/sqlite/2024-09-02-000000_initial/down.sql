@@ -0,0 +1,39 @@
+DROP VIEW IF EXISTS network_metrics;
+DROP VIEW IF EXISTS real_time_tps;
+DROP TABLE IF EXISTS chain_identifier;
+DROP TABLE IF EXISTS event_senders;
+DROP TABLE IF EXISTS event_struct_instantiation;
+DROP TABLE IF EXISTS event_struct_name;
+DROP TABLE IF EXISTS event_struct_module;
+DROP TABLE IF EXISTS event_struct_package;
+DROP TABLE IF EXISTS event_emit_module;
+DROP TABLE IF EXISTS event_emit_package;
+DROP TABLE IF EXISTS display;
+DROP TABLE IF EXISTS tx_kinds;
+DROP TABLE IF EXISTS tx_digests;
+DROP TABLE IF EXISTS tx_calls_fun;
+DROP TABLE IF EXISTS tx_calls_mod;
+DROP TABLE IF EXISTS tx_calls_pkg;
+DROP TABLE IF EXISTS tx_changed_objects;
+DROP TABLE IF EXISTS tx_input_objects;
+DROP TABLE IF EXISTS tx_recipients;
+DROP TABLE IF EXISTS tx_senders;
+DROP TABLE IF EXISTS epoch_peak_tps;
+DROP TABLE IF EXISTS address_metrics;
+DROP TABLE IF EXISTS active_addresses;
+DROP TABLE IF EXISTS addresses;
+DROP TABLE IF EXISTS move_call_metrics;
+DROP TABLE IF EXISTS move_calls;
+DROP TABLE IF EXISTS tx_count_metrics;
+DROP TABLE IF EXISTS packages;
+DROP TABLE IF EXISTS feature_flags;
+DROP TABLE IF EXISTS protocol_configs;
+DROP TABLE IF EXISTS epochs;
+DROP TABLE IF EXISTS pruner_cp_watermark;
+DROP TABLE IF EXISTS checkpoints;
+DROP TABLE IF EXISTS transactions;
+DROP TABLE IF EXISTS objects_version;
+DROP TABLE IF EXISTS objects_snapshot;
+DROP TABLE IF EXISTS objects_history;
+DROP TABLE IF EXISTS objects;
+DROP TABLE IF EXISTS events;
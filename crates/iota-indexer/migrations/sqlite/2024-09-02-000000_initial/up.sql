@@ -0,0 +1,532 @@
+-- SQLite has no table partitioning, so the tables that are partitioned by
+-- epoch or by object ID on postgres are plain tables here. Array columns are
+-- stored as JSON arrays of byte arrays.
+
+CREATE TABLE events
+(
+    tx_sequence_number          BIGINT       NOT NULL,
+    event_sequence_number       BIGINT       NOT NULL,
+    transaction_digest          BLOB         NOT NULL,
+    -- JSON array of IotaAddress in bytes. All signers of the transaction.
+    senders                     TEXT         NOT NULL,
+    -- bytes of the entry package ID. Notice that the package and module here
+    -- are the package and module of the function that emitted the event, different
+    -- from the package and module of the event type.
+    package                     BLOB         NOT NULL,
+    -- entry module name
+    module                      TEXT         NOT NULL,
+    -- StructTag in Display format, fully qualified including type parameters
+    event_type                  TEXT         NOT NULL,
+    -- timestamp of the checkpoint when the event was emitted
+    timestamp_ms                BIGINT       NOT NULL,
+    -- bcs of the Event contents (Event.contents)
+    bcs                         BLOB         NOT NULL,
+    PRIMARY KEY(tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX events_package ON events (package, tx_sequence_number, event_sequence_number);
+CREATE INDEX events_package_module ON events (package, module, tx_sequence_number, event_sequence_number);
+CREATE INDEX events_event_type ON events (event_type, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE objects (
+    object_id                   BLOB          PRIMARY KEY,
+    object_version              BIGINT        NOT NULL,
+    object_digest               BLOB          NOT NULL,
+    checkpoint_sequence_number  BIGINT        NOT NULL,
+    -- Immutable/Address/Object/Shared, see types.rs
+    owner_type                  SMALLINT      NOT NULL,
+    -- bytes of IotaAddress/ObjectID of the owner ID.
+    -- Non-null for objects with an owner: Addresso or Objects
+    owner_id                    BLOB,
+    -- Object type
+    object_type                 TEXT,
+    -- Components of the StructTag: package, module, name (name of the struct, without type parameters)
+    object_type_package         BLOB,
+    object_type_module          TEXT,
+    object_type_name            TEXT,
+    -- bcs serialized Object
+    serialized_object           BLOB          NOT NULL,
+    -- Non-null when the object is a coin.
+    -- e.g. `0x2::iota::IOTA`
+    coin_type                   TEXT,
+    -- Non-null when the object is a coin.
+    coin_balance                BIGINT,
+    -- DynamicField/DynamicObject, see types.rs
+    -- Non-null when the object is a dynamic field
+    df_kind                     SMALLINT,
+    -- bcs serialized DynamicFieldName
+    -- Non-null when the object is a dynamic field
+    df_name                     BLOB,
+    -- object_type in DynamicFieldInfo.
+    df_object_type              TEXT,
+    -- object_id in DynamicFieldInfo.
+    df_object_id                BLOB
+);
+
+-- OwnerType: 1: Address, 2: Object, see types.rs
+CREATE INDEX objects_owner ON objects (owner_type, owner_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_coin ON objects (owner_id, coin_type) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_checkpoint_sequence_number ON objects (checkpoint_sequence_number);
+CREATE INDEX objects_package_module_name_full_type ON objects (object_type_package, object_type_module, object_type_name, object_type);
+CREATE INDEX objects_owner_package_module_name_full_type ON objects (owner_id, object_type_package, object_type_module, object_type_name, object_type);
+
+CREATE TABLE objects_history (
+    object_id                   BLOB          NOT NULL,
+    object_version              BIGINT        NOT NULL,
+    object_status               SMALLINT      NOT NULL,
+    object_digest               BLOB,
+    checkpoint_sequence_number  BIGINT        NOT NULL,
+    owner_type                  SMALLINT,
+    owner_id                    BLOB,
+    object_type                 TEXT,
+    object_type_package         BLOB,
+    object_type_module          TEXT,
+    object_type_name            TEXT,
+    serialized_object           BLOB,
+    coin_type                   TEXT,
+    coin_balance                BIGINT,
+    df_kind                     SMALLINT,
+    df_name                     BLOB,
+    df_object_type              TEXT,
+    df_object_id                BLOB,
+    CONSTRAINT objects_history_pk PRIMARY KEY (checkpoint_sequence_number, object_id, object_version)
+);
+CREATE INDEX objects_history_id_version ON objects_history (object_id, object_version, checkpoint_sequence_number);
+CREATE INDEX objects_history_owner ON objects_history (checkpoint_sequence_number, owner_type, owner_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_history_coin_owner ON objects_history (checkpoint_sequence_number, owner_id, coin_type, object_id) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_history_coin_only ON objects_history (checkpoint_sequence_number, coin_type, object_id) WHERE coin_type IS NOT NULL;
+CREATE INDEX objects_history_type ON objects_history (checkpoint_sequence_number, object_type);
+CREATE INDEX objects_history_package_module_name_full_type ON objects_history (checkpoint_sequence_number, object_type_package, object_type_module, object_type_name, object_type);
+CREATE INDEX objects_history_owner_package_module_name_full_type ON objects_history (checkpoint_sequence_number, owner_id, object_type_package, object_type_module, object_type_name, object_type);
+
+CREATE TABLE objects_snapshot (
+    object_id                   BLOB          PRIMARY KEY,
+    object_version              BIGINT        NOT NULL,
+    object_status               SMALLINT      NOT NULL,
+    object_digest               BLOB,
+    checkpoint_sequence_number  BIGINT        NOT NULL,
+    owner_type                  SMALLINT,
+    owner_id                    BLOB,
+    object_type                 TEXT,
+    object_type_package         BLOB,
+    object_type_module          TEXT,
+    object_type_name            TEXT,
+    serialized_object           BLOB,
+    coin_type                   TEXT,
+    coin_balance                BIGINT,
+    df_kind                     SMALLINT,
+    df_name                     BLOB,
+    df_object_type              TEXT,
+    df_object_id                BLOB
+);
+CREATE INDEX objects_snapshot_checkpoint_sequence_number ON objects_snapshot (checkpoint_sequence_number);
+CREATE INDEX objects_snapshot_owner ON objects_snapshot (owner_type, owner_id, object_id) WHERE owner_type BETWEEN 1 AND 2 AND owner_id IS NOT NULL;
+CREATE INDEX objects_snapshot_coin_owner ON objects_snapshot (owner_id, coin_type, object_id) WHERE coin_type IS NOT NULL AND owner_type = 1;
+CREATE INDEX objects_snapshot_coin_only ON objects_snapshot (coin_type, object_id) WHERE coin_type IS NOT NULL;
+CREATE INDEX objects_snapshot_type_id ON objects_snapshot (object_type_package, object_type_module, object_type_name, object_type, object_id);
+CREATE INDEX objects_snapshot_id_type ON objects_snapshot (object_id, object_type_package, object_type_module, object_type_name, object_type);
+CREATE INDEX objects_snapshot_owner_package_module_name_full_type ON objects_snapshot (owner_id, object_type_package, object_type_module, object_type_name, object_type);
+
+-- Indexing table mapping an object's ID and version to its checkpoint
+-- sequence number.
+CREATE TABLE objects_version (
+    object_id           BLOB          NOT NULL,
+    object_version      BIGINT        NOT NULL,
+    cp_sequence_number  BIGINT        NOT NULL,
+    PRIMARY KEY (object_id, object_version)
+);
+
+CREATE TABLE transactions (
+    tx_sequence_number          BIGINT       NOT NULL,
+    transaction_digest          BLOB         NOT NULL,
+    -- bcs serialized SenderSignedData bytes
+    raw_transaction             BLOB         NOT NULL,
+    -- bcs serialized TransactionEffects bytes
+    raw_effects                 BLOB         NOT NULL,
+    checkpoint_sequence_number  BIGINT       NOT NULL,
+    timestamp_ms                BIGINT       NOT NULL,
+    -- JSON array of bcs serialized IndexedObjectChange bytes
+    object_changes              TEXT         NOT NULL,
+    -- JSON array of bcs serialized BalanceChange bytes
+    balance_changes             TEXT         NOT NULL,
+    -- JSON array of bcs serialized StoredEvent bytes
+    events                      TEXT         NOT NULL,
+    -- SystemTransaction/ProgrammableTransaction. See types.rs
+    transaction_kind            SMALLINT     NOT NULL,
+    -- number of successful commands in this transaction, bound by number of command
+    -- in a programmaable transaction.
+    success_command_count       SMALLINT     NOT NULL,
+    PRIMARY KEY (tx_sequence_number)
+);
+
+CREATE TABLE checkpoints
+(
+    sequence_number                     BIGINT       PRIMARY KEY,
+    checkpoint_digest                   BLOB         NOT NULL,
+    epoch                               BIGINT       NOT NULL,
+    -- total transactions in the network at the end of this checkpoint (including itself)
+    network_total_transactions          BIGINT       NOT NULL,
+    previous_checkpoint_digest          BLOB,
+    -- if this checkpoitn is the last checkpoint of an epoch
+    end_of_epoch                        BOOLEAN      NOT NULL,
+    -- JSON array of TranscationDigest in bytes included in this checkpoint
+    tx_digests                          TEXT         NOT NULL,
+    timestamp_ms                        BIGINT       NOT NULL,
+    total_gas_cost                      BIGINT       NOT NULL,
+    computation_cost                    BIGINT       NOT NULL,
+    storage_cost                        BIGINT       NOT NULL,
+    storage_rebate                      BIGINT       NOT NULL,
+    non_refundable_storage_fee          BIGINT       NOT NULL,
+    -- bcs serialized Vec<CheckpointCommitment> bytes
+    checkpoint_commitments              BLOB         NOT NULL,
+    -- bcs serialized AggregateAuthoritySignature bytes
+    validator_signature                 BLOB         NOT NULL,
+    -- bcs serialzied EndOfEpochData bytes, if the checkpoint marks end of an epoch
+    end_of_epoch_data                   BLOB,
+    min_tx_sequence_number              BIGINT,
+    max_tx_sequence_number              BIGINT
+);
+
+CREATE INDEX checkpoints_epoch ON checkpoints (epoch, sequence_number);
+CREATE INDEX checkpoints_digest ON checkpoints (checkpoint_digest);
+
+CREATE TABLE pruner_cp_watermark (
+    checkpoint_sequence_number  BIGINT       PRIMARY KEY,
+    min_tx_sequence_number      BIGINT       NOT NULL,
+    max_tx_sequence_number      BIGINT       NOT NULL
+);
+
+CREATE TABLE epochs
+(
+    epoch                           BIGINT      PRIMARY KEY,
+    first_checkpoint_id             BIGINT      NOT NULL,
+    epoch_start_timestamp           BIGINT      NOT NULL,
+    reference_gas_price             BIGINT      NOT NULL,
+    protocol_version                BIGINT      NOT NULL,
+    total_stake                     BIGINT      NOT NULL,
+    storage_fund_balance            BIGINT      NOT NULL,
+    system_state                    BLOB        NOT NULL,
+    -- The following fields are nullable because they are filled in
+    -- only at the end of an epoch.
+    epoch_total_transactions        BIGINT,
+    last_checkpoint_id              BIGINT,
+    epoch_end_timestamp             BIGINT,
+    -- The following fields are from SystemEpochInfoEventV1 emitted
+    -- **after** advancing to the next epoch
+    storage_charge                  BIGINT,
+    storage_rebate                  BIGINT,
+    total_gas_fees                  BIGINT,
+    total_stake_rewards_distributed BIGINT,
+    -- bcs serialized Vec<EpochCommitment> bytes, found in last CheckpointSummary
+    -- of the epoch
+    epoch_commitments               BLOB,
+    burnt_tokens_amount             BIGINT,
+    minted_tokens_amount            BIGINT
+);
+
+-- Table storing the protocol configs for each protocol version.
+-- Examples include gas schedule, transaction limits, etc.
+CREATE TABLE protocol_configs
+(
+    protocol_version                BIGINT       NOT NULL,
+    config_name                     TEXT         NOT NULL,
+    config_value                    TEXT,
+    PRIMARY KEY(protocol_version, config_name)
+);
+
+-- Table storing the feature flags for each protocol version.
+CREATE TABLE feature_flags
+(
+    protocol_version                BIGINT       NOT NULL,
+    flag_name                       TEXT         NOT NULL,
+    flag_value                      BOOLEAN      NOT NULL,
+    PRIMARY KEY(protocol_version, flag_name)
+);
+
+CREATE TABLE packages
+(
+    package_id                   BLOB           NOT NULL,
+    original_id                  BLOB           NOT NULL,
+    package_version              BIGINT         NOT NULL,
+    -- bcs serialized MovePackage
+    move_package                 BLOB           NOT NULL,
+    checkpoint_sequence_number   BIGINT         NOT NULL,
+    CONSTRAINT packages_pkey PRIMARY KEY (package_id, original_id, package_version),
+    CONSTRAINT packages_unique_package_id UNIQUE (package_id)
+);
+
+CREATE INDEX packages_cp_id_version ON packages (checkpoint_sequence_number, original_id, package_version);
+CREATE INDEX packages_id_version_cp ON packages (original_id, package_version, checkpoint_sequence_number);
+
+CREATE TABLE tx_count_metrics
+(
+    checkpoint_sequence_number          BIGINT  PRIMARY KEY,
+    epoch                               BIGINT  NOT NULL,
+    timestamp_ms                        BIGINT  NOT NULL,
+    total_transaction_blocks            BIGINT  NOT NULL,
+    total_successful_transaction_blocks BIGINT  NOT NULL,
+    total_successful_transactions       BIGINT  NOT NULL
+);
+-- epoch for peak 30D TPS filter
+CREATE INDEX tx_count_metrics_epoch ON tx_count_metrics (epoch);
+-- timestamp for timestamp grouping, in case multiple checkpoints have the same timestamp
+CREATE INDEX tx_count_metrics_timestamp_ms ON tx_count_metrics (timestamp_ms);
+
+CREATE TABLE move_calls (
+    transaction_sequence_number BIGINT  NOT NULL,
+    checkpoint_sequence_number  BIGINT  NOT NULL,
+    epoch                       BIGINT  NOT NULL,
+    move_package                BLOB    NOT NULL,
+    move_module                 TEXT    NOT NULL,
+    move_function               TEXT    NOT NULL,
+    PRIMARY KEY(transaction_sequence_number, move_package, move_module, move_function)
+);
+CREATE INDEX idx_move_calls_epoch_etc ON move_calls (epoch, move_package, move_module, move_function);
+
+CREATE TABLE move_call_metrics (
+    -- Diesel only supports table with a primary key.
+    id                          INTEGER     PRIMARY KEY AUTOINCREMENT,
+    epoch                       BIGINT      NOT NULL,
+    day                         BIGINT      NOT NULL,
+    move_package                TEXT        NOT NULL,
+    move_module                 TEXT        NOT NULL,
+    move_function               TEXT        NOT NULL,
+    count                       BIGINT      NOT NULL
+);
+CREATE INDEX move_call_metrics_epoch_day ON move_call_metrics (epoch, day);
+
+-- senders or recipients of transactions
+CREATE TABLE addresses
+(
+    address                 BLOB    PRIMARY KEY,
+    first_appearance_tx     BIGINT  NOT NULL,
+    first_appearance_time   BIGINT  NOT NULL,
+    last_appearance_tx      BIGINT  NOT NULL,
+    last_appearance_time    BIGINT  NOT NULL
+);
+
+-- senders of transactions
+CREATE TABLE active_addresses
+(
+    address                 BLOB    PRIMARY KEY,
+    first_appearance_tx     BIGINT  NOT NULL,
+    first_appearance_time   BIGINT  NOT NULL,
+    last_appearance_tx      BIGINT  NOT NULL,
+    last_appearance_time    BIGINT  NOT NULL
+);
+
+CREATE TABLE address_metrics
+(
+    checkpoint                  BIGINT  PRIMARY KEY,
+    epoch                       BIGINT  NOT NULL,
+    timestamp_ms                BIGINT  NOT NULL,
+    cumulative_addresses        BIGINT  NOT NULL,
+    cumulative_active_addresses BIGINT  NOT NULL,
+    daily_active_addresses      BIGINT  NOT NULL
+);
+CREATE INDEX address_metrics_epoch_idx ON address_metrics (epoch);
+
+CREATE TABLE epoch_peak_tps
+(
+    epoch           BIGINT  PRIMARY KEY,
+    peak_tps        DOUBLE  NOT NULL,
+    peak_tps_30d    DOUBLE  NOT NULL
+);
+
+CREATE VIEW real_time_tps AS
+WITH recent_checkpoints AS (
+  SELECT
+    checkpoint_sequence_number as sequence_number,
+    total_successful_transactions,
+    timestamp_ms
+  FROM
+    tx_count_metrics
+  ORDER BY
+    timestamp_ms DESC
+  LIMIT 100
+),
+diff_checkpoints AS (
+  SELECT
+    MAX(sequence_number) as sequence_number,
+    SUM(total_successful_transactions) as total_successful_transactions,
+    timestamp_ms - LAG(timestamp_ms) OVER (ORDER BY timestamp_ms) AS time_diff
+  FROM
+    recent_checkpoints
+  GROUP BY
+    timestamp_ms
+)
+SELECT
+  CAST(total_successful_transactions * 1000.0 / time_diff AS DOUBLE) as recent_tps
+FROM
+  diff_checkpoints
+WHERE
+  time_diff IS NOT NULL
+ORDER BY sequence_number DESC LIMIT 1;
+
+-- SQLite keeps no row count estimates, so the totals are counted exactly.
+CREATE VIEW network_metrics AS
+SELECT  COALESCE((SELECT recent_tps from real_time_tps), 0)                                             AS current_tps,
+        COALESCE((SELECT peak_tps_30d FROM epoch_peak_tps ORDER BY epoch DESC LIMIT 1), 0)              AS tps_30_days,
+        (SELECT COUNT(*) FROM addresses)                                                                AS total_addresses,
+        (SELECT COUNT(*) FROM objects)                                                                  AS total_objects,
+        (SELECT COUNT(*) FROM packages)                                                                 AS total_packages,
+        (SELECT MAX(epoch) FROM epochs)                                                                 AS current_epoch,
+        (SELECT MAX(sequence_number) FROM checkpoints)                                                  AS current_checkpoint;
+
+CREATE TABLE tx_senders (
+    tx_sequence_number          BIGINT       NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(sender, tx_sequence_number)
+);
+
+CREATE TABLE tx_recipients (
+    tx_sequence_number          BIGINT       NOT NULL,
+    recipient                   BLOB         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(recipient, tx_sequence_number)
+);
+CREATE INDEX tx_recipients_sender ON tx_recipients (sender, recipient, tx_sequence_number);
+
+CREATE TABLE tx_input_objects (
+    tx_sequence_number          BIGINT       NOT NULL,
+    object_id                   BLOB         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(object_id, tx_sequence_number)
+);
+CREATE INDEX tx_input_objects_tx_sequence_number_index ON tx_input_objects (tx_sequence_number);
+CREATE INDEX tx_input_objects_sender ON tx_input_objects (sender, object_id, tx_sequence_number);
+
+CREATE TABLE tx_changed_objects (
+    tx_sequence_number          BIGINT       NOT NULL,
+    object_id                   BLOB         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(object_id, tx_sequence_number)
+);
+CREATE INDEX tx_changed_objects_tx_sequence_number_index ON tx_changed_objects (tx_sequence_number);
+CREATE INDEX tx_changed_objects_sender ON tx_changed_objects (sender, object_id, tx_sequence_number);
+
+CREATE TABLE tx_calls_pkg (
+    tx_sequence_number          BIGINT       NOT NULL,
+    package                     BLOB         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number)
+);
+CREATE INDEX tx_calls_pkg_sender ON tx_calls_pkg (sender, package, tx_sequence_number);
+
+CREATE TABLE tx_calls_mod (
+    tx_sequence_number          BIGINT       NOT NULL,
+    package                     BLOB         NOT NULL,
+    module                      TEXT         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number)
+);
+CREATE INDEX tx_calls_mod_sender ON tx_calls_mod (sender, package, module, tx_sequence_number);
+
+CREATE TABLE tx_calls_fun (
+    tx_sequence_number          BIGINT       NOT NULL,
+    package                     BLOB         NOT NULL,
+    module                      TEXT         NOT NULL,
+    func                        TEXT         NOT NULL,
+    sender                      BLOB         NOT NULL,
+    PRIMARY KEY(package, module, func, tx_sequence_number)
+);
+CREATE INDEX tx_calls_fun_sender ON tx_calls_fun (sender, package, module, func, tx_sequence_number);
+
+CREATE TABLE tx_digests (
+    tx_digest                   BLOB         PRIMARY KEY,
+    tx_sequence_number          BIGINT       NOT NULL
+);
+CREATE INDEX tx_digests_tx_sequence_number ON tx_digests (tx_sequence_number);
+
+CREATE TABLE tx_kinds (
+    tx_sequence_number          BIGINT       NOT NULL,
+    tx_kind                     SMALLINT     NOT NULL,
+    PRIMARY KEY(tx_kind, tx_sequence_number)
+);
+
+CREATE TABLE display
+(
+    object_type     TEXT        PRIMARY KEY,
+    id              BLOB        NOT NULL,
+    version         SMALLINT    NOT NULL,
+    bcs             BLOB        NOT NULL
+);
+
+CREATE TABLE event_emit_package
+(
+    package                     BLOB    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_emit_package_sender ON event_emit_package (sender, package, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_emit_module
+(
+    package                     BLOB    NOT NULL,
+    module                      TEXT    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_emit_module_sender ON event_emit_module (sender, package, module, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_package
+(
+    package                     BLOB    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_package_sender ON event_struct_package (sender, package, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_module
+(
+    package                     BLOB    NOT NULL,
+    module                      TEXT    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, module, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_module_sender ON event_struct_module (sender, package, module, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_name
+(
+    package                     BLOB    NOT NULL,
+    module                      TEXT    NOT NULL,
+    type_name                   TEXT    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, module, type_name, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_name_sender ON event_struct_name (sender, package, module, type_name, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_struct_instantiation
+(
+    package                     BLOB    NOT NULL,
+    module                      TEXT    NOT NULL,
+    type_instantiation          TEXT    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    sender                      BLOB    NOT NULL,
+    PRIMARY KEY(package, module, type_instantiation, tx_sequence_number, event_sequence_number)
+);
+CREATE INDEX event_struct_instantiation_sender ON event_struct_instantiation (sender, package, module, type_instantiation, tx_sequence_number, event_sequence_number);
+
+CREATE TABLE event_senders
+(
+    sender                      BLOB    NOT NULL,
+    tx_sequence_number          BIGINT  NOT NULL,
+    event_sequence_number       BIGINT  NOT NULL,
+    PRIMARY KEY(sender, tx_sequence_number, event_sequence_number)
+);
+
+CREATE TABLE chain_identifier
+(
+    checkpoint_digest   BLOB     NOT NULL,
+    PRIMARY KEY(checkpoint_digest)
+);
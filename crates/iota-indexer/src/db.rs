@@ -5,7 +5,7 @@
 use std::time::Duration;
 
 use anyhow::anyhow;
-#[cfg(feature = "postgres-feature")]
+#[cfg(any(feature = "postgres-feature", feature = "sqlite-feature"))]
 use diesel::query_dsl::RunQueryDsl;
 use diesel::{
     connection::BoxableConnection,
@@ -115,7 +115,46 @@ impl<T: R2D2Connection + 'static> diesel::r2d2::CustomizeConnection<T, diesel::r
                 )?;
             Ok(())
         }
-        #[cfg(not(feature = "postgres-feature"))]
+        #[cfg(feature = "sqlite-feature")]
+        #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+        {
+            _conn
+                .as_any_mut()
+                .downcast_mut::<diesel::SqliteConnection>()
+                .map_or_else(
+                    || {
+                        Err(diesel::r2d2::Error::QueryError(
+                            diesel::result::Error::DeserializationError(
+                                "Failed to downcast connection to SqliteConnection"
+                                    .to_string()
+                                    .into(),
+                            ),
+                        ))
+                    },
+                    |sqlite_conn| {
+                        // Readers and the writer share a single database file, so
+                        // let them wait on each other's locks rather than fail.
+                        diesel::sql_query(format!(
+                            "PRAGMA busy_timeout = {}",
+                            self.statement_timeout.as_millis(),
+                        ))
+                        .execute(sqlite_conn)
+                        .map_err(diesel::r2d2::Error::QueryError)?;
+                        diesel::sql_query("PRAGMA journal_mode = WAL")
+                            .execute(sqlite_conn)
+                            .map_err(diesel::r2d2::Error::QueryError)?;
+
+                        if self.read_only {
+                            diesel::sql_query("PRAGMA query_only = 1")
+                                .execute(sqlite_conn)
+                                .map_err(diesel::r2d2::Error::QueryError)?;
+                        }
+                        Ok(())
+                    },
+                )?;
+            Ok(())
+        }
+        #[cfg(not(any(feature = "postgres-feature", feature = "sqlite-feature")))]
         {
             Ok(())
         }
@@ -189,6 +228,19 @@ pub fn reset_database<T: R2D2Connection + Send + 'static>(
                 },
             )?;
     }
+    #[cfg(feature = "sqlite-feature")]
+    #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+    {
+        conn.as_any_mut()
+            .downcast_mut::<PoolConnection<diesel::SqliteConnection>>()
+            .map_or_else(
+                || Err(anyhow!("Failed to downcast connection to SqliteConnection")),
+                |sqlite_conn| {
+                    setup_sqlite::reset_database(sqlite_conn)?;
+                    Ok(())
+                },
+            )?;
+    }
     Ok(())
 }
 
@@ -478,3 +530,128 @@ pub mod setup_mysql {
         Ok(())
     }
 }
+
+#[cfg(feature = "sqlite-feature")]
+#[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+pub mod setup_sqlite {
+    use anyhow::anyhow;
+    use diesel::{RunQueryDsl, SqliteConnection, migration::MigrationSource};
+    use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+    use prometheus::Registry;
+    use secrecy::ExposeSecret;
+    use tracing::{error, info};
+
+    use crate::{
+        IndexerConfig,
+        db::{PoolConnection, get_pool_connection, new_connection_pool},
+        errors::IndexerError,
+        indexer::Indexer,
+        metrics::IndexerMetrics,
+        store::PgIndexerStore,
+    };
+
+    const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite");
+
+    pub fn reset_database(
+        conn: &mut PoolConnection<SqliteConnection>,
+    ) -> Result<(), anyhow::Error> {
+        info!("Resetting SQLite database ...");
+
+        let object_names: Vec<(String, String)> =
+            diesel::dsl::sql::<(diesel::sql_types::Text, diesel::sql_types::Text)>(
+                "SELECT type, name FROM sqlite_master \
+                WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%'",
+            )
+            .load(conn)?;
+        for (object_type, object_name) in object_names {
+            let drop_query = format!("DROP {} IF EXISTS {}", object_type, object_name);
+            diesel::sql_query(drop_query).execute(conn)?;
+        }
+        info!("Drop tables complete.");
+
+        diesel::sql_query(
+            "
+            CREATE TABLE __diesel_schema_migrations (
+                version VARCHAR(50) PRIMARY KEY NOT NULL,
+                run_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+        ",
+        )
+        .execute(conn)?;
+        info!("Created __diesel_schema_migrations table.");
+
+        conn.run_migrations(&MIGRATIONS.migrations().unwrap())
+            .map_err(|e| anyhow!("Failed to run migrations {e}"))?;
+        info!("All migrations complete, reset database complete");
+        Ok(())
+    }
+
+    pub async fn setup(
+        indexer_config: IndexerConfig,
+        registry: Registry,
+    ) -> Result<(), IndexerError> {
+        let db_url_secret = indexer_config.get_db_url().map_err(|e| {
+            IndexerError::PgPoolConnection(format!(
+                "Failed parsing database url with error {:?}",
+                e
+            ))
+        })?;
+        let db_url = db_url_secret.expose_secret();
+        let blocking_cp = new_connection_pool::<SqliteConnection>(db_url, None).map_err(|e| {
+            error!("Failed creating SQLite connection pool with error {:?}", e);
+            e
+        })?;
+        info!("SQLite database connection pool is created at {}", db_url);
+        let mut conn = get_pool_connection(&blocking_cp).map_err(|e| {
+            error!(
+                "Failed getting SQLite connection from connection pool with error {:?}",
+                e
+            );
+            e
+        })?;
+        if indexer_config.reset_db {
+            reset_database(&mut conn).map_err(|e| {
+                let db_err_msg = format!(
+                    "Failed resetting database with url: {:?} and error: {:?}",
+                    db_url, e
+                );
+                error!("{}", db_err_msg);
+                IndexerError::PostgresReset(db_err_msg)
+            })?;
+            info!("Reset SQLite database complete.");
+        } else {
+            // A fresh database file has no schema yet, so bring it up to date
+            // instead of requiring a separate setup step.
+            conn.run_pending_migrations(MIGRATIONS).map_err(|e| {
+                let db_err_msg = format!(
+                    "Failed running migrations on database with url: {:?} and error: {:?}",
+                    db_url, e
+                );
+                error!("{}", db_err_msg);
+                IndexerError::PostgresReset(db_err_msg)
+            })?;
+        }
+        drop(conn);
+        let indexer_metrics = IndexerMetrics::new(&registry);
+        iota_metrics::init_metrics(&registry);
+
+        if indexer_config.fullnode_sync_worker {
+            let store =
+                PgIndexerStore::<SqliteConnection>::new(blocking_cp, indexer_metrics.clone());
+            return Indexer::start_writer::<PgIndexerStore<SqliteConnection>, SqliteConnection>(
+                &indexer_config,
+                store,
+                indexer_metrics,
+            )
+            .await;
+        } else if indexer_config.rpc_server_worker {
+            return Indexer::start_reader::<SqliteConnection>(
+                &indexer_config,
+                &registry,
+                db_url.to_string(),
+            )
+            .await;
+        }
+        Ok(())
+    }
+}
@@ -816,64 +816,55 @@ impl<U: R2D2Connection> IndexerReader<U> {
                 module,
                 function,
             }) => {
-                let package = Hex::encode(package.to_vec());
+                let package = bytes_literal(&package.to_vec());
                 match (module, function) {
                     (Some(module), Some(function)) => (
                         "tx_calls_fun".into(),
                         format!(
-                            "package = '\\x{}'::bytea AND module = '{}' AND func = '{}'",
+                            "package = {} AND module = '{}' AND func = '{}'",
                             package, module, function
                         ),
                     ),
                     (Some(module), None) => (
                         "tx_calls_mod".into(),
-                        format!(
-                            "package = '\\x{}'::bytea AND module = '{}'",
-                            package, module
-                        ),
+                        format!("package = {} AND module = '{}'", package, module),
                     ),
                     (None, Some(_)) => {
                         return Err(IndexerError::InvalidArgument(
                             "Function cannot be present without Module.".into(),
                         ));
                     }
-                    (None, None) => (
-                        "tx_calls_pkg".into(),
-                        format!("package = '\\x{}'::bytea", package),
-                    ),
+                    (None, None) => ("tx_calls_pkg".into(), format!("package = {}", package)),
                 }
             }
             Some(TransactionFilter::InputObject(object_id)) => {
-                let object_id = Hex::encode(object_id.to_vec());
+                let object_id = bytes_literal(&object_id.to_vec());
                 (
                     "tx_input_objects".into(),
-                    format!("object_id = '\\x{}'::bytea", object_id),
+                    format!("object_id = {}", object_id),
                 )
             }
             Some(TransactionFilter::ChangedObject(object_id)) => {
-                let object_id = Hex::encode(object_id.to_vec());
+                let object_id = bytes_literal(&object_id.to_vec());
                 (
                     "tx_changed_objects".into(),
-                    format!("object_id = '\\x{}'::bytea", object_id),
+                    format!("object_id = {}", object_id),
                 )
             }
             Some(TransactionFilter::FromAddress(from_address)) => {
-                let from_address = Hex::encode(from_address.to_vec());
-                (
-                    "tx_senders".into(),
-                    format!("sender = '\\x{}'::bytea", from_address),
-                )
+                let from_address = bytes_literal(&from_address.to_vec());
+                ("tx_senders".into(), format!("sender = {}", from_address))
             }
             Some(TransactionFilter::ToAddress(to_address)) => {
-                let to_address = Hex::encode(to_address.to_vec());
+                let to_address = bytes_literal(&to_address.to_vec());
                 (
                     "tx_recipients".into(),
-                    format!("recipient = '\\x{}'::bytea", to_address),
+                    format!("recipient = {}", to_address),
                 )
             }
             Some(TransactionFilter::FromAndToAddress { from, to }) => {
-                let from_address = Hex::encode(from.to_vec());
-                let to_address = Hex::encode(to.to_vec());
+                let from_address = bytes_literal(&from.to_vec());
+                let to_address = bytes_literal(&to.to_vec());
                 // Need to remove ambiguities for tx_sequence_number column
                 let cursor_clause = if let Some(cursor_tx_seq) = cursor_tx_seq {
                     if is_descending {
@@ -895,8 +886,8 @@ impl<U: R2D2Connection> IndexerReader<U> {
                     FROM tx_senders \
                     JOIN tx_recipients \
                     ON tx_senders.{TX_SEQUENCE_NUMBER_STR} = tx_recipients.{TX_SEQUENCE_NUMBER_STR} \
-                    WHERE tx_senders.sender = '\\x{}'::BYTEA \
-                    AND tx_recipients.recipient = '\\x{}'::BYTEA \
+                    WHERE tx_senders.sender = {} \
+                    AND tx_recipients.recipient = {} \
                     {} \
                     ORDER BY {TX_SEQUENCE_NUMBER_STR} {} \
                     LIMIT {}) AS inner_query
@@ -910,19 +901,49 @@ impl<U: R2D2Connection> IndexerReader<U> {
                 (inner_query, "1 = 1".into())
             }
             Some(TransactionFilter::FromOrToAddress { addr }) => {
-                let address = Hex::encode(addr.to_vec());
+                let address = bytes_literal(&addr.to_vec());
+                // SQLite does not accept parenthesized operands of a compound
+                // select, so each side of the union is wrapped in a subquery.
+                #[cfg(feature = "sqlite-feature")]
+                #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+                let inner_query = format!(
+                    "( \
+                        SELECT * FROM ( \
+                            SELECT {TX_SEQUENCE_NUMBER_STR} FROM tx_senders \
+                            WHERE sender = {} {} \
+                            ORDER BY {TX_SEQUENCE_NUMBER_STR} {} \
+                            LIMIT {} \
+                        ) AS senders \
+                        UNION \
+                        SELECT * FROM ( \
+                            SELECT {TX_SEQUENCE_NUMBER_STR} FROM tx_recipients \
+                            WHERE recipient = {} {} \
+                            ORDER BY {TX_SEQUENCE_NUMBER_STR} {} \
+                            LIMIT {} \
+                        ) AS recipients \
+                    ) AS combined",
+                    address,
+                    cursor_clause,
+                    order_str,
+                    limit,
+                    address,
+                    cursor_clause,
+                    order_str,
+                    limit,
+                );
+                #[cfg(any(feature = "postgres-feature", feature = "mysql-feature"))]
                 let inner_query = format!(
                     "( \
                         ( \
                             SELECT {TX_SEQUENCE_NUMBER_STR} FROM tx_senders \
-                            WHERE sender = '\\x{}'::BYTEA {} \
+                            WHERE sender = {} {} \
                             ORDER BY {TX_SEQUENCE_NUMBER_STR} {} \
                             LIMIT {} \
                         ) \
                         UNION \
                         ( \
                             SELECT {TX_SEQUENCE_NUMBER_STR} FROM tx_recipients \
-                            WHERE recipient = '\\x{}'::BYTEA {} \
+                            WHERE recipient = {} {} \
                             ORDER BY {TX_SEQUENCE_NUMBER_STR} {} \
                             LIMIT {} \
                         ) \
@@ -1067,12 +1088,12 @@ impl<U: R2D2Connection> IndexerReader<U> {
             FROM EVENTS e \
             JOIN TRANSACTIONS t \
             ON t.tx_sequence_number = e.tx_sequence_number \
-            AND t.transaction_digest = '\\x{}'::bytea \
+            AND t.transaction_digest = {} \
             WHERE {cursor} \
             ORDER BY e.{EVENT_SEQUENCE_NUMBER_STR} {order_clause} \
             LIMIT {limit}
             ",
-            Hex::encode(tx_digest.into_inner()),
+            bytes_literal(&tx_digest.into_inner()),
         ))
     }
 
@@ -1136,12 +1157,12 @@ impl<U: R2D2Connection> IndexerReader<U> {
                     FROM tx_senders s
                     JOIN events e
                     ON e.tx_sequence_number = s.tx_sequence_number
-                    AND s.sender = '\\x{}'::bytea
+                    AND s.sender = {}
                     WHERE {} \
                     ORDER BY {} \
                     LIMIT {}
                 )",
-                Hex::encode(sender.to_vec()),
+                bytes_literal(&sender.to_vec()),
                 cursor_clause,
                 order_clause,
                 limit,
@@ -1151,12 +1172,12 @@ impl<U: R2D2Connection> IndexerReader<U> {
         } else {
             let main_where_clause = match filter {
                 EventFilter::Package(package_id) => {
-                    format!("package = '\\x{}'::bytea", package_id.to_hex())
+                    format!("package = {}", bytes_literal(&package_id.to_vec()))
                 }
                 EventFilter::MoveModule { package, module } => {
                     format!(
-                        "package = '\\x{}'::bytea AND module = '{}'",
-                        package.to_hex(),
+                        "package = {} AND module = '{}'",
+                        bytes_literal(&package.to_vec()),
                         module,
                     )
                 }
@@ -1490,13 +1511,13 @@ impl<U: R2D2Connection> IndexerReader<U> {
             CAST(SUM(coin_balance) AS BIGINT) AS coin_balance \
             FROM objects \
             WHERE owner_type = {} \
-            AND owner_id = '\\x{}'::BYTEA \
+            AND owner_id = {} \
             AND coin_type {} \
             GROUP BY coin_type \
             ORDER BY coin_type ASC
         ",
             OwnerType::Address as i16,
-            Hex::encode(owner.to_vec()),
+            bytes_literal(&owner.to_vec()),
             coin_type_filter,
         );
 
@@ -1819,3 +1840,16 @@ fn get_single_obj_id_from_package_publish<U: R2D2Connection>(
         Ok(None)
     }
 }
+
+/// Formats `bytes` as a blob literal for raw SQL queries. SQLite uses its own
+/// blob syntax, the other backends keep the `bytea` cast.
+fn bytes_literal(bytes: &[u8]) -> String {
+    if cfg!(all(
+        feature = "sqlite-feature",
+        not(any(feature = "postgres-feature", feature = "mysql-feature"))
+    )) {
+        format!("X'{}'", Hex::encode(bytes))
+    } else {
+        format!("'\\x{}'::bytea", Hex::encode(bytes))
+    }
+}
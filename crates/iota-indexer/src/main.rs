@@ -38,5 +38,9 @@ async fn main() -> Result<(), IndexerError> {
     #[cfg(feature = "mysql-feature")]
     #[cfg(not(feature = "postgres-feature"))]
     iota_indexer::db::setup_mysql::setup(indexer_config, registry).await?;
+
+    #[cfg(feature = "sqlite-feature")]
+    #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+    iota_indexer::db::setup_sqlite::setup(indexer_config, registry).await?;
     Ok(())
 }
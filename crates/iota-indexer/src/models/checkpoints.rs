@@ -29,7 +29,7 @@ pub struct StoredCheckpoint {
     pub end_of_epoch: bool,
     #[cfg(feature = "postgres-feature")]
     pub tx_digests: Vec<Option<Vec<u8>>>,
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     #[diesel(sql_type = diesel::sql_types::Json)]
     pub tx_digests: serde_json::Value,
@@ -58,7 +58,7 @@ impl From<&IndexedCheckpoint> for StoredCheckpoint {
                 .iter()
                 .map(|tx| Some(tx.into_inner().to_vec()))
                 .collect(),
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
             tx_digests: serde_json::to_value(
                 c.tx_digests
@@ -134,7 +134,7 @@ impl TryFrom<StoredCheckpoint> for RpcCheckpoint {
                     })
                     .collect::<Result<Vec<TransactionDigest>, IndexerError>>()?
             }
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
             {
                 checkpoint
@@ -152,8 +152,15 @@ impl TryFrom<StoredCheckpoint> for RpcCheckpoint {
                                 "tx_digests should not contain null elements".to_string(),
                             ))
                         }
-                        serde_json::Value::String(tx_digest) => {
-                            TransactionDigest::try_from(tx_digest.as_bytes()).map_err(|e| {
+                        serde_json::Value::Array(_) => {
+                            let tx_digest: Vec<u8> = serde_json::from_value(tx_digest.clone())
+                                .map_err(|e| {
+                                    IndexerError::PersistentStorageDataCorruption(format!(
+                                        "Failed to parse transaction digest bytes with err: {:?}",
+                                        e
+                                    ))
+                                })?;
+                            TransactionDigest::try_from(tx_digest.as_slice()).map_err(|e| {
                                 IndexerError::PersistentStorageDataCorruption(format!(
                                     "Failed to decode transaction digest: {:?} with err: {:?}",
                                     tx_digest, e
@@ -161,7 +168,7 @@ impl TryFrom<StoredCheckpoint> for RpcCheckpoint {
                             })
                         }
                         _ => Err(IndexerError::PersistentStorageDataCorruption(
-                            "tx_digests should contain only string elements".to_string(),
+                            "tx_digests should contain only byte array elements".to_string(),
                         )),
                     })
                     .collect::<Result<Vec<TransactionDigest>, IndexerError>>()?
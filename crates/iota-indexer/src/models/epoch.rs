@@ -14,7 +14,10 @@ use crate::{
 
 #[derive(Queryable, Insertable, Debug, Clone, Default)]
 #[diesel(table_name = epochs)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[cfg_attr(
+    feature = "postgres-feature",
+    diesel(check_for_backend(diesel::pg::Pg))
+)]
 pub struct StoredEpochInfo {
     pub epoch: i64,
     pub first_checkpoint_id: i64,
@@ -54,7 +57,10 @@ pub struct StoredFeatureFlag {
 
 #[derive(Queryable, Selectable, Clone)]
 #[diesel(table_name = epochs)]
-#[diesel(check_for_backend(diesel::pg::Pg))]
+#[cfg_attr(
+    feature = "postgres-feature",
+    diesel(check_for_backend(diesel::pg::Pg))
+)]
 pub struct QueryableEpochInfo {
     pub epoch: i64,
     pub first_checkpoint_id: i64,
@@ -34,7 +34,7 @@ pub struct StoredEvent {
     #[diesel(sql_type = diesel::sql_types::Array<diesel::sql_types::Nullable<diesel::pg::sql_types::Bytea>>)]
     pub senders: Vec<Option<Vec<u8>>>,
 
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     #[diesel(sql_type = diesel::sql_types::Json)]
     pub senders: serde_json::Value,
@@ -58,13 +58,15 @@ pub struct StoredEvent {
 #[cfg(feature = "postgres-feature")]
 pub type SendersType = Vec<Option<Vec<u8>>>;
 
-#[cfg(feature = "postgres-feature")]
+#[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
 #[cfg(not(feature = "postgres-feature"))]
 pub type SendersType = serde_json::Value;
 
-impl From<IndexedEvent> for StoredEvent {
-    fn from(event: IndexedEvent) -> Self {
-        Self {
+impl TryFrom<IndexedEvent> for StoredEvent {
+    type Error = IndexerError;
+
+    fn try_from(event: IndexedEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
             tx_sequence_number: event.tx_sequence_number as i64,
             event_sequence_number: event.event_sequence_number as i64,
             transaction_digest: event.transaction_digest.into_inner().to_vec(),
@@ -74,15 +76,17 @@ impl From<IndexedEvent> for StoredEvent {
                 .into_iter()
                 .map(|sender| Some(sender.to_vec()))
                 .collect(),
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
-            senders: serde_json::to_value(event.senders).unwrap(),
+            senders: serde_json::to_value(event.senders).map_err(|e| {
+                IndexerError::Serde(format!("Failed to serialize event senders: {e}"))
+            })?,
             package: event.package.to_vec(),
             module: event.module.clone(),
             event_type: event.event_type.clone(),
             bcs: event.bcs.clone(),
             timestamp_ms: event.timestamp_ms as i64,
-        }
+        })
     }
 }
 
@@ -126,6 +130,24 @@ impl StoredEvent {
                     .as_str()
                     .map(|s| s.as_bytes().to_vec())
             }
+            #[cfg(feature = "sqlite-feature")]
+            #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+            {
+                // The senders are stored as a JSON array of hex encoded addresses.
+                serde_json::from_value::<Vec<IotaAddress>>(self.senders.clone())
+                    .map_err(|e| {
+                        IndexerError::PersistentStorageDataCorruption(format!(
+                            "Failed to parse event senders: {e}"
+                        ))
+                    })?
+                    .first()
+                    .map(|sender| Some(sender.to_vec()))
+                    .ok_or_else(|| {
+                        IndexerError::PersistentStorageDataCorruption(
+                            "Event senders should contain at least one address".to_string(),
+                        )
+                    })?
+            }
         };
         let sender = match sender {
             Some(ref s) => IotaAddress::from_bytes(s).map_err(|_e| {
@@ -202,7 +224,7 @@ mod tests {
 
         let indexed_event = IndexedEvent::from_event(1, 1, 1, tx_digest, &event, 100);
 
-        let stored_event = StoredEvent::from(indexed_event);
+        let stored_event = StoredEvent::try_from(indexed_event).unwrap();
 
         assert_eq!(
             stored_event.event_type,
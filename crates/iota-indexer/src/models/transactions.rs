@@ -24,11 +24,15 @@ use move_core_types::{
 
 use crate::{
     db::ConnectionPool,
-    errors::{Context, IndexerError},
-    models::large_objects::{get_large_object_in_chunks, put_large_object_in_chunks},
+    errors::IndexerError,
     schema::transactions,
     types::{IndexedObjectChange, IndexedTransaction, IndexerResult},
 };
+#[cfg(feature = "postgres-feature")]
+use crate::{
+    errors::Context,
+    models::large_objects::{get_large_object_in_chunks, put_large_object_in_chunks},
+};
 
 #[derive(Clone, Debug, Queryable, Insertable, QueryableByName, Selectable)]
 #[diesel(table_name = transactions)]
@@ -43,19 +47,19 @@ pub struct StoredTransaction {
     pub timestamp_ms: i64,
     #[cfg(feature = "postgres-feature")]
     pub object_changes: Vec<Option<Vec<u8>>>,
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     #[diesel(sql_type = diesel::sql_types::Json)]
     pub object_changes: serde_json::Value,
     #[cfg(feature = "postgres-feature")]
     pub balance_changes: Vec<Option<Vec<u8>>>,
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     #[diesel(sql_type = diesel::sql_types::Json)]
     pub balance_changes: serde_json::Value,
     #[cfg(feature = "postgres-feature")]
     pub events: Vec<Option<Vec<u8>>>,
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     #[diesel(sql_type = diesel::sql_types::Json)]
     pub events: serde_json::Value,
@@ -66,7 +70,7 @@ pub struct StoredTransaction {
 #[cfg(feature = "postgres-feature")]
 pub type StoredTransactionEvents = Vec<Option<Vec<u8>>>;
 
-#[cfg(feature = "mysql-feature")]
+#[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
 #[cfg(not(feature = "postgres-feature"))]
 pub type StoredTransactionEvents = serde_json::Value;
 
@@ -115,7 +119,7 @@ impl From<&IndexedTransaction> for StoredTransaction {
                 .iter()
                 .map(|oc| Some(bcs::to_bytes(&oc).unwrap()))
                 .collect(),
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
             object_changes: serde_json::to_value(
                 tx.object_changes
@@ -130,7 +134,7 @@ impl From<&IndexedTransaction> for StoredTransaction {
                 .iter()
                 .map(|bc| Some(bcs::to_bytes(&bc).unwrap()))
                 .collect(),
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
             balance_changes: serde_json::to_value(
                 tx.balance_change
@@ -145,7 +149,7 @@ impl From<&IndexedTransaction> for StoredTransaction {
                 .iter()
                 .map(|e| Some(bcs::to_bytes(&e).unwrap()))
                 .collect(),
-            #[cfg(feature = "mysql-feature")]
+            #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
             #[cfg(not(feature = "postgres-feature"))]
             events: serde_json::to_value(
                 tx.events
@@ -167,7 +171,7 @@ impl StoredTransaction {
         {
             self.balance_changes.len()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
             self.balance_changes.as_array().unwrap().len()
@@ -179,12 +183,13 @@ impl StoredTransaction {
         {
             self.balance_changes.get(idx).cloned().flatten()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
-            self.balance_changes.as_array().unwrap()[idx]
-                .as_str()
-                .map(|s| s.as_bytes().to_vec())
+            self.balance_changes
+                .get(idx)
+                .cloned()
+                .and_then(|bc| serde_json::from_value(bc).ok())
         }
     }
 
@@ -193,7 +198,7 @@ impl StoredTransaction {
         {
             self.object_changes.len()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
             self.object_changes.as_array().unwrap().len()
@@ -205,12 +210,13 @@ impl StoredTransaction {
         {
             self.object_changes.get(idx).cloned().flatten()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
-            self.object_changes.as_array().unwrap()[idx]
-                .as_str()
-                .map(|s| s.as_bytes().to_vec())
+            self.object_changes
+                .get(idx)
+                .cloned()
+                .and_then(|oc| serde_json::from_value(oc).ok())
         }
     }
 
@@ -219,7 +225,7 @@ impl StoredTransaction {
         {
             self.events.len()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
             self.events.as_array().unwrap().len()
@@ -231,15 +237,17 @@ impl StoredTransaction {
         {
             self.events.get(idx).cloned().flatten()
         }
-        #[cfg(feature = "mysql-feature")]
+        #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
         #[cfg(not(feature = "postgres-feature"))]
         {
-            self.events.as_array().unwrap()[idx]
-                .as_str()
-                .map(|s| s.as_bytes().to_vec())
+            self.events
+                .get(idx)
+                .cloned()
+                .and_then(|e| serde_json::from_value(e).ok())
         }
     }
 
+    #[cfg(feature = "postgres-feature")]
     const LARGE_OBJECT_CHUNK_SIZE: usize = 100 * 1024 * 1024;
     pub async fn try_into_iota_transaction_block_response(
         self,
@@ -302,7 +310,7 @@ impl StoredTransaction {
                         })
                         .collect::<Result<Vec<Event>, IndexerError>>()?
                 }
-                #[cfg(feature = "mysql-feature")]
+                #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
                 #[cfg(not(feature = "postgres-feature"))]
                 {
                     self.events
@@ -317,8 +325,14 @@ impl StoredTransaction {
                             serde_json::Value::Null => Err(IndexerError::PersistentStorageDataCorruption(
                                 "events should not contain null elements".to_string(),
                             )),
-                            serde_json::Value::String(event) => {
-                                let event: Event = bcs::from_bytes(event.as_bytes()).map_err(|e| {
+                            serde_json::Value::Array(_) => {
+                                let event: Vec<u8> = serde_json::from_value(event.clone()).map_err(|e| {
+                                    IndexerError::PersistentStorageDataCorruption(format!(
+                                        "Can't parse event bytes. tx_digest={:?} Error: {e}",
+                                        tx_digest
+                                    ))
+                                })?;
+                                let event: Event = bcs::from_bytes(&event).map_err(|e| {
                                     IndexerError::PersistentStorageDataCorruption(format!(
                                         "Can't convert event bytes into Event. tx_digest={:?} Error: {e}",
                                         tx_digest
@@ -327,7 +341,7 @@ impl StoredTransaction {
                                 Ok(event)
                             }
                             _ => Err(IndexerError::PersistentStorageDataCorruption(
-                                "events should contain only string elements".to_string(),
+                                "events should contain only byte array elements".to_string(),
                             )),
                         })
                         .collect::<Result<Vec<Event>, IndexerError>>()?
@@ -358,7 +372,7 @@ impl StoredTransaction {
                         }
                     }).collect::<Result<Vec<ObjectChange>, IndexerError>>()?
                 }
-                #[cfg(feature = "mysql-feature")]
+                #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
                 #[cfg(not(feature = "postgres-feature"))]
                 {
                     self.object_changes
@@ -373,15 +387,19 @@ impl StoredTransaction {
                             serde_json::Value::Null => Err(IndexerError::PersistentStorageDataCorruption(
                                 "object_changes should not contain null elements".to_string(),
                             )),
-                            serde_json::Value::String(object_change) => {
-                                let object_change: IndexedObjectChange = bcs::from_bytes(object_change.as_bytes())
+                            serde_json::Value::Array(_) => {
+                                let object_change: Vec<u8> = serde_json::from_value(object_change.clone())
+                                    .map_err(|e| IndexerError::PersistentStorageDataCorruption(
+                                        format!("Can't parse object_change bytes. tx_digest={:?} Error: {e}", tx_digest)
+                                    ))?;
+                                let object_change: IndexedObjectChange = bcs::from_bytes(&object_change)
                                     .map_err(|e| IndexerError::PersistentStorageDataCorruption(
                                         format!("Can't convert object_change bytes into IndexedObjectChange. tx_digest={:?} Error: {e}", tx_digest)
                                     ))?;
                                 Ok(ObjectChange::from(object_change))
                             }
                             _ => Err(IndexerError::PersistentStorageDataCorruption(
-                                "object_changes should contain only string elements".to_string(),
+                                "object_changes should contain only byte array elements".to_string(),
                             )),
                         })
                         .collect::<Result<Vec<ObjectChange>, IndexerError>>()?
@@ -409,7 +427,7 @@ impl StoredTransaction {
                         }
                     }).collect::<Result<Vec<BalanceChange>, IndexerError>>()?
                 }
-                #[cfg(feature = "mysql-feature")]
+                #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
                 #[cfg(not(feature = "postgres-feature"))]
                 {
                     self.balance_changes
@@ -424,15 +442,19 @@ impl StoredTransaction {
                             serde_json::Value::Null => Err(IndexerError::PersistentStorageDataCorruption(
                                 "balance_changes should not contain null elements".to_string(),
                             )),
-                            serde_json::Value::String(balance_change) => {
-                                let balance_change: BalanceChange = bcs::from_bytes(balance_change.as_bytes())
+                            serde_json::Value::Array(_) => {
+                                let balance_change: Vec<u8> = serde_json::from_value(balance_change.clone())
+                                    .map_err(|e| IndexerError::PersistentStorageDataCorruption(
+                                        format!("Can't parse balance_change bytes. tx_digest={:?} Error: {e}", tx_digest)
+                                    ))?;
+                                let balance_change: BalanceChange = bcs::from_bytes(&balance_change)
                                     .map_err(|e| IndexerError::PersistentStorageDataCorruption(
                                         format!("Can't convert balance_change bytes into BalanceChange. tx_digest={:?} Error: {e}", tx_digest)
                                     ))?;
                                 Ok(balance_change)
                             }
                             _ => Err(IndexerError::PersistentStorageDataCorruption(
-                                "balance_changes should contain only string elements".to_string(),
+                                "balance_changes should contain only byte array elements".to_string(),
                             )),
                         })
                         .collect::<Result<Vec<BalanceChange>, IndexerError>>()?
@@ -632,6 +654,27 @@ impl StoredTransaction {
     }
 }
 
+#[cfg(not(feature = "postgres-feature"))]
+impl StoredTransaction {
+    /// Large objects are only supported by postgres, other backends store the
+    /// genesis transaction data inline.
+    pub fn store_inner_genesis_data_as_large_object<T: R2D2Connection + Send + 'static>(
+        self,
+        _pool: &ConnectionPool<T>,
+    ) -> Result<Self, IndexerError> {
+        Ok(self)
+    }
+
+    /// Large objects are only supported by postgres, other backends already
+    /// hold the genesis transaction data inline.
+    pub fn set_genesis_large_object_as_inner_data<T: R2D2Connection + Send + 'static>(
+        self,
+        _pool: &ConnectionPool<T>,
+    ) -> Result<Self, IndexerError> {
+        Ok(self)
+    }
+}
+
 pub fn stored_events_to_events(
     stored_events: StoredTransactionEvents,
 ) -> Result<Vec<Event>, IndexerError> {
@@ -654,7 +697,7 @@ pub fn stored_events_to_events(
             })
             .collect::<Result<Vec<Event>, IndexerError>>()
     }
-    #[cfg(feature = "mysql-feature")]
+    #[cfg(any(feature = "mysql-feature", feature = "sqlite-feature"))]
     #[cfg(not(feature = "postgres-feature"))]
     {
         stored_events
@@ -669,8 +712,13 @@ pub fn stored_events_to_events(
                 serde_json::Value::Null => Err(IndexerError::PersistentStorageDataCorruption(
                     "events should not contain null elements".to_string(),
                 )),
-                serde_json::Value::String(event) => {
-                    let event: Event = bcs::from_bytes(event.as_bytes()).map_err(|e| {
+                serde_json::Value::Array(_) => {
+                    let event: Vec<u8> = serde_json::from_value(event.clone()).map_err(|e| {
+                        IndexerError::PersistentStorageDataCorruption(format!(
+                            "Can't parse event bytes. Error: {e}",
+                        ))
+                    })?;
+                    let event: Event = bcs::from_bytes(&event).map_err(|e| {
                         IndexerError::PersistentStorageDataCorruption(format!(
                             "Can't convert event bytes into Event. Error: {e}",
                         ))
@@ -678,7 +726,7 @@ pub fn stored_events_to_events(
                     Ok(event)
                 }
                 _ => Err(IndexerError::PersistentStorageDataCorruption(
-                    "events should contain only string elements".to_string(),
+                    "events should contain only byte array elements".to_string(),
                 )),
             })
             .collect::<Result<Vec<Event>, IndexerError>>()
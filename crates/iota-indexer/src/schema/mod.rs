@@ -11,6 +11,10 @@ mod mysql;
 #[cfg(feature = "postgres-feature")]
 mod pg;
 
+#[cfg(feature = "sqlite-feature")]
+#[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+mod sqlite;
+
 #[cfg(feature = "postgres-feature")]
 mod inner {
     pub use crate::schema::pg::{
@@ -37,7 +41,24 @@ mod inner {
     };
 }
 
-#[cfg(feature = "postgres-feature")]
+#[cfg(feature = "sqlite-feature")]
+#[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+mod inner {
+    pub use crate::schema::sqlite::{
+        active_addresses, address_metrics, addresses, chain_identifier, checkpoints, display,
+        epoch_peak_tps, epochs, event_emit_module, event_emit_package, event_senders,
+        event_struct_instantiation, event_struct_module, event_struct_name, event_struct_package,
+        events, feature_flags, move_call_metrics, move_calls, objects, objects_history,
+        objects_snapshot, objects_version, packages, protocol_configs, pruner_cp_watermark,
+        transactions, tx_calls_fun, tx_calls_mod, tx_calls_pkg, tx_changed_objects,
+        tx_count_metrics, tx_digests, tx_input_objects, tx_kinds, tx_recipients, tx_senders,
+    };
+}
+
+#[cfg(any(
+    feature = "postgres-feature",
+    all(feature = "sqlite-feature", not(feature = "mysql-feature"))
+))]
 pub use inner::{
     active_addresses, address_metrics, addresses, epoch_peak_tps, move_call_metrics, move_calls,
     tx_count_metrics,
@@ -4,12 +4,14 @@
 
 pub(crate) use indexer_analytics_store::IndexerAnalyticalStore;
 pub(crate) use indexer_store::*;
+#[cfg(feature = "postgres-feature")]
 pub use pg_indexer_analytical_store::PgIndexerAnalyticalStore;
 pub use pg_indexer_store::PgIndexerStore;
 
 mod indexer_analytics_store;
 pub mod indexer_store;
 pub mod package_resolver;
+#[cfg(feature = "postgres-feature")]
 mod pg_indexer_analytical_store;
 mod pg_indexer_store;
 pub mod pg_partition_manager;
@@ -30,7 +32,7 @@ pub mod diesel_macro {
                 pool_conn
                     .as_any_mut()
                     .downcast_mut::<PoolConnection<diesel::PgConnection>>()
-                    .unwrap()
+                    .expect("connection should be a PgConnection in Postgres builds")
                     .build_transaction()
                     .read_only()
                     .repeatable_read()
@@ -44,7 +46,19 @@ pub mod diesel_macro {
                 pool_conn
                     .as_any_mut()
                     .downcast_mut::<PoolConnection<diesel::MysqlConnection>>()
-                    .unwrap()
+                    .expect("connection should be a MysqlConnection in MySQL builds")
+                    .transaction($query)
+                    .map_err(|e| IndexerError::PostgresRead(e.to_string()))
+            }
+            #[cfg(feature = "sqlite-feature")]
+            #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+            {
+                use diesel::Connection;
+                let mut pool_conn = get_pool_connection($pool)?;
+                pool_conn
+                    .as_any_mut()
+                    .downcast_mut::<PoolConnection<diesel::SqliteConnection>>()
+                    .expect("connection should be a SqliteConnection in SQLite builds")
                     .transaction($query)
                     .map_err(|e| IndexerError::PostgresRead(e.to_string()))
             }
@@ -62,7 +76,7 @@ pub mod diesel_macro {
                 pool_conn
                     .as_any_mut()
                     .downcast_mut::<PoolConnection<diesel::PgConnection>>()
-                    .unwrap()
+                    .expect("connection should be a PgConnection in Postgres builds")
                     .build_transaction()
                     .read_only()
                     .run($query)
@@ -76,7 +90,19 @@ pub mod diesel_macro {
                 pool_conn
                     .as_any_mut()
                     .downcast_mut::<PoolConnection<diesel::MysqlConnection>>()
-                    .unwrap()
+                    .expect("connection should be a MysqlConnection in MySQL builds")
+                    .transaction($query)
+                    .map_err(|e| IndexerError::PostgresRead(e.to_string()))
+            }
+            #[cfg(feature = "sqlite-feature")]
+            #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+            {
+                use diesel::Connection;
+                let mut pool_conn = get_pool_connection($pool)?;
+                pool_conn
+                    .as_any_mut()
+                    .downcast_mut::<PoolConnection<diesel::SqliteConnection>>()
+                    .expect("connection should be a SqliteConnection in SQLite builds")
                     .transaction($query)
                     .map_err(|e| IndexerError::PostgresRead(e.to_string()))
             }
@@ -103,7 +129,7 @@ pub mod diesel_macro {
                     pool_conn
                         .as_any_mut()
                         .downcast_mut::<PoolConnection<diesel::PgConnection>>()
-                        .unwrap()
+                        .expect("connection should be a PgConnection in Postgres builds")
                         .build_transaction()
                         .read_write()
                         .run($query)
@@ -130,7 +156,7 @@ pub mod diesel_macro {
                     pool_conn
                         .as_any_mut()
                         .downcast_mut::<PoolConnection<diesel::MysqlConnection>>()
-                        .unwrap()
+                        .expect("connection should be a MysqlConnection in MySQL builds")
                         .transaction($query)
                         .map_err(|e| IndexerError::PostgresRead(e.to_string()))
                         .map_err(|e| {
@@ -144,6 +170,31 @@ pub mod diesel_macro {
                             }
                         })
                 }
+                #[cfg(feature = "sqlite-feature")]
+                #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+                {
+                    use diesel::Connection;
+                    let mut pool_conn =
+                        get_pool_connection($pool).map_err(|e| backoff::Error::Transient {
+                            err: IndexerError::PostgresWrite(e.to_string()),
+                            retry_after: None,
+                        })?;
+                    pool_conn
+                        .as_any_mut()
+                        .downcast_mut::<PoolConnection<diesel::SqliteConnection>>()
+                        .expect("connection should be a SqliteConnection in SQLite builds")
+                        .immediate_transaction($query)
+                        .map_err(|e| {
+                            tracing::error!(
+                                "Error with persisting data into DB: {:?}, retrying...",
+                                e
+                            );
+                            backoff::Error::Transient {
+                                err: IndexerError::PostgresWrite(e.to_string()),
+                                retry_after: None,
+                            }
+                        })
+                }
             }) {
                 Ok(v) => Ok(v),
                 Err(backoff::Error::Transient { err, .. }) => Err(err),
@@ -175,7 +226,7 @@ pub mod diesel_macro {
                         pool_conn
                             .as_any_mut()
                             .downcast_mut::<PoolConnection<diesel::PgConnection>>()
-                            .unwrap()
+                            .expect("connection should be a PgConnection in Postgres builds")
                             .build_transaction()
                             .read_only()
                             .repeatable_read()
@@ -185,7 +236,7 @@ pub mod diesel_macro {
                         pool_conn
                             .as_any_mut()
                             .downcast_mut::<PoolConnection<diesel::PgConnection>>()
-                            .unwrap()
+                            .expect("connection should be a PgConnection in Postgres builds")
                             .build_transaction()
                             .read_only()
                             .run($query)
@@ -199,7 +250,18 @@ pub mod diesel_macro {
                     pool_conn
                         .as_any_mut()
                         .downcast_mut::<PoolConnection<diesel::MysqlConnection>>()
-                        .unwrap()
+                        .expect("connection should be a MysqlConnection in MySQL builds")
+                        .transaction($query)
+                        .map_err(|e| IndexerError::PostgresRead(e.to_string()))
+                }
+                #[cfg(feature = "sqlite-feature")]
+                #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+                {
+                    use diesel::Connection;
+                    pool_conn
+                        .as_any_mut()
+                        .downcast_mut::<PoolConnection<diesel::SqliteConnection>>()
+                        .expect("connection should be a SqliteConnection in SQLite builds")
                         .transaction($query)
                         .map_err(|e| IndexerError::PostgresRead(e.to_string()))
                 }
@@ -232,6 +294,16 @@ pub mod diesel_macro {
                     .map_err(IndexerError::from)
                     .context(error_message)?;
             }
+            #[cfg(feature = "sqlite-feature")]
+            #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+            {
+                diesel::insert_into($table)
+                    .values($values)
+                    .on_conflict_do_nothing()
+                    .execute($conn)
+                    .map_err(IndexerError::from)
+                    .context(error_message)?;
+            }
         }};
     }
 
@@ -261,6 +333,16 @@ pub mod diesel_macro {
                         .execute($conn)?;
                 }
             }
+            #[cfg(feature = "sqlite-feature")]
+            #[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+            {
+                diesel::insert_into($table)
+                    .values($values)
+                    .on_conflict($target)
+                    .do_update()
+                    .set($pg_columns)
+                    .execute($conn)?;
+            }
         }};
     }
 
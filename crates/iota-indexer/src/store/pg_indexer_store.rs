@@ -10,7 +10,7 @@ use std::{
 };
 
 use async_trait::async_trait;
-#[cfg(feature = "postgres-feature")]
+#[cfg(any(feature = "postgres-feature", feature = "sqlite-feature"))]
 use diesel::upsert::excluded;
 use diesel::{
     ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
@@ -630,17 +630,20 @@ impl<T: R2D2Connection + 'static> PgIndexerStore<T> {
     }
 
     fn update_objects_snapshot(&self, start_cp: u64, end_cp: u64) -> Result<(), IndexerError> {
-        let work_mem_gb = std::env::var("INDEXER_PG_WORK_MEM")
-            .unwrap_or_else(|_e| "16".to_string())
-            .parse::<i64>()
-            .unwrap();
-        let pg_work_mem_query_string = format!("SET work_mem = '{}GB'", work_mem_gb);
-        let pg_work_mem_query = pg_work_mem_query_string.as_str();
-        transactional_blocking_with_retry!(
-            &self.blocking_cp,
-            |conn| { RunQueryDsl::execute(diesel::sql_query(pg_work_mem_query), conn,) },
-            PG_DB_COMMIT_SLEEP_DURATION
-        )?;
+        #[cfg(feature = "postgres-feature")]
+        {
+            let work_mem_gb = std::env::var("INDEXER_PG_WORK_MEM")
+                .unwrap_or_else(|_e| "16".to_string())
+                .parse::<i64>()
+                .unwrap();
+            let pg_work_mem_query_string = format!("SET work_mem = '{}GB'", work_mem_gb);
+            let pg_work_mem_query = pg_work_mem_query_string.as_str();
+            transactional_blocking_with_retry!(
+                &self.blocking_cp,
+                |conn| { RunQueryDsl::execute(diesel::sql_query(pg_work_mem_query), conn,) },
+                PG_DB_COMMIT_SLEEP_DURATION
+            )?;
+        }
 
         transactional_blocking_with_retry!(
             &self.blocking_cp,
@@ -805,8 +808,8 @@ impl<T: R2D2Connection + 'static> PgIndexerStore<T> {
         let len = events.len();
         let events = events
             .into_iter()
-            .map(StoredEvent::from)
-            .collect::<Vec<_>>();
+            .map(StoredEvent::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
 
         transactional_blocking_with_retry!(
             &self.blocking_cp,
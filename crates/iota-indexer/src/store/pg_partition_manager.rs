@@ -43,6 +43,20 @@ WHERE TABLE_SCHEMA = DATABASE()
 AND PARTITION_NAME IS NOT NULL
 GROUP BY table_name;
 "
+} else if cfg!(feature = "sqlite-feature")
+    && cfg!(not(any(
+        feature = "postgres-feature",
+        feature = "mysql-feature"
+    )))
+{
+    // SQLite has no table partitioning, so tables are never reported as
+    // partitioned and epoch changes leave them untouched.
+    r"
+SELECT '' AS table_name,
+       0  AS first_partition,
+       0  AS last_partition
+WHERE 0;
+"
 } else {
     ""
 };
@@ -0,0 +1,174 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "sqlite-feature")]
+#[cfg(not(any(feature = "postgres-feature", feature = "mysql-feature")))]
+mod sqlite_tests {
+    use std::{path::PathBuf, sync::Arc, time::Duration};
+
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
+    use iota_config::local_ip_utils::new_local_tcp_socket_for_testing;
+    use iota_indexer::{
+        db::{get_pool_connection, new_connection_pool, reset_database},
+        errors::IndexerError,
+        indexer_reader::IndexerReader,
+        models::{checkpoints::StoredCheckpoint, transactions::StoredTransaction},
+        schema::{checkpoints, transactions},
+        store::{PgIndexerStore, indexer_store::IndexerStore},
+        test_utils::{ReaderWriterConfig, start_test_indexer_impl},
+    };
+    use iota_json_rpc_types::{EventFilter, IotaTransactionBlockResponseOptions};
+    use iota_types::{
+        IOTA_SYSTEM_PACKAGE_ID, base_types::IotaAddress, effects::TransactionEffectsAPI,
+    };
+    use simulacrum::Simulacrum;
+    use tempfile::tempdir;
+    use tokio_util::sync::CancellationToken;
+
+    fn sqlite_db_url() -> String {
+        tempdir()
+            .unwrap()
+            .into_path()
+            .join("indexer.db")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    async fn start_simulacrum_rest_api_with_sqlite_writer(
+        sim: Arc<Simulacrum>,
+        data_ingestion_path: PathBuf,
+        db_url: String,
+    ) -> PgIndexerStore<SqliteConnection> {
+        let server_url = new_local_tcp_socket_for_testing();
+        tokio::spawn(async move {
+            iota_rest_api::RestService::new_without_version(sim)
+                .start_service(server_url)
+                .await;
+        });
+        let (store, _) = start_test_indexer_impl::<SqliteConnection>(
+            Some(db_url),
+            format!("http://{}", server_url),
+            ReaderWriterConfig::writer_mode(None),
+            false,
+            Some(data_ingestion_path),
+            CancellationToken::new(),
+            None,
+        )
+        .await;
+        store
+    }
+
+    async fn indexer_wait_for_checkpoint(
+        store: &PgIndexerStore<SqliteConnection>,
+        checkpoint_sequence_number: u64,
+    ) {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while store
+                .get_latest_checkpoint_sequence_number()
+                .await
+                .unwrap()
+                .map_or(true, |cp| cp < checkpoint_sequence_number)
+            {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .expect("Timeout waiting for indexer to catchup to checkpoint");
+    }
+
+    #[test]
+    fn test_migrations_apply() -> Result<(), IndexerError> {
+        let db_url = sqlite_db_url();
+        let pool = new_connection_pool::<SqliteConnection>(&db_url, None)?;
+        let mut conn = get_pool_connection(&pool)?;
+        reset_database(&mut conn).expect("failed to run the SQLite migrations");
+
+        let applied_migrations: i64 = diesel::dsl::sql::<diesel::sql_types::BigInt>(
+            "SELECT COUNT(*) FROM __diesel_schema_migrations",
+        )
+        .get_result(&mut conn)
+        .unwrap();
+        assert!(applied_migrations > 0);
+
+        // The schema matches the migrated tables, so querying them succeeds.
+        let checkpoints = checkpoints::table
+            .load::<StoredCheckpoint>(&mut conn)
+            .unwrap();
+        assert!(checkpoints.is_empty());
+        let transactions = transactions::table
+            .load::<StoredTransaction>(&mut conn)
+            .unwrap();
+        assert!(transactions.is_empty());
+
+        // Resetting an already migrated database starts over from scratch.
+        reset_database(&mut conn).expect("failed to reset the SQLite database");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ingest_and_read_back() -> Result<(), IndexerError> {
+        let mut sim = Simulacrum::new();
+        let data_ingestion_path = tempdir().unwrap().into_path();
+        sim.set_data_ingestion_path(data_ingestion_path.clone());
+
+        let transfer_recipient = IotaAddress::random_for_testing_only();
+        let (transaction, _) = sim.transfer_txn(transfer_recipient);
+        let (effects, err) = sim.execute_transaction(transaction.clone()).unwrap();
+        assert!(err.is_none());
+        let checkpoint = sim.create_checkpoint();
+        // The epoch change transaction emits the system epoch info event.
+        sim.advance_epoch();
+
+        let db_url = sqlite_db_url();
+        let store = start_simulacrum_rest_api_with_sqlite_writer(
+            Arc::new(sim),
+            data_ingestion_path,
+            db_url.clone(),
+        )
+        .await;
+        indexer_wait_for_checkpoint(&store, 2).await;
+
+        let digest = *effects.transaction_digest();
+        let mut conn = get_pool_connection(&store.blocking_cp())?;
+        let db_txn = transactions::table
+            .filter(transactions::transaction_digest.eq(digest.inner().to_vec()))
+            .first::<StoredTransaction>(&mut conn)
+            .unwrap();
+        assert_eq!(db_txn.tx_sequence_number, 1);
+        assert_eq!(db_txn.checkpoint_sequence_number, 1);
+        assert_eq!(db_txn.timestamp_ms, checkpoint.timestamp_ms as i64);
+        assert_eq!(
+            db_txn.raw_transaction,
+            bcs::to_bytes(&transaction.data()).unwrap()
+        );
+
+        let reader = IndexerReader::<SqliteConnection>::new(db_url).unwrap();
+        let responses = reader
+            .multi_get_transaction_block_response_in_blocking_task(
+                vec![digest],
+                IotaTransactionBlockResponseOptions::full_content(),
+            )
+            .await?;
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].digest, digest);
+        assert_eq!(responses[0].checkpoint, Some(1));
+        assert!(responses[0].effects.is_some());
+
+        let events = reader
+            .query_events_in_blocking_task(
+                EventFilter::Package(IOTA_SYSTEM_PACKAGE_ID),
+                None,
+                10,
+                false,
+            )
+            .await?;
+        assert!(!events.is_empty());
+        assert!(events.iter().all(|event| event.sender == IotaAddress::ZERO));
+        assert!(
+            events
+                .iter()
+                .any(|event| event.type_.name.as_str() == "SystemEpochInfoEventV1")
+        );
+        Ok(())
+    }
+}
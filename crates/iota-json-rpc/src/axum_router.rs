@@ -234,9 +234,10 @@ async fn process_raw_request<L: Logger>(
         }
 
         // handle response tallying
+        let method = request.method.to_string();
         let response = process_request(request, api_version, service.call_data()).await;
         if let Some(traffic_controller) = &service.traffic_controller {
-            handle_traffic_resp(traffic_controller.clone(), client, &response);
+            handle_traffic_resp(traffic_controller.clone(), client, method, &response);
         }
 
         response
@@ -268,6 +269,7 @@ async fn handle_traffic_req(
 fn handle_traffic_resp(
     traffic_controller: Arc<TrafficController>,
     client: Option<IpAddr>,
+    method: String,
     response: &MethodResponse,
 ) {
     let error = response.as_error_code().map(ErrorCode::from);
@@ -280,9 +282,11 @@ fn handle_traffic_resp(
         // such as `iota_executeTransactionBlock`, as this can enable
         // node operators who wish to rate limit their transcation
         // traffic and incentivize high volume clients to choose a
-        // suitable rpc provider (or run their own). Later we may want
-        // to provide a weight distribution based on the method being called.
+        // suitable rpc provider (or run their own). Policies that weigh
+        // requests by cost, such as the token bucket policy, can assign
+        // a cost to each method instead.
         spam_weight: Weight::one(),
+        method: Some(method),
         timestamp: SystemTime::now(),
     });
 }
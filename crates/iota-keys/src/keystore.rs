@@ -9,11 +9,14 @@ use std::{
     fs::File,
     io::BufReader,
     path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
 };
 
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce, aead::Aead};
 use anyhow::{Context, anyhow, bail, ensure};
 use bip32::DerivationPath;
 use bip39::{Language, Mnemonic, Seed};
+use fastcrypto::encoding::{Base64, Encoding};
 use iota_types::{
     base_types::IotaAddress,
     crypto::{
@@ -21,7 +24,7 @@ use iota_types::{
         get_key_pair_from_rng,
     },
 };
-use rand::{SeedableRng, rngs::StdRng};
+use rand::{RngCore, SeedableRng, rngs::StdRng};
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use shared_crypto::intent::{Intent, IntentMessage};
@@ -36,7 +39,20 @@ use crate::{
 pub enum Keystore {
     File(FileBasedKeystore),
     InMem(InMemKeystore),
+    EncryptedFile(EncryptedFileKeystore),
 }
+
+/// Environment variable holding the passphrase of an encrypted keystore. It is
+/// only consulted if no [`PassphraseProvider`] has been set.
+pub const IOTA_KEYSTORE_PASSPHRASE_ENV: &str = "IOTA_KEYSTORE_PASSPHRASE";
+
+const ENCRYPTED_KEYSTORE_VERSION: u8 = 1;
+/// Known plaintext used to verify a passphrase without decrypting any key.
+const ENCRYPTED_KEYSTORE_CHECK: &[u8] = b"iota-encrypted-keystore";
+const ENCRYPTION_KEY_LENGTH: usize = 32;
+const AES_GCM_NONCE_LENGTH: usize = 12;
+const KDF_SALT_LENGTH: usize = 32;
+
 #[enum_dispatch]
 pub trait AccountKeystore: Send + Sync {
     fn add_key(&mut self, alias: Option<String>, keypair: IotaKeyPair)
@@ -157,6 +173,18 @@ pub trait AccountKeystore: Send + Sync {
     }
 }
 
+impl Keystore {
+    /// Opens the keystore file at `path`, detecting whether it is encrypted.
+    /// A missing file is opened as an empty plaintext keystore.
+    pub fn from_path(path: &PathBuf) -> Result<Self, anyhow::Error> {
+        Ok(if EncryptedFileKeystore::is_encrypted_keystore_file(path) {
+            Keystore::EncryptedFile(EncryptedFileKeystore::new(path)?)
+        } else {
+            Keystore::File(FileBasedKeystore::new(path)?)
+        })
+    }
+}
+
 impl Display for Keystore {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut writer = String::new();
@@ -170,6 +198,11 @@ impl Display for Keystore {
                 writeln!(writer, "Keystore Type: InMem")?;
                 write!(f, "{}", writer)
             }
+            Keystore::EncryptedFile(file) => {
+                writeln!(writer, "Keystore Type: EncryptedFile")?;
+                write!(writer, "Keystore Path : {:?}", file.path)?;
+                write!(f, "{}", writer)
+            }
         }
     }
 }
@@ -356,34 +389,7 @@ impl FileBasedKeystore {
         aliases_path.set_extension("aliases");
 
         let aliases = if aliases_path.exists() {
-            let reader = BufReader::new(File::open(&aliases_path).with_context(|| {
-                format!(
-                    "Cannot open aliases file in keystore: {}",
-                    aliases_path.display()
-                )
-            })?);
-
-            let aliases: Vec<Alias> = serde_json::from_reader(reader).with_context(|| {
-                format!(
-                    "Cannot deserialize aliases file in keystore: {}",
-                    aliases_path.display(),
-                )
-            })?;
-
-            aliases
-                .into_iter()
-                .map(|alias| {
-                    let key = PublicKey::decode_base64(&alias.public_key_base64);
-                    key.map(|k| (Into::<IotaAddress>::into(&k), alias))
-                })
-                .collect::<Result<BTreeMap<_, _>, _>>()
-                .map_err(|e| {
-                    anyhow!(
-                        "Invalid aliases file in keystore: {}. {}",
-                        aliases_path.display(),
-                        e
-                    )
-                })?
+            read_aliases(&aliases_path)?
         } else if keys.is_empty() {
             BTreeMap::new()
         } else {
@@ -422,20 +428,7 @@ impl FileBasedKeystore {
     }
 
     pub fn save_aliases(&self) -> Result<(), anyhow::Error> {
-        let aliases_store = serde_json::to_string_pretty(
-            &self.aliases.values().collect::<Vec<_>>(),
-        )
-        .with_context(|| {
-            format!(
-                "Cannot serialize aliases to file in keystore: {}",
-                self.path.display()
-            )
-        })?;
-
-        let mut aliases_path = self.path.clone();
-        aliases_path.set_extension("aliases");
-        fs::write(aliases_path, aliases_store)?;
-        Ok(())
+        write_aliases(&self.path, &self.aliases)
     }
 
     /// Keys saved as Base64 with 33 bytes `flag || privkey` ($BASE64_STR).
@@ -625,6 +618,579 @@ impl InMemKeystore {
     }
 }
 
+/// Parameters of the scrypt key derivation used by [`EncryptedFileKeystore`]
+/// to turn a passphrase into an AES-256-GCM key.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self {
+            log_n: scrypt::Params::RECOMMENDED_LOG_N,
+            r: scrypt::Params::RECOMMENDED_R,
+            p: scrypt::Params::RECOMMENDED_P,
+        }
+    }
+}
+
+impl ScryptParams {
+    /// Cheap parameters which must only be used in tests.
+    pub fn new_insecure_for_tests() -> Self {
+        Self {
+            log_n: 4,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// Returns the passphrase of an [`EncryptedFileKeystore`] when it needs to be
+/// unlocked, e.g. by prompting the user.
+pub type PassphraseProvider = Arc<dyn Fn() -> Result<String, anyhow::Error> + Send + Sync>;
+
+/// Base64 encoded AES-256-GCM nonce and ciphertext.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct EncryptedBlob {
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncryptedKeyEntry {
+    public_key_base64: String,
+    #[serde(flatten)]
+    blob: EncryptedBlob,
+}
+
+/// On-disk format of an [`EncryptedFileKeystore`].
+#[derive(Serialize, Deserialize)]
+struct EncryptedKeystoreFile {
+    version: u8,
+    kdf: ScryptParams,
+    salt: String,
+    check: EncryptedBlob,
+    keys: Vec<EncryptedKeyEntry>,
+}
+
+struct EncryptedKey {
+    public_key: PublicKey,
+    blob: EncryptedBlob,
+    /// Set the first time the key is decrypted.
+    keypair: OnceLock<IotaKeyPair>,
+}
+
+/// A file based keystore which encrypts every private key with an AES-256-GCM
+/// key derived from a passphrase with scrypt. Public keys and aliases are
+/// stored in plaintext, so the keystore can be listed while it is locked.
+///
+/// Keys are decrypted on demand. If the keystore has not been unlocked with
+/// [`EncryptedFileKeystore::unlock`], the passphrase is requested from the
+/// [`PassphraseProvider`] or, if none is set, read from the
+/// [`IOTA_KEYSTORE_PASSPHRASE_ENV`] environment variable.
+pub struct EncryptedFileKeystore {
+    keys: BTreeMap<IotaAddress, EncryptedKey>,
+    aliases: BTreeMap<IotaAddress, Alias>,
+    path: PathBuf,
+    kdf: ScryptParams,
+    salt: Vec<u8>,
+    check: EncryptedBlob,
+    cipher_key: OnceLock<[u8; ENCRYPTION_KEY_LENGTH]>,
+    passphrase_provider: Option<PassphraseProvider>,
+}
+
+impl Serialize for EncryptedFileKeystore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.path.to_str().unwrap_or(""))
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptedFileKeystore {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        EncryptedFileKeystore::new(&PathBuf::from(String::deserialize(deserializer)?))
+            .map_err(D::Error::custom)
+    }
+}
+
+impl AccountKeystore for EncryptedFileKeystore {
+    fn sign_hashed(
+        &self,
+        address: &IotaAddress,
+        msg: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        Ok(Signature::new_hashed(
+            msg,
+            self.get_key(address)
+                .map_err(|e| signature::Error::from_source(e.to_string()))?,
+        ))
+    }
+    fn sign_secure<T>(
+        &self,
+        address: &IotaAddress,
+        msg: &T,
+        intent: Intent,
+    ) -> Result<Signature, signature::Error>
+    where
+        T: Serialize,
+    {
+        Ok(Signature::new_secure(
+            &IntentMessage::new(intent, msg),
+            self.get_key(address)
+                .map_err(|e| signature::Error::from_source(e.to_string()))?,
+        ))
+    }
+
+    fn add_key(
+        &mut self,
+        alias: Option<String>,
+        keypair: IotaKeyPair,
+    ) -> Result<(), anyhow::Error> {
+        let address: IotaAddress = (&keypair.public()).into();
+        let alias = self.create_alias(alias)?;
+        let blob = encrypt(self.cipher_key()?, keypair.encode_base64().as_bytes())?;
+        self.aliases.insert(address, Alias {
+            alias,
+            public_key_base64: keypair.public().encode_base64(),
+        });
+        self.keys.insert(address, EncryptedKey {
+            public_key: keypair.public(),
+            blob,
+            keypair: OnceLock::from(keypair),
+        });
+        self.save()?;
+        Ok(())
+    }
+
+    fn aliases(&self) -> Vec<&Alias> {
+        self.aliases.values().collect()
+    }
+
+    fn addresses_with_alias(&self) -> Vec<(&IotaAddress, &Alias)> {
+        self.aliases.iter().collect::<Vec<_>>()
+    }
+
+    fn aliases_mut(&mut self) -> Vec<&mut Alias> {
+        self.aliases.values_mut().collect()
+    }
+
+    fn keys(&self) -> Vec<PublicKey> {
+        self.keys
+            .values()
+            .map(|key| key.public_key.clone())
+            .collect()
+    }
+
+    /// This function returns an error if the provided alias already exists. If
+    /// the alias has not already been used, then it returns the alias.
+    /// If no alias has been passed, it will generate a new alias.
+    fn create_alias(&self, alias: Option<String>) -> Result<String, anyhow::Error> {
+        match alias {
+            Some(a) if self.alias_exists(&a) => {
+                bail!("Alias {a} already exists. Please choose another alias.")
+            }
+            Some(a) => validate_alias(&a),
+            None => Ok(random_name(
+                &self
+                    .alias_names()
+                    .into_iter()
+                    .map(|x| x.to_string())
+                    .collect::<HashSet<_>>(),
+            )),
+        }
+    }
+
+    /// Get the address by its alias
+    fn get_address_by_alias(&self, alias: String) -> Result<&IotaAddress, anyhow::Error> {
+        self.addresses_with_alias()
+            .iter()
+            .find(|x| x.1.alias == alias)
+            .ok_or_else(|| anyhow!("Cannot resolve alias {alias} to an address"))
+            .map(|x| x.0)
+    }
+
+    /// Get the alias if it exists, or return an error if it does not exist.
+    fn get_alias_by_address(&self, address: &IotaAddress) -> Result<String, anyhow::Error> {
+        match self.aliases.get(address) {
+            Some(alias) => Ok(alias.alias.clone()),
+            None => bail!("Cannot find alias for address {address}"),
+        }
+    }
+
+    /// Decrypts the key on first use, unlocking the keystore if needed.
+    fn get_key(&self, address: &IotaAddress) -> Result<&IotaKeyPair, anyhow::Error> {
+        let entry = self
+            .keys
+            .get(address)
+            .ok_or_else(|| anyhow!("Cannot find key for address: [{address}]"))?;
+        if let Some(keypair) = entry.keypair.get() {
+            return Ok(keypair);
+        }
+        let plaintext = decrypt(self.cipher_key()?, &entry.blob)
+            .with_context(|| format!("Cannot decrypt key for address: [{address}]"))?;
+        let keypair = std::str::from_utf8(&plaintext)
+            .map_err(|e| anyhow!(e))
+            .and_then(|encoded| IotaKeyPair::decode_base64(encoded).map_err(|e| anyhow!(e)))
+            .with_context(|| format!("Invalid encrypted key for address: [{address}]"))?;
+        Ok(entry.keypair.get_or_init(|| keypair))
+    }
+
+    /// Updates an old alias to the new alias and saves it to the alias file.
+    /// If the new_alias is None, it will generate a new random alias.
+    fn update_alias(
+        &mut self,
+        old_alias: &str,
+        new_alias: Option<&str>,
+    ) -> Result<String, anyhow::Error> {
+        let new_alias_name = self.update_alias_value(old_alias, new_alias)?;
+        self.save_aliases()?;
+        Ok(new_alias_name)
+    }
+}
+
+impl EncryptedFileKeystore {
+    /// Opens an existing encrypted keystore. The keystore is locked until a
+    /// key is needed or [`EncryptedFileKeystore::unlock`] is called.
+    pub fn new(path: &PathBuf) -> Result<Self, anyhow::Error> {
+        let reader = BufReader::new(
+            File::open(path)
+                .with_context(|| format!("Cannot open the keystore file: {}", path.display()))?,
+        );
+        let file: EncryptedKeystoreFile = serde_json::from_reader(reader).with_context(|| {
+            format!(
+                "Cannot deserialize the encrypted keystore file: {}",
+                path.display()
+            )
+        })?;
+        ensure!(
+            file.version == ENCRYPTED_KEYSTORE_VERSION,
+            "Unsupported encrypted keystore version {} in {}",
+            file.version,
+            path.display()
+        );
+
+        let keys = file
+            .keys
+            .into_iter()
+            .map(|entry| {
+                let public_key = PublicKey::decode_base64(&entry.public_key_base64)?;
+                Ok((IotaAddress::from(&public_key), EncryptedKey {
+                    public_key,
+                    blob: entry.blob,
+                    keypair: OnceLock::new(),
+                }))
+            })
+            .collect::<Result<BTreeMap<_, _>, fastcrypto::error::FastCryptoError>>()
+            .map_err(|e| anyhow!("Invalid keystore file: {}. {}", path.display(), e))?;
+
+        let mut aliases_path = path.clone();
+        aliases_path.set_extension("aliases");
+        let aliases = if aliases_path.exists() {
+            read_aliases(&aliases_path)?
+        } else {
+            let names: Vec<String> = random_names(HashSet::new(), keys.len());
+            let aliases = keys
+                .iter()
+                .zip(names)
+                .map(|((iota_address, key), alias)| {
+                    (*iota_address, Alias {
+                        alias,
+                        public_key_base64: key.public_key.encode_base64(),
+                    })
+                })
+                .collect::<BTreeMap<_, _>>();
+            write_aliases(path, &aliases)?;
+            aliases
+        };
+
+        Ok(Self {
+            keys,
+            aliases,
+            path: path.to_path_buf(),
+            kdf: file.kdf,
+            salt: Base64::decode(&file.salt)
+                .map_err(|e| anyhow!("Invalid keystore file: {}. {}", path.display(), e))?,
+            check: file.check,
+            cipher_key: OnceLock::new(),
+            passphrase_provider: None,
+        })
+    }
+
+    /// Creates a new, empty encrypted keystore protected by `passphrase`. The
+    /// returned keystore is unlocked.
+    pub fn create(
+        path: &PathBuf,
+        passphrase: &str,
+        kdf: ScryptParams,
+    ) -> Result<Self, anyhow::Error> {
+        ensure!(
+            !path.exists(),
+            "Keystore file already exists: {}",
+            path.display()
+        );
+        let keystore = Self::init(path, passphrase, kdf)?;
+        keystore.save()?;
+        Ok(keystore)
+    }
+
+    /// Encrypts all keys of a plaintext keystore with `passphrase` and
+    /// replaces its keystore file in place. Aliases are kept as they are. The
+    /// returned keystore is unlocked.
+    pub fn from_file_keystore(
+        keystore: &FileBasedKeystore,
+        passphrase: &str,
+        kdf: ScryptParams,
+    ) -> Result<Self, anyhow::Error> {
+        let mut encrypted = Self::init(&keystore.path, passphrase, kdf)?;
+        let cipher_key = encrypted.cipher_key()?;
+        let keys = keystore
+            .keys
+            .iter()
+            .map(|(address, keypair)| {
+                let blob = encrypt(cipher_key, keypair.encode_base64().as_bytes())?;
+                Ok((*address, EncryptedKey {
+                    public_key: keypair.public(),
+                    blob,
+                    keypair: OnceLock::from(keypair.copy()),
+                }))
+            })
+            .collect::<Result<BTreeMap<_, _>, anyhow::Error>>()?;
+        encrypted.keys = keys;
+        encrypted.aliases = keystore.aliases.clone();
+        encrypted.save()?;
+        Ok(encrypted)
+    }
+
+    fn init(path: &Path, passphrase: &str, kdf: ScryptParams) -> Result<Self, anyhow::Error> {
+        let mut salt = vec![0u8; KDF_SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let cipher_key = derive_cipher_key(passphrase, &salt, &kdf)?;
+        let check = encrypt(&cipher_key, ENCRYPTED_KEYSTORE_CHECK)?;
+        Ok(Self {
+            keys: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            path: path.to_path_buf(),
+            kdf,
+            salt,
+            check,
+            cipher_key: OnceLock::from(cipher_key),
+            passphrase_provider: None,
+        })
+    }
+
+    /// Returns true if the file at `path` is an encrypted keystore.
+    pub fn is_encrypted_keystore_file(path: &Path) -> bool {
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<EncryptedKeystoreFile>(&bytes).ok())
+            .is_some()
+    }
+
+    /// Sets the provider which is asked for the passphrase when a key must be
+    /// decrypted while the keystore is locked.
+    pub fn set_passphrase_provider(&mut self, provider: PassphraseProvider) {
+        self.passphrase_provider = Some(provider);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.cipher_key.get().is_none()
+    }
+
+    /// Unlocks the keystore, failing if the passphrase is wrong.
+    pub fn unlock(&self, passphrase: &str) -> Result<(), anyhow::Error> {
+        if !self.is_locked() {
+            return Ok(());
+        }
+        let cipher_key = derive_cipher_key(passphrase, &self.salt, &self.kdf)?;
+        match decrypt(&cipher_key, &self.check) {
+            Ok(check) if check == ENCRYPTED_KEYSTORE_CHECK => {
+                // Another thread may have unlocked the keystore concurrently with the same
+                // passphrase, in which case the key is already set.
+                let _ = self.cipher_key.set(cipher_key);
+                Ok(())
+            }
+            _ => bail!("Invalid passphrase for keystore: {}", self.path.display()),
+        }
+    }
+
+    /// Drops the derived key and all decrypted keys from memory.
+    pub fn lock(&mut self) {
+        self.cipher_key = OnceLock::new();
+        for key in self.keys.values_mut() {
+            key.keypair = OnceLock::new();
+        }
+    }
+
+    fn cipher_key(&self) -> Result<&[u8; ENCRYPTION_KEY_LENGTH], anyhow::Error> {
+        if let Some(cipher_key) = self.cipher_key.get() {
+            return Ok(cipher_key);
+        }
+        let passphrase = match &self.passphrase_provider {
+            Some(provider) => provider()?,
+            None => std::env::var(IOTA_KEYSTORE_PASSPHRASE_ENV).map_err(|_| {
+                anyhow!(
+                    "Keystore {} is locked, set {IOTA_KEYSTORE_PASSPHRASE_ENV} to unlock it",
+                    self.path.display()
+                )
+            })?,
+        };
+        self.unlock(&passphrase)?;
+        // Safe to unwrap because a successful unlock always sets the key
+        Ok(self.cipher_key.get().unwrap())
+    }
+
+    pub fn set_path(&mut self, path: &Path) {
+        self.path = path.to_path_buf();
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn save_aliases(&self) -> Result<(), anyhow::Error> {
+        write_aliases(&self.path, &self.aliases)
+    }
+
+    /// Writes the keystore to a temporary file first and then renames it, so
+    /// an interrupted write never leaves a truncated keystore behind.
+    pub fn save_keystore(&self) -> Result<(), anyhow::Error> {
+        let file = EncryptedKeystoreFile {
+            version: ENCRYPTED_KEYSTORE_VERSION,
+            kdf: self.kdf,
+            salt: Base64::encode(&self.salt),
+            check: self.check.clone(),
+            keys: self
+                .keys
+                .values()
+                .map(|key| EncryptedKeyEntry {
+                    public_key_base64: key.public_key.encode_base64(),
+                    blob: key.blob.clone(),
+                })
+                .collect(),
+        };
+        let store = serde_json::to_string_pretty(&file).with_context(|| {
+            format!("Cannot serialize keystore to file: {}", self.path.display())
+        })?;
+        let mut tmp_path = self.path.clone();
+        tmp_path.set_extension("keystore.tmp");
+        fs::write(&tmp_path, store)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    pub fn save(&self) -> Result<(), anyhow::Error> {
+        self.save_aliases()?;
+        self.save_keystore()?;
+        Ok(())
+    }
+}
+
+fn derive_cipher_key(
+    passphrase: &str,
+    salt: &[u8],
+    kdf: &ScryptParams,
+) -> Result<[u8; ENCRYPTION_KEY_LENGTH], anyhow::Error> {
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p, ENCRYPTION_KEY_LENGTH)
+        .map_err(|e| anyhow!("Invalid scrypt parameters: {e}"))?;
+    let mut cipher_key = [0u8; ENCRYPTION_KEY_LENGTH];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut cipher_key)
+        .map_err(|e| anyhow!("Cannot derive keystore encryption key: {e}"))?;
+    Ok(cipher_key)
+}
+
+fn encrypt(
+    cipher_key: &[u8; ENCRYPTION_KEY_LENGTH],
+    plaintext: &[u8],
+) -> Result<EncryptedBlob, anyhow::Error> {
+    let cipher = Aes256Gcm::new(cipher_key.into());
+    let mut nonce = [0u8; AES_GCM_NONCE_LENGTH];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| anyhow!("Cannot encrypt keystore entry"))?;
+    Ok(EncryptedBlob {
+        nonce: Base64::encode(nonce),
+        ciphertext: Base64::encode(ciphertext),
+    })
+}
+
+fn decrypt(
+    cipher_key: &[u8; ENCRYPTION_KEY_LENGTH],
+    blob: &EncryptedBlob,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let nonce = Base64::decode(&blob.nonce).map_err(|e| anyhow!(e))?;
+    ensure!(
+        nonce.len() == AES_GCM_NONCE_LENGTH,
+        "Invalid nonce length {}",
+        nonce.len()
+    );
+    let ciphertext = Base64::decode(&blob.ciphertext).map_err(|e| anyhow!(e))?;
+    let cipher = Aes256Gcm::new(cipher_key.into());
+    cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| anyhow!("Cannot decrypt keystore entry, the passphrase may be wrong"))
+}
+
+fn read_aliases(aliases_path: &Path) -> Result<BTreeMap<IotaAddress, Alias>, anyhow::Error> {
+    let reader = BufReader::new(File::open(aliases_path).with_context(|| {
+        format!(
+            "Cannot open aliases file in keystore: {}",
+            aliases_path.display()
+        )
+    })?);
+
+    let aliases: Vec<Alias> = serde_json::from_reader(reader).with_context(|| {
+        format!(
+            "Cannot deserialize aliases file in keystore: {}",
+            aliases_path.display(),
+        )
+    })?;
+
+    aliases
+        .into_iter()
+        .map(|alias| {
+            let key = PublicKey::decode_base64(&alias.public_key_base64);
+            key.map(|k| (Into::<IotaAddress>::into(&k), alias))
+        })
+        .collect::<Result<BTreeMap<_, _>, _>>()
+        .map_err(|e| {
+            anyhow!(
+                "Invalid aliases file in keystore: {}. {}",
+                aliases_path.display(),
+                e
+            )
+        })
+}
+
+/// Writes the aliases next to the keystore at `keystore_path`, using the
+/// `.aliases` extension.
+fn write_aliases(
+    keystore_path: &Path,
+    aliases: &BTreeMap<IotaAddress, Alias>,
+) -> Result<(), anyhow::Error> {
+    let aliases_store = serde_json::to_string_pretty(&aliases.values().collect::<Vec<_>>())
+        .with_context(|| {
+            format!(
+                "Cannot serialize aliases to file in keystore: {}",
+                keystore_path.display()
+            )
+        })?;
+
+    let mut aliases_path = keystore_path.to_path_buf();
+    aliases_path.set_extension("aliases");
+    fs::write(aliases_path, aliases_store)?;
+    Ok(())
+}
+
 fn validate_alias(alias: &str) -> Result<String, anyhow::Error> {
     let re = Regex::new(r"^[A-Za-z][A-Za-z0-9-_\.]*$")
         .map_err(|_| anyhow!("Cannot build the regex needed to validate the alias naming"))?;
@@ -2,16 +2,21 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs, str::FromStr};
+use std::{fs, str::FromStr, sync::Arc};
 
 use fastcrypto::hash::HashFunction;
 use iota_keys::{
     key_derive::generate_new_key,
-    keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore},
+    keystore::{
+        AccountKeystore, EncryptedFileKeystore, FileBasedKeystore, InMemKeystore, Keystore,
+        ScryptParams,
+    },
 };
 use iota_types::{
     base_types::{IOTA_ADDRESS_LENGTH, IotaAddress},
-    crypto::{DefaultHash, Ed25519IotaSignature, IotaSignatureInner, SignatureScheme},
+    crypto::{
+        DefaultHash, Ed25519IotaSignature, EncodeDecodeBase64, IotaSignatureInner, SignatureScheme,
+    },
 };
 use tempfile::TempDir;
 
@@ -267,3 +272,111 @@ fn get_alias_by_address_test() {
     let address = generate_new_key(SignatureScheme::ED25519, None, None).unwrap();
     assert!(keystore.get_alias_by_address(&address.0).is_err())
 }
+
+#[test]
+fn encrypted_keystore_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("iota.keystore");
+    let mut keystore = Keystore::from(
+        EncryptedFileKeystore::create(
+            &keystore_path,
+            "passphrase",
+            ScryptParams::new_insecure_for_tests(),
+        )
+        .unwrap(),
+    );
+    let (address, _, _) = keystore
+        .generate_and_add_new_key(
+            SignatureScheme::ED25519,
+            Some("alias".to_string()),
+            None,
+            None,
+        )
+        .unwrap();
+
+    // Private keys must not be stored in plaintext
+    let contents = fs::read_to_string(&keystore_path).unwrap();
+    let private_key = keystore.get_key(&address).unwrap().encode_base64();
+    assert!(!contents.contains(&private_key));
+
+    // Reopening the keystore keeps it locked, but addresses and aliases are
+    // readable
+    let keystore = Keystore::from_path(&keystore_path).unwrap();
+    let Keystore::EncryptedFile(encrypted) = &keystore else {
+        panic!("expected an encrypted keystore");
+    };
+    assert!(encrypted.is_locked());
+    assert_eq!(vec![address], keystore.addresses());
+    assert_eq!("alias", keystore.get_alias_by_address(&address).unwrap());
+
+    assert!(encrypted.unlock("wrong passphrase").is_err());
+    assert!(encrypted.is_locked());
+    encrypted.unlock("passphrase").unwrap();
+    assert_eq!(
+        private_key,
+        keystore.get_key(&address).unwrap().encode_base64()
+    );
+}
+
+#[test]
+fn encrypted_keystore_passphrase_provider_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("iota.keystore");
+    let mut keystore = EncryptedFileKeystore::create(
+        &keystore_path,
+        "passphrase",
+        ScryptParams::new_insecure_for_tests(),
+    )
+    .unwrap();
+    let (address, _, _) = keystore
+        .generate_and_add_new_key(SignatureScheme::ED25519, None, None, None)
+        .unwrap();
+    keystore.lock();
+    assert!(keystore.is_locked());
+
+    keystore.set_passphrase_provider(Arc::new(|| Ok("passphrase".to_string())));
+    let msg = b"hello";
+    let signature = keystore.sign_hashed(&address, msg).unwrap();
+    assert!(!keystore.is_locked());
+    assert_eq!(signature, keystore.sign_hashed(&address, msg).unwrap());
+}
+
+#[test]
+fn migrate_file_keystore_to_encrypted_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("iota.keystore");
+    let mut keystore = FileBasedKeystore::new(&keystore_path).unwrap();
+    keystore
+        .generate_and_add_new_key(SignatureScheme::ED25519, Some("ed".to_string()), None, None)
+        .unwrap();
+    keystore
+        .generate_and_add_new_key(
+            SignatureScheme::Secp256k1,
+            Some("k1".to_string()),
+            None,
+            None,
+        )
+        .unwrap();
+
+    EncryptedFileKeystore::from_file_keystore(
+        &keystore,
+        "passphrase",
+        ScryptParams::new_insecure_for_tests(),
+    )
+    .unwrap();
+    assert!(FileBasedKeystore::new(&keystore_path).is_err());
+
+    let encrypted = EncryptedFileKeystore::new(&keystore_path).unwrap();
+    assert_eq!(keystore.addresses(), encrypted.addresses());
+    encrypted.unlock("passphrase").unwrap();
+    for address in keystore.addresses() {
+        assert_eq!(
+            keystore.get_alias_by_address(&address).unwrap(),
+            encrypted.get_alias_by_address(&address).unwrap()
+        );
+        assert_eq!(
+            keystore.get_key(&address).unwrap().encode_base64(),
+            encrypted.get_key(&address).unwrap().encode_base64()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A self-hostable history service: checkpoints are ingested into a local
+//! RocksDB database, and the transactions, effects, events, checkpoints and
+//! objects they contain are served over the HTTP protocol read by
+//! `iota_storage::http_key_value_store::HttpKVStore`.
+
+mod server;
+mod store;
+#[cfg(test)]
+mod tests;
+mod worker;
+
+pub use server::serve;
+pub use store::KVStore;
+pub use worker::KVStoreWorker;
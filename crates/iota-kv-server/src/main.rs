@@ -0,0 +1,100 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use iota_data_ingestion_core::{
+    DataIngestionMetrics, IndexerExecutor, ReaderOptions, RocksDBProgressStore, WorkerPool,
+};
+use iota_kv_server::{KVStore, KVStoreWorker, serve};
+use prometheus::Registry;
+use tokio::{net::TcpListener, signal, sync::oneshot};
+
+/// Ingests checkpoints into a local RocksDB database and serves them over the
+/// HTTP protocol of `HttpKVStore`, to be used as a fallback history service by
+/// fullnodes.
+#[derive(Parser, Debug)]
+#[command(name = "iota-kv-server")]
+struct Args {
+    /// Directory of the RocksDB databases holding the ingested data and the
+    /// ingestion progress.
+    #[arg(long)]
+    db_path: PathBuf,
+    /// Local directory the checkpoints are read from.
+    #[arg(long)]
+    checkpoints_path: PathBuf,
+    /// Remote store to fetch the checkpoints from, when they are not found in
+    /// `checkpoints_path`.
+    #[arg(long)]
+    remote_store_url: Option<String>,
+    /// Options of the remote store, as `key=value` pairs.
+    #[arg(long = "remote-store-option", value_parser = parse_key_value)]
+    remote_store_options: Vec<(String, String)>,
+    /// Number of checkpoints ingested concurrently.
+    #[arg(long, default_value_t = 10)]
+    concurrency: usize,
+    /// Address the key-value store is served on.
+    #[arg(long, default_value = "0.0.0.0:9000")]
+    listen_address: SocketAddr,
+    /// Address the prometheus metrics are served on.
+    #[arg(long, default_value = "127.0.0.1:8081")]
+    metrics_address: SocketAddr,
+}
+
+fn parse_key_value(s: &str) -> Result<(String, String)> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected `key=value`, got `{s}`"))?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn setup_env(exit_sender: oneshot::Sender<()>) {
+    tokio::spawn(async {
+        signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+        exit_sender
+            .send(())
+            .expect("Failed to gracefully process shutdown");
+    });
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let (exit_sender, exit_receiver) = oneshot::channel();
+    setup_env(exit_sender);
+
+    let _guard = telemetry_subscribers::TelemetryConfig::new()
+        .with_env()
+        .init();
+    let registry_service = iota_metrics::start_prometheus_server(args.metrics_address);
+    let registry: Registry = registry_service.default_registry();
+    iota_metrics::init_metrics(&registry);
+    let metrics = DataIngestionMetrics::new(&registry);
+
+    let store = Arc::new(KVStore::new(&args.db_path.join("store")));
+    let progress_store = RocksDBProgressStore::new(&args.db_path.join("progress"));
+    let mut executor = IndexerExecutor::new(progress_store, 1, metrics);
+    executor
+        .register(WorkerPool::new(
+            KVStoreWorker::new(store.clone()),
+            "kv_server".to_string(),
+            args.concurrency,
+        ))
+        .await?;
+
+    let listener = TcpListener::bind(args.listen_address).await?;
+    tokio::select! {
+        result = serve(store, listener) => result,
+        result = executor.run(
+            args.checkpoints_path,
+            args.remote_store_url,
+            args.remote_store_options,
+            ReaderOptions::default(),
+            exit_receiver,
+        ) => result.map(|_| ()),
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use iota_storage::http_key_value_store::path_elements_to_key;
+use tokio::net::TcpListener;
+use tracing::error;
+
+use crate::KVStore;
+
+/// Serves the content of `store` on `listener`, following the protocol of
+/// `HttpKVStore`: `GET /{encoded_key}/{item_type}` returns the BCS bytes of
+/// the value, or 404 if it is unknown.
+pub async fn serve(store: Arc<KVStore>, listener: TcpListener) -> anyhow::Result<()> {
+    let app = Router::new()
+        .route("/:encoded_key/:item_type", get(get_item))
+        .with_state(store);
+
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn get_item(
+    State(store): State<Arc<KVStore>>,
+    Path((encoded_key, item_type)): Path<(String, String)>,
+) -> Response {
+    let key = match path_elements_to_key(&encoded_key, &item_type) {
+        Ok(key) => key,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+    match store.get(key) {
+        Ok(Some(bytes)) => {
+            ([(header::CONTENT_TYPE, "application/octet-stream")], bytes).into_response()
+        }
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            error!("failed to read {key:?}: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+    }
+}
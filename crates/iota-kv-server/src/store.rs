@@ -0,0 +1,164 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use anyhow::Result;
+use iota_storage::http_key_value_store::Key;
+use iota_types::{
+    digests::{
+        CheckpointContentsDigest, CheckpointDigest, TransactionDigest, TransactionEventsDigest,
+    },
+    effects::{TransactionEffects, TransactionEvents},
+    full_checkpoint_content::CheckpointData,
+    messages_checkpoint::{
+        CertifiedCheckpointSummary, CheckpointContents, CheckpointSequenceNumber,
+    },
+    object::Object,
+    storage::ObjectKey,
+    transaction::Transaction,
+};
+use serde::Serialize;
+use typed_store::{
+    DBMapUtils, Map,
+    rocks::{DBMap, MetricConf},
+    traits::{TableSummary, TypedStoreDebug},
+};
+
+#[derive(DBMapUtils)]
+struct KVStoreTables {
+    transactions: DBMap<TransactionDigest, Transaction>,
+    effects: DBMap<TransactionDigest, TransactionEffects>,
+    events: DBMap<TransactionEventsDigest, TransactionEvents>,
+    /// The output objects of every transaction.
+    objects: DBMap<ObjectKey, Object>,
+    /// The certified checkpoint summaries, keyed by sequence number.
+    checkpoint_summaries: DBMap<CheckpointSequenceNumber, CertifiedCheckpointSummary>,
+    /// Index of the checkpoint summaries by digest.
+    checkpoint_summary_digests: DBMap<CheckpointDigest, CheckpointSequenceNumber>,
+    /// The checkpoint contents, keyed by sequence number.
+    checkpoint_contents: DBMap<CheckpointSequenceNumber, CheckpointContents>,
+    /// Index of the checkpoint contents by digest.
+    checkpoint_contents_digests: DBMap<CheckpointContentsDigest, CheckpointSequenceNumber>,
+    /// The checkpoint each transaction was executed in.
+    transactions_to_checkpoint: DBMap<TransactionDigest, CheckpointSequenceNumber>,
+}
+
+/// The data of the ingested checkpoints, stored in an embedded RocksDB
+/// database.
+pub struct KVStore {
+    tables: KVStoreTables,
+}
+
+impl KVStore {
+    /// Opens the store located at `path`, creating it if it does not exist
+    /// yet.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            tables: KVStoreTables::open_tables_read_write(
+                path.to_path_buf(),
+                MetricConf::new("kv_server"),
+                None,
+                None,
+            ),
+        }
+    }
+
+    /// Saves the checkpoint and the transactions it contains in a single write
+    /// batch.
+    pub fn write_checkpoint(&self, checkpoint: &CheckpointData) -> Result<()> {
+        let tables = &self.tables;
+        let summary = &checkpoint.checkpoint_summary;
+        let sequence_number = summary.sequence_number;
+
+        let mut batch = tables.transactions.batch();
+        batch.insert_batch(
+            &tables.transactions,
+            checkpoint
+                .transactions
+                .iter()
+                .map(|tx| (*tx.transaction.digest(), &tx.transaction)),
+        )?;
+        batch.insert_batch(
+            &tables.effects,
+            checkpoint
+                .transactions
+                .iter()
+                .map(|tx| (*tx.transaction.digest(), &tx.effects)),
+        )?;
+        batch.insert_batch(
+            &tables.events,
+            checkpoint
+                .transactions
+                .iter()
+                .filter_map(|tx| tx.events.as_ref())
+                .map(|events| (events.digest(), events)),
+        )?;
+        batch.insert_batch(
+            &tables.objects,
+            checkpoint
+                .transactions
+                .iter()
+                .flat_map(|tx| &tx.output_objects)
+                .map(|object| (ObjectKey(object.id(), object.version()), object)),
+        )?;
+        batch.insert_batch(
+            &tables.transactions_to_checkpoint,
+            checkpoint
+                .transactions
+                .iter()
+                .map(|tx| (*tx.transaction.digest(), sequence_number)),
+        )?;
+        batch.insert_batch(
+            &tables.checkpoint_summaries,
+            std::iter::once((sequence_number, summary)),
+        )?;
+        batch.insert_batch(
+            &tables.checkpoint_summary_digests,
+            std::iter::once((*summary.digest(), sequence_number)),
+        )?;
+        batch.insert_batch(
+            &tables.checkpoint_contents,
+            std::iter::once((sequence_number, &checkpoint.checkpoint_contents)),
+        )?;
+        batch.insert_batch(
+            &tables.checkpoint_contents_digests,
+            std::iter::once((*checkpoint.checkpoint_contents.digest(), sequence_number)),
+        )?;
+        batch.write()?;
+        Ok(())
+    }
+
+    /// Returns the BCS bytes of the value stored under `key`, as served to an
+    /// `HttpKVStore`.
+    pub fn get(&self, key: Key) -> Result<Option<Vec<u8>>> {
+        let tables = &self.tables;
+        match key {
+            Key::Tx(digest) => encode(tables.transactions.get(&digest)?),
+            Key::Fx(digest) => encode(tables.effects.get(&digest)?),
+            Key::Events(digest) => encode(tables.events.get(&digest)?),
+            Key::CheckpointContents(seq) => encode(tables.checkpoint_contents.get(&seq)?),
+            Key::CheckpointSummary(seq) => encode(tables.checkpoint_summaries.get(&seq)?),
+            Key::CheckpointContentsByDigest(digest) => {
+                match tables.checkpoint_contents_digests.get(&digest)? {
+                    Some(seq) => encode(tables.checkpoint_contents.get(&seq)?),
+                    None => Ok(None),
+                }
+            }
+            Key::CheckpointSummaryByDigest(digest) => {
+                match tables.checkpoint_summary_digests.get(&digest)? {
+                    Some(seq) => encode(tables.checkpoint_summaries.get(&seq)?),
+                    None => Ok(None),
+                }
+            }
+            Key::TxToCheckpoint(digest) => encode(tables.transactions_to_checkpoint.get(&digest)?),
+            Key::ObjectKey(object_id, version) => {
+                encode(tables.objects.get(&ObjectKey(object_id, version))?)
+            }
+        }
+    }
+}
+
+fn encode<T: Serialize>(value: Option<T>) -> Result<Option<Vec<u8>>> {
+    Ok(value.map(|value| bcs::to_bytes(&value)).transpose()?)
+}
@@ -0,0 +1,151 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use iota_data_ingestion_core::Worker;
+use iota_protocol_config::ProtocolConfig;
+use iota_storage::{
+    http_key_value_store::HttpKVStore, key_value_store::TransactionKeyValueStoreTrait,
+};
+use iota_types::{
+    base_types::{ObjectID, SequenceNumber},
+    crypto::KeypairTraits,
+    digests::TransactionDigest,
+    effects::{TestEffectsBuilder, TransactionEffectsAPI, TransactionEvents},
+    full_checkpoint_content::{CheckpointData, CheckpointTransaction},
+    gas::GasCostSummary,
+    messages_checkpoint::{
+        CertifiedCheckpointSummary, CheckpointContents, CheckpointSummary, SignedCheckpointSummary,
+    },
+    object::Object,
+    utils::{create_fake_transaction, make_committee_key},
+};
+use rand::{SeedableRng, rngs::StdRng};
+use tokio::net::TcpListener;
+
+use crate::{KVStore, KVStoreWorker, serve};
+
+fn mock_checkpoint_data() -> CheckpointData {
+    let transaction = create_fake_transaction();
+    let events = TransactionEvents { data: vec![] };
+    let effects = TestEffectsBuilder::new(transaction.data())
+        .with_events_digest(events.digest())
+        .build();
+    let object = Object::immutable_with_id_for_testing(ObjectID::random());
+    let contents =
+        CheckpointContents::new_with_digests_only_for_tests([effects.execution_digests()]);
+
+    let mut rng = StdRng::from_seed([0; 32]);
+    let (keys, committee) = make_committee_key(&mut rng);
+    let summary = CheckpointSummary::new(
+        &ProtocolConfig::get_for_max_version_UNSAFE(),
+        0,
+        3,
+        1,
+        &contents,
+        None,
+        GasCostSummary::default(),
+        None,
+        0,
+        Vec::new(),
+    );
+    let sign_infos: Vec<_> = keys
+        .iter()
+        .map(|k| {
+            let name = k.public().into();
+            SignedCheckpointSummary::sign(committee.epoch, &summary, k, name)
+        })
+        .collect();
+
+    CheckpointData {
+        checkpoint_summary: CertifiedCheckpointSummary::new(summary, sign_infos, &committee)
+            .unwrap(),
+        checkpoint_contents: contents,
+        transactions: vec![CheckpointTransaction {
+            transaction,
+            effects,
+            events: Some(events),
+            input_objects: vec![],
+            output_objects: vec![object],
+        }],
+    }
+}
+
+#[tokio::test]
+async fn serve_ingested_checkpoint() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(KVStore::new(dir.path()));
+    let checkpoint = mock_checkpoint_data();
+    KVStoreWorker::new(store.clone())
+        .process_checkpoint(checkpoint.clone())
+        .await
+        .unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(serve(store, listener));
+    let client = HttpKVStore::new(&format!("http://{address}")).unwrap();
+
+    let tx = &checkpoint.transactions[0];
+    let tx_digest = *tx.transaction.digest();
+    let events = tx.events.clone().unwrap();
+    let unknown_digest = TransactionDigest::random();
+
+    let (transactions, effects, events_results) = client
+        .multi_get(&[tx_digest, unknown_digest], &[tx_digest], &[
+            events.digest()
+        ])
+        .await
+        .unwrap();
+    assert_eq!(transactions, vec![Some(tx.transaction.clone()), None]);
+    assert_eq!(effects, vec![Some(tx.effects.clone())]);
+    assert_eq!(events_results, vec![Some(events)]);
+
+    let summary = &checkpoint.checkpoint_summary;
+    let contents = &checkpoint.checkpoint_contents;
+    let (summaries, contents_results, summaries_by_digest, contents_by_digest) = client
+        .multi_get_checkpoints(
+            &[summary.sequence_number, summary.sequence_number + 1],
+            &[summary.sequence_number],
+            &[*summary.digest()],
+            &[*contents.digest()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(summaries, vec![Some(summary.clone()), None]);
+    assert_eq!(contents_results, vec![Some(contents.clone())]);
+    assert_eq!(summaries_by_digest, vec![Some(summary.clone())]);
+    assert_eq!(contents_by_digest, vec![Some(contents.clone())]);
+
+    assert_eq!(
+        client
+            .get_transaction_perpetual_checkpoint(tx_digest)
+            .await
+            .unwrap(),
+        Some(summary.sequence_number)
+    );
+    assert_eq!(
+        client
+            .multi_get_transactions_perpetual_checkpoints(&[tx_digest, unknown_digest])
+            .await
+            .unwrap(),
+        vec![Some(summary.sequence_number), None]
+    );
+
+    let object = &tx.output_objects[0];
+    assert_eq!(
+        client
+            .get_object(object.id(), object.version())
+            .await
+            .unwrap(),
+        Some(object.clone())
+    );
+    assert_eq!(
+        client
+            .get_object(object.id(), SequenceNumber::from_u64(42))
+            .await
+            .unwrap(),
+        None
+    );
+}
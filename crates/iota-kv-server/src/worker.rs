@@ -0,0 +1,29 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use iota_data_ingestion_core::Worker;
+use iota_types::full_checkpoint_content::CheckpointData;
+
+use crate::KVStore;
+
+/// Ingestion worker writing every checkpoint to a [`KVStore`].
+pub struct KVStoreWorker {
+    store: Arc<KVStore>,
+}
+
+impl KVStoreWorker {
+    pub fn new(store: Arc<KVStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl Worker for KVStoreWorker {
+    async fn process_checkpoint(&self, checkpoint: CheckpointData) -> Result<()> {
+        self.store.write_checkpoint(&checkpoint)
+    }
+}
@@ -2,13 +2,16 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs, path::PathBuf, str::FromStr};
+use std::{fs, path::PathBuf, str::FromStr, time::Duration};
 
 use clap::Parser;
 use iota_json::IotaJsonValue;
-use iota_light_client::utils::{
-    Config, RemotePackageStore, SCommands, check_and_sync_checkpoints,
-    get_verified_effects_and_events, get_verified_object,
+use iota_light_client::{
+    proof_server,
+    utils::{
+        Config, RemotePackageStore, SCommands, check_and_sync_checkpoints,
+        get_verified_effects_and_events, get_verified_object,
+    },
 };
 use iota_package_resolver::Resolver;
 use iota_types::{base_types::ObjectID, digests::TransactionDigest, object::Data};
@@ -114,6 +117,21 @@ pub async fn main() {
                 .await
                 .expect("Failed to sync checkpoints");
         }
+        Some(SCommands::Serve {
+            listen_address,
+            sync_interval_secs,
+        }) => {
+            let _guard = telemetry_subscribers::TelemetryConfig::new()
+                .with_env()
+                .init();
+            proof_server::run(
+                config,
+                listen_address,
+                Duration::from_secs(sync_interval_secs),
+            )
+            .await
+            .expect("Failed to run the proof server");
+        }
         _ => {}
     };
 }
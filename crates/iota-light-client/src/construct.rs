@@ -4,7 +4,7 @@
 
 use anyhow::anyhow;
 use iota_rest_api::{CheckpointData, CheckpointTransaction};
-use iota_types::effects::TransactionEffectsAPI;
+use iota_types::{digests::TransactionDigest, effects::TransactionEffectsAPI};
 
 use crate::proof::{Proof, ProofTarget, TransactionProof};
 
@@ -36,9 +36,9 @@ pub fn construct_proof(targets: ProofTarget, data: &CheckpointData) -> anyhow::R
         }
     }
 
-    // If proof targets include objects or events, we need to include the contents
-    // proof Need to ensure that all targets refer to the same transaction first
-    // of all
+    // If proof targets include objects, events, dynamic fields or transfers, we
+    // need to include the contents proof Need to ensure that all targets refer
+    // to the same transaction first of all
     let object_tx = this_proof
         .targets
         .objects
@@ -49,7 +49,20 @@ pub fn construct_proof(targets: ProofTarget, data: &CheckpointData) -> anyhow::R
         .events
         .iter()
         .map(|(eid, _)| eid.tx_digest);
-    let mut all_tx = object_tx.chain(event_tx);
+    let dynamic_field_tx = this_proof
+        .targets
+        .dynamic_fields
+        .iter()
+        .map(|field| field.field_object.previous_transaction);
+    let transfer_tx = this_proof
+        .targets
+        .transfers
+        .iter()
+        .map(|transfer| transfer.tx_digest);
+    let mut all_tx = object_tx
+        .chain(event_tx)
+        .chain(dynamic_field_tx)
+        .chain(transfer_tx);
 
     // Get the first tx ID
     let target_tx_id = if let Some(first_tx) = all_tx.next() {
@@ -64,11 +77,42 @@ pub fn construct_proof(targets: ProofTarget, data: &CheckpointData) -> anyhow::R
         return Err(anyhow!("All targets must refer to the same transaction"));
     }
 
-    // Find the transaction in the checkpoint data
+    // Add all the transaction data in there
+    this_proof.contents_proof = Some(transaction_proof(target_tx_id, data)?);
+
+    // TODO: should we check that the objects & events are in the transaction, to
+    //       avoid constructing invalid proofs? I opt to not check because the check
+    //       is expensive (sequential scan of all objects).
+
+    Ok(this_proof)
+}
+
+/// Construct a proof certifying that the given transaction, along with its
+/// effects and events, is included in the checkpoint.
+///
+/// As with `construct_proof`, consider calling `verify_proof` on the
+/// constructed proof if you need guaranteed validity.
+pub fn construct_transaction_proof(
+    tx_digest: TransactionDigest,
+    data: &CheckpointData,
+) -> anyhow::Result<Proof> {
+    Ok(Proof {
+        targets: ProofTarget::new(),
+        checkpoint_summary: data.checkpoint_summary.clone(),
+        contents_proof: Some(transaction_proof(tx_digest, data)?),
+    })
+}
+
+/// Find the transaction in the checkpoint data and gather the evidence
+/// certifying it.
+fn transaction_proof(
+    tx_digest: TransactionDigest,
+    data: &CheckpointData,
+) -> anyhow::Result<TransactionProof> {
     let tx = data
         .transactions
         .iter()
-        .find(|t| t.effects.transaction_digest() == &target_tx_id)
+        .find(|t| t.effects.transaction_digest() == &tx_digest)
         .ok_or(anyhow!("Transaction not found in checkpoint data"))?
         .clone();
 
@@ -79,17 +123,10 @@ pub fn construct_proof(targets: ProofTarget, data: &CheckpointData) -> anyhow::R
         ..
     } = tx;
 
-    // Add all the transaction data in there
-    this_proof.contents_proof = Some(TransactionProof {
+    Ok(TransactionProof {
         checkpoint_contents: data.checkpoint_contents.clone(),
         transaction,
         effects,
         events,
-    });
-
-    // TODO: should we check that the objects & events are in the transaction, to
-    //       avoid constructing invalid proofs? I opt to not check because the check
-    //       is expensive (sequential scan of all objects).
-
-    Ok(this_proof)
+    })
 }
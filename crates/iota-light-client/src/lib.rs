@@ -4,6 +4,7 @@
 
 pub mod construct;
 pub mod proof;
+pub mod proof_server;
 pub mod utils;
 
 #[doc(inline)]
@@ -4,17 +4,22 @@
 
 use anyhow::anyhow;
 use iota_types::{
-    base_types::ObjectRef,
+    TypeTag,
+    base_types::{IotaAddress, ObjectID, ObjectRef},
     committee::Committee,
+    digests::TransactionDigest,
+    dynamic_field::derive_dynamic_field_id,
     effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
     event::{Event, EventID},
     messages_checkpoint::{CertifiedCheckpointSummary, CheckpointContents, EndOfEpochData},
-    object::Object,
+    object::{Object, Owner},
+    storage::WriteKind,
     transaction::Transaction,
 };
+use serde::{Deserialize, Serialize};
 
 /// Define aspect of Iota state that needs to be certified in a proof
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct ProofTarget {
     /// Objects that need to be certified.
     pub objects: Vec<(ObjectRef, Object)>,
@@ -22,6 +27,12 @@ pub struct ProofTarget {
     /// Events that need to be certified.
     pub events: Vec<(EventID, Event)>,
 
+    /// Dynamic fields that need to be certified.
+    pub dynamic_fields: Vec<DynamicFieldTarget>,
+
+    /// Ownership changes that need to be certified.
+    pub transfers: Vec<TransferTarget>,
+
     /// The next committee being certified.
     pub committee: Option<Committee>,
 }
@@ -49,6 +60,51 @@ impl ProofTarget {
         self
     }
 
+    /// Add a dynamic field to be certified by its parent, key and value, along
+    /// with the `Field` object storing it. A verified proof will ensure that
+    /// the field of the parent with this key had this value as of the
+    /// transaction which wrote the `Field` object, without requiring the
+    /// parent object itself.
+    ///
+    /// The key type and bcs bytes are the ones passed to `dynamic_field::add`,
+    /// which for dynamic object fields is a `dynamic_object_field::Wrapper` of
+    /// the key, with the ID of the child object as value.
+    pub fn add_dynamic_field(
+        mut self,
+        parent: ObjectID,
+        key_type: TypeTag,
+        key_bytes: Vec<u8>,
+        value_bytes: Vec<u8>,
+        field_object: Object,
+    ) -> Self {
+        self.dynamic_fields.push(DynamicFieldTarget {
+            parent,
+            key_type,
+            key_bytes,
+            value_bytes,
+            field_object,
+        });
+        self
+    }
+
+    /// Add an ownership change to be certified. A verified proof will ensure
+    /// that the transaction transferred the object at this reference to the
+    /// recipient address, i.e. that the recipient did not own the object before
+    /// the transaction and owns it after.
+    pub fn add_transfer(
+        mut self,
+        tx_digest: TransactionDigest,
+        object_ref: ObjectRef,
+        recipient: IotaAddress,
+    ) -> Self {
+        self.transfers.push(TransferTarget {
+            tx_digest,
+            object_ref,
+            recipient,
+        });
+        self
+    }
+
     /// Add the next committee to be certified. A verified proof will ensure
     /// that the next committee is correct.
     pub fn set_committee(mut self, committee: Committee) -> Self {
@@ -57,8 +113,42 @@ impl ProofTarget {
     }
 }
 
+/// A dynamic field of a parent object to be certified, see
+/// `ProofTarget::add_dynamic_field`.
+#[derive(Serialize, Deserialize)]
+pub struct DynamicFieldTarget {
+    /// The object the field belongs to.
+    pub parent: ObjectID,
+
+    /// The type of the key of the field.
+    pub key_type: TypeTag,
+
+    /// The bcs bytes of the key of the field.
+    pub key_bytes: Vec<u8>,
+
+    /// The bcs bytes of the value of the field.
+    pub value_bytes: Vec<u8>,
+
+    /// The `dynamic_field::Field` object storing the field.
+    pub field_object: Object,
+}
+
+/// An ownership change to be certified, see `ProofTarget::add_transfer`.
+#[derive(Serialize, Deserialize)]
+pub struct TransferTarget {
+    /// The transaction transferring the object.
+    pub tx_digest: TransactionDigest,
+
+    /// The reference of the object written by the transaction.
+    pub object_ref: ObjectRef,
+
+    /// The address the object was transferred to.
+    pub recipient: IotaAddress,
+}
+
 /// Part of a proof that provides evidence relating to a specific transaction to
 /// certify objects and events.
+#[derive(Serialize, Deserialize)]
 pub struct TransactionProof {
     /// Checkpoint contents including this transaction.
     pub checkpoint_contents: CheckpointContents,
@@ -75,6 +165,7 @@ pub struct TransactionProof {
 
 /// A proof for specific targets. It certifies a checkpoint summary and
 /// optionally includes transaction evidence to certify objects and events.
+#[derive(Serialize, Deserialize)]
 pub struct Proof {
     /// Targets of the proof are a committee, objects, or events that need to be
     /// certified.
@@ -144,7 +235,10 @@ pub fn verify_proof(committee: &Committee, proof: &Proof) -> anyhow::Result<()>
     // Non empty object or event targets require the optional contents proof
     // If it is not present return an error
 
-    if (!proof.targets.objects.is_empty() || !proof.targets.events.is_empty())
+    if (!proof.targets.objects.is_empty()
+        || !proof.targets.events.is_empty()
+        || !proof.targets.dynamic_fields.is_empty()
+        || !proof.targets.transfers.is_empty())
         && proof.contents_proof.is_none()
     {
         return Err(anyhow!("Contents proof is missing"));
@@ -227,6 +321,88 @@ pub fn verify_proof(committee: &Committee, proof: &Proof) -> anyhow::Result<()>
         }
 
         // MILESTONE 7: Object references are correct and in the effects
+
+        for field in &proof.targets.dynamic_fields {
+            verify_dynamic_field(field, &changed_objects)?;
+        }
+
+        // MILESTONE 8: Dynamic fields are correct and in the effects
+
+        let old_objects = contents_proof.effects.old_object_metadata();
+
+        for transfer in &proof.targets.transfers {
+            // Check the transfer corresponds to the transaction
+            if transfer.tx_digest != digests.transaction {
+                return Err(anyhow!("Transfer does not belong to the transaction"));
+            }
+
+            // The object must be written by the transaction and owned by the recipient
+            changed_objects
+                .iter()
+                .find(|(object_ref, owner, _)| {
+                    object_ref == &transfer.object_ref
+                        && owner == &Owner::AddressOwner(transfer.recipient)
+                })
+                .ok_or(anyhow!("Object not transferred to the recipient"))?;
+
+            // The recipient must not have owned the object before the transaction
+            if old_objects.iter().any(|(object_ref, owner)| {
+                object_ref.0 == transfer.object_ref.0
+                    && owner == &Owner::AddressOwner(transfer.recipient)
+            }) {
+                return Err(anyhow!("Object already owned by the recipient"));
+            }
+        }
+
+        // MILESTONE 9: Transfers are correct and in the effects
+    }
+
+    Ok(())
+}
+
+/// Verify a dynamic field target against the objects changed by the
+/// transaction of the proof.
+fn verify_dynamic_field(
+    field: &DynamicFieldTarget,
+    changed_objects: &[(ObjectRef, Owner, WriteKind)],
+) -> anyhow::Result<()> {
+    let object_ref = field.field_object.compute_object_reference();
+
+    // Has this field object been written in these effects?
+    changed_objects
+        .iter()
+        .find(|effects_object_ref| effects_object_ref.0 == object_ref)
+        .ok_or(anyhow!("Dynamic field object not found"))?;
+
+    // Is the field object owned by the parent?
+    if field.field_object.owner != Owner::ObjectOwner(field.parent.into()) {
+        return Err(anyhow!("Dynamic field object not owned by the parent"));
+    }
+
+    // Is the field object the one of this key?
+    if object_ref.0 != derive_dynamic_field_id(field.parent, &field.key_type, &field.key_bytes)? {
+        return Err(anyhow!("Dynamic field object does not match the key"));
+    }
+
+    let move_object = field
+        .field_object
+        .data
+        .try_as_move()
+        .ok_or(anyhow!("Dynamic field object is not a Move object"))?;
+    if !move_object.type_().is_dynamic_field()
+        || move_object.type_().type_params().first() != Some(&field.key_type)
+    {
+        return Err(anyhow!("Dynamic field object has an unexpected type"));
+    }
+
+    // A `Field` object is laid out as its ID, followed by the key and the value
+    let contents = move_object.contents();
+    let key_start = ObjectID::LENGTH;
+    let value_start = key_start + field.key_bytes.len();
+    if contents.get(key_start..value_start) != Some(field.key_bytes.as_slice())
+        || contents.get(value_start..) != Some(field.value_bytes.as_slice())
+    {
+        return Err(anyhow!("Dynamic field contents do not match"));
     }
 
     Ok(())
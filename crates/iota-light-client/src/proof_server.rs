@@ -0,0 +1,241 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A server keeping the committee of every epoch verified and up to date, and
+//! serving proofs for objects, events and transactions which can be checked
+//! with `verify_proof` by anyone holding the committee of the proof's epoch.
+
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use iota_rest_api::{
+    APPLICATION_BCS, CheckpointData, Client, accept::AcceptFormat, client::reqwest,
+};
+use iota_types::{
+    base_types::ObjectID,
+    committee::{Committee, EpochId},
+    digests::TransactionDigest,
+    event::EventID,
+};
+use tracing::error;
+
+use crate::{
+    construct::{construct_proof, construct_transaction_proof},
+    proof::{Proof, ProofTarget, verify_proof},
+    utils::{
+        Config, check_and_sync_checkpoints, get_checkpoint_sequence_number, sync_committees_after,
+    },
+};
+
+#[derive(Clone)]
+struct AppState {
+    config: Arc<Config>,
+    committees: Arc<RwLock<BTreeMap<EpochId, Committee>>>,
+}
+
+/// Syncs the end-of-epoch checkpoints, then serves proofs on `listen_address`
+/// while syncing them again every `sync_interval`.
+pub async fn run(
+    config: Config,
+    listen_address: SocketAddr,
+    sync_interval: Duration,
+) -> anyhow::Result<()> {
+    let state = AppState {
+        committees: Arc::new(RwLock::new(check_and_sync_checkpoints(&config).await?)),
+        config: Arc::new(config),
+    };
+
+    tokio::spawn(sync_committees(state.clone(), sync_interval));
+
+    let app = Router::new()
+        .route("/proof/object/:object_id", get(object_proof))
+        .route("/proof/event/:tx_digest/:event_seq", get(event_proof))
+        .route("/proof/tx/:tx_digest", get(transaction_proof))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(listen_address).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+/// Periodically syncs the end-of-epoch checkpoints to learn the committees of
+/// new epochs. Only the checkpoints following the latest known committee are
+/// verified.
+async fn sync_committees(state: AppState, sync_interval: Duration) {
+    let mut interval = tokio::time::interval(sync_interval);
+    // The first tick completes immediately, and the initial sync already happened
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let latest = state
+            .committees
+            .read()
+            .expect("committees lock should not be poisoned")
+            .last_key_value()
+            .map(|(_, committee)| committee.clone())
+            .expect("the genesis committee is always known");
+        match sync_committees_after(&state.config, &latest).await {
+            Ok(committees) => state
+                .committees
+                .write()
+                .expect("committees lock should not be poisoned")
+                .extend(
+                    committees
+                        .into_iter()
+                        .map(|committee| (committee.epoch, committee)),
+                ),
+            Err(e) => error!("Failed to sync checkpoints: {e}"),
+        }
+    }
+}
+
+/// Proves the latest version of an object, through the transaction which
+/// wrote it.
+async fn object_proof(
+    Path(object_id): Path<ObjectID>,
+    accept: AcceptFormat,
+    State(state): State<AppState>,
+) -> Result<Response, ProofServerError> {
+    let client = Client::new(state.config.rest_url());
+    let object = client.get_object(object_id).await.map_err(|e| {
+        if e.status() == Some(reqwest::StatusCode::NOT_FOUND) {
+            ProofServerError::NotFound(format!("Object {object_id} not found"))
+        } else {
+            ProofServerError::Internal(e.into())
+        }
+    })?;
+
+    let tx_digest = object.previous_transaction;
+    prove(&state, tx_digest, accept, |data| {
+        let target = ProofTarget::new().add_object(object.compute_object_reference(), object);
+        construct_proof(target, data)
+    })
+    .await
+}
+
+/// Proves an event, through the transaction which emitted it.
+async fn event_proof(
+    Path((tx_digest, event_seq)): Path<(TransactionDigest, u64)>,
+    accept: AcceptFormat,
+    State(state): State<AppState>,
+) -> Result<Response, ProofServerError> {
+    prove(
+        &state,
+        tx_digest,
+        accept,
+        |data| -> Result<_, ProofServerError> {
+            let event = data
+                .transactions
+                .iter()
+                .find(|tx| tx.transaction.digest() == &tx_digest)
+                .and_then(|tx| tx.events.as_ref())
+                .and_then(|events| events.data.get(event_seq as usize))
+                .ok_or_else(|| {
+                    ProofServerError::NotFound(format!(
+                        "Event {event_seq} of transaction {tx_digest} not found"
+                    ))
+                })?
+                .clone();
+
+            let target = ProofTarget::new().add_event(EventID::from((tx_digest, event_seq)), event);
+            Ok(construct_proof(target, data)?)
+        },
+    )
+    .await
+}
+
+/// Proves a transaction along with its effects and events.
+async fn transaction_proof(
+    Path(tx_digest): Path<TransactionDigest>,
+    accept: AcceptFormat,
+    State(state): State<AppState>,
+) -> Result<Response, ProofServerError> {
+    prove(&state, tx_digest, accept, |data| {
+        construct_transaction_proof(tx_digest, data)
+    })
+    .await
+}
+
+/// Builds a proof with `construct` from the checkpoint including the
+/// transaction, and verifies it against the committee of the checkpoint's
+/// epoch before responding with it.
+async fn prove<E>(
+    state: &AppState,
+    tx_digest: TransactionDigest,
+    accept: AcceptFormat,
+    construct: impl FnOnce(&CheckpointData) -> Result<Proof, E>,
+) -> Result<Response, ProofServerError>
+where
+    ProofServerError: From<E>,
+{
+    let seq = get_checkpoint_sequence_number(&state.config, tx_digest)
+        .await?
+        .ok_or_else(|| {
+            ProofServerError::NotFound(format!("Transaction {tx_digest} not found in a checkpoint"))
+        })?;
+    let data = Client::new(state.config.rest_url())
+        .get_full_checkpoint(seq)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    let epoch = data.checkpoint_summary.epoch();
+    let committee = state
+        .committees
+        .read()
+        .expect("committees lock should not be poisoned")
+        .get(&epoch)
+        .cloned()
+        .ok_or(ProofServerError::CommitteeNotSynced(epoch))?;
+
+    let proof = construct(&data)?;
+    verify_proof(&committee, &proof)?;
+
+    let response = match accept {
+        AcceptFormat::Json => Json(proof).into_response(),
+        AcceptFormat::Bcs => (
+            [(header::CONTENT_TYPE, APPLICATION_BCS)],
+            bcs::to_bytes(&proof).map_err(anyhow::Error::from)?,
+        )
+            .into_response(),
+    };
+    Ok(response)
+}
+
+enum ProofServerError {
+    NotFound(String),
+    CommitteeNotSynced(EpochId),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ProofServerError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Internal(error)
+    }
+}
+
+impl IntoResponse for ProofServerError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(message) => (StatusCode::NOT_FOUND, message).into_response(),
+            Self::CommitteeNotSynced(epoch) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("The committee of epoch {epoch} has not been synced yet"),
+            )
+                .into_response(),
+            Self::Internal(error) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+            }
+        }
+    }
+}
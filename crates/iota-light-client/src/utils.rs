@@ -2,8 +2,10 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 use std::{
+    collections::BTreeMap,
     fs,
     io::{Read, Write},
+    net::SocketAddr,
     path::PathBuf,
     sync::Arc,
 };
@@ -12,13 +14,11 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use clap::Subcommand;
 use iota_config::genesis::Genesis;
-use iota_json_rpc_types::IotaTransactionBlockResponseOptions;
 use iota_package_resolver::{Package, PackageStore, Result as ResolverResult};
-use iota_rest_api::{CheckpointData, Client};
-use iota_sdk::IotaClientBuilder;
+use iota_rest_api::{CheckpointData, Client, client::reqwest};
 use iota_types::{
     base_types::ObjectID,
-    committee::Committee,
+    committee::{Committee, EpochId},
     crypto::AuthorityQuorumSignInfo,
     digests::TransactionDigest,
     effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
@@ -28,29 +28,57 @@ use iota_types::{
 };
 use move_core_types::account_address::AccountAddress;
 
-pub async fn check_and_sync_checkpoints(config: &Config) -> anyhow::Result<()> {
-    sync_checkpoint_list_to_latest(config).await?;
-
-    // Get the local checkpoint list
-    let checkpoints_list: CheckpointsList = read_checkpoint_list_from_config(config)?;
-
+/// Syncs and verifies the end-of-epoch checkpoints, returning the committee of
+/// every epoch up to the latest one.
+pub async fn check_and_sync_checkpoints(
+    config: &Config,
+) -> anyhow::Result<BTreeMap<EpochId, Committee>> {
     // Load the genesis committee
     let mut genesis_path = config.checkpoint_summary_dir.clone();
     genesis_path.push(&config.genesis_filename);
     let genesis_committee = Genesis::load(&genesis_path)?.committee()?;
 
-    // Check the signatures of all checkpoints
+    let mut committees = BTreeMap::from([(genesis_committee.epoch, genesis_committee.clone())]);
+    committees.extend(
+        sync_committees_after(config, &genesis_committee)
+            .await?
+            .into_iter()
+            .map(|committee| (committee.epoch, committee)),
+    );
+    Ok(committees)
+}
+
+/// Syncs the end-of-epoch checkpoints and verifies the ones following the
+/// epoch of `committee`, returning the committees of the next epochs. The
+/// end-of-epoch checkpoints of the previous epochs are expected to be already
+/// verified.
+pub async fn sync_committees_after(
+    config: &Config,
+    committee: &Committee,
+) -> anyhow::Result<Vec<Committee>> {
+    sync_checkpoint_list_to_latest(config).await?;
+
+    // Get the local checkpoint list, which holds the end-of-epoch checkpoint
+    // of every epoch in order
+    let checkpoints_list: CheckpointsList = read_checkpoint_list_from_config(config)?;
+
+    // Check the signatures of the new checkpoints
     // And download any missing ones
 
-    let mut prev_committee = genesis_committee;
-    for ckp_id in &checkpoints_list.checkpoints {
+    let mut committees = vec![];
+    let mut prev_committee = committee.clone();
+    for ckp_id in checkpoints_list
+        .checkpoints
+        .iter()
+        .skip(committee.epoch as usize)
+    {
         // check if there is a file with this name ckp_id.yaml in the
         // checkpoint_summary_dir
         let mut checkpoint_path = config.checkpoint_summary_dir.clone();
         checkpoint_path.push(format!("{}.json", ckp_id));
         // If file exists read the file otherwise download it from the server
         let summary = if checkpoint_path.exists() {
-            read_checkpoint(config, *ckp_id)?
+            read_checkpoint_summary(config, *ckp_id)?
         } else {
             // Download the checkpoint from the server
             let summary = download_checkpoint_summary(config, *ckp_id).await?;
@@ -59,6 +87,11 @@ pub async fn check_and_sync_checkpoints(config: &Config) -> anyhow::Result<()> {
             write_checkpoint(config, &summary)?;
             summary
         };
+        anyhow::ensure!(
+            summary.epoch() == prev_committee.epoch,
+            "Expected checkpoint {ckp_id} to be the end-of-epoch checkpoint of epoch {}",
+            prev_committee.epoch
+        );
 
         // Print the id of the checkpoint and the epoch number
         println!(
@@ -76,6 +109,7 @@ pub async fn check_and_sync_checkpoints(config: &Config) -> anyhow::Result<()> {
             let next_committee = next_epoch_committee.iter().cloned().collect();
             prev_committee =
                 Committee::new(summary.epoch().checked_add(1).unwrap(), next_committee);
+            committees.push(prev_committee.clone());
         } else {
             return Err(anyhow!(
                 "Expected all checkpoints to be end-of-epoch checkpoints"
@@ -83,7 +117,7 @@ pub async fn check_and_sync_checkpoints(config: &Config) -> anyhow::Result<()> {
         }
     }
 
-    Ok(())
+    Ok(committees)
 }
 
 pub fn write_checkpoint(
@@ -246,24 +280,27 @@ pub fn extract_verified_effects_and_events(
     Ok((matching_tx.effects.clone(), matching_tx.events.clone()))
 }
 
+/// Returns the sequence number of the checkpoint including the transaction,
+/// or `None` if the node does not know the transaction or has not included it
+/// in a checkpoint yet.
+pub async fn get_checkpoint_sequence_number(
+    config: &Config,
+    tid: TransactionDigest,
+) -> anyhow::Result<Option<u64>> {
+    let client = iota_rest_api::client::sdk::Client::new(config.rest_url())?;
+    match client.get_transaction(&tid.into()).await {
+        Ok(response) => Ok(response.into_inner().checkpoint),
+        Err(e) if e.status() == Some(reqwest::StatusCode::NOT_FOUND) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
 pub async fn get_verified_effects_and_events(
     config: &Config,
     tid: TransactionDigest,
 ) -> anyhow::Result<(TransactionEffects, Option<TransactionEvents>)> {
-    let iota_mainnet: Arc<iota_sdk::IotaClient> = Arc::new(
-        IotaClientBuilder::default()
-            .build(config.full_node_url.as_str())
-            .await
-            .unwrap(),
-    );
-    let read_api = iota_mainnet.read_api();
-
-    // Lookup the transaction id and get the checkpoint sequence number
-    let options = IotaTransactionBlockResponseOptions::new();
-    let seq = read_api
-        .get_transaction_with_options(tid, options)
+    let seq = get_checkpoint_sequence_number(config, tid)
         .await?
-        .checkpoint
         .ok_or(anyhow!("Transaction not found"))?;
 
     // Download the full checkpoint for this sequence number
@@ -369,6 +406,18 @@ pub enum SCommands {
         #[arg(short, long, value_name = "OID")]
         oid: String,
     },
+
+    /// Runs a server keeping the end-of-epoch checkpoints synced and serving
+    /// proofs for objects, events and transactions
+    Serve {
+        /// Address to listen on
+        #[arg(short, long, default_value = "0.0.0.0:9191")]
+        listen_address: SocketAddr,
+
+        /// Seconds to wait between syncs of the end-of-epoch checkpoints
+        #[arg(short, long, default_value_t = 60)]
+        sync_interval_secs: u64,
+    },
 }
 
 // The config file for the light client including the root of trust genesis
@@ -418,6 +467,17 @@ pub fn read_checkpoint_list_from_config(config: &Config) -> anyhow::Result<Check
     read_checkpoint_list(checkpoints_path)
 }
 
+/// Reads a checkpoint summary written by `write_checkpoint`.
+pub fn read_checkpoint_summary(
+    config: &Config,
+    seq: u64,
+) -> anyhow::Result<CertifiedCheckpointSummary> {
+    let mut checkpoint_path = config.checkpoint_summary_dir.clone();
+    checkpoint_path.push(format!("{}.json", seq));
+    let reader = fs::File::open(&checkpoint_path)?;
+    serde_json::from_reader(reader).map_err(|_| anyhow!("Unable to parse checkpoint summary file"))
+}
+
 pub fn read_checkpoint(
     config: &Config,
     seq: u64,
@@ -10,16 +10,18 @@ use std::{
 
 use anyhow::anyhow;
 use iota_light_client::{
-    construct::construct_proof,
+    construct::{construct_proof, construct_transaction_proof},
     proof::{Proof, ProofTarget, verify_proof},
     utils::{CheckpointsList, read_checkpoint_list},
 };
 use iota_rest_api::CheckpointData;
 use iota_types::{
+    TypeTag,
+    base_types::{IotaAddress, ObjectID},
     committee::Committee,
     effects::TransactionEffectsAPI,
     event::{Event, EventID},
-    object::Object,
+    object::{Object, Owner},
 };
 
 async fn read_full_checkpoint(checkpoint_path: &PathBuf) -> anyhow::Result<CheckpointData> {
@@ -301,3 +303,135 @@ async fn test_event_target_fail_bad_event() {
 
     assert!(verify_proof(&committee, &event_proof).is_err());
 }
+
+#[tokio::test]
+async fn test_transaction_proof_success() {
+    let (committee, full_checkpoint) = read_test_data().await;
+
+    let tx_digest = *full_checkpoint.transactions[1].effects.transaction_digest();
+    let tx_proof = construct_transaction_proof(tx_digest, &full_checkpoint).unwrap();
+    assert!(verify_proof(&committee, &tx_proof).is_ok());
+
+    // The proof can be verified after a round trip through its serialization
+    let bytes = bcs::to_bytes(&tx_proof).unwrap();
+    let tx_proof: Proof = bcs::from_bytes(&bytes).unwrap();
+    assert!(verify_proof(&committee, &tx_proof).is_ok());
+}
+
+#[tokio::test]
+async fn test_dynamic_field_target_success() {
+    let (committee, full_checkpoint) = read_test_data().await;
+
+    // The epoch change transaction writes `Field<u64, _>` objects, such as the
+    // inner system state
+    let field_object: Object = full_checkpoint.transactions[1]
+        .output_objects
+        .iter()
+        .find(|o| {
+            o.data.try_as_move().is_some_and(|m| {
+                m.type_().is_dynamic_field()
+                    && m.type_().type_params().first() == Some(&TypeTag::U64)
+            })
+        })
+        .expect("the epoch change transaction should write a dynamic field")
+        .clone();
+    let Owner::ObjectOwner(parent) = field_object.owner else {
+        panic!("a dynamic field object should be owned by its parent");
+    };
+
+    // The contents are the field ID, followed by the u64 key and the value
+    let contents = field_object.data.try_as_move().unwrap().contents();
+    let value_start = ObjectID::LENGTH + 8;
+    let key_bytes = contents[ObjectID::LENGTH..value_start].to_vec();
+    let value_bytes = contents[value_start..].to_vec();
+
+    let target = ProofTarget::new().add_dynamic_field(
+        parent.into(),
+        TypeTag::U64,
+        key_bytes.clone(),
+        value_bytes.clone(),
+        field_object.clone(),
+    );
+    let field_proof = construct_proof(target, &full_checkpoint).unwrap();
+    assert!(verify_proof(&committee, &field_proof).is_ok());
+
+    let mut wrong_value_bytes = value_bytes;
+    wrong_value_bytes.push(0); // WRONG
+
+    let target = ProofTarget::new().add_dynamic_field(
+        parent.into(),
+        TypeTag::U64,
+        key_bytes,
+        wrong_value_bytes,
+        field_object,
+    );
+    let field_proof = construct_proof(target, &full_checkpoint).unwrap();
+    assert!(verify_proof(&committee, &field_proof).is_err());
+}
+
+#[tokio::test]
+async fn test_dynamic_field_target_fail_not_a_field() {
+    let (committee, full_checkpoint) = read_test_data().await;
+
+    let sample_object: Object = full_checkpoint.transactions[0].output_objects[0].clone();
+    let key_bytes = bcs::to_bytes(&0u64).unwrap();
+
+    let target = ProofTarget::new().add_dynamic_field(
+        sample_object.id(), // WRONG
+        TypeTag::U64,
+        key_bytes,
+        vec![],
+        sample_object,
+    );
+    let field_proof = construct_proof(target, &full_checkpoint).unwrap();
+
+    assert!(verify_proof(&committee, &field_proof).is_err());
+}
+
+#[tokio::test]
+async fn test_transfer_target_fail_not_transferred() {
+    let (committee, full_checkpoint) = read_test_data().await;
+
+    let effects = &full_checkpoint.transactions[1].effects;
+    let (gas_ref, gas_owner) = effects.gas_object();
+
+    // The gas object is owned by the sender before and after the transaction
+    let target = ProofTarget::new().add_transfer(
+        *effects.transaction_digest(),
+        gas_ref,
+        gas_owner.get_owner_address().unwrap_or_default(),
+    );
+    let transfer_proof = construct_proof(target, &full_checkpoint).unwrap();
+
+    assert!(verify_proof(&committee, &transfer_proof).is_err());
+}
+
+#[tokio::test]
+async fn test_transfer_target_success() {
+    let (committee, full_checkpoint) = read_test_data().await;
+
+    // The epoch change transaction creates objects owned by addresses, such as
+    // staking rewards
+    let effects = &full_checkpoint.transactions[1].effects;
+    let (object_ref, recipient) = effects
+        .created()
+        .into_iter()
+        .find_map(|(object_ref, owner)| match owner {
+            Owner::AddressOwner(recipient) => Some((object_ref, recipient)),
+            _ => None,
+        })
+        .expect("the epoch change transaction should create an address owned object");
+
+    let target =
+        ProofTarget::new().add_transfer(*effects.transaction_digest(), object_ref, recipient);
+    let transfer_proof = construct_proof(target, &full_checkpoint).unwrap();
+    assert!(verify_proof(&committee, &transfer_proof).is_ok());
+
+    let target = ProofTarget::new().add_transfer(
+        *effects.transaction_digest(),
+        object_ref,
+        IotaAddress::ZERO, // WRONG
+    );
+    let transfer_proof = construct_proof(target, &full_checkpoint).unwrap();
+    assert!(verify_proof(&committee, &transfer_proof).is_err());
+}
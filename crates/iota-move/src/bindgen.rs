@@ -0,0 +1,721 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::bail;
+use clap::Parser;
+use iota_move_build::BuildConfig;
+use iota_types::{IOTA_FRAMEWORK_ADDRESS, MOVE_STDLIB_ADDRESS, base_types::ObjectID};
+use move_binary_format::{
+    CompiledModule,
+    file_format::{DatatypeTyParameter, Visibility},
+    normalized::{self, Bytecode, Field, Type},
+};
+use move_cli::base;
+use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_package::BuildConfig as MoveBuildConfig;
+
+use crate::manage_package::resolve_lock_file_path;
+
+/// Keywords which cannot be used as Rust identifiers without escaping them.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Generate Rust bindings for a Move package: serde types mirroring its
+/// structs and enums, decoding of its events, and functions adding calls to
+/// its public and entry functions to a programmable transaction.
+#[derive(Parser)]
+#[group(id = "iota-move-bindgen")]
+pub struct Bindgen {
+    /// Generate the bindings of the package published on-chain with this ID,
+    /// rather than of the package at the given path.
+    #[clap(long)]
+    pub package_id: Option<ObjectID>,
+    /// The file to write the bindings to. They are printed to the standard
+    /// output if not set.
+    #[clap(long, short)]
+    pub output: Option<PathBuf>,
+    /// The modules of the on-chain package, fetched before executing the
+    /// command when `package_id` is set.
+    #[clap(skip)]
+    pub modules: Vec<CompiledModule>,
+    /// The modules of the dependencies of the on-chain package.
+    #[clap(skip)]
+    pub dependency_modules: Vec<CompiledModule>,
+}
+
+impl Bindgen {
+    pub fn execute(self, path: Option<&Path>, config: MoveBuildConfig) -> anyhow::Result<()> {
+        let bindings = match self.package_id {
+            Some(package_id) => {
+                if self.modules.is_empty() {
+                    bail!("The modules of package {package_id} have not been fetched");
+                }
+                generate_bindings(Some(package_id), &self.modules, &self.dependency_modules)?
+            }
+            None => {
+                let rerooted_path = base::reroot_path(path)?;
+                let config = resolve_lock_file_path(config, Some(&rerooted_path))?;
+                let pkg = BuildConfig {
+                    config,
+                    run_bytecode_verifier: true,
+                    print_diags_to_stderr: true,
+                    chain_id: None,
+                }
+                .build(&rerooted_path)?;
+                let modules = pkg.get_modules().cloned().collect::<Vec<_>>();
+                let dependency_modules = pkg.get_dependent_modules().cloned().collect::<Vec<_>>();
+                generate_bindings(pkg.published_at.ok(), &modules, &dependency_modules)?
+            }
+        };
+        match self.output {
+            Some(output) => fs::write(output, bindings)?,
+            None => print!("{bindings}"),
+        }
+        Ok(())
+    }
+}
+
+/// Generates the Rust bindings of the package made of `modules`, which can be
+/// written to a file and included as a Rust module, e.g. from a build script.
+///
+/// `dependency_modules` are the modules of the dependencies of the package,
+/// from which the types used by the package are generated as well. The
+/// bindings require the `anyhow`, `bcs`, `iota-types`, `move-core-types` and
+/// `serde` crates.
+pub fn generate_bindings(
+    package_id: Option<ObjectID>,
+    modules: &[CompiledModule],
+    dependency_modules: &[CompiledModule],
+) -> anyhow::Result<String> {
+    Generator::new(modules, dependency_modules).generate(package_id)
+}
+
+/// A struct or enum, identified by its address, module and name.
+type DatatypeKey = (AccountAddress, Identifier, Identifier);
+
+enum Datatype<'a> {
+    Struct(&'a normalized::Struct),
+    Enum(&'a normalized::Enum),
+}
+
+impl Datatype<'_> {
+    fn type_parameters(&self) -> &[DatatypeTyParameter] {
+        match self {
+            Self::Struct(s) => &s.type_parameters,
+            Self::Enum(e) => &e.type_parameters,
+        }
+    }
+
+    fn fields(&self) -> Vec<&Field> {
+        match self {
+            Self::Struct(s) => s.fields.iter().collect(),
+            Self::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+        }
+    }
+}
+
+/// Move types which have a Rust counterpart in the standard library or in
+/// `iota-types`.
+enum KnownType {
+    /// A type without type parameters in Rust.
+    Path(&'static str),
+    /// `std::option::Option`.
+    Option,
+}
+
+fn known_type(address: &AccountAddress, module: &str, name: &str) -> Option<KnownType> {
+    if *address == MOVE_STDLIB_ADDRESS {
+        match (module, name) {
+            ("string" | "ascii", "String") => Some(KnownType::Path("::std::string::String")),
+            ("option", "Option") => Some(KnownType::Option),
+            _ => None,
+        }
+    } else if *address == IOTA_FRAMEWORK_ADDRESS {
+        match (module, name) {
+            ("object", "UID") => Some(KnownType::Path("::iota_types::id::UID")),
+            ("object", "ID") => Some(KnownType::Path("::iota_types::id::ID")),
+            ("balance", "Balance") => Some(KnownType::Path("::iota_types::balance::Balance")),
+            ("coin", "Coin") => Some(KnownType::Path("::iota_types::coin::Coin")),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+struct Generator {
+    modules: BTreeMap<(AccountAddress, Identifier), normalized::Module>,
+    root_modules: BTreeSet<(AccountAddress, Identifier)>,
+}
+
+impl Generator {
+    fn new(modules: &[CompiledModule], dependency_modules: &[CompiledModule]) -> Self {
+        let mut generator = Self {
+            modules: BTreeMap::new(),
+            root_modules: BTreeSet::new(),
+        };
+        for module in dependency_modules.iter().chain(modules) {
+            let module = normalized::Module::new(module);
+            generator
+                .modules
+                .insert((module.address, module.name.clone()), module);
+        }
+        for module in modules {
+            let id = module.self_id();
+            generator
+                .root_modules
+                .insert((*id.address(), id.name().to_owned()));
+        }
+        generator
+    }
+
+    fn generate(&self, package_id: Option<ObjectID>) -> anyhow::Result<String> {
+        // the datatypes of the package, along with the ones of its
+        // dependencies they use
+        let mut datatypes = BTreeSet::new();
+        let mut pending = Vec::new();
+        for key in &self.root_modules {
+            let module = &self.modules[key];
+            for name in module.structs.keys().chain(module.enums.keys()) {
+                pending.push((key.0, key.1.clone(), name.clone()));
+            }
+        }
+        while let Some(key) = pending.pop() {
+            if datatypes.contains(&key) {
+                continue;
+            }
+            let mut referenced = BTreeSet::new();
+            for field in self.datatype(&key)?.fields() {
+                self.referenced_datatypes(&field.type_, &mut referenced)?;
+            }
+            pending.extend(referenced);
+            datatypes.insert(key);
+        }
+
+        let mut out = String::new();
+        writeln!(out, "// @generated by `iota move bindgen`, do not edit.")?;
+        writeln!(out, "//")?;
+        writeln!(
+            out,
+            "// Requires the `anyhow`, `bcs`, `iota-types`, `move-core-types` and `serde` crates."
+        )?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "#![allow(clippy::all, dead_code, non_camel_case_types, non_snake_case, unused)]"
+        )?;
+        if let Some(package_id) = package_id {
+            writeln!(out)?;
+            writeln!(out, "/// ID of the package the functions are called from.")?;
+            writeln!(
+                out,
+                "pub const PACKAGE_ID: ::iota_types::base_types::ObjectID = \
+                 ::iota_types::base_types::ObjectID::new({:?});",
+                package_id.into_bytes()
+            )?;
+        }
+
+        let dependency_modules = datatypes
+            .iter()
+            .map(|(address, module, _)| (*address, module.clone()))
+            .filter(|key| !self.root_modules.contains(key))
+            .collect::<BTreeSet<_>>();
+        for key in self.root_modules.iter().chain(&dependency_modules) {
+            let module = &self.modules[key];
+            writeln!(out)?;
+            writeln!(
+                out,
+                "/// Bindings of the Move module `{}`.",
+                module_display(key)
+            )?;
+            writeln!(out, "pub mod {} {{", self.rust_module_name(&key.0, &key.1))?;
+            writeln!(
+                out,
+                "    /// Address of the package defining the module, which is the one of its types."
+            )?;
+            writeln!(
+                out,
+                "    pub const ADDRESS: ::move_core_types::account_address::AccountAddress = \
+                 ::move_core_types::account_address::AccountAddress::new({:?});",
+                key.0.into_bytes()
+            )?;
+            writeln!(out, "    /// Name of the module.")?;
+            writeln!(out, "    pub const MODULE_NAME: &str = \"{}\";", key.1)?;
+
+            let events = self.root_modules.contains(key).then(|| self.events(module));
+            for datatype in &datatypes {
+                if (datatype.0, &datatype.1) == (key.0, &key.1) {
+                    let is_event = events
+                        .as_ref()
+                        .is_some_and(|events| events.contains(datatype));
+                    self.write_datatype(&mut out, datatype, is_event)?;
+                }
+            }
+            if self.root_modules.contains(key) {
+                for (name, function) in &module.functions {
+                    if function.visibility == Visibility::Public || function.is_entry {
+                        self.write_function(&mut out, &key.1, name, function)?;
+                    }
+                }
+            }
+            writeln!(out, "}}")?;
+        }
+        Ok(out)
+    }
+
+    fn datatype(&self, (address, module, name): &DatatypeKey) -> anyhow::Result<Datatype<'_>> {
+        let Some(m) = self.modules.get(&(*address, module.clone())) else {
+            bail!(
+                "Module {} is not available to generate the bindings of its types",
+                module_display(&(*address, module.clone()))
+            );
+        };
+        if let Some(s) = m.structs.get(name) {
+            Ok(Datatype::Struct(s))
+        } else if let Some(e) = m.enums.get(name) {
+            Ok(Datatype::Enum(e))
+        } else {
+            bail!(
+                "Type {name} not found in module {}",
+                module_display(&(*address, module.clone()))
+            )
+        }
+    }
+
+    /// Returns the indices of the type parameters of a datatype which are
+    /// kept in its Rust version, i.e. the ones its fields depend on. Phantom
+    /// type parameters, in particular, are dropped.
+    fn rust_type_params(&self, key: &DatatypeKey) -> anyhow::Result<Vec<u16>> {
+        let mut used = BTreeSet::new();
+        for field in self.datatype(key)?.fields() {
+            self.used_type_params(&field.type_, &mut used)?;
+        }
+        Ok(used.into_iter().collect())
+    }
+
+    fn used_type_params(&self, ty: &Type, used: &mut BTreeSet<u16>) -> anyhow::Result<()> {
+        match ty {
+            Type::TypeParameter(i) => {
+                used.insert(*i);
+            }
+            Type::Vector(ty) => self.used_type_params(ty, used)?,
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => match known_type(address, module.as_str(), name.as_str()) {
+                Some(KnownType::Option) => self.used_type_params(&type_arguments[0], used)?,
+                Some(KnownType::Path(_)) => (),
+                None => {
+                    for i in self.rust_type_params(&(*address, module.clone(), name.clone()))? {
+                        self.used_type_params(&type_arguments[i as usize], used)?;
+                    }
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Collects the datatypes which need to be generated to use `ty` in Rust.
+    fn referenced_datatypes(
+        &self,
+        ty: &Type,
+        referenced: &mut BTreeSet<DatatypeKey>,
+    ) -> anyhow::Result<()> {
+        match ty {
+            Type::Vector(ty) => self.referenced_datatypes(ty, referenced)?,
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => match known_type(address, module.as_str(), name.as_str()) {
+                Some(KnownType::Option) => {
+                    self.referenced_datatypes(&type_arguments[0], referenced)?
+                }
+                Some(KnownType::Path(_)) => (),
+                None => {
+                    let key = (*address, module.clone(), name.clone());
+                    for i in self.rust_type_params(&key)? {
+                        self.referenced_datatypes(&type_arguments[i as usize], referenced)?;
+                    }
+                    referenced.insert(key);
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn rust_type(&self, ty: &Type) -> anyhow::Result<String> {
+        Ok(match ty {
+            Type::Bool => "bool".to_string(),
+            Type::U8 => "u8".to_string(),
+            Type::U16 => "u16".to_string(),
+            Type::U32 => "u32".to_string(),
+            Type::U64 => "u64".to_string(),
+            Type::U128 => "u128".to_string(),
+            Type::U256 => "::move_core_types::u256::U256".to_string(),
+            Type::Address => "::iota_types::base_types::IotaAddress".to_string(),
+            Type::Vector(ty) => format!("::std::vec::Vec<{}>", self.rust_type(ty)?),
+            Type::TypeParameter(i) => format!("T{i}"),
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => match known_type(address, module.as_str(), name.as_str()) {
+                Some(KnownType::Option) => format!(
+                    "::std::option::Option<{}>",
+                    self.rust_type(&type_arguments[0])?
+                ),
+                Some(KnownType::Path(path)) => path.to_string(),
+                None => {
+                    let params =
+                        self.rust_type_params(&(*address, module.clone(), name.clone()))?;
+                    let mut path = format!(
+                        "super::{}::{}",
+                        self.rust_module_name(address, module),
+                        rust_ident(name.as_str())
+                    );
+                    if !params.is_empty() {
+                        let arguments = params
+                            .iter()
+                            .map(|i| self.rust_type(&type_arguments[*i as usize]))
+                            .collect::<anyhow::Result<Vec<_>>>()?;
+                        write!(path, "<{}>", arguments.join(", "))?;
+                    }
+                    path
+                }
+            },
+            Type::Signer | Type::Reference(_) | Type::MutableReference(_) => {
+                bail!("Type {ty} has no Rust version")
+            }
+        })
+    }
+
+    /// Returns the Rust type of a function parameter which can be passed as
+    /// a pure value, if any.
+    fn pure_type(&self, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Bool
+            | Type::U8
+            | Type::U16
+            | Type::U32
+            | Type::U64
+            | Type::U128
+            | Type::U256
+            | Type::Address => self.rust_type(ty).ok(),
+            Type::Vector(ty) => Some(format!("::std::vec::Vec<{}>", self.pure_type(ty)?)),
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => match known_type(address, module.as_str(), name.as_str())? {
+                KnownType::Option => Some(format!(
+                    "::std::option::Option<{}>",
+                    self.pure_type(&type_arguments[0])?
+                )),
+                KnownType::Path("::iota_types::id::ID") => {
+                    Some("::iota_types::base_types::ObjectID".to_string())
+                }
+                KnownType::Path(path @ "::std::string::String") => Some(path.to_string()),
+                KnownType::Path(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn rust_module_name(&self, address: &AccountAddress, module: &Identifier) -> String {
+        if self.root_modules.contains(&(*address, module.clone())) {
+            rust_ident(module.as_str())
+        } else {
+            format!("{module}_{}", address.short_str_lossless())
+        }
+    }
+
+    /// Returns the datatypes of `module` emitted as events, by looking for
+    /// calls to `iota::event::emit` in its functions.
+    fn events(&self, module: &normalized::Module) -> BTreeSet<DatatypeKey> {
+        let mut events = BTreeSet::new();
+        for function in module.functions.values() {
+            for bytecode in &function.code {
+                let Bytecode::CallGeneric((function_ref, type_arguments)) = bytecode else {
+                    continue;
+                };
+                let module_id = &function_ref.module_id;
+                if *module_id.address() != IOTA_FRAMEWORK_ADDRESS
+                    || module_id.name().as_str() != "event"
+                    || function_ref.function_ident.as_str() != "emit"
+                {
+                    continue;
+                }
+                if let Some(Type::Struct {
+                    address,
+                    module,
+                    name,
+                    ..
+                }) = type_arguments.first()
+                {
+                    events.insert((*address, module.clone(), name.clone()));
+                }
+            }
+        }
+        events
+    }
+
+    fn write_datatype(
+        &self,
+        out: &mut String,
+        key: &DatatypeKey,
+        is_event: bool,
+    ) -> anyhow::Result<()> {
+        let (_, module, name) = key;
+        let datatype = self.datatype(key)?;
+        let params = self.rust_type_params(key)?;
+        let generics = if params.is_empty() {
+            String::new()
+        } else {
+            let params = params.iter().map(|i| format!("T{i}")).collect::<Vec<_>>();
+            format!("<{}>", params.join(", "))
+        };
+        let rust_name = rust_ident(name.as_str());
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    /// Rust version of the Move type `{module}::{name}`."
+        )?;
+        writeln!(
+            out,
+            "    #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]"
+        )?;
+        match datatype {
+            Datatype::Struct(s) => {
+                writeln!(out, "    pub struct {rust_name}{generics} {{")?;
+                for field in &s.fields {
+                    writeln!(
+                        out,
+                        "        pub {}: {},",
+                        rust_ident(field.name.as_str()),
+                        self.rust_type(&field.type_)?
+                    )?;
+                }
+                writeln!(out, "    }}")?;
+            }
+            Datatype::Enum(e) => {
+                writeln!(out, "    pub enum {rust_name}{generics} {{")?;
+                for variant in &e.variants {
+                    let variant_name = rust_ident(variant.name.as_str());
+                    if variant.fields.is_empty() {
+                        writeln!(out, "        {variant_name},")?;
+                        continue;
+                    }
+                    writeln!(out, "        {variant_name} {{")?;
+                    for field in &variant.fields {
+                        writeln!(
+                            out,
+                            "            {}: {},",
+                            rust_ident(field.name.as_str()),
+                            self.rust_type(&field.type_)?
+                        )?;
+                    }
+                    writeln!(out, "        }},")?;
+                }
+                writeln!(out, "    }}")?;
+            }
+        }
+
+        let type_params_count = datatype.type_parameters().len();
+        writeln!(out)?;
+        writeln!(out, "    impl{generics} {rust_name}{generics} {{")?;
+        writeln!(
+            out,
+            "        /// Returns the Move type of `{name}`, instantiated with `type_params`."
+        )?;
+        if type_params_count == 0 {
+            writeln!(
+                out,
+                "        pub fn struct_tag() -> ::move_core_types::language_storage::StructTag {{"
+            )?;
+        } else {
+            writeln!(
+                out,
+                "        pub fn struct_tag(type_params: [::iota_types::TypeTag; {type_params_count}]) \
+                 -> ::move_core_types::language_storage::StructTag {{"
+            )?;
+        }
+        writeln!(
+            out,
+            "            ::move_core_types::language_storage::StructTag {{"
+        )?;
+        writeln!(out, "                address: ADDRESS,")?;
+        writeln!(
+            out,
+            "                module: ::iota_types::Identifier::new(MODULE_NAME)\n                    \
+                 .expect(\"Move module names are valid identifiers\"),"
+        )?;
+        writeln!(
+            out,
+            "                name: ::iota_types::Identifier::new(\"{name}\")\n                    \
+                 .expect(\"Move type names are valid identifiers\"),"
+        )?;
+        if type_params_count == 0 {
+            writeln!(out, "                type_params: vec![],")?;
+        } else {
+            writeln!(out, "                type_params: type_params.into(),")?;
+        }
+        writeln!(out, "            }}")?;
+        writeln!(out, "        }}")?;
+        if is_event {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "        /// Decodes an event from its type and BCS contents, e.g. the `type_` and"
+            )?;
+            writeln!(
+                out,
+                "        /// `contents` of an `Event`, or `None` if it is not a `{name}` event."
+            )?;
+            writeln!(
+                out,
+                "        pub fn from_event(\n            \
+                 type_: &::move_core_types::language_storage::StructTag,\n            \
+                 contents: &[u8],\n        \
+                 ) -> ::std::result::Result<::std::option::Option<Self>, ::bcs::Error>\n        \
+                 where\n            \
+                 Self: ::serde::de::DeserializeOwned,\n        \
+                 {{"
+            )?;
+            writeln!(
+                out,
+                "            if type_.address != ADDRESS\n                \
+                 || type_.module.as_str() != MODULE_NAME\n                \
+                 || type_.name.as_str() != \"{name}\"\n            \
+                 {{\n                \
+                 return Ok(None);\n            \
+                 }}"
+            )?;
+            writeln!(out, "            ::bcs::from_bytes(contents).map(Some)")?;
+            writeln!(out, "        }}")?;
+        }
+        writeln!(out, "    }}")?;
+        Ok(())
+    }
+
+    fn write_function(
+        &self,
+        out: &mut String,
+        module: &Identifier,
+        name: &Identifier,
+        function: &normalized::Function,
+    ) -> anyhow::Result<()> {
+        let mut parameters = vec![
+            "builder: &mut ::iota_types::programmable_transaction_builder::ProgrammableTransactionBuilder".to_string(),
+            "package: ::iota_types::base_types::ObjectID".to_string(),
+        ];
+        if !function.type_parameters.is_empty() {
+            parameters.push(format!(
+                "type_arguments: [::iota_types::TypeTag; {}]",
+                function.type_parameters.len()
+            ));
+        }
+        let mut arguments = Vec::new();
+        for (i, ty) in function.parameters.iter().enumerate() {
+            if is_tx_context(ty) {
+                continue;
+            }
+            match self.pure_type(ty) {
+                Some(pure_type) => {
+                    parameters.push(format!("arg{i}: {pure_type}"));
+                    arguments.push(format!("builder.pure(arg{i})?"));
+                }
+                None => {
+                    parameters.push(format!("arg{i}: ::iota_types::transaction::Argument"));
+                    arguments.push(format!("arg{i}"));
+                }
+            }
+        }
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    /// Adds a call to the Move function `{module}::{name}` to the transaction."
+        )?;
+        writeln!(out, "    pub fn {}(", rust_ident(name.as_str()))?;
+        for parameter in parameters {
+            writeln!(out, "        {parameter},")?;
+        }
+        writeln!(
+            out,
+            "    ) -> ::anyhow::Result<::iota_types::transaction::Argument> {{"
+        )?;
+        writeln!(
+            out,
+            "        let arguments = vec![{}];",
+            arguments.join(", ")
+        )?;
+        writeln!(out, "        Ok(builder.programmable_move_call(")?;
+        writeln!(out, "            package,")?;
+        writeln!(
+            out,
+            "            ::iota_types::Identifier::new(MODULE_NAME)?,"
+        )?;
+        writeln!(
+            out,
+            "            ::iota_types::Identifier::new(\"{name}\")?,"
+        )?;
+        if function.type_parameters.is_empty() {
+            writeln!(out, "            vec![],")?;
+        } else {
+            writeln!(out, "            type_arguments.into(),")?;
+        }
+        writeln!(out, "            arguments,")?;
+        writeln!(out, "        ))")?;
+        writeln!(out, "    }}")?;
+        Ok(())
+    }
+}
+
+/// Escapes a Move identifier which is a Rust keyword.
+fn rust_ident(name: &str) -> String {
+    match name {
+        "self" | "Self" | "super" | "crate" => format!("{name}_"),
+        _ if RUST_KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn module_display((address, module): &(AccountAddress, Identifier)) -> String {
+    format!("{}::{module}", address.short_str_lossless())
+}
+
+/// Whether the type is a reference to `iota::tx_context::TxContext`, which is
+/// not passed explicitly to Move calls.
+fn is_tx_context(ty: &Type) -> bool {
+    let (Type::Reference(ty) | Type::MutableReference(ty)) = ty else {
+        return false;
+    };
+    matches!(
+        &**ty,
+        Type::Struct { address, module, name, .. }
+            if *address == IOTA_FRAMEWORK_ADDRESS
+                && module.as_str() == "tx_context"
+                && name.as_str() == "TxContext"
+    )
+}
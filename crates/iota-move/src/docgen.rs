@@ -0,0 +1,21 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use clap::Parser;
+use move_cli::base::docgen;
+use move_package::BuildConfig as MoveBuildConfig;
+
+#[derive(Parser)]
+#[group(id = "iota-move-docgen")]
+pub struct Docgen {
+    #[clap(flatten)]
+    pub docgen: docgen::Docgen,
+}
+
+impl Docgen {
+    pub fn execute(self, path: Option<&Path>, config: MoveBuildConfig) -> anyhow::Result<()> {
+        self.docgen.execute(path, config)
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use clap::Parser;
+use move_cli::base::fmt;
+use move_package::BuildConfig as MoveBuildConfig;
+
+#[derive(Parser)]
+#[group(id = "iota-move-fmt")]
+pub struct Fmt {
+    #[clap(flatten)]
+    pub fmt: fmt::Fmt,
+}
+
+impl Fmt {
+    pub fn execute(self, path: Option<&Path>, config: MoveBuildConfig) -> anyhow::Result<()> {
+        self.fmt.execute(path, config)
+    }
+}
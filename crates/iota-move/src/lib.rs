@@ -10,12 +10,15 @@ use iota_move_build::set_iota_flavor;
 use move_cli::base::test::UnitTestResult;
 use move_package::BuildConfig;
 
+pub mod bindgen;
 #[cfg(feature = "build")]
 pub mod build;
 #[cfg(feature = "coverage")]
 pub mod coverage;
 #[cfg(feature = "disassemble")]
 pub mod disassemble;
+pub mod docgen;
+pub mod fmt;
 pub mod manage_package;
 pub mod migrate;
 pub mod new;
@@ -24,12 +27,15 @@ pub mod unit_test;
 
 #[derive(Parser)]
 pub enum Command {
+    Bindgen(bindgen::Bindgen),
     #[cfg(feature = "build")]
     Build(build::Build),
     #[cfg(feature = "coverage")]
     Coverage(coverage::Coverage),
     #[cfg(feature = "disassemble")]
     Disassemble(disassemble::Disassemble),
+    Docgen(docgen::Docgen),
+    Fmt(fmt::Fmt),
     ManagePackage(manage_package::ManagePackage),
     Migrate(migrate::Migrate),
     New(new::New),
@@ -53,12 +59,15 @@ pub fn execute_move_command(
         anyhow::bail!(err_msg);
     }
     match command {
+        Command::Bindgen(c) => c.execute(package_path, build_config),
         #[cfg(feature = "build")]
         Command::Build(c) => c.execute(package_path, build_config),
         #[cfg(feature = "coverage")]
         Command::Coverage(c) => c.execute(package_path, build_config),
         #[cfg(feature = "disassemble")]
         Command::Disassemble(c) => c.execute(package_path, build_config),
+        Command::Docgen(c) => c.execute(package_path, build_config),
+        Command::Fmt(c) => c.execute(package_path, build_config),
         Command::ManagePackage(c) => c.execute(package_path, build_config),
         Command::Migrate(c) => c.execute(package_path, build_config),
         Command::New(c) => c.execute(package_path),
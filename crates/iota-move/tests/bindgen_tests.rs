@@ -0,0 +1,84 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::PathBuf};
+
+use iota_move::bindgen::generate_bindings;
+use iota_move_build::BuildConfig;
+use iota_types::{
+    base_types::{IotaAddress, ObjectID},
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{CallArg, Command},
+};
+
+// The bindings of the `bindgen` test package are checked in and compiled as
+// part of this test, so that they are checked to build with the crates they
+// require.
+#[path = "data/bindgen/bindings.rs"]
+mod bindings;
+
+use bindings::basics::{NewValueEvent, Object};
+
+fn package_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/bindgen")
+}
+
+#[test]
+fn bindings_are_up_to_date() {
+    let package = BuildConfig::new_for_testing()
+        .build(&package_path())
+        .unwrap();
+    let modules = package.get_modules().cloned().collect::<Vec<_>>();
+    let dependency_modules = package.get_dependent_modules().cloned().collect::<Vec<_>>();
+    let bindings = generate_bindings(None, &modules, &dependency_modules).unwrap();
+
+    let bindings_path = package_path().join("bindings.rs");
+    if std::env::var_os("UPDATE").is_some() {
+        fs::write(&bindings_path, &bindings).unwrap();
+    }
+    let expected = fs::read_to_string(&bindings_path).unwrap();
+    assert!(
+        expected == bindings,
+        "Generated bindings do not match the checked-in ones. Re-run with `UPDATE=1` to update them, e.g.\n\n\
+         UPDATE=1 cargo test -p iota-move --test bindgen_tests"
+    );
+}
+
+#[test]
+fn bindings_decode_emitted_events() {
+    let event = NewValueEvent { new_value: 42 };
+    let contents = bcs::to_bytes(&event).unwrap();
+
+    let decoded = NewValueEvent::from_event(&NewValueEvent::struct_tag(), &contents).unwrap();
+    assert_eq!(decoded, Some(event));
+    // events of other types are skipped
+    let decoded = NewValueEvent::from_event(&Object::struct_tag(), &contents).unwrap();
+    assert_eq!(decoded, None);
+}
+
+#[test]
+fn bindings_add_move_calls() {
+    let package = ObjectID::random();
+    let mut builder = ProgrammableTransactionBuilder::new();
+    bindings::basics::create(&mut builder, package, 7, IotaAddress::ZERO).unwrap();
+    let transaction = builder.finish();
+
+    // the transaction context is not an argument of the call
+    assert_eq!(transaction.inputs.len(), 2);
+    assert!(
+        transaction
+            .inputs
+            .iter()
+            .all(|input| matches!(input, CallArg::Pure(_)))
+    );
+    let [Command::MoveCall(call)] = transaction.commands.as_slice() else {
+        panic!(
+            "Expected a single Move call, got {:?}",
+            transaction.commands
+        );
+    };
+    assert_eq!(call.package, package);
+    assert_eq!(call.module.as_str(), "basics");
+    assert_eq!(call.function.as_str(), "create");
+    assert_eq!(call.arguments.len(), 2);
+}
@@ -0,0 +1,177 @@
+// @generated by `iota move bindgen`, do not edit.
+//
+// Requires the `anyhow`, `bcs`, `iota-types`, `move-core-types` and `serde`
+// crates.
+
+#![allow(clippy::all, dead_code, non_camel_case_types, non_snake_case, unused)]
+
+/// Bindings of the Move module `0::basics`.
+pub mod basics {
+    /// Address of the package defining the module, which is the one of its
+    /// types.
+    pub const ADDRESS: ::move_core_types::account_address::AccountAddress =
+        ::move_core_types::account_address::AccountAddress::new([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ]);
+    /// Name of the module.
+    pub const MODULE_NAME: &str = "basics";
+
+    /// Rust version of the Move type `basics::NewValueEvent`.
+    #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct NewValueEvent {
+        pub new_value: u64,
+    }
+
+    impl NewValueEvent {
+        /// Returns the Move type of `NewValueEvent`, instantiated with
+        /// `type_params`.
+        pub fn struct_tag() -> ::move_core_types::language_storage::StructTag {
+            ::move_core_types::language_storage::StructTag {
+                address: ADDRESS,
+                module: ::iota_types::Identifier::new(MODULE_NAME)
+                    .expect("Move module names are valid identifiers"),
+                name: ::iota_types::Identifier::new("NewValueEvent")
+                    .expect("Move type names are valid identifiers"),
+                type_params: vec![],
+            }
+        }
+
+        /// Decodes an event from its type and BCS contents, e.g. the `type_`
+        /// and `contents` of an `Event`, or `None` if it is not a
+        /// `NewValueEvent` event.
+        pub fn from_event(
+            type_: &::move_core_types::language_storage::StructTag,
+            contents: &[u8],
+        ) -> ::std::result::Result<::std::option::Option<Self>, ::bcs::Error>
+        where
+            Self: ::serde::de::DeserializeOwned,
+        {
+            if type_.address != ADDRESS
+                || type_.module.as_str() != MODULE_NAME
+                || type_.name.as_str() != "NewValueEvent"
+            {
+                return Ok(None);
+            }
+            ::bcs::from_bytes(contents).map(Some)
+        }
+    }
+
+    /// Rust version of the Move type `basics::Object`.
+    #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Object {
+        pub id: ::iota_types::id::UID,
+        pub value: u64,
+    }
+
+    impl Object {
+        /// Returns the Move type of `Object`, instantiated with `type_params`.
+        pub fn struct_tag() -> ::move_core_types::language_storage::StructTag {
+            ::move_core_types::language_storage::StructTag {
+                address: ADDRESS,
+                module: ::iota_types::Identifier::new(MODULE_NAME)
+                    .expect("Move module names are valid identifiers"),
+                name: ::iota_types::Identifier::new("Object")
+                    .expect("Move type names are valid identifiers"),
+                type_params: vec![],
+            }
+        }
+    }
+
+    /// Rust version of the Move type `basics::Status`.
+    #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum Status {
+        Active,
+        Paused { until: u64 },
+    }
+
+    impl Status {
+        /// Returns the Move type of `Status`, instantiated with `type_params`.
+        pub fn struct_tag() -> ::move_core_types::language_storage::StructTag {
+            ::move_core_types::language_storage::StructTag {
+                address: ADDRESS,
+                module: ::iota_types::Identifier::new(MODULE_NAME)
+                    .expect("Move module names are valid identifiers"),
+                name: ::iota_types::Identifier::new("Status")
+                    .expect("Move type names are valid identifiers"),
+                type_params: vec![],
+            }
+        }
+    }
+
+    /// Rust version of the Move type `basics::Wrapper`.
+    #[derive(Clone, Debug, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Wrapper<T0> {
+        pub id: ::iota_types::id::UID,
+        pub inner: T0,
+        pub label: ::std::option::Option<::std::string::String>,
+    }
+
+    impl<T0> Wrapper<T0> {
+        /// Returns the Move type of `Wrapper`, instantiated with `type_params`.
+        pub fn struct_tag(
+            type_params: [::iota_types::TypeTag; 2],
+        ) -> ::move_core_types::language_storage::StructTag {
+            ::move_core_types::language_storage::StructTag {
+                address: ADDRESS,
+                module: ::iota_types::Identifier::new(MODULE_NAME)
+                    .expect("Move module names are valid identifiers"),
+                name: ::iota_types::Identifier::new("Wrapper")
+                    .expect("Move type names are valid identifiers"),
+                type_params: type_params.into(),
+            }
+        }
+    }
+
+    /// Adds a call to the Move function `basics::create` to the transaction.
+    pub fn create(
+        builder: &mut ::iota_types::programmable_transaction_builder::ProgrammableTransactionBuilder,
+        package: ::iota_types::base_types::ObjectID,
+        arg0: u64,
+        arg1: ::iota_types::base_types::IotaAddress,
+    ) -> ::anyhow::Result<::iota_types::transaction::Argument> {
+        let arguments = vec![builder.pure(arg0)?, builder.pure(arg1)?];
+        Ok(builder.programmable_move_call(
+            package,
+            ::iota_types::Identifier::new(MODULE_NAME)?,
+            ::iota_types::Identifier::new("create")?,
+            vec![],
+            arguments,
+        ))
+    }
+
+    /// Adds a call to the Move function `basics::set_value` to the transaction.
+    pub fn set_value(
+        builder: &mut ::iota_types::programmable_transaction_builder::ProgrammableTransactionBuilder,
+        package: ::iota_types::base_types::ObjectID,
+        arg0: ::iota_types::transaction::Argument,
+        arg1: u64,
+    ) -> ::anyhow::Result<::iota_types::transaction::Argument> {
+        let arguments = vec![arg0, builder.pure(arg1)?];
+        Ok(builder.programmable_move_call(
+            package,
+            ::iota_types::Identifier::new(MODULE_NAME)?,
+            ::iota_types::Identifier::new("set_value")?,
+            vec![],
+            arguments,
+        ))
+    }
+
+    /// Adds a call to the Move function `basics::wrap` to the transaction.
+    pub fn wrap(
+        builder: &mut ::iota_types::programmable_transaction_builder::ProgrammableTransactionBuilder,
+        package: ::iota_types::base_types::ObjectID,
+        type_arguments: [::iota_types::TypeTag; 2],
+        arg0: ::iota_types::transaction::Argument,
+        arg1: ::std::option::Option<::std::string::String>,
+    ) -> ::anyhow::Result<::iota_types::transaction::Argument> {
+        let arguments = vec![arg0, builder.pure(arg1)?];
+        Ok(builder.programmable_move_call(
+            package,
+            ::iota_types::Identifier::new(MODULE_NAME)?,
+            ::iota_types::Identifier::new("wrap")?,
+            type_arguments.into(),
+            arguments,
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Types and functions covering the different kinds of generated bindings.
+module bindgen::basics {
+    use iota::event;
+    use std::string::String;
+
+    public struct Object has key, store {
+        id: UID,
+        value: u64,
+    }
+
+    public struct Wrapper<T: store, phantom Marker> has key, store {
+        id: UID,
+        inner: T,
+        label: Option<String>,
+    }
+
+    public enum Status has copy, drop, store {
+        Active,
+        Paused { until: u64 },
+    }
+
+    public struct NewValueEvent has copy, drop {
+        new_value: u64,
+    }
+
+    public entry fun create(value: u64, recipient: address, ctx: &mut TxContext) {
+        transfer::public_transfer(Object { id: object::new(ctx), value }, recipient)
+    }
+
+    public fun set_value(o: &mut Object, value: u64) {
+        o.value = value;
+        event::emit(NewValueEvent { new_value: value })
+    }
+
+    public fun wrap<T: store, Marker>(
+        inner: T,
+        label: Option<String>,
+        ctx: &mut TxContext,
+    ): Wrapper<T, Marker> {
+        Wrapper { id: object::new(ctx), inner, label }
+    }
+
+    fun reset(o: &mut Object) {
+        o.value = 0
+    }
+}
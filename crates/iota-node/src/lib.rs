@@ -954,6 +954,7 @@ impl IotaNode {
                 &config.snapshot_path(),
                 remote_store_config.clone(),
                 60,
+                config.state_snapshot_write_config.full_snapshot_interval,
                 prometheus_registry,
                 checkpoint_store,
             )?;
@@ -180,6 +180,14 @@ struct FeatureFlags {
     // This flag is used to provide the correct MoveVM configuration for clients.
     #[serde(skip_serializing_if = "is_true")]
     rethrow_serialization_type_layout_errors: bool,
+
+    // Enable BIP-340 Schnorr signature verification over secp256k1.
+    #[serde(skip_serializing_if = "is_false")]
+    enable_schnorr_verify: bool,
+
+    // Enable group operations over BN254.
+    #[serde(skip_serializing_if = "is_false")]
+    enable_group_ops_bn254: bool,
 }
 
 fn is_true(b: &bool) -> bool {
@@ -732,6 +740,9 @@ pub struct ProtocolConfig {
     ecdsa_k1_secp256k1_verify_sha256_msg_cost_per_byte: Option<u64>,
     ecdsa_k1_secp256k1_verify_sha256_msg_cost_per_block: Option<u64>,
 
+    // ecdsa_k1::schnorr_verify
+    ecdsa_k1_schnorr_verify_cost_base: Option<u64>,
+
     // ecdsa_r1::ecrecover
     ecdsa_r1_ecrecover_keccak256_cost_base: Option<u64>,
     ecdsa_r1_ecrecover_keccak256_msg_cost_per_byte: Option<u64>,
@@ -815,6 +826,24 @@ pub struct ProtocolConfig {
     group_ops_bls12381_msm_max_len: Option<u32>,
     group_ops_bls12381_pairing_cost: Option<u64>,
 
+    // group ops over BN254
+    group_ops_bn254_decode_scalar_cost: Option<u64>,
+    group_ops_bn254_decode_g1_cost: Option<u64>,
+    group_ops_bn254_decode_g2_cost: Option<u64>,
+    group_ops_bn254_scalar_add_cost: Option<u64>,
+    group_ops_bn254_g1_add_cost: Option<u64>,
+    group_ops_bn254_g2_add_cost: Option<u64>,
+    group_ops_bn254_gt_add_cost: Option<u64>,
+    group_ops_bn254_scalar_sub_cost: Option<u64>,
+    group_ops_bn254_g1_sub_cost: Option<u64>,
+    group_ops_bn254_g2_sub_cost: Option<u64>,
+    group_ops_bn254_gt_sub_cost: Option<u64>,
+    group_ops_bn254_scalar_mul_cost: Option<u64>,
+    group_ops_bn254_g1_mul_cost: Option<u64>,
+    group_ops_bn254_g2_mul_cost: Option<u64>,
+    group_ops_bn254_gt_mul_cost: Option<u64>,
+    group_ops_bn254_pairing_cost: Option<u64>,
+
     // hmac::hmac_sha3_256
     hmac_hmac_sha3_256_cost_base: Option<u64>,
     hmac_hmac_sha3_256_input_cost_per_byte: Option<u64>,
@@ -1026,6 +1055,14 @@ impl ProtocolConfig {
         self.feature_flags.enable_vdf
     }
 
+    pub fn enable_schnorr_verify(&self) -> bool {
+        self.feature_flags.enable_schnorr_verify
+    }
+
+    pub fn enable_group_ops_bn254(&self) -> bool {
+        self.feature_flags.enable_group_ops_bn254
+    }
+
     pub fn passkey_auth(&self) -> bool {
         self.feature_flags.passkey_auth
     }
@@ -1392,6 +1429,9 @@ impl ProtocolConfig {
             ecdsa_k1_secp256k1_verify_sha256_msg_cost_per_byte: Some(2),
             ecdsa_k1_secp256k1_verify_sha256_msg_cost_per_block: Some(2),
 
+            // ecdsa_k1::schnorr_verify
+            ecdsa_k1_schnorr_verify_cost_base: None,
+
             // ecdsa_r1::ecrecover
             ecdsa_r1_ecrecover_keccak256_cost_base: Some(52),
             ecdsa_r1_ecrecover_keccak256_msg_cost_per_byte: Some(2),
@@ -1478,6 +1518,24 @@ impl ProtocolConfig {
             group_ops_bls12381_msm_max_len: Some(32),
             group_ops_bls12381_pairing_cost: Some(52),
 
+            // group ops over BN254
+            group_ops_bn254_decode_scalar_cost: None,
+            group_ops_bn254_decode_g1_cost: None,
+            group_ops_bn254_decode_g2_cost: None,
+            group_ops_bn254_scalar_add_cost: None,
+            group_ops_bn254_g1_add_cost: None,
+            group_ops_bn254_g2_add_cost: None,
+            group_ops_bn254_gt_add_cost: None,
+            group_ops_bn254_scalar_sub_cost: None,
+            group_ops_bn254_g1_sub_cost: None,
+            group_ops_bn254_g2_sub_cost: None,
+            group_ops_bn254_gt_sub_cost: None,
+            group_ops_bn254_scalar_mul_cost: None,
+            group_ops_bn254_g1_mul_cost: None,
+            group_ops_bn254_g2_mul_cost: None,
+            group_ops_bn254_gt_mul_cost: None,
+            group_ops_bn254_pairing_cost: None,
+
             // zklogin::check_zklogin_id
             check_zklogin_id_cost_base: Some(200),
             // zklogin::check_zklogin_issuer
@@ -1629,6 +1687,29 @@ impl ProtocolConfig {
             cfg.vdf_hash_to_input_cost = Some(100);
 
             cfg.feature_flags.passkey_auth = true;
+
+            cfg.feature_flags.enable_schnorr_verify = true;
+            // Priced like the other secp256k1 signature verifications.
+            cfg.ecdsa_k1_schnorr_verify_cost_base = Some(52);
+
+            cfg.feature_flags.enable_group_ops_bn254 = true;
+            // Priced like the corresponding BLS12-381 operations for now.
+            cfg.group_ops_bn254_decode_scalar_cost = Some(52);
+            cfg.group_ops_bn254_decode_g1_cost = Some(52);
+            cfg.group_ops_bn254_decode_g2_cost = Some(52);
+            cfg.group_ops_bn254_scalar_add_cost = Some(52);
+            cfg.group_ops_bn254_g1_add_cost = Some(52);
+            cfg.group_ops_bn254_g2_add_cost = Some(52);
+            cfg.group_ops_bn254_gt_add_cost = Some(52);
+            cfg.group_ops_bn254_scalar_sub_cost = Some(52);
+            cfg.group_ops_bn254_g1_sub_cost = Some(52);
+            cfg.group_ops_bn254_g2_sub_cost = Some(52);
+            cfg.group_ops_bn254_gt_sub_cost = Some(52);
+            cfg.group_ops_bn254_scalar_mul_cost = Some(52);
+            cfg.group_ops_bn254_g1_mul_cost = Some(52);
+            cfg.group_ops_bn254_g2_mul_cost = Some(52);
+            cfg.group_ops_bn254_gt_mul_cost = Some(52);
+            cfg.group_ops_bn254_pairing_cost = Some(52);
         }
 
         // Ignore this check for the fake versions for
@@ -2,30 +2,44 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeMap, num::NonZeroUsize, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
 
+use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iota_core::authority::NodeStateDump;
 use iota_json_rpc_api::QUERY_MAX_RESULT_LIMIT;
 use iota_json_rpc_types::{
     EventFilter, IotaEvent, IotaGetPastObjectRequest, IotaObjectData, IotaObjectDataOptions,
-    IotaObjectResponse, IotaPastObjectResponse, IotaTransactionBlockResponse,
-    IotaTransactionBlockResponseOptions,
+    IotaObjectResponse, IotaPastObjectResponse, IotaTransactionBlock, IotaTransactionBlockEffects,
+    IotaTransactionBlockResponse, IotaTransactionBlockResponseOptions,
 };
 use iota_sdk::IotaClient;
+use iota_storage::blob::Blob;
 use iota_types::{
     base_types::{ObjectID, SequenceNumber, VersionNumber},
-    digests::TransactionDigest,
-    object::Object,
+    digests::{ChainIdentifier, ObjectDigest, TransactionDigest},
+    effects::TransactionEffects,
+    event::{Event, EventID, SystemEpochInfoEventV1},
+    full_checkpoint_content::{CheckpointData, CheckpointTransaction},
+    in_memory_storage::InMemoryStorage,
+    messages_checkpoint::CheckpointSequenceNumber,
+    object::{Object, Owner},
     transaction::{
         EndOfEpochTransactionKind, SenderSignedData, TransactionDataAPI, TransactionKind,
     },
 };
 use lru::LruCache;
-use move_core_types::parser::parse_struct_tag;
-use parking_lot::RwLock;
-use rand::Rng;
+use move_core_types::{language_storage::StructTag, parser::parse_struct_tag};
+use parking_lot::{Mutex, RwLock};
+use rand::{Rng, seq::SliceRandom};
+use tracing::info;
 
 use crate::types::{EPOCH_CHANGE_STRUCT_TAG, ReplayEngineError};
 
@@ -95,13 +109,16 @@ pub(crate) trait DataFetcher {
 pub enum Fetchers {
     Remote(RemoteFetcher),
     NodeStateDump(NodeStateDumpFetcher),
+    CheckpointFiles(CheckpointFilesFetcher),
 }
 
 impl Fetchers {
     pub fn as_remote(&self) -> &RemoteFetcher {
         match self {
             Fetchers::Remote(q) => q,
-            Fetchers::NodeStateDump(_) => panic!("not a remote fetcher"),
+            Fetchers::NodeStateDump(_) | Fetchers::CheckpointFiles(_) => {
+                panic!("not a remote fetcher")
+            }
         }
     }
 
@@ -113,13 +130,17 @@ impl Fetchers {
                 q.clear_cache_for_new_task();
                 q
             }
-            Fetchers::NodeStateDump(_) => panic!("not a remote fetcher"),
+            Fetchers::NodeStateDump(_) | Fetchers::CheckpointFiles(_) => {
+                panic!("not a remote fetcher")
+            }
         }
     }
 
     pub fn as_node_state_dump(&self) -> &NodeStateDumpFetcher {
         match self {
-            Fetchers::Remote(_) => panic!("not a node state dump fetcher"),
+            Fetchers::Remote(_) | Fetchers::CheckpointFiles(_) => {
+                panic!("not a node state dump fetcher")
+            }
             Fetchers::NodeStateDump(q) => q,
         }
     }
@@ -135,6 +156,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.multi_get_versioned(objects).await,
             Fetchers::NodeStateDump(q) => q.multi_get_versioned(objects).await,
+            Fetchers::CheckpointFiles(q) => q.multi_get_versioned(objects).await,
         }
     }
 
@@ -145,6 +167,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.multi_get_latest(objects).await,
             Fetchers::NodeStateDump(q) => q.multi_get_latest(objects).await,
+            Fetchers::CheckpointFiles(q) => q.multi_get_latest(objects).await,
         }
     }
 
@@ -155,6 +178,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_checkpoint_txs(id).await,
             Fetchers::NodeStateDump(q) => q.get_checkpoint_txs(id).await,
+            Fetchers::CheckpointFiles(q) => q.get_checkpoint_txs(id).await,
         }
     }
 
@@ -165,6 +189,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_transaction(tx_digest).await,
             Fetchers::NodeStateDump(q) => q.get_transaction(tx_digest).await,
+            Fetchers::CheckpointFiles(q) => q.get_transaction(tx_digest).await,
         }
     }
 
@@ -175,6 +200,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_loaded_child_objects(tx_digest).await,
             Fetchers::NodeStateDump(q) => q.get_loaded_child_objects(tx_digest).await,
+            Fetchers::CheckpointFiles(q) => q.get_loaded_child_objects(tx_digest).await,
         }
     }
 
@@ -182,6 +208,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_latest_checkpoint_sequence_number().await,
             Fetchers::NodeStateDump(q) => q.get_latest_checkpoint_sequence_number().await,
+            Fetchers::CheckpointFiles(q) => q.get_latest_checkpoint_sequence_number().await,
         }
     }
 
@@ -199,6 +226,10 @@ impl DataFetcher for Fetchers {
                 q.fetch_random_transaction(checkpoint_id_start, checkpoint_id_end)
                     .await
             }
+            Fetchers::CheckpointFiles(q) => {
+                q.fetch_random_transaction(checkpoint_id_start, checkpoint_id_end)
+                    .await
+            }
         }
     }
 
@@ -209,6 +240,7 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_epoch_start_timestamp_and_rgp(epoch_id).await,
             Fetchers::NodeStateDump(q) => q.get_epoch_start_timestamp_and_rgp(epoch_id).await,
+            Fetchers::CheckpointFiles(q) => q.get_epoch_start_timestamp_and_rgp(epoch_id).await,
         }
     }
 
@@ -219,12 +251,14 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_epoch_change_events(reverse).await,
             Fetchers::NodeStateDump(q) => q.get_epoch_change_events(reverse).await,
+            Fetchers::CheckpointFiles(q) => q.get_epoch_change_events(reverse).await,
         }
     }
     async fn get_chain_id(&self) -> Result<String, ReplayEngineError> {
         match self {
             Fetchers::Remote(q) => q.get_chain_id().await,
             Fetchers::NodeStateDump(q) => q.get_chain_id().await,
+            Fetchers::CheckpointFiles(q) => q.get_chain_id().await,
         }
     }
     async fn get_child_object(
@@ -235,6 +269,9 @@ impl DataFetcher for Fetchers {
         match self {
             Fetchers::Remote(q) => q.get_child_object(object_id, version_upper_bound).await,
             Fetchers::NodeStateDump(q) => q.get_child_object(object_id, version_upper_bound).await,
+            Fetchers::CheckpointFiles(q) => {
+                q.get_child_object(object_id, version_upper_bound).await
+            }
         }
     }
 }
@@ -242,6 +279,7 @@ impl DataFetcher for Fetchers {
 const VERSIONED_OBJECT_CACHE_CAPACITY: Option<NonZeroUsize> = NonZeroUsize::new(1_000);
 const LATEST_OBJECT_CACHE_CAPACITY: Option<NonZeroUsize> = NonZeroUsize::new(1_000);
 const EPOCH_INFO_CACHE_CAPACITY: Option<NonZeroUsize> = NonZeroUsize::new(10_000);
+const CHECKPOINT_FILE_CACHE_CAPACITY: Option<NonZeroUsize> = NonZeroUsize::new(100);
 
 pub struct RemoteFetcher {
     /// This is used to download items not in store
@@ -815,3 +853,679 @@ impl DataFetcher for NodeStateDumpFetcher {
         unimplemented!("get child object is not implemented for state dump");
     }
 }
+
+/// Fetches data from the checkpoint files written by the data ingestion
+/// framework (`<sequence number>.chk`) in a local directory, so that
+/// transactions can be replayed without access to a fullnode.
+///
+/// Object versions are rebuilt from the input and output objects of the
+/// checkpoint transactions, so besides the checkpoints to replay the directory
+/// must contain the genesis checkpoint and the checkpoints which wrote the
+/// objects only read by the replayed transactions, like packages or immutable
+/// objects. Archives written by `iota-archival` only hold checkpoint summaries
+/// and contents, so they can't be used to replay transactions.
+///
+/// Only the location of the transactions and object versions is kept in
+/// memory, the checkpoint files are read again when their data is fetched.
+#[derive(Clone)]
+pub struct CheckpointFilesFetcher {
+    index: Arc<CheckpointFilesIndex>,
+    /// Recently read checkpoint files, shared by the clones of the fetcher
+    checkpoint_cache: Arc<Mutex<LruCache<CheckpointSequenceNumber, Arc<CheckpointData>>>>,
+}
+
+#[derive(Default)]
+struct CheckpointFilesIndex {
+    /// Set if the genesis checkpoint is part of the files
+    chain_id: Option<ChainIdentifier>,
+    files: BTreeMap<CheckpointSequenceNumber, PathBuf>,
+    checkpoint_txs: BTreeMap<CheckpointSequenceNumber, Vec<TransactionDigest>>,
+    transactions: HashMap<TransactionDigest, IndexedTransaction>,
+    /// The transaction which has each object version as input or output
+    objects: BTreeMap<(ObjectID, SequenceNumber), TransactionLocation>,
+    /// Deleted or wrapped objects, keyed by the version at which they were
+    /// removed
+    removed_objects: BTreeMap<(ObjectID, SequenceNumber), ObjectDigest>,
+    /// In the order they were emitted
+    epoch_change_events: Vec<IotaEvent>,
+    /// Start timestamp and reference gas price of each epoch
+    epoch_info: BTreeMap<u64, (u64, u64)>,
+}
+
+struct IndexedTransaction {
+    location: TransactionLocation,
+    timestamp_ms: u64,
+    /// Input objects owned by another object, i.e. the child objects modified
+    /// by the transaction
+    loaded_child_objects: Vec<(ObjectID, SequenceNumber)>,
+}
+
+/// Position of a transaction in the checkpoint files
+#[derive(Clone, Copy)]
+struct TransactionLocation {
+    checkpoint: CheckpointSequenceNumber,
+    /// Index of the transaction in the checkpoint
+    index: usize,
+}
+
+impl CheckpointFilesFetcher {
+    /// Reads and indexes all the checkpoint files in the directory at `path`.
+    pub fn new(path: &Path) -> Result<Self, ReplayEngineError> {
+        let mut files = vec![];
+        for entry in std::fs::read_dir(path)
+            .with_context(|| format!("unable to read directory {}", path.display()))?
+        {
+            let file = entry.map_err(anyhow::Error::from)?.path();
+            let sequence_number = file
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".chk"))
+                .and_then(|name| name.parse::<CheckpointSequenceNumber>().ok());
+            if let Some(sequence_number) = sequence_number {
+                files.push((sequence_number, file));
+            }
+        }
+        if files.is_empty() {
+            return Err(ReplayEngineError::GeneralError {
+                err: format!("no checkpoint files found in {}", path.display()),
+            });
+        }
+        files.sort();
+
+        let epoch_change_struct_tag = parse_struct_tag(EPOCH_CHANGE_STRUCT_TAG)?;
+        let mut index = CheckpointFilesIndex::default();
+        for (sequence_number, file) in files {
+            index.insert(&read_checkpoint_file(&file)?, &epoch_change_struct_tag)?;
+            index.files.insert(sequence_number, file);
+        }
+        info!(
+            "Indexed {} checkpoints and {} object versions from {}",
+            index.checkpoint_txs.len(),
+            index.objects.len(),
+            path.display()
+        );
+
+        Ok(Self {
+            index: Arc::new(index),
+            checkpoint_cache: Arc::new(Mutex::new(LruCache::new(
+                CHECKPOINT_FILE_CACHE_CAPACITY.expect("Cache size must be non zero"),
+            ))),
+        })
+    }
+
+    fn read_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Arc<CheckpointData>, ReplayEngineError> {
+        if let Some(checkpoint) = self.checkpoint_cache.lock().get(&sequence_number) {
+            return Ok(checkpoint.clone());
+        }
+
+        let file = self.index.files.get(&sequence_number).ok_or(
+            ReplayEngineError::CheckpointNotFound {
+                checkpoint: sequence_number,
+            },
+        )?;
+        let checkpoint = Arc::new(read_checkpoint_file(file)?);
+        self.checkpoint_cache
+            .lock()
+            .put(sequence_number, checkpoint.clone());
+        Ok(checkpoint)
+    }
+
+    fn read_transaction(
+        &self,
+        location: TransactionLocation,
+    ) -> Result<CheckpointTransaction, ReplayEngineError> {
+        self.read_checkpoint(location.checkpoint)?
+            .transactions
+            .get(location.index)
+            .cloned()
+            .ok_or_else(|| ReplayEngineError::GeneralError {
+                err: format!(
+                    "checkpoint file {} changed since it was indexed",
+                    location.checkpoint
+                ),
+            })
+    }
+
+    fn read_object(
+        &self,
+        id: ObjectID,
+        version: SequenceNumber,
+        location: TransactionLocation,
+    ) -> Result<Object, ReplayEngineError> {
+        self.read_checkpoint(location.checkpoint)?
+            .transactions
+            .get(location.index)
+            .and_then(|tx| {
+                tx.input_objects
+                    .iter()
+                    .chain(&tx.output_objects)
+                    .find(|object| object.id() == id && object.version() == version)
+            })
+            .cloned()
+            .ok_or(ReplayEngineError::ObjectVersionNotFound { id, version })
+    }
+
+    fn get_object(
+        &self,
+        id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<Object, ReplayEngineError> {
+        let location = self.index.get_object(id, version)?;
+        self.read_object(id, version, location)
+    }
+
+    /// Returns the highest version of the object which is lower or equal to
+    /// `version_upper_bound`, unless it was removed afterwards
+    fn get_object_before_version(
+        &self,
+        id: ObjectID,
+        version_upper_bound: SequenceNumber,
+    ) -> Result<Object, ReplayEngineError> {
+        let (version, location) = self
+            .index
+            .get_object_before_version(id, version_upper_bound)?;
+        self.read_object(id, version, location)
+    }
+}
+
+fn read_checkpoint_file(file: &Path) -> Result<CheckpointData, ReplayEngineError> {
+    let bytes = std::fs::read(file)
+        .with_context(|| format!("unable to read checkpoint file {}", file.display()))?;
+    Ok(Blob::from_bytes(&bytes)?)
+}
+
+impl CheckpointFilesIndex {
+    fn insert(
+        &mut self,
+        checkpoint: &CheckpointData,
+        epoch_change_struct_tag: &StructTag,
+    ) -> Result<(), ReplayEngineError> {
+        let sequence_number = checkpoint.checkpoint_summary.sequence_number;
+        let timestamp_ms = checkpoint.checkpoint_summary.timestamp_ms;
+        if sequence_number == 0 {
+            self.chain_id = Some(ChainIdentifier::from(
+                *checkpoint.checkpoint_summary.digest(),
+            ));
+        }
+
+        let mut tx_digests = vec![];
+        for (index, tx) in checkpoint.transactions.iter().enumerate() {
+            let tx_digest = *tx.transaction.digest();
+            let location = TransactionLocation {
+                checkpoint: sequence_number,
+                index,
+            };
+            for (id, version, digest) in tx.removed_object_refs_post_version() {
+                self.removed_objects.insert((id, version), digest);
+            }
+            for (event_seq, event) in tx.events.iter().flat_map(|e| &e.data).enumerate() {
+                if &event.type_ != epoch_change_struct_tag {
+                    continue;
+                }
+                let info: SystemEpochInfoEventV1 =
+                    bcs::from_bytes(&event.contents).map_err(anyhow::Error::from)?;
+                let epoch_start_timestamp_ms = match tx.transaction.transaction_data().kind() {
+                    TransactionKind::EndOfEpochTransaction(kinds) => {
+                        kinds.iter().find_map(|kind| match kind {
+                            EndOfEpochTransactionKind::ChangeEpoch(change) => {
+                                Some(change.epoch_start_timestamp_ms)
+                            }
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                }
+                .ok_or(ReplayEngineError::InvalidEpochChangeTx { epoch: info.epoch })?;
+
+                self.epoch_info.insert(
+                    info.epoch,
+                    (epoch_start_timestamp_ms, info.reference_gas_price),
+                );
+                self.epoch_change_events.push(epoch_change_event(
+                    tx_digest,
+                    event_seq as u64,
+                    event,
+                    &info,
+                    timestamp_ms,
+                )?);
+            }
+            let loaded_child_objects = tx
+                .input_objects
+                .iter()
+                .filter(|object| matches!(object.owner, Owner::ObjectOwner(_)))
+                .map(|object| (object.id(), object.version()))
+                .collect();
+            for object in tx.input_objects.iter().chain(&tx.output_objects) {
+                self.objects
+                    .insert((object.id(), object.version()), location);
+            }
+
+            tx_digests.push(tx_digest);
+            self.transactions.insert(tx_digest, IndexedTransaction {
+                location,
+                timestamp_ms,
+                loaded_child_objects,
+            });
+        }
+        self.checkpoint_txs.insert(sequence_number, tx_digests);
+        Ok(())
+    }
+
+    fn get_object(
+        &self,
+        id: ObjectID,
+        version: SequenceNumber,
+    ) -> Result<TransactionLocation, ReplayEngineError> {
+        if let Some(location) = self.objects.get(&(id, version)) {
+            return Ok(*location);
+        }
+        if let Some(digest) = self.removed_objects.get(&(id, version)) {
+            return Err(ReplayEngineError::ObjectDeleted {
+                id,
+                version,
+                digest: *digest,
+            });
+        }
+        if self
+            .objects
+            .range((id, SequenceNumber::MIN)..=(id, SequenceNumber::MAX))
+            .next()
+            .is_none()
+        {
+            return Err(ReplayEngineError::ObjectNotExist { id });
+        }
+        Err(ReplayEngineError::ObjectVersionNotFound { id, version })
+    }
+
+    /// Returns the highest version of the object which is lower or equal to
+    /// `version_upper_bound` and its location, unless it was removed
+    /// afterwards
+    fn get_object_before_version(
+        &self,
+        id: ObjectID,
+        version_upper_bound: SequenceNumber,
+    ) -> Result<(SequenceNumber, TransactionLocation), ReplayEngineError> {
+        let range = (id, SequenceNumber::MIN)..=(id, version_upper_bound);
+        let object = self.objects.range(range.clone()).next_back();
+        let removed = self.removed_objects.range(range).next_back();
+        match (object, removed) {
+            (Some(((_, version), location)), Some(((_, removed_version), _)))
+                if version > removed_version =>
+            {
+                Ok((*version, *location))
+            }
+            (Some(((_, version), location)), None) => Ok((*version, *location)),
+            (_, Some(((_, version), digest))) => Err(ReplayEngineError::ObjectDeleted {
+                id,
+                version: *version,
+                digest: *digest,
+            }),
+            (None, None) => Err(ReplayEngineError::ObjectNotExist { id }),
+        }
+    }
+}
+
+/// Builds the event the RPC returns for an epoch change event, as its JSON
+/// representation is what the epoch info is extracted from.
+fn epoch_change_event(
+    tx_digest: TransactionDigest,
+    event_seq: u64,
+    event: &Event,
+    info: &SystemEpochInfoEventV1,
+    timestamp_ms: u64,
+) -> Result<IotaEvent, ReplayEngineError> {
+    Ok(IotaEvent {
+        id: EventID {
+            tx_digest,
+            event_seq,
+        },
+        package_id: event.package_id,
+        transaction_module: event.transaction_module.clone(),
+        sender: event.sender,
+        type_: event.type_.clone(),
+        parsed_json: serde_json::to_value(info).map_err(anyhow::Error::from)?,
+        bcs: event.contents.clone(),
+        timestamp_ms: Some(timestamp_ms),
+    })
+}
+
+#[async_trait]
+impl DataFetcher for CheckpointFilesFetcher {
+    async fn multi_get_versioned(
+        &self,
+        objects: &[(ObjectID, SequenceNumber)],
+    ) -> Result<Vec<Object>, ReplayEngineError> {
+        objects
+            .iter()
+            .map(|(id, version)| self.get_object(*id, *version))
+            .collect()
+    }
+
+    async fn multi_get_latest(
+        &self,
+        objects: &[ObjectID],
+    ) -> Result<Vec<Object>, ReplayEngineError> {
+        objects
+            .iter()
+            .map(|id| self.get_object_before_version(*id, SequenceNumber::MAX))
+            .collect()
+    }
+
+    async fn get_checkpoint_txs(
+        &self,
+        id: u64,
+    ) -> Result<Vec<TransactionDigest>, ReplayEngineError> {
+        self.index
+            .checkpoint_txs
+            .get(&id)
+            .cloned()
+            .ok_or(ReplayEngineError::CheckpointNotFound { checkpoint: id })
+    }
+
+    async fn get_transaction(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Result<IotaTransactionBlockResponse, ReplayEngineError> {
+        let tx = self
+            .index
+            .transactions
+            .get(tx_digest)
+            .ok_or(ReplayEngineError::TransactionNotFound { digest: *tx_digest })?;
+        let CheckpointTransaction {
+            transaction,
+            effects,
+            ..
+        } = self.read_transaction(tx.location)?;
+        let sender_signed_data = transaction.data().clone();
+
+        Ok(IotaTransactionBlockResponse {
+            raw_transaction: bcs::to_bytes(&sender_signed_data).map_err(anyhow::Error::from)?,
+            // The input types of Move calls are not resolved as they are only used for display
+            transaction: Some(IotaTransactionBlock::try_from(
+                sender_signed_data,
+                &InMemoryStorage::default(),
+                *tx_digest,
+            )?),
+            effects: Some(IotaTransactionBlockEffects::try_from(effects)?),
+            timestamp_ms: Some(tx.timestamp_ms),
+            checkpoint: Some(tx.location.checkpoint),
+            ..IotaTransactionBlockResponse::new(*tx_digest)
+        })
+    }
+
+    /// Checkpoint files only hold the child objects modified by the
+    /// transaction, the ones it only read are fetched on demand through
+    /// `get_child_object`.
+    async fn get_loaded_child_objects(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Result<Vec<(ObjectID, SequenceNumber)>, ReplayEngineError> {
+        self.index
+            .transactions
+            .get(tx_digest)
+            .map(|tx| tx.loaded_child_objects.clone())
+            .ok_or(ReplayEngineError::TransactionNotFound { digest: *tx_digest })
+    }
+
+    async fn get_latest_checkpoint_sequence_number(&self) -> Result<u64, ReplayEngineError> {
+        Ok(*self
+            .index
+            .checkpoint_txs
+            .keys()
+            .next_back()
+            .expect("at least one checkpoint file is indexed"))
+    }
+
+    async fn fetch_random_transaction(
+        &self,
+        checkpoint_id_start_inclusive: Option<u64>,
+        checkpoint_id_end_inclusive: Option<u64>,
+    ) -> Result<TransactionDigest, ReplayEngineError> {
+        let txs: Vec<_> = self
+            .index
+            .checkpoint_txs
+            .range(
+                checkpoint_id_start_inclusive.unwrap_or(1)
+                    ..=checkpoint_id_end_inclusive.unwrap_or(u64::MAX),
+            )
+            .flat_map(|(_, txs)| txs)
+            .collect();
+
+        txs.choose(&mut rand::thread_rng())
+            .map(|tx| **tx)
+            .ok_or_else(|| ReplayEngineError::GeneralError {
+                err: "no transaction found in the checkpoint range".to_string(),
+            })
+    }
+
+    async fn get_epoch_start_timestamp_and_rgp(
+        &self,
+        epoch_id: u64,
+    ) -> Result<(u64, u64), ReplayEngineError> {
+        self.index
+            .epoch_info
+            .get(&epoch_id)
+            .copied()
+            .ok_or(ReplayEngineError::EventNotFound { epoch: epoch_id })
+    }
+
+    async fn get_epoch_change_events(
+        &self,
+        reverse: bool,
+    ) -> Result<Vec<IotaEvent>, ReplayEngineError> {
+        let mut events = self.index.epoch_change_events.clone();
+        if reverse {
+            events.reverse();
+        }
+        Ok(events)
+    }
+
+    async fn get_chain_id(&self) -> Result<String, ReplayEngineError> {
+        self.index
+            .chain_id
+            .as_ref()
+            .map(|chain_id| chain_id.to_string())
+            .ok_or_else(|| ReplayEngineError::UnableToGetChainId {
+                err: "the genesis checkpoint file is missing".to_string(),
+            })
+    }
+
+    async fn get_child_object(
+        &self,
+        object_id: &ObjectID,
+        version_upper_bound: VersionNumber,
+    ) -> Result<Object, ReplayEngineError> {
+        self.get_object_before_version(*object_id, version_upper_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_types::{
+        base_types::IotaAddress, effects::TransactionEffectsAPI,
+        iota_system_state::IotaSystemStateTrait,
+    };
+    use simulacrum::Simulacrum;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Writes the checkpoint files of a genesis checkpoint, a checkpoint with
+    /// a transfer and an epoch change checkpoint, returning the transfer
+    /// transaction digest
+    fn write_checkpoint_files(sim: &mut Simulacrum, dir: &TempDir) -> TransactionDigest {
+        sim.set_data_ingestion_path(dir.path().to_path_buf());
+        let (transaction, _) = sim.transfer_txn(IotaAddress::random_for_testing_only());
+        let (effects, err) = sim.execute_transaction(transaction).unwrap();
+        assert!(err.is_none());
+        sim.create_checkpoint();
+        sim.advance_epoch();
+        // Other files in the directory are ignored
+        std::fs::write(dir.path().join("README.md"), "checkpoint files").unwrap();
+        *effects.transaction_digest()
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_files_index() {
+        let mut sim = Simulacrum::new();
+        let dir = tempfile::tempdir().unwrap();
+        let transfer_digest = write_checkpoint_files(&mut sim, &dir);
+        let fetcher = CheckpointFilesFetcher::new(dir.path()).unwrap();
+
+        let genesis = sim.store().get_checkpoint_by_sequence_number(0).unwrap();
+        assert_eq!(
+            fetcher.get_chain_id().await.unwrap(),
+            ChainIdentifier::from(*genesis.digest()).to_string()
+        );
+        assert_eq!(
+            fetcher
+                .get_latest_checkpoint_sequence_number()
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(fetcher.get_checkpoint_txs(1).await.unwrap(), vec![
+            transfer_digest
+        ]);
+        assert!(matches!(
+            fetcher.get_checkpoint_txs(3).await,
+            Err(ReplayEngineError::CheckpointNotFound { checkpoint: 3 })
+        ));
+
+        let response = fetcher.get_transaction(&transfer_digest).await.unwrap();
+        assert_eq!(response.digest, transfer_digest);
+        assert_eq!(response.checkpoint, Some(1));
+        assert!(response.transaction.is_some());
+        assert!(response.effects.is_some());
+        assert_eq!(
+            fetcher
+                .fetch_random_transaction(Some(1), Some(1))
+                .await
+                .unwrap(),
+            transfer_digest
+        );
+
+        // The epoch change event carries the protocol version and reference gas
+        // price of the new epoch
+        let events = fetcher.get_epoch_change_events(false).await.unwrap();
+        assert_eq!(events.len(), 1);
+        let epoch_change_digest = fetcher.get_checkpoint_txs(2).await.unwrap()[0];
+        assert_eq!(events[0].id.tx_digest, epoch_change_digest);
+        assert_eq!(
+            extract_epoch_and_version(events[0].clone()).unwrap(),
+            (1, sim.store().get_system_state().protocol_version())
+        );
+        let (epoch_start_timestamp_ms, reference_gas_price) =
+            fetcher.get_epoch_start_timestamp_and_rgp(1).await.unwrap();
+        assert_eq!(
+            epoch_start_timestamp_ms,
+            sim.store().get_clock().timestamp_ms()
+        );
+        assert_eq!(reference_gas_price, sim.reference_gas_price());
+        assert!(matches!(
+            fetcher.get_epoch_start_timestamp_and_rgp(2).await,
+            Err(ReplayEngineError::EventNotFound { epoch: 2 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_files_loaded_child_objects() {
+        let mut sim = Simulacrum::new();
+        let dir = tempfile::tempdir().unwrap();
+        let transfer_digest = write_checkpoint_files(&mut sim, &dir);
+        let fetcher = CheckpointFilesFetcher::new(dir.path()).unwrap();
+
+        assert!(
+            fetcher
+                .get_loaded_child_objects(&transfer_digest)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // The epoch change modifies the inner system state, a dynamic field of
+        // the system state object
+        let epoch_change_digest = fetcher.get_checkpoint_txs(2).await.unwrap()[0];
+        let loaded_child_objects = fetcher
+            .get_loaded_child_objects(&epoch_change_digest)
+            .await
+            .unwrap();
+        assert!(!loaded_child_objects.is_empty());
+        for (id, version) in loaded_child_objects {
+            let object = fetcher.get_object(id, version).unwrap();
+            assert!(matches!(object.owner, Owner::ObjectOwner(_)));
+        }
+
+        assert!(matches!(
+            fetcher
+                .get_loaded_child_objects(&TransactionDigest::random())
+                .await,
+            Err(ReplayEngineError::TransactionNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_files_get_object_before_version() {
+        let mut sim = Simulacrum::new();
+        let dir = tempfile::tempdir().unwrap();
+        let transfer_digest = write_checkpoint_files(&mut sim, &dir);
+        let fetcher = CheckpointFilesFetcher::new(dir.path()).unwrap();
+
+        // The gas coin of the transfer is both read and written by it
+        let effects = fetcher
+            .read_transaction(fetcher.index.transactions[&transfer_digest].location)
+            .unwrap()
+            .effects;
+        let ((gas_id, gas_version, _), _) = effects.gas_object();
+        let (_, input_version) = effects
+            .modified_at_versions()
+            .into_iter()
+            .find(|(id, _)| *id == gas_id)
+            .unwrap();
+
+        let latest = fetcher
+            .get_object_before_version(gas_id, SequenceNumber::MAX)
+            .unwrap();
+        assert_eq!(latest.version(), gas_version);
+        let at_version = fetcher
+            .get_object_before_version(gas_id, gas_version)
+            .unwrap();
+        assert_eq!(at_version.version(), gas_version);
+        let before = fetcher
+            .get_object_before_version(gas_id, gas_version.one_before().unwrap())
+            .unwrap();
+        assert_eq!(before.version(), input_version);
+        assert!(matches!(
+            fetcher.get_object_before_version(gas_id, input_version.one_before().unwrap()),
+            Err(ReplayEngineError::ObjectNotExist { .. })
+        ));
+        assert!(matches!(
+            fetcher.get_object_before_version(ObjectID::random(), SequenceNumber::MAX),
+            Err(ReplayEngineError::ObjectNotExist { .. })
+        ));
+
+        assert_eq!(
+            fetcher
+                .multi_get_versioned(&[(gas_id, input_version), (gas_id, gas_version)])
+                .await
+                .unwrap()
+                .iter()
+                .map(|object| object.version())
+                .collect::<Vec<_>>(),
+            vec![input_version, gas_version]
+        );
+        assert!(matches!(
+            fetcher
+                .multi_get_versioned(&[(gas_id, gas_version.next())])
+                .await,
+            Err(ReplayEngineError::ObjectVersionNotFound { .. })
+        ));
+        assert_eq!(
+            fetcher.multi_get_latest(&[gas_id]).await.unwrap()[0].version(),
+            gas_version
+        );
+    }
+}
@@ -4,7 +4,6 @@
 
 use std::{cmp::max, env, io::BufRead, path::PathBuf, str::FromStr};
 
-use async_recursion::async_recursion;
 use clap::Parser;
 use config::ReplayableNetworkConfigSet;
 use fuzz::{ReplayFuzzer, ReplayFuzzerConfig};
@@ -22,6 +21,7 @@ use transaction_provider::{FuzzStartPoint, TransactionSource};
 
 use crate::{
     config::get_rpc_url,
+    data_fetcher::CheckpointFilesFetcher,
     replay::{ExecutionSandboxState, LocalExec, ProtocolVersionSummary},
 };
 
@@ -110,6 +110,11 @@ pub enum ReplayToolCommand {
         /// regulated coin types and that has been denied.
         #[arg(long, num_args = 2..)]
         config_objects: Option<Vec<String>>,
+        /// Replay offline from the checkpoint files written by the data
+        /// ingestion framework in this directory, instead of querying an RPC
+        /// node.
+        #[arg(long)]
+        checkpoints_path: Option<PathBuf>,
     },
 
     /// Replay transactions listed in a file
@@ -172,6 +177,11 @@ pub enum ReplayToolCommand {
         terminate_early: bool,
         #[arg(long, short, default_value = "16")]
         max_tasks: u64,
+        /// Replay offline from the checkpoint files written by the data
+        /// ingestion framework in this directory, instead of querying an RPC
+        /// node.
+        #[arg(long)]
+        checkpoints_path: Option<PathBuf>,
     },
 
     /// Replay all transactions in an epoch
@@ -183,6 +193,11 @@ pub enum ReplayToolCommand {
         terminate_early: bool,
         #[arg(long, short, default_value = "16")]
         max_tasks: u64,
+        /// Replay offline from the checkpoint files written by the data
+        /// ingestion framework in this directory, instead of querying an RPC
+        /// node.
+        #[arg(long)]
+        checkpoints_path: Option<PathBuf>,
     },
 
     /// Run the replay based fuzzer
@@ -200,7 +215,6 @@ pub enum ReplayToolCommand {
     Report,
 }
 
-#[async_recursion]
 pub async fn execute_replay_command(
     rpc_url: Option<String>,
     safety_checks: bool,
@@ -386,20 +400,38 @@ pub async fn execute_replay_command(
             executor_version,
             protocol_version,
             config_objects,
+            checkpoints_path,
         } => {
             let tx_digest = TransactionDigest::from_str(&tx_digest)?;
             info!("Executing tx: {}", tx_digest);
-            let sandbox_state = LocalExec::replay_with_network_config(
-                get_rpc_url(rpc_url, cfg_path, chain)?,
-                tx_digest,
-                safety,
-                use_authority,
-                executor_version,
-                protocol_version,
-                None,
-                parse_configs_versions(config_objects),
-            )
-            .await?;
+            let sandbox_state = match checkpoints_path {
+                Some(path) => {
+                    LocalExec::replay_with_checkpoint_files(
+                        &path,
+                        tx_digest,
+                        safety,
+                        use_authority,
+                        executor_version,
+                        protocol_version,
+                        None,
+                        parse_configs_versions(config_objects),
+                    )
+                    .await?
+                }
+                None => {
+                    LocalExec::replay_with_network_config(
+                        get_rpc_url(rpc_url, cfg_path, chain)?,
+                        tx_digest,
+                        safety,
+                        use_authority,
+                        executor_version,
+                        protocol_version,
+                        None,
+                        parse_configs_versions(config_objects),
+                    )
+                    .await?
+                }
+            };
 
             if show_effects {
                 println!("{}", sandbox_state.local_exec_effects);
@@ -464,75 +496,42 @@ pub async fn execute_replay_command(
             end,
             terminate_early,
             max_tasks,
+            checkpoints_path,
         } => {
-            assert!(start <= end, "Start checkpoint must be <= end checkpoint");
-            assert!(max_tasks > 0, "Max tasks must be > 0");
-            let checkpoints_per_task = ((end - start + max_tasks) / max_tasks) as usize;
-            let mut handles = vec![];
-            info!(
-                "Executing checkpoints {} to {} with at most {} tasks and at most {} checkpoints per task",
-                start, end, max_tasks, checkpoints_per_task
-            );
-
-            let range: Vec<_> = (start..=end).collect();
-            for (task_count, checkpoints) in range.chunks(checkpoints_per_task).enumerate() {
-                let checkpoints = checkpoints.to_vec();
-                let rpc_url = rpc_url.clone();
-                let safety = safety.clone();
-                handles.push(tokio::spawn(async move {
-                    info!("Spawning task {task_count} for checkpoints {checkpoints:?}");
-                    let time = std::time::Instant::now();
-                    let (succeeded, total) = LocalExec::new_from_fn_url(&rpc_url.expect("Url must be provided"))
-                        .await
-                        .unwrap()
-                        .init_for_execution()
-                        .await
-                        .unwrap()
-                        .execute_all_in_checkpoints(&checkpoints, &safety, terminate_early, use_authority)
-                        .await
-                        .unwrap();
-                    let time = time.elapsed();
-                    info!(
-                        "Task {task_count}: executed checkpoints {:?} @ {} total transactions, {} succeeded",
-                        checkpoints, total, succeeded
-                    );
-                    (succeeded, total, time)
-                }));
-            }
-
-            let mut total_tx = 0;
-            let mut total_time_ms = 0;
-            let mut total_succeeded = 0;
-            futures::future::join_all(handles)
-                .await
-                .into_iter()
-                .for_each(|x| match x {
-                    Ok((succeeded, total, time)) => {
-                        total_tx += total;
-                        total_time_ms += time.as_millis() as u64;
-                        total_succeeded += succeeded;
-                    }
-                    Err(e) => {
-                        error!("Task failed: {:?}", e);
-                    }
-                });
-            info!(
-                "Executed {} checkpoints @ {}/{} total TXs succeeded in {} ms ({}) avg TX/s",
-                end - start + 1,
-                total_succeeded,
-                total_tx,
-                total_time_ms,
-                (total_tx as f64) / (total_time_ms as f64 / 1000.0)
-            );
-            Some((total_succeeded, total_tx))
+            let checkpoint_files = checkpoints_path
+                .map(|path| CheckpointFilesFetcher::new(&path))
+                .transpose()?;
+            let (succeeded, total) = replay_checkpoints(
+                start,
+                end,
+                terminate_early,
+                max_tasks,
+                rpc_url,
+                checkpoint_files,
+                safety,
+                use_authority,
+            )
+            .await;
+            Some((succeeded, total))
         }
         ReplayToolCommand::ReplayEpoch {
             epoch,
             terminate_early,
             max_tasks,
+            checkpoints_path,
         } => {
-            let lx =
-                LocalExec::new_from_fn_url(&rpc_url.clone().expect("Url must be provided")).await?;
+            // The checkpoint files are indexed once, both to find the checkpoints
+            // of the epoch and to replay them
+            let checkpoint_files = checkpoints_path
+                .map(|path| CheckpointFilesFetcher::new(&path))
+                .transpose()?;
+            let lx = match &checkpoint_files {
+                Some(fetcher) => LocalExec::new_for_checkpoint_files(fetcher.clone()),
+                None => {
+                    LocalExec::new_from_fn_url(&rpc_url.clone().expect("Url must be provided"))
+                        .await?
+                }
+            };
 
             let (start, end) = lx.checkpoints_for_epoch(epoch).await?;
 
@@ -540,37 +539,22 @@ pub async fn execute_replay_command(
                 "Executing epoch {} (checkpoint range {}-{}) with at most {} tasks",
                 epoch, start, end, max_tasks
             );
-            let status = execute_replay_command(
+            let (succeeded, total) = replay_checkpoints(
+                start,
+                end,
+                terminate_early,
+                max_tasks,
                 rpc_url,
-                safety_checks,
+                checkpoint_files,
+                safety,
                 use_authority,
-                cfg_path,
-                chain,
-                ReplayToolCommand::ReplayCheckpoints {
-                    start,
-                    end,
-                    terminate_early,
-                    max_tasks,
-                },
             )
             .await;
-            match status {
-                Ok(Some((succeeded, total))) => {
-                    info!(
-                        "Epoch {} replay finished {} out of {} TXs",
-                        epoch, succeeded, total
-                    );
-
-                    return Ok(Some((succeeded, total)));
-                }
-                Ok(None) => {
-                    return Ok(None);
-                }
-                Err(e) => {
-                    error!("Epoch {} replay failed: {:?}", epoch, e);
-                    return Err(e);
-                }
-            }
+            info!(
+                "Epoch {} replay finished {} out of {} TXs",
+                epoch, succeeded, total
+            );
+            Some((succeeded, total))
         }
     })
 }
@@ -590,6 +574,88 @@ pub(crate) fn chain_from_chain_id(chain: &str) -> Chain {
     }
 }
 
+/// Replays the checkpoints in `start..=end` with at most `max_tasks` tasks,
+/// from the checkpoint files if provided or from the RPC node at `rpc_url`.
+/// Returns the number of transactions which succeeded and the total number of
+/// transactions.
+async fn replay_checkpoints(
+    start: u64,
+    end: u64,
+    terminate_early: bool,
+    max_tasks: u64,
+    rpc_url: Option<String>,
+    checkpoint_files: Option<CheckpointFilesFetcher>,
+    safety: ExpensiveSafetyCheckConfig,
+    use_authority: bool,
+) -> (u64, u64) {
+    assert!(start <= end, "Start checkpoint must be <= end checkpoint");
+    assert!(max_tasks > 0, "Max tasks must be > 0");
+    let checkpoints_per_task = ((end - start + max_tasks) / max_tasks) as usize;
+    let mut handles = vec![];
+    info!(
+        "Executing checkpoints {} to {} with at most {} tasks and at most {} checkpoints per task",
+        start, end, max_tasks, checkpoints_per_task
+    );
+
+    let range: Vec<_> = (start..=end).collect();
+    for (task_count, checkpoints) in range.chunks(checkpoints_per_task).enumerate() {
+        let checkpoints = checkpoints.to_vec();
+        let rpc_url = rpc_url.clone();
+        // The checkpoint files are indexed once and shared by all the tasks
+        let checkpoint_files = checkpoint_files.clone();
+        let safety = safety.clone();
+        handles.push(tokio::spawn(async move {
+            info!("Spawning task {task_count} for checkpoints {checkpoints:?}");
+            let time = std::time::Instant::now();
+            let local_exec = match checkpoint_files {
+                Some(fetcher) => LocalExec::new_for_checkpoint_files(fetcher),
+                None => LocalExec::new_from_fn_url(&rpc_url.expect("Url must be provided"))
+                    .await
+                    .unwrap(),
+            };
+            let (succeeded, total) = local_exec
+                .init_for_execution()
+                .await
+                .unwrap()
+                .execute_all_in_checkpoints(&checkpoints, &safety, terminate_early, use_authority)
+                .await
+                .unwrap();
+            let time = time.elapsed();
+            info!(
+                "Task {task_count}: executed checkpoints {:?} @ {} total transactions, {} succeeded",
+                checkpoints, total, succeeded
+            );
+            (succeeded, total, time)
+        }));
+    }
+
+    let mut total_tx = 0;
+    let mut total_time_ms = 0;
+    let mut total_succeeded = 0;
+    futures::future::join_all(handles)
+        .await
+        .into_iter()
+        .for_each(|x| match x {
+            Ok((succeeded, total, time)) => {
+                total_tx += total;
+                total_time_ms += time.as_millis() as u64;
+                total_succeeded += succeeded;
+            }
+            Err(e) => {
+                error!("Task failed: {:?}", e);
+            }
+        });
+    info!(
+        "Executed {} checkpoints @ {}/{} total TXs succeeded in {} ms ({}) avg TX/s",
+        end - start + 1,
+        total_succeeded,
+        total_tx,
+        total_time_ms,
+        (total_tx as f64) / (total_time_ms as f64 / 1000.0)
+    );
+    (total_succeeded, total_tx)
+}
+
 fn parse_configs_versions(
     configs_and_versions: Option<Vec<String>>,
 ) -> Option<Vec<(ObjectID, SequenceNumber)>> {
@@ -4,7 +4,7 @@
 
 use std::{
     collections::{BTreeMap, HashSet},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
 
@@ -56,7 +56,8 @@ use tracing::{error, info, trace, warn};
 use crate::{
     chain_from_chain_id,
     data_fetcher::{
-        DataFetcher, Fetchers, NodeStateDumpFetcher, RemoteFetcher, extract_epoch_and_version,
+        CheckpointFilesFetcher, DataFetcher, Fetchers, NodeStateDumpFetcher, RemoteFetcher,
+        extract_epoch_and_version,
     },
     displays::{
         Pretty,
@@ -348,6 +349,34 @@ impl LocalExec {
             .await
     }
 
+    /// Replays a transaction offline, from the checkpoint files in the
+    /// directory at `path`.
+    pub async fn replay_with_checkpoint_files(
+        path: &Path,
+        tx_digest: TransactionDigest,
+        expensive_safety_check_config: ExpensiveSafetyCheckConfig,
+        use_authority: bool,
+        executor_version: Option<i64>,
+        protocol_version: Option<i64>,
+        enable_profiler: Option<PathBuf>,
+        config_and_versions: Option<Vec<(ObjectID, SequenceNumber)>>,
+    ) -> Result<ExecutionSandboxState, ReplayEngineError> {
+        info!("Using checkpoint files in: {}", path.display());
+        LocalExec::new_for_checkpoint_files(CheckpointFilesFetcher::new(path)?)
+            .init_for_execution()
+            .await?
+            .execute_transaction(
+                &tx_digest,
+                expensive_safety_check_config,
+                use_authority,
+                executor_version,
+                protocol_version,
+                enable_profiler,
+                config_and_versions,
+            )
+            .await
+    }
+
     /// This captures the state of the network at a given point in time and
     /// populates prptocol version tables including which system packages to
     /// fetch If this function is called across epoch boundaries, the info
@@ -440,6 +469,29 @@ impl LocalExec {
         })
     }
 
+    pub fn new_for_checkpoint_files(fetcher: CheckpointFilesFetcher) -> Self {
+        // Use a throwaway metrics registry for local execution.
+        let registry = prometheus::Registry::new();
+        let metrics = Arc::new(LimitsMetrics::new(&registry));
+
+        Self {
+            client: None,
+            protocol_version_epoch_table: BTreeMap::new(),
+            protocol_version_system_package_table: BTreeMap::new(),
+            current_protocol_version: 0,
+            exec_store_events: Arc::new(Mutex::new(Vec::new())),
+            metrics,
+            storage: Storage::default(),
+            fetcher: Fetchers::CheckpointFiles(fetcher),
+            num_retries_for_timeout: RPC_TIMEOUT_ERR_NUM_RETRIES,
+            sleep_period_for_timeout: RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD,
+            executor_version: None,
+            protocol_version: None,
+            enable_profiler: None,
+            config_and_versions: None,
+        }
+    }
+
     pub async fn multi_download_and_store(
         &mut self,
         objs: &[(ObjectID, SequenceNumber)],
@@ -727,18 +779,6 @@ impl LocalExec {
                 reason: "System transaction".to_string(),
             });
         }
-        // Before protocol version 16, the generation of effects depends on the wrapped
-        // tombstones. It is not possible to retrieve such data for replay.
-        if tx_info.protocol_version.as_u64() < 16 {
-            warn!(
-                "Protocol version ({:?}) too old: {}, skipping transaction",
-                tx_info.protocol_version, tx_digest
-            );
-            return Err(ReplayEngineError::TransactionNotSupported {
-                digest: *tx_digest,
-                reason: "Protocol version too old".to_string(),
-            });
-        }
         // Initialize the state necessary for execution
         // Get the input objects
         let input_objects = self.initialize_execution_env_state(tx_info).await?;
@@ -1098,8 +1138,13 @@ impl LocalExec {
         Ok(Some(o))
     }
 
+    /// Whether transactions are resolved from their digest through the fetcher,
+    /// as opposed to being read from a node state dump
     pub fn is_remote_replay(&self) -> bool {
-        matches!(self.fetcher, Fetchers::Remote(_))
+        matches!(
+            self.fetcher,
+            Fetchers::Remote(_) | Fetchers::CheckpointFiles(_)
+        )
     }
 
     /// Must be called after `populate_protocol_version_tables`
@@ -1108,7 +1153,7 @@ impl LocalExec {
         protocol_version: u64,
     ) -> Result<Vec<(ObjectID, SequenceNumber)>, ReplayEngineError> {
         match &self.fetcher {
-            Fetchers::Remote(_) => Ok(self
+            Fetchers::Remote(_) | Fetchers::CheckpointFiles(_) => Ok(self
                 .protocol_version_system_package_table
                 .get(&protocol_version)
                 .ok_or(ReplayEngineError::FrameworkObjectVersionTableNotPopulated {
@@ -204,6 +204,12 @@ pub enum ReplayEngineError {
 
     #[error("Unable to get chain id: {}", err)]
     UnableToGetChainId { err: String },
+
+    #[error("Transaction {digest} not found in the checkpoint files")]
+    TransactionNotFound { digest: TransactionDigest },
+
+    #[error("Checkpoint {checkpoint} not found in the checkpoint files")]
+    CheckpointNotFound { checkpoint: u64 },
 }
 
 impl From<IotaObjectResponseError> for ReplayEngineError {
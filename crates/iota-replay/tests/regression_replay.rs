@@ -5,6 +5,8 @@
 use std::path::PathBuf;
 
 use iota_replay::{ReplayToolCommand, execute_replay_command};
+use iota_types::{base_types::IotaAddress, effects::TransactionEffectsAPI};
+use simulacrum::Simulacrum;
 
 #[tokio::test]
 async fn replay_sandboxes() {
@@ -24,3 +26,41 @@ async fn replay_sandboxes() {
             .unwrap();
     }
 }
+
+#[tokio::test]
+async fn replay_checkpoint_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sim = Simulacrum::new();
+    sim.set_data_ingestion_path(dir.path().to_path_buf());
+    let (transaction, _) = sim.transfer_txn(IotaAddress::random_for_testing_only());
+    let (effects, err) = sim.execute_transaction(transaction).unwrap();
+    assert!(err.is_none());
+    sim.create_checkpoint();
+    sim.advance_epoch();
+
+    // The local effects are checked against the ones in the checkpoint files
+    let cmd = ReplayToolCommand::ReplayTransaction {
+        tx_digest: effects.transaction_digest().to_string(),
+        show_effects: false,
+        executor_version: None,
+        protocol_version: None,
+        config_objects: None,
+        checkpoints_path: Some(dir.path().to_path_buf()),
+    };
+    let result = execute_replay_command(None, true, true, None, None, cmd)
+        .await
+        .unwrap();
+    assert_eq!(result, Some((1, 1)));
+
+    let cmd = ReplayToolCommand::ReplayCheckpoints {
+        start: 1,
+        end: 1,
+        terminate_early: true,
+        max_tasks: 1,
+        checkpoints_path: Some(dir.path().to_path_buf()),
+    };
+    let result = execute_replay_command(None, true, true, None, None, cmd)
+        .await
+        .unwrap();
+    assert_eq!(result, Some((1, 1)));
+}
@@ -3,8 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use axum::extract::{Path, Query, State};
-use iota_sdk2::types::{Address, ObjectId, StructTag, Version};
-use iota_types::iota_sdk2_conversions::struct_tag_core_to_sdk;
+use iota_sdk2::types::{Address, ObjectDigest, ObjectId, StructTag, Version};
+use iota_types::iota_sdk2_conversions::{struct_tag_core_to_sdk, struct_tag_sdk_to_core};
 use openapiv3::v3_1::Operation;
 use tap::Pipe;
 
@@ -110,3 +110,219 @@ pub struct AccountOwnedObjectInfo {
     #[serde(rename = "type")]
     pub type_: StructTag,
 }
+
+pub struct ListAccountBalances;
+
+impl ApiEndpoint<RestService> for ListAccountBalances {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/accounts/{account}/balances"
+    }
+
+    fn operation(&self, generator: &mut schemars::gen::SchemaGenerator) -> Operation {
+        OperationBuilder::new()
+            .tag("Account")
+            .operation_id("ListAccountBalances")
+            .path_parameter::<Address>("account", generator)
+            .query_parameters::<ListAccountBalancesQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<Vec<AccountBalance>>(generator)
+                    .header::<String>(crate::types::X_IOTA_CURSOR, generator)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> crate::openapi::RouteHandler<RestService> {
+        RouteHandler::new(self.method(), list_account_balances)
+    }
+}
+
+async fn list_account_balances(
+    Path(address): Path<Address>,
+    Query(parameters): Query<ListAccountBalancesQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Page<AccountBalance, StructTag>> {
+    let limit = parameters.limit();
+    let start = parameters.start.map(struct_tag_sdk_to_core);
+
+    let mut balances: Vec<AccountBalance> = vec![];
+    let mut cursor = None;
+
+    for coin in state.inner().account_coins_iter(
+        address.into(),
+        None,
+        start
+            .as_ref()
+            .map(|coin_type| (coin_type, iota_types::base_types::ObjectID::ZERO)),
+    )? {
+        let coin_type = struct_tag_core_to_sdk(coin.coin_type);
+        match balances.last_mut() {
+            Some(balance) if balance.coin_type == coin_type => {
+                balance.total_balance = balance.total_balance.saturating_add(coin.balance);
+                balance.coin_object_count += 1;
+            }
+            _ if balances.len() == limit => {
+                // The first coin type past the limit is where the next page starts
+                cursor = Some(coin_type);
+                break;
+            }
+            _ => balances.push(AccountBalance {
+                coin_type,
+                coin_object_count: 1,
+                total_balance: coin.balance,
+            }),
+        }
+    }
+
+    balances
+        .pipe(ResponseContent::Json)
+        .pipe(|entries| Page { entries, cursor })
+        .pipe(Ok)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListAccountBalancesQueryParameters {
+    pub limit: Option<u32>,
+    /// The coin type to start listing balances from.
+    pub start: Option<StructTag>,
+}
+
+impl ListAccountBalancesQueryParameters {
+    pub fn limit(&self) -> usize {
+        self.limit
+            .map(|l| (l as usize).clamp(1, crate::MAX_PAGE_SIZE))
+            .unwrap_or(crate::DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// The balance of a coin type owned by an account
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct AccountBalance {
+    pub coin_type: StructTag,
+    /// The number of coin objects of this type owned by the account.
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub coin_object_count: u64,
+    /// The sum of the balances of all coin objects of this type owned by the
+    /// account.
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub total_balance: u64,
+}
+
+pub struct ListAccountCoins;
+
+impl ApiEndpoint<RestService> for ListAccountCoins {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/accounts/{account}/coins"
+    }
+
+    fn operation(&self, generator: &mut schemars::gen::SchemaGenerator) -> Operation {
+        OperationBuilder::new()
+            .tag("Account")
+            .operation_id("ListAccountCoins")
+            .path_parameter::<Address>("account", generator)
+            .query_parameters::<ListAccountCoinsQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<Vec<AccountCoin>>(generator)
+                    .header::<String>(crate::types::X_IOTA_CURSOR, generator)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> crate::openapi::RouteHandler<RestService> {
+        RouteHandler::new(self.method(), list_account_coins)
+    }
+}
+
+async fn list_account_coins(
+    Path(address): Path<Address>,
+    Query(parameters): Query<ListAccountCoinsQueryParameters>,
+    State(state): State<StateReader>,
+) -> Result<Page<AccountCoin, ObjectId>> {
+    let limit = parameters.limit();
+    let coin_type = struct_tag_sdk_to_core(parameters.coin_type());
+
+    let mut coins = state
+        .inner()
+        .account_coins_iter(
+            address.into(),
+            Some(&coin_type),
+            parameters
+                .start
+                .map(|start| (&coin_type, iota_types::base_types::ObjectID::from(start))),
+        )?
+        .map(|coin| AccountCoin {
+            coin_type: struct_tag_core_to_sdk(coin.coin_type),
+            object_id: coin.object_id.into(),
+            version: coin.version.into(),
+            digest: coin.digest.into(),
+            balance: coin.balance,
+        })
+        .take(limit + 1)
+        .collect::<Vec<_>>();
+
+    let cursor = if coins.len() > limit {
+        // SAFETY: We've already verified that coins is greater than limit, which
+        // is guaranteed to be >= 1.
+        coins.pop().unwrap().object_id.pipe(Some)
+    } else {
+        None
+    };
+
+    coins
+        .pipe(ResponseContent::Json)
+        .pipe(|entries| Page { entries, cursor })
+        .pipe(Ok)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListAccountCoinsQueryParameters {
+    /// The type of the coins to list, `0x2::iota::IOTA` if not provided.
+    pub coin_type: Option<StructTag>,
+    pub limit: Option<u32>,
+    pub start: Option<ObjectId>,
+}
+
+impl ListAccountCoinsQueryParameters {
+    pub fn limit(&self) -> usize {
+        self.limit
+            .map(|l| (l as usize).clamp(1, crate::MAX_PAGE_SIZE))
+            .unwrap_or(crate::DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn coin_type(&self) -> StructTag {
+        self.coin_type
+            .clone()
+            .unwrap_or_else(|| struct_tag_core_to_sdk(iota_types::gas_coin::GAS::type_()))
+    }
+}
+
+/// A coin owned by an account
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct AccountCoin {
+    pub coin_type: StructTag,
+    pub object_id: ObjectId,
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub version: Version,
+    pub digest: ObjectDigest,
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub balance: u64,
+}
@@ -12,13 +12,16 @@ use iota_types::{
     full_checkpoint_content::CheckpointData,
     messages_checkpoint::{CertifiedCheckpointSummary, CheckpointSequenceNumber},
     object::Object,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionData},
 };
 pub use reqwest;
 use sdk::Result;
 
 use self::sdk::Response;
-use crate::transactions::ExecuteTransactionQueryParameters;
+use crate::transactions::{
+    ExecuteTransactionQueryParameters, InspectTransactionQueryParameters,
+    SimulateTransactionQueryParameters, TransactionSimulationResponse,
+};
 
 #[derive(Clone)]
 pub struct Client {
@@ -121,6 +124,28 @@ impl Client {
 
         self.inner.bcs(response).await.map(Response::into_inner)
     }
+
+    pub async fn simulate_transaction(
+        &self,
+        parameters: &SimulateTransactionQueryParameters,
+        transaction: &TransactionData,
+    ) -> Result<TransactionSimulationResponse> {
+        self.inner
+            .simulate_transaction(parameters, &transaction.clone().into())
+            .await
+            .map(Response::into_inner)
+    }
+
+    pub async fn inspect_transaction(
+        &self,
+        parameters: &InspectTransactionQueryParameters,
+        transaction: &TransactionData,
+    ) -> Result<TransactionSimulationResponse> {
+        self.inner
+            .inspect_transaction(parameters, &transaction.clone().into())
+            .await
+            .map(Response::into_inner)
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -4,15 +4,18 @@
 
 use iota_sdk2::types::{
     Address, CheckpointData, CheckpointDigest, CheckpointSequenceNumber, EpochId, Object, ObjectId,
-    SignedCheckpointSummary, SignedTransaction, StructTag, TransactionDigest, ValidatorCommittee,
-    Version,
+    SignedCheckpointSummary, SignedTransaction, StructTag, Transaction, TransactionDigest,
+    ValidatorCommittee, Version,
 };
 use reqwest::{StatusCode, Url, header::HeaderValue};
 use tap::Pipe;
 
 use crate::{
     ExecuteTransactionQueryParameters,
-    accounts::{AccountOwnedObjectInfo, ListAccountOwnedObjectsQueryParameters},
+    accounts::{
+        AccountBalance, AccountCoin, AccountOwnedObjectInfo, ListAccountBalancesQueryParameters,
+        ListAccountCoinsQueryParameters, ListAccountOwnedObjectsQueryParameters,
+    },
     checkpoints::ListCheckpointsQueryParameters,
     coins::CoinInfo,
     health::Threshold,
@@ -23,7 +26,9 @@ use crate::{
         X_IOTA_MIN_SUPPORTED_PROTOCOL_VERSION,
     },
     transactions::{
-        ListTransactionsQueryParameters, TransactionExecutionResponse, TransactionResponse,
+        InspectTransactionQueryParameters, ListTransactionsQueryParameters,
+        SimulateTransactionQueryParameters, TransactionExecutionResponse, TransactionResponse,
+        TransactionSimulationResponse,
     },
     types::{
         X_IOTA_CHAIN, X_IOTA_CHAIN_ID, X_IOTA_CHECKPOINT_HEIGHT, X_IOTA_CURSOR, X_IOTA_EPOCH,
@@ -124,6 +129,42 @@ impl Client {
         self.json(response).await
     }
 
+    pub async fn list_account_balances(
+        &self,
+        account: Address,
+        parameters: &ListAccountBalancesQueryParameters,
+    ) -> Result<Response<Vec<AccountBalance>>> {
+        let url = self.url().join(&format!("accounts/{account}/balances"))?;
+
+        let response = self
+            .inner
+            .get(url)
+            .query(parameters)
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
+            .send()
+            .await?;
+
+        self.json(response).await
+    }
+
+    pub async fn list_account_coins(
+        &self,
+        account: Address,
+        parameters: &ListAccountCoinsQueryParameters,
+    ) -> Result<Response<Vec<AccountCoin>>> {
+        let url = self.url().join(&format!("accounts/{account}/coins"))?;
+
+        let response = self
+            .inner
+            .get(url)
+            .query(parameters)
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_JSON)
+            .send()
+            .await?;
+
+        self.json(response).await
+    }
+
     pub async fn get_object(&self, object_id: ObjectId) -> Result<Response<Object>> {
         let url = self.url().join(&format!("objects/{object_id}"))?;
 
@@ -386,6 +427,50 @@ impl Client {
         self.bcs(response).await
     }
 
+    pub async fn simulate_transaction(
+        &self,
+        parameters: &SimulateTransactionQueryParameters,
+        transaction: &Transaction,
+    ) -> Result<Response<TransactionSimulationResponse>> {
+        let url = self.url().join("transactions/simulate")?;
+
+        let body = bcs::to_bytes(transaction)?;
+
+        let response = self
+            .inner
+            .post(url)
+            .query(parameters)
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
+            .header(reqwest::header::CONTENT_TYPE, crate::APPLICATION_BCS)
+            .body(body)
+            .send()
+            .await?;
+
+        self.bcs(response).await
+    }
+
+    pub async fn inspect_transaction(
+        &self,
+        parameters: &InspectTransactionQueryParameters,
+        transaction: &Transaction,
+    ) -> Result<Response<TransactionSimulationResponse>> {
+        let url = self.url().join("transactions/inspect")?;
+
+        let body = bcs::to_bytes(transaction)?;
+
+        let response = self
+            .inner
+            .post(url)
+            .query(parameters)
+            .header(reqwest::header::ACCEPT, crate::APPLICATION_BCS)
+            .header(reqwest::header::CONTENT_TYPE, crate::APPLICATION_BCS)
+            .body(body)
+            .send()
+            .await?;
+
+        self.bcs(response).await
+    }
+
     async fn check_response(
         &self,
         response: reqwest::Response,
@@ -48,6 +48,22 @@ impl From<anyhow::Error> for RestError {
     }
 }
 
+impl From<iota_types::error::IotaError> for RestError {
+    fn from(error: iota_types::error::IotaError) -> Self {
+        use iota_types::error::IotaError;
+
+        match error {
+            IotaError::UserInput { error } => {
+                RestError::new(StatusCode::BAD_REQUEST, error.to_string())
+            }
+            IotaError::UnsupportedFeature { error } => {
+                RestError::new(StatusCode::BAD_REQUEST, error)
+            }
+            err => RestError::new(StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        }
+    }
+}
+
 impl From<iota_types::quorum_driver_types::QuorumDriverError> for RestError {
     fn from(error: iota_types::quorum_driver_types::QuorumDriverError) -> Self {
         use iota_types::{error::IotaError, quorum_driver_types::QuorumDriverError::*};
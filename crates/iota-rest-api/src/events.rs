@@ -0,0 +1,189 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::sse::{Event as SseEvent, KeepAlive, Sse},
+};
+use futures::{Stream, TryStreamExt, stream};
+use iota_json_rpc_types::{EventFilter, Filter, IotaEvent};
+use iota_package_resolver::{PackageStoreWithLruCache, Resolver};
+use iota_sdk2::types::{Event, TransactionDigest};
+use move_core_types::annotated_value::{MoveDatatypeLayout, MoveTypeLayout};
+use schemars::JsonSchema;
+use tap::Pipe;
+
+use crate::{
+    RestService, Result,
+    openapi::{ApiEndpoint, OperationBuilder, ResponseBuilder, RouteHandler},
+    reader::StateReader,
+    streams::{StreamCursor, checkpoint_stream, last_event_id, parse_filter, start_checkpoint},
+};
+
+pub struct StreamEvents;
+
+impl ApiEndpoint<RestService> for StreamEvents {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/events/stream"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Events")
+            .operation_id("StreamEvents")
+            .query_parameters::<EventStreamQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .event_stream_content::<EventResponse>(generator)
+                    .build(),
+            )
+            .response(400, ResponseBuilder::new().build())
+            .response(410, ResponseBuilder::new().build())
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), stream_events)
+    }
+}
+
+/// Stream Events REST endpoint.
+///
+/// Streams the events matching the provided filter as Server-Sent Events, as
+/// the checkpoints including them are executed by the node. The id of each
+/// message can be provided in the `Last-Event-ID` header to resume the stream
+/// right after it, otherwise the stream starts with the next checkpoint.
+async fn stream_events(
+    Query(parameters): Query<EventStreamQueryParameters>,
+    headers: HeaderMap,
+    State(state): State<StateReader>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, anyhow::Error>>>> {
+    let cursor = last_event_id(&headers)?;
+    let filter = parse_filter::<EventFilter>(parameters.filter.as_deref())?.map(Arc::new);
+    let start = start_checkpoint(&state, cursor)?;
+    // Filtering by event fields requires the events to be decoded
+    let resolver = Arc::new(Resolver::new(PackageStoreWithLruCache::new(state.clone())));
+
+    checkpoint_stream(state, start)
+        .and_then(move |(summary, transactions)| {
+            let filter = filter.clone();
+            let resolver = resolver.clone();
+            async move {
+                let mut messages = vec![];
+                let mut index = 0;
+                for transaction in transactions {
+                    let transaction_digest = *transaction.transaction.digest();
+                    let events = transaction
+                        .events
+                        .into_iter()
+                        .flat_map(|events| events.data);
+                    for (event_seq, event) in events.enumerate() {
+                        let event_cursor = StreamCursor {
+                            checkpoint: summary.sequence_number,
+                            index,
+                        };
+                        index += 1;
+
+                        // Skip the events up to the one the stream is resumed after
+                        if !event_cursor.follows(cursor) {
+                            continue;
+                        }
+                        if let Some(filter) = &filter {
+                            let event = decode_event(
+                                &resolver,
+                                event.clone(),
+                                transaction_digest,
+                                event_seq as u64,
+                                summary.timestamp_ms,
+                            )
+                            .await?;
+                            if !filter.matches(&event) {
+                                continue;
+                            }
+                        }
+
+                        let response = EventResponse {
+                            checkpoint: summary.sequence_number,
+                            timestamp_ms: summary.timestamp_ms,
+                            transaction_digest: transaction_digest.into(),
+                            event: event.into(),
+                        };
+                        messages.push(
+                            SseEvent::default()
+                                .id(event_cursor.to_string())
+                                .json_data(response)?,
+                        );
+                    }
+                }
+
+                anyhow::Ok(stream::iter(messages.into_iter().map(Ok)))
+            }
+        })
+        .try_flatten()
+        .pipe(Sse::new)
+        .keep_alive(KeepAlive::default())
+        .pipe(Ok)
+}
+
+/// Decodes an event into its JSON-RPC representation, which event filters
+/// are matched against.
+async fn decode_event(
+    resolver: &Resolver<PackageStoreWithLruCache<StateReader>>,
+    event: iota_types::event::Event,
+    transaction_digest: iota_types::digests::TransactionDigest,
+    event_seq: u64,
+    timestamp_ms: u64,
+) -> anyhow::Result<IotaEvent> {
+    let layout = match resolver.type_layout(event.type_.clone().into()).await? {
+        MoveTypeLayout::Struct(layout) => MoveDatatypeLayout::Struct(layout),
+        MoveTypeLayout::Enum(layout) => MoveDatatypeLayout::Enum(layout),
+        _ => anyhow::bail!("event type {} is not a datatype", event.type_),
+    };
+
+    Ok(IotaEvent::try_from(
+        event,
+        transaction_digest,
+        event_seq,
+        Some(timestamp_ms),
+        layout,
+    )?)
+}
+
+/// Query parameters for the stream events endpoint.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct EventStreamQueryParameters {
+    /// A JSON encoded event filter, with the same format and semantics as the
+    /// filters of the JSON-RPC event subscription, e.g.
+    /// `{"MoveEventModule":{"package":"0x3","module":"validator"}}`.
+    ///
+    /// All the events are streamed if no filter is provided.
+    pub filter: Option<String>,
+}
+
+/// An event streamed by the stream events endpoint
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct EventResponse {
+    /// The checkpoint including the transaction which emitted the event.
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub checkpoint: u64,
+    /// The timestamp of the checkpoint, in milliseconds since the unix epoch.
+    #[serde_as(as = "iota_types::iota_serde::BigInt<u64>")]
+    #[schemars(with = "crate::_schemars::U64")]
+    pub timestamp_ms: u64,
+    /// The transaction which emitted the event.
+    pub transaction_digest: TransactionDigest,
+    pub event: Event,
+}
@@ -12,13 +12,14 @@ use reader::StateReader;
 use tap::Pipe;
 
 pub mod accept;
-mod accounts;
+pub mod accounts;
 mod checkpoints;
 pub mod client;
 mod coins;
 mod committee;
 pub mod content_type;
 mod error;
+mod events;
 mod health;
 mod info;
 mod metrics;
@@ -26,6 +27,7 @@ mod objects;
 pub mod openapi;
 mod reader;
 mod response;
+mod streams;
 mod system;
 pub mod transactions;
 pub mod types;
@@ -70,6 +72,8 @@ const ENDPOINTS: &[&dyn ApiEndpoint<RestService>] = &[
     &info::GetNodeInfo,
     &health::HealthCheck,
     &accounts::ListAccountObjects,
+    &accounts::ListAccountBalances,
+    &accounts::ListAccountCoins,
     &objects::GetObject,
     &objects::GetObjectWithVersion,
     &objects::ListDynamicFields,
@@ -78,6 +82,7 @@ const ENDPOINTS: &[&dyn ApiEndpoint<RestService>] = &[
     &checkpoints::GetCheckpointFull,
     &transactions::GetTransaction,
     &transactions::ListTransactions,
+    &transactions::StreamTransactions,
     &committee::GetCommittee,
     &committee::GetLatestCommittee,
     &system::GetSystemStateSummary,
@@ -85,7 +90,10 @@ const ENDPOINTS: &[&dyn ApiEndpoint<RestService>] = &[
     &system::GetProtocolConfig,
     &system::GetGasInfo,
     &transactions::ExecuteTransaction,
+    &transactions::SimulateTransaction,
+    &transactions::InspectTransaction,
     &coins::GetCoinInfo,
+    &events::StreamEvents,
 ];
 
 #[derive(Clone)]
@@ -223,6 +231,31 @@ mod _schemars {
             false
         }
     }
+
+    pub(crate) struct Base64;
+
+    impl JsonSchema for Base64 {
+        fn schema_name() -> String {
+            "Base64".to_owned()
+        }
+
+        fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+            SchemaObject {
+                metadata: Some(Box::new(Metadata {
+                    description: Some("Base64 encoded data".to_owned()),
+                    ..Default::default()
+                })),
+                instance_type: Some(InstanceType::String.into()),
+                format: Some("base64".to_owned()),
+                ..Default::default()
+            }
+            .into()
+        }
+
+        fn is_referenceable() -> bool {
+            false
+        }
+    }
 }
 
 #[cfg(test)]
@@ -600,6 +600,24 @@ impl ResponseBuilder {
         self.content(crate::APPLICATION_BCS, MediaType::default())
     }
 
+    /// A stream of Server-Sent Events, each carrying a JSON encoded `T`.
+    pub fn event_stream_content<T: JsonSchema>(
+        &mut self,
+        generator: &mut SchemaGenerator,
+    ) -> &mut Self {
+        let schema_object = SchemaObject {
+            json_schema: generator.subschema_for::<T>(),
+            external_docs: None,
+            example: None,
+        };
+        let media_type = MediaType {
+            schema: Some(schema_object),
+            ..Default::default()
+        };
+
+        self.content(mime::TEXT_EVENT_STREAM.as_ref(), media_type)
+    }
+
     pub fn text_content(&mut self) -> &mut Self {
         self.content(mime::TEXT_PLAIN_UTF_8.as_ref(), MediaType::default())
     }
@@ -4,6 +4,8 @@
 
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use iota_package_resolver::{Package, PackageStore, error::Error as PackageResolverError};
 use iota_sdk2::types::{
     CheckpointSequenceNumber, EpochId, Object, ObjectId, SignedTransaction, ValidatorCommittee,
     Version,
@@ -12,10 +14,13 @@ use iota_types::storage::{
     ObjectStore, RestStateReader,
     error::{Error as StorageError, Result},
 };
+use move_core_types::account_address::AccountAddress;
 use tap::Pipe;
 
 use crate::Direction;
 
+const STORE: &str = "RestStateReader";
+
 #[derive(Clone)]
 pub struct StateReader {
     inner: Arc<dyn RestStateReader>,
@@ -148,6 +153,24 @@ impl StateReader {
     }
 }
 
+/// Reads the packages needed to resolve Move type layouts from the node's
+/// object store.
+#[async_trait]
+impl PackageStore for StateReader {
+    async fn fetch(&self, id: AccountAddress) -> iota_package_resolver::Result<Arc<Package>> {
+        let object = self
+            .inner
+            .get_object(&id.into())
+            .map_err(|e| PackageResolverError::Store {
+                store: STORE,
+                source: Arc::new(e),
+            })?
+            .ok_or(PackageResolverError::PackageNotFound(id))?;
+
+        Ok(Arc::new(Package::read_from_object(&object)?))
+    }
+}
+
 pub struct CheckpointTransactionsIter {
     reader: StateReader,
     direction: Direction,
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use axum::{
-    extract::State,
+    extract::{FromRequest, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
 };
@@ -113,6 +113,36 @@ impl axum::response::IntoResponse for BcsRejection {
     }
 }
 
+/// Request body extractor which deserializes the body as either BCS or JSON,
+/// depending on its `Content-Type`.
+pub struct JsonOrBcs<T>(pub T);
+
+#[axum::async_trait]
+impl<T, S> axum::extract::FromRequest<S> for JsonOrBcs<T>
+where
+    T: serde::de::DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        req: axum::http::Request<axum::body::Body>,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        if bcs_content_type(req.headers()) {
+            let Bcs(inner) = Bcs::<T>::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            Ok(Self(inner))
+        } else {
+            let axum::Json(inner) = axum::Json::<T>::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            Ok(Self(inner))
+        }
+    }
+}
+
 impl<T, J> axum::response::IntoResponse for ResponseContent<T, J>
 where
     T: serde::Serialize,
@@ -0,0 +1,270 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers shared by the endpoints streaming data over Server-Sent Events as
+//! checkpoints are executed by the node.
+
+use std::{sync::Arc, time::Duration};
+
+use axum::http::{HeaderMap, StatusCode};
+use futures::Stream;
+use iota_sdk2::types::CheckpointSequenceNumber;
+use iota_types::{
+    effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
+    messages_checkpoint::CheckpointSummary,
+    transaction::VerifiedTransaction,
+};
+use serde::de::DeserializeOwned;
+use tap::Pipe;
+
+use crate::{RestError, Result, reader::StateReader};
+
+/// Name of the header used by clients to resume a stream after the last
+/// message they received.
+pub const LAST_EVENT_ID: &str = "last-event-id";
+
+/// How often the node is polled for newly executed checkpoints.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The id of a message sent by a stream, used to resume it.
+///
+/// Has the format of: `<checkpoint>.<index>`
+/// where `<checkpoint>` is the sequence number of a checkpoint and `<index>` is
+/// the index of the streamed item (a transaction or an event) within it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StreamCursor {
+    pub checkpoint: CheckpointSequenceNumber,
+    pub index: u64,
+}
+
+impl StreamCursor {
+    /// Returns whether the item at this position has to be streamed to a
+    /// client resuming the stream after `cursor`.
+    pub fn follows(&self, cursor: Option<StreamCursor>) -> bool {
+        cursor.map_or(true, |cursor| {
+            (self.checkpoint, self.index) > (cursor.checkpoint, cursor.index)
+        })
+    }
+}
+
+impl std::fmt::Display for StreamCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.checkpoint, self.index)
+    }
+}
+
+impl std::str::FromStr for StreamCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (checkpoint, index) = s
+            .split_once('.')
+            .ok_or_else(|| anyhow::anyhow!("expected a `<checkpoint>.<index>` cursor"))?;
+        Self {
+            checkpoint: checkpoint.parse()?,
+            index: index.parse()?,
+        }
+        .pipe(Ok)
+    }
+}
+
+/// Reads the cursor a client wants to resume the stream after, from the
+/// `Last-Event-ID` header.
+pub fn last_event_id(headers: &HeaderMap) -> Result<Option<StreamCursor>> {
+    let Some(value) = headers.get(LAST_EVENT_ID) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .map_err(anyhow::Error::from)
+        .and_then(str::parse)
+        .map_err(|e| {
+            RestError::new(
+                StatusCode::BAD_REQUEST,
+                format!("invalid {LAST_EVENT_ID} header: {e}"),
+            )
+        })?
+        .pipe(Some)
+        .pipe(Ok)
+}
+
+/// Parses the JSON encoded filter a client wants to apply to a stream, if any.
+pub fn parse_filter<F: DeserializeOwned>(filter: Option<&str>) -> Result<Option<F>> {
+    filter
+        .map(|filter| {
+            serde_json::from_str(filter).map_err(|e| {
+                RestError::new(StatusCode::BAD_REQUEST, format!("invalid filter: {e}"))
+            })
+        })
+        .transpose()
+}
+
+/// Returns the checkpoint a stream should start at, which is the one of the
+/// resume cursor if any, or the one following the latest executed checkpoint.
+pub fn start_checkpoint(
+    state: &StateReader,
+    cursor: Option<StreamCursor>,
+) -> Result<CheckpointSequenceNumber> {
+    let Some(cursor) = cursor else {
+        return Ok(state.inner().get_latest_checkpoint()?.sequence_number + 1);
+    };
+
+    if cursor.checkpoint < state.inner().get_lowest_available_checkpoint()? {
+        return Err(RestError::new(
+            StatusCode::GONE,
+            "The checkpoint to resume from has been pruned",
+        ));
+    }
+
+    Ok(cursor.checkpoint)
+}
+
+/// A transaction executed as part of a checkpoint.
+pub struct CheckpointTransaction {
+    pub transaction: Arc<VerifiedTransaction>,
+    pub effects: TransactionEffects,
+    pub events: Option<TransactionEvents>,
+}
+
+/// Streams the transactions of every checkpoint starting at `start`, waiting
+/// for new checkpoints to be executed once the latest one has been reached.
+///
+/// The stream ends after yielding an error.
+pub fn checkpoint_stream(
+    state: StateReader,
+    start: CheckpointSequenceNumber,
+) -> impl Stream<Item = anyhow::Result<(CheckpointSummary, Vec<CheckpointTransaction>)>> {
+    futures::stream::unfold(Some(start), move |next| {
+        let state = state.clone();
+        async move {
+            let sequence_number = next?;
+            loop {
+                match state.inner().get_latest_checkpoint() {
+                    Ok(latest) if latest.sequence_number >= sequence_number => break,
+                    Ok(_) => tokio::time::sleep(POLL_INTERVAL).await,
+                    Err(e) => return Some((Err(e.into()), None)),
+                }
+            }
+
+            match read_checkpoint(&state, sequence_number) {
+                Ok(checkpoint) => Some((Ok(checkpoint), sequence_number.checked_add(1))),
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+}
+
+fn read_checkpoint(
+    state: &StateReader,
+    sequence_number: CheckpointSequenceNumber,
+) -> anyhow::Result<(CheckpointSummary, Vec<CheckpointTransaction>)> {
+    let missing = || anyhow::anyhow!("checkpoint {sequence_number} is missing data");
+
+    let summary = state
+        .inner()
+        .get_checkpoint_by_sequence_number(sequence_number)?
+        .ok_or_else(missing)?
+        .into_inner()
+        .into_data();
+    let contents = state
+        .inner()
+        .get_checkpoint_contents_by_sequence_number(sequence_number)?
+        .ok_or_else(missing)?;
+
+    let transactions = contents
+        .iter()
+        .map(|digests| {
+            let transaction = state
+                .inner()
+                .get_transaction(&digests.transaction)?
+                .ok_or_else(missing)?;
+            let effects = state
+                .inner()
+                .get_transaction_effects(&digests.transaction)?
+                .ok_or_else(missing)?;
+            let events = effects
+                .events_digest()
+                .map(|digest| state.inner().get_events(digest)?.ok_or_else(missing))
+                .transpose()?;
+
+            Ok(CheckpointTransaction {
+                transaction,
+                effects,
+                events,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok((summary, transactions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_cursor_roundtrip() {
+        let cursor = StreamCursor {
+            checkpoint: 42,
+            index: 7,
+        };
+        assert_eq!(cursor.to_string(), "42.7");
+        assert_eq!("42.7".parse::<StreamCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_stream_cursor_invalid() {
+        for invalid in ["", "42", "42.", ".7", "a.7", "42.b", "42.7.1", "-1.7"] {
+            assert!(
+                invalid.parse::<StreamCursor>().is_err(),
+                "{invalid:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_last_event_id() {
+        let mut headers = HeaderMap::new();
+        assert!(matches!(last_event_id(&headers), Ok(None)));
+
+        headers.insert(LAST_EVENT_ID, "10.3".parse().unwrap());
+        assert_eq!(
+            last_event_id(&headers).ok().flatten(),
+            Some(StreamCursor {
+                checkpoint: 10,
+                index: 3,
+            })
+        );
+
+        headers.insert(LAST_EVENT_ID, "10".parse().unwrap());
+        assert!(last_event_id(&headers).is_err());
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        assert!(matches!(parse_filter::<Vec<u64>>(None), Ok(None)));
+        assert_eq!(
+            parse_filter::<Vec<u64>>(Some("[1, 2]")).ok().flatten(),
+            Some(vec![1, 2])
+        );
+        assert!(parse_filter::<Vec<u64>>(Some("{}")).is_err());
+    }
+
+    #[test]
+    fn test_stream_cursor_follows() {
+        let cursor = StreamCursor {
+            checkpoint: 10,
+            index: 3,
+        };
+        let at = |checkpoint, index| StreamCursor { checkpoint, index };
+
+        // Without a cursor everything is streamed.
+        assert!(at(10, 0).follows(None));
+        // Resuming skips the items up to and including the cursor.
+        assert!(!at(10, 0).follows(Some(cursor)));
+        assert!(!at(10, 3).follows(Some(cursor)));
+        assert!(at(10, 4).follows(Some(cursor)));
+        assert!(at(11, 0).follows(Some(cursor)));
+    }
+}
@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod execution;
+mod simulation;
+mod stream;
 
 use axum::{
     extract::{Path, Query, State},
@@ -16,6 +18,12 @@ use iota_sdk2::types::{
     CheckpointSequenceNumber, Transaction, TransactionDigest, TransactionEffects,
     TransactionEvents, UserSignature,
 };
+pub use simulation::{
+    CommandResult, InspectTransaction, InspectTransactionQueryParameters, MutatedReference,
+    ReturnValue, SimulateTransaction, SimulateTransactionQueryParameters,
+    TransactionSimulationResponse,
+};
+pub use stream::{StreamTransactions, TransactionStreamQueryParameters};
 use tap::Pipe;
 
 use crate::{
@@ -0,0 +1,326 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use iota_sdk2::types::{
+    Argument, BalanceChange, Object, Transaction, TransactionEffects, TransactionEvents, TypeTag,
+};
+use iota_types::{
+    iota_sdk2_conversions::type_tag_core_to_sdk,
+    transaction_executor::{SimulateTransactionResult, TransactionExecutor},
+};
+use schemars::JsonSchema;
+use tap::Pipe;
+
+use crate::{
+    RestService, Result,
+    accept::AcceptFormat,
+    openapi::{ApiEndpoint, OperationBuilder, RequestBodyBuilder, ResponseBuilder, RouteHandler},
+    response::{JsonOrBcs, ResponseContent},
+    transactions::execution::derive_balance_changes,
+};
+
+pub struct SimulateTransaction;
+
+impl ApiEndpoint<RestService> for SimulateTransaction {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::POST
+    }
+
+    fn path(&self) -> &'static str {
+        "/transactions/simulate"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Transactions")
+            .operation_id("SimulateTransaction")
+            .query_parameters::<SimulateTransactionQueryParameters>(generator)
+            .request_body(
+                RequestBodyBuilder::new()
+                    .json_content::<Transaction>(generator)
+                    .bcs_content()
+                    .build(),
+            )
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<TransactionSimulationResponse>(generator)
+                    .bcs_content()
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), simulate_transaction)
+    }
+}
+
+/// Simulate Transaction REST endpoint.
+///
+/// Executes the provided unsigned transaction on top of the latest state
+/// known to the node, without committing its results, so that clients can
+/// estimate its gas cost and preview its outcome. If the transaction does not
+/// provide a gas payment, a mock gas coin owned by the gas owner is used.
+///
+/// The transaction can be provided either as BCS or JSON, and the response
+/// format is selected using the `Accept` header.
+async fn simulate_transaction(
+    State(state): State<Option<Arc<dyn TransactionExecutor>>>,
+    Query(parameters): Query<SimulateTransactionQueryParameters>,
+    accept: AcceptFormat,
+    JsonOrBcs(transaction): JsonOrBcs<Transaction>,
+) -> Result<ResponseContent<TransactionSimulationResponse>> {
+    let executor = state.ok_or_else(|| anyhow::anyhow!("No Transaction Executor"))?;
+    let result = executor.simulate_transaction(transaction.into()).await?;
+
+    let response = simulation_response(result, &parameters);
+
+    match accept {
+        AcceptFormat::Json => ResponseContent::Json(response),
+        AcceptFormat::Bcs => ResponseContent::Bcs(response),
+    }
+    .pipe(Ok)
+}
+
+pub struct InspectTransaction;
+
+impl ApiEndpoint<RestService> for InspectTransaction {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::POST
+    }
+
+    fn path(&self) -> &'static str {
+        "/transactions/inspect"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Transactions")
+            .operation_id("InspectTransaction")
+            .query_parameters::<InspectTransactionQueryParameters>(generator)
+            .request_body(
+                RequestBodyBuilder::new()
+                    .json_content::<Transaction>(generator)
+                    .bcs_content()
+                    .build(),
+            )
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .json_content::<TransactionSimulationResponse>(generator)
+                    .bcs_content()
+                    .build(),
+            )
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), inspect_transaction)
+    }
+}
+
+/// Inspect Transaction REST endpoint.
+///
+/// Executes the provided unsigned transaction in dev-inspect mode on top of
+/// the latest state known to the node, without committing its results. Unlike
+/// a simulation, the response also contains the values returned by each
+/// command of the transaction, and by default only lightweight checks are
+/// performed on its inputs, which allows calling any Move function.
+///
+/// The transaction can be provided either as BCS or JSON, and the response
+/// format is selected using the `Accept` header.
+async fn inspect_transaction(
+    State(state): State<Option<Arc<dyn TransactionExecutor>>>,
+    Query(parameters): Query<InspectTransactionQueryParameters>,
+    accept: AcceptFormat,
+    JsonOrBcs(transaction): JsonOrBcs<Transaction>,
+) -> Result<ResponseContent<TransactionSimulationResponse>> {
+    let executor = state.ok_or_else(|| anyhow::anyhow!("No Transaction Executor"))?;
+    let result = executor
+        .dev_inspect_transaction(transaction.into(), !parameters.full_checks)
+        .await?;
+
+    let parameters = SimulateTransactionQueryParameters {
+        events: parameters.events,
+        balance_changes: parameters.balance_changes,
+        input_objects: parameters.input_objects,
+        output_objects: parameters.output_objects,
+    };
+    let response = simulation_response(result, &parameters);
+
+    match accept {
+        AcceptFormat::Json => ResponseContent::Json(response),
+        AcceptFormat::Bcs => ResponseContent::Bcs(response),
+    }
+    .pipe(Ok)
+}
+
+fn simulation_response(
+    result: SimulateTransactionResult,
+    parameters: &SimulateTransactionQueryParameters,
+) -> TransactionSimulationResponse {
+    let SimulateTransactionResult {
+        mut input_objects,
+        mut output_objects,
+        events,
+        effects,
+        mock_gas_id,
+        execution_results,
+    } = result;
+
+    let effects: TransactionEffects = effects.into();
+
+    let balance_changes = parameters.balance_changes.then(|| {
+        let input_objects = input_objects
+            .values()
+            .cloned()
+            .map(Into::into)
+            .collect::<Vec<Object>>();
+        let output_objects = output_objects
+            .values()
+            .cloned()
+            .map(Into::into)
+            .collect::<Vec<Object>>();
+        derive_balance_changes(&effects, &input_objects, &output_objects)
+    });
+
+    // The mock gas coin only exists for the duration of the simulation, so it is
+    // only accounted for in the balance changes, as it pays for the gas.
+    if let Some(mock_gas_id) = mock_gas_id {
+        input_objects.remove(&mock_gas_id);
+        output_objects.remove(&mock_gas_id);
+    }
+
+    TransactionSimulationResponse {
+        effects,
+        events: events.filter(|_| parameters.events).map(Into::into),
+        balance_changes,
+        input_objects: parameters
+            .input_objects
+            .then(|| input_objects.into_values().map(Into::into).collect()),
+        output_objects: parameters
+            .output_objects
+            .then(|| output_objects.into_values().map(Into::into).collect()),
+        command_results: execution_results
+            .map(|results| results.into_iter().map(CommandResult::from).collect()),
+    }
+}
+
+/// Query parameters for the simulate transaction endpoint
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SimulateTransactionQueryParameters {
+    /// Request `TransactionEvents` be included in the Response.
+    #[serde(default)]
+    pub events: bool,
+    /// Request `BalanceChanges` be included in the Response.
+    #[serde(default)]
+    pub balance_changes: bool,
+    /// Request input `Object`s be included in the Response.
+    #[serde(default)]
+    pub input_objects: bool,
+    /// Request output `Object`s be included in the Response.
+    #[serde(default)]
+    pub output_objects: bool,
+}
+
+/// Query parameters for the inspect transaction endpoint
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct InspectTransactionQueryParameters {
+    /// Request `TransactionEvents` be included in the Response.
+    #[serde(default)]
+    pub events: bool,
+    /// Request `BalanceChanges` be included in the Response.
+    #[serde(default)]
+    pub balance_changes: bool,
+    /// Request input `Object`s be included in the Response.
+    #[serde(default)]
+    pub input_objects: bool,
+    /// Request output `Object`s be included in the Response.
+    #[serde(default)]
+    pub output_objects: bool,
+    /// Perform the full set of checks on the transaction inputs, as is done
+    /// when executing it, instead of the lightweight dev-inspect ones.
+    #[serde(default)]
+    pub full_checks: bool,
+}
+
+/// Response type for the simulate and inspect transaction endpoints
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct TransactionSimulationResponse {
+    pub effects: TransactionEffects,
+
+    pub events: Option<TransactionEvents>,
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    pub input_objects: Option<Vec<Object>>,
+    pub output_objects: Option<Vec<Object>>,
+    /// The results of each command of the transaction, only provided by the
+    /// inspect endpoint when the transaction executed successfully.
+    pub command_results: Option<Vec<CommandResult>>,
+}
+
+/// The values produced by a single command of an inspected transaction
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct CommandResult {
+    /// The final values of the mutable references passed to the command.
+    pub mutated_references: Vec<MutatedReference>,
+    /// The values returned by the command.
+    pub return_values: Vec<ReturnValue>,
+}
+
+/// The final value of a mutable reference passed to a command
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct MutatedReference {
+    /// The argument that was passed by mutable reference.
+    pub argument: Argument,
+    /// The BCS encoded value of the argument after the command executed.
+    #[serde_as(as = "iota_types::iota_serde::Readable<fastcrypto::encoding::Base64, _>")]
+    #[schemars(with = "crate::_schemars::Base64")]
+    pub value: Vec<u8>,
+    /// The Move type of the value.
+    pub type_tag: TypeTag,
+}
+
+/// A value returned by a command
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ReturnValue {
+    /// The BCS encoded value.
+    #[serde_as(as = "iota_types::iota_serde::Readable<fastcrypto::encoding::Base64, _>")]
+    #[schemars(with = "crate::_schemars::Base64")]
+    pub value: Vec<u8>,
+    /// The Move type of the value.
+    pub type_tag: TypeTag,
+}
+
+impl From<iota_types::execution::ExecutionResult> for CommandResult {
+    fn from((mutated_references, return_values): iota_types::execution::ExecutionResult) -> Self {
+        Self {
+            mutated_references: mutated_references
+                .into_iter()
+                .map(|(argument, value, type_tag)| MutatedReference {
+                    argument: argument.into(),
+                    value,
+                    type_tag: type_tag_core_to_sdk(type_tag),
+                })
+                .collect(),
+            return_values: return_values
+                .into_iter()
+                .map(|(value, type_tag)| ReturnValue {
+                    value,
+                    type_tag: type_tag_core_to_sdk(type_tag),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event as SseEvent, KeepAlive, Sse},
+};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+use iota_json_rpc_types::{EffectsWithInput, Filter, TransactionFilter};
+use iota_sdk2::types::SignedTransaction;
+use schemars::JsonSchema;
+use tap::Pipe;
+
+use crate::{
+    RestError, RestService, Result,
+    openapi::{ApiEndpoint, OperationBuilder, ResponseBuilder, RouteHandler},
+    reader::StateReader,
+    streams::{StreamCursor, checkpoint_stream, last_event_id, parse_filter, start_checkpoint},
+    transactions::TransactionResponse,
+};
+
+pub struct StreamTransactions;
+
+impl ApiEndpoint<RestService> for StreamTransactions {
+    fn method(&self) -> axum::http::Method {
+        axum::http::Method::GET
+    }
+
+    fn path(&self) -> &'static str {
+        "/transactions/stream"
+    }
+
+    fn operation(
+        &self,
+        generator: &mut schemars::gen::SchemaGenerator,
+    ) -> openapiv3::v3_1::Operation {
+        OperationBuilder::new()
+            .tag("Transactions")
+            .operation_id("StreamTransactions")
+            .query_parameters::<TransactionStreamQueryParameters>(generator)
+            .response(
+                200,
+                ResponseBuilder::new()
+                    .event_stream_content::<TransactionResponse>(generator)
+                    .build(),
+            )
+            .response(400, ResponseBuilder::new().build())
+            .response(410, ResponseBuilder::new().build())
+            .build()
+    }
+
+    fn handler(&self) -> RouteHandler<RestService> {
+        RouteHandler::new(self.method(), stream_transactions)
+    }
+}
+
+/// Stream Transactions REST endpoint.
+///
+/// Streams the transactions matching the provided filter as Server-Sent
+/// Events, as the checkpoints including them are executed by the node. The id
+/// of each message can be provided in the `Last-Event-ID` header to resume the
+/// stream right after it, otherwise the stream starts with the next
+/// checkpoint.
+async fn stream_transactions(
+    Query(parameters): Query<TransactionStreamQueryParameters>,
+    headers: HeaderMap,
+    State(state): State<StateReader>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, anyhow::Error>>>> {
+    let cursor = last_event_id(&headers)?;
+    let filter = parse_filter::<TransactionFilter>(parameters.filter.as_deref())?;
+    // Like the JSON-RPC transaction subscription, reject the filters which
+    // can't be matched against a single transaction.
+    if let Some(TransactionFilter::Checkpoint(_) | TransactionFilter::FromOrToAddress { .. }) =
+        &filter
+    {
+        return Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            "the Checkpoint and FromOrToAddress filters are not supported by the stream",
+        ));
+    }
+    let start = start_checkpoint(&state, cursor)?;
+
+    checkpoint_stream(state, start)
+        .map(move |checkpoint| -> anyhow::Result<_> {
+            let (summary, transactions) = checkpoint?;
+
+            let mut messages = vec![];
+            for (index, transaction) in transactions.into_iter().enumerate() {
+                let transaction_cursor = StreamCursor {
+                    checkpoint: summary.sequence_number,
+                    index: index as u64,
+                };
+
+                // Skip the transactions up to the one the stream is resumed after
+                if !transaction_cursor.follows(cursor) {
+                    continue;
+                }
+                if let Some(filter) = &filter {
+                    let effects = EffectsWithInput {
+                        effects: transaction.effects.clone().try_into()?,
+                        input: transaction.transaction.data().transaction_data().clone(),
+                    };
+                    if !filter.matches(&effects) {
+                        continue;
+                    }
+                }
+
+                let SignedTransaction {
+                    transaction: sdk_transaction,
+                    signatures,
+                } = transaction.transaction.as_ref().clone().into_inner().into();
+                let response = TransactionResponse {
+                    digest: sdk_transaction.digest(),
+                    transaction: sdk_transaction,
+                    signatures,
+                    effects: transaction.effects.into(),
+                    events: transaction.events.map(Into::into),
+                    checkpoint: Some(summary.sequence_number),
+                    timestamp_ms: Some(summary.timestamp_ms),
+                };
+                messages.push(
+                    SseEvent::default()
+                        .id(transaction_cursor.to_string())
+                        .json_data(response)?,
+                );
+            }
+
+            Ok(stream::iter(messages.into_iter().map(Ok)))
+        })
+        .try_flatten()
+        .pipe(Sse::new)
+        .keep_alive(KeepAlive::default())
+        .pipe(Ok)
+}
+
+/// Query parameters for the stream transactions endpoint.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct TransactionStreamQueryParameters {
+    /// A JSON encoded transaction filter, with the same format and semantics
+    /// as the filters of the JSON-RPC transaction subscription, e.g.
+    /// `{"FromAddress":"0x..."}`. The `Checkpoint` and `FromOrToAddress`
+    /// filters are not supported.
+    ///
+    /// All the transactions are streamed if no filter is provided.
+    pub filter: Option<String>,
+}
@@ -298,6 +298,10 @@ impl ReadApi {
     /// Get an object by object ID with optional fields enabled by
     /// [IotaObjectDataOptions].
     ///
+    /// If the client was built with quorum reads enabled, the object is read
+    /// from several endpoints and only returned if a majority of them agree on
+    /// it.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -345,6 +349,9 @@ impl ReadApi {
         object_id: ObjectID,
         options: IotaObjectDataOptions,
     ) -> IotaRpcResult<IotaObjectResponse> {
+        if let Some(quorum) = self.api.http.quorum_reads() {
+            return Ok(quorum.get_object(object_id, Some(options)).await?);
+        }
         Ok(self.api.http.get_object(object_id, Some(options)).await?)
     }
 
@@ -0,0 +1,730 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pool of gas coins owned by a single address, handing out exclusive
+//! leases so that transactions can be sent in parallel from that address
+//! without two of them locking the same gas coin.
+//!
+//! The pool splits coins until it holds the configured number of them, and
+//! merges back together the coins whose balance fell below the dust threshold.
+//! The outstanding leases can be saved to a file, so that the coins leased when
+//! the process stopped are recovered with their latest version once the
+//! transactions they paid for can no longer be in flight.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, ensure};
+use iota_json_rpc_api::QUERY_MAX_RESULT_LIMIT;
+use iota_json_rpc_types::{
+    IotaExecutionStatus, IotaObjectDataOptions, IotaTransactionBlockEffects,
+    IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponseOptions,
+};
+use iota_keys::keystore::{AccountKeystore, Keystore};
+use iota_types::{
+    base_types::{IotaAddress, ObjectID, ObjectRef},
+    gas_coin::{GasCoin, NANOS_PER_IOTA},
+    object::Owner,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    quorum_driver_types::ExecuteTransactionRequestType,
+    transaction::{Transaction, TransactionData, TransactionDataAPI},
+};
+use serde::{Deserialize, Serialize};
+use shared_crypto::intent::Intent;
+use tokio::sync::Notify;
+use tracing::warn;
+
+use crate::{IotaClient, wallet_context::WalletContext};
+
+/// Maximum number of coins merged together by a single transaction, as
+/// bounded by the number of gas payment objects.
+const MAX_MERGED_COINS: usize = 256;
+/// Maximum number of coins split off by a single transaction.
+const MAX_SPLIT_COINS: usize = 256;
+
+/// Signs the transactions sent by a [`GasPool`] to split and merge its coins.
+pub trait GasPoolSigner: Send + Sync {
+    fn sign(&self, data: TransactionData) -> anyhow::Result<Transaction>;
+}
+
+impl GasPoolSigner for Keystore {
+    fn sign(&self, data: TransactionData) -> anyhow::Result<Transaction> {
+        let signature = self.sign_secure(&data.sender(), &data, Intent::iota_transaction())?;
+        Ok(Transaction::from_data(data, vec![signature]))
+    }
+}
+
+impl GasPoolSigner for WalletContext {
+    fn sign(&self, data: TransactionData) -> anyhow::Result<Transaction> {
+        self.config().keystore.sign(data)
+    }
+}
+
+impl<T: GasPoolSigner> GasPoolSigner for Arc<T> {
+    fn sign(&self, data: TransactionData) -> anyhow::Result<Transaction> {
+        (**self).sign(data)
+    }
+}
+
+/// Configuration of a [`GasPool`].
+#[derive(Clone, Debug)]
+pub struct GasPoolConfig {
+    /// Number of coins maintained by the pool.
+    pub size: usize,
+    /// Balance of the coins split off to fill the pool.
+    pub coin_balance: u64,
+    /// Coins with a lower balance are merged back together.
+    pub dust_threshold: u64,
+    /// Gas budget of the transactions splitting and merging coins.
+    pub maintenance_gas_budget: u64,
+    /// Time after which the coin of a lease that was dropped without being
+    /// released, or that was outstanding when the process stopped, is fetched
+    /// again and returned to the pool. It should exceed the time a
+    /// transaction takes to be executed once its gas is leased.
+    pub lease_timeout: Duration,
+    /// File the outstanding leases are saved to, so that their coins are
+    /// recovered after a crash.
+    pub lease_file: Option<PathBuf>,
+}
+
+impl Default for GasPoolConfig {
+    fn default() -> Self {
+        Self {
+            size: 10,
+            coin_balance: NANOS_PER_IOTA,
+            dust_threshold: NANOS_PER_IOTA / 10,
+            maintenance_gas_budget: NANOS_PER_IOTA / 20,
+            lease_timeout: Duration::from_secs(60),
+            lease_file: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PooledCoin {
+    object_ref: ObjectRef,
+    balance: u64,
+}
+
+#[derive(Default)]
+struct PoolState {
+    /// Coins available to be leased.
+    idle: Vec<PooledCoin>,
+    /// Leased coins, along with the time (in milliseconds since the unix
+    /// epoch) after which they are recovered if the lease is lost.
+    leased: HashMap<ObjectID, (PooledCoin, u64)>,
+    /// Coins whose latest version is unknown, along with the time after which
+    /// they are fetched again and returned to the pool.
+    recovering: HashMap<ObjectID, u64>,
+    /// Incremented whenever the leases are snapshotted to be saved.
+    lease_generation: u64,
+}
+
+/// A coin leased, or being recovered, when the lease file was saved.
+#[derive(Serialize, Deserialize)]
+struct LeaseRecord {
+    object_id: ObjectID,
+    recover_at_ms: u64,
+}
+
+/// The leases of the pool at some point, to be saved to the lease file once
+/// the pool state is unlocked.
+struct LeaseSnapshot {
+    generation: u64,
+    records: Vec<LeaseRecord>,
+}
+
+struct Inner {
+    client: IotaClient,
+    signer: Box<dyn GasPoolSigner>,
+    address: IotaAddress,
+    config: GasPoolConfig,
+    state: Mutex<PoolState>,
+    /// Serializes the writes of the lease file, and holds the generation of
+    /// the last snapshot written to it.
+    lease_file: Mutex<u64>,
+    released: Notify,
+}
+
+/// A pool of gas coins owned by a single address.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+///
+/// use iota_sdk::{
+///     IotaClientBuilder,
+///     gas_pool::{GasPool, GasPoolConfig},
+///     types::transaction::{ProgrammableTransaction, TransactionData},
+///     wallet_context::WalletContext,
+/// };
+/// # async fn example(wallet: WalletContext, pt: ProgrammableTransaction) -> anyhow::Result<()> {
+/// let wallet = Arc::new(wallet);
+/// let client = IotaClientBuilder::default().build_localnet().await?;
+/// let address = wallet.get_addresses()[0];
+/// let pool = GasPool::new(client, wallet.clone(), address, GasPoolConfig::default()).await?;
+///
+/// // pay for the programmable transaction `pt` with a leased coin
+/// let budget = 10_000_000;
+/// let lease = pool.acquire(budget).await?;
+/// let gas_price = wallet.get_reference_gas_price().await?;
+/// let data =
+///     TransactionData::new_programmable(address, vec![lease.object_ref()], pt, budget, gas_price);
+/// let response = wallet
+///     .execute_transaction_may_fail(wallet.sign_transaction(&data))
+///     .await?;
+/// let effects = response
+///     .effects
+///     .ok_or_else(|| anyhow::anyhow!("missing transaction effects"))?;
+/// lease.release(&effects)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GasPool {
+    inner: Arc<Inner>,
+}
+
+impl GasPool {
+    /// Loads the coins owned by `address`, recovers the coins leased when the
+    /// pool was last stopped, and splits coins until the pool holds
+    /// `config.size` of them.
+    pub async fn new(
+        client: IotaClient,
+        signer: impl GasPoolSigner + 'static,
+        address: IotaAddress,
+        config: GasPoolConfig,
+    ) -> anyhow::Result<Self> {
+        ensure!(config.size > 0, "the gas pool size must be positive");
+        ensure!(
+            config.coin_balance >= config.dust_threshold,
+            "the balance of the split coins must not be below the dust threshold"
+        );
+
+        let mut state = PoolState::default();
+        if let Some(path) = &config.lease_file {
+            state.recovering = load_leases(path)?;
+        }
+
+        let mut cursor = None;
+        loop {
+            let page = client
+                .coin_read_api()
+                .get_coins(address, None, cursor, None)
+                .await?;
+            state.idle.extend(
+                page.data
+                    .iter()
+                    .filter(|coin| !state.recovering.contains_key(&coin.coin_object_id))
+                    .map(|coin| PooledCoin {
+                        object_ref: coin.object_ref(),
+                        balance: coin.balance,
+                    }),
+            );
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        let pool = Self {
+            inner: Arc::new(Inner {
+                client,
+                signer: Box::new(signer),
+                address,
+                config,
+                state: Mutex::new(state),
+                lease_file: Mutex::new(0),
+                released: Notify::new(),
+            }),
+        };
+        pool.maintain().await?;
+        Ok(pool)
+    }
+
+    /// The address owning the coins of the pool.
+    pub fn address(&self) -> IotaAddress {
+        self.inner.address
+    }
+
+    /// The references and balances of the coins available to be leased.
+    pub fn available_coins(&self) -> Vec<(ObjectRef, u64)> {
+        self.inner
+            .lock()
+            .idle
+            .iter()
+            .map(|coin| (coin.object_ref, coin.balance))
+            .collect()
+    }
+
+    /// The number of coins currently leased.
+    pub fn num_leased(&self) -> usize {
+        self.inner.lock().leased.len()
+    }
+
+    /// Leases the coin with the lowest balance covering `budget`, waiting for
+    /// one to be released if they are all leased.
+    pub async fn acquire(&self, budget: u64) -> anyhow::Result<GasLease> {
+        loop {
+            let released = self.inner.released.notified();
+            let next_recovery = {
+                let mut state = self.inner.lock();
+                if let Some(coin) = self.inner.take(&mut state, budget)? {
+                    let snapshot = self.inner.snapshot_leases(&mut state);
+                    drop(state);
+                    self.inner.save_leases(snapshot);
+                    return Ok(GasLease {
+                        inner: self.inner.clone(),
+                        coin,
+                        released: false,
+                    });
+                }
+                state.recovering.values().min().copied()
+            };
+
+            if self.recover_due_coins().await? > 0 {
+                continue;
+            }
+            match next_recovery {
+                Some(recover_at_ms) => {
+                    let wait = Duration::from_millis(recover_at_ms.saturating_sub(now_ms()));
+                    tokio::select! {
+                        _ = released => {}
+                        _ = tokio::time::sleep(wait) => {}
+                    }
+                }
+                None => released.await,
+            }
+        }
+    }
+
+    /// Returns the recovered coins to the pool, merges the dust coins together
+    /// and splits new coins until the pool holds `size` of them.
+    ///
+    /// Runs when the pool is created, and should then be called periodically.
+    pub async fn maintain(&self) -> anyhow::Result<()> {
+        self.recover_due_coins().await?;
+
+        let config = &self.inner.config;
+        let (payment, balance, splits, snapshot) = {
+            let mut state = self.inner.lock();
+            state
+                .idle
+                .sort_by_key(|coin| std::cmp::Reverse(coin.balance));
+            let Some(primary) = state.idle.first().copied() else {
+                return Ok(());
+            };
+            let (others, dust): (Vec<_>, Vec<_>) = state.idle[1..]
+                .iter()
+                .partition(|coin| coin.balance >= config.dust_threshold);
+            let dust = dust
+                .into_iter()
+                .take(MAX_MERGED_COINS - 1)
+                .copied()
+                .collect::<Vec<_>>();
+
+            let held = 1 + others.len() + state.leased.len() + state.recovering.len();
+            let balance = primary.balance + dust.iter().map(|coin| coin.balance).sum::<u64>();
+            let available = balance
+                .saturating_sub(config.maintenance_gas_budget + config.dust_threshold)
+                / config.coin_balance;
+            let splits = config
+                .size
+                .saturating_sub(held)
+                .min(available as usize)
+                .min(MAX_SPLIT_COINS);
+            if dust.is_empty() && splits == 0 {
+                return Ok(());
+            }
+
+            // lease the coins spent by the maintenance transaction, so that they
+            // are recovered if it is interrupted
+            let payment = std::iter::once(primary).chain(dust).collect::<Vec<_>>();
+            let recover_at_ms = now_ms() + config.lease_timeout.as_millis() as u64;
+            state
+                .idle
+                .retain(|coin| !payment.iter().any(|p| p.object_ref.0 == coin.object_ref.0));
+            for coin in &payment {
+                state
+                    .leased
+                    .insert(coin.object_ref.0, (*coin, recover_at_ms));
+            }
+            (
+                payment,
+                balance,
+                splits,
+                self.inner.snapshot_leases(&mut state),
+            )
+        };
+        self.inner.save_leases(snapshot);
+
+        let effects = match self.rebalance(&payment, splits).await {
+            Ok(effects) => effects,
+            Err(e) => {
+                let snapshot = {
+                    let mut state = self.inner.lock();
+                    for coin in &payment {
+                        if let Some((_, recover_at_ms)) = state.leased.remove(&coin.object_ref.0) {
+                            state.recovering.insert(coin.object_ref.0, recover_at_ms);
+                        }
+                    }
+                    self.inner.snapshot_leases(&mut state)
+                };
+                self.inner.save_leases(snapshot);
+                return Err(e);
+            }
+        };
+
+        let created = effects
+            .created()
+            .iter()
+            .filter(|o| o.owner == Owner::AddressOwner(self.inner.address))
+            .map(|o| PooledCoin {
+                object_ref: o.reference.to_object_ref(),
+                balance: config.coin_balance,
+            })
+            .collect::<Vec<_>>();
+        let primary = PooledCoin {
+            object_ref: effects.gas_object().reference.to_object_ref(),
+            balance: subtract_gas(
+                balance - created.len() as u64 * config.coin_balance,
+                &effects,
+            ),
+        };
+        let snapshot = {
+            let mut state = self.inner.lock();
+            for coin in &payment {
+                state.leased.remove(&coin.object_ref.0);
+            }
+            state.idle.push(primary);
+            state.idle.extend(created);
+            self.inner.snapshot_leases(&mut state)
+        };
+        self.inner.save_leases(snapshot);
+        self.inner.released.notify_waiters();
+
+        if let IotaExecutionStatus::Failure { error } = effects.status() {
+            bail!("gas pool maintenance transaction failed: {error}");
+        }
+        Ok(())
+    }
+
+    /// Sends a transaction paid with `payment`, merging its coins into the
+    /// first one, which `splits` new coins are then split from.
+    async fn rebalance(
+        &self,
+        payment: &[PooledCoin],
+        splits: usize,
+    ) -> anyhow::Result<IotaTransactionBlockEffects> {
+        let Inner {
+            client,
+            signer,
+            address,
+            config,
+            ..
+        } = &*self.inner;
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        if splits > 0 {
+            builder.pay_iota(vec![*address; splits], vec![config.coin_balance; splits])?;
+        } else {
+            builder.pay_all_iota(*address);
+        }
+        let gas_price = client.governance_api().get_reference_gas_price().await?;
+        let data = TransactionData::new_programmable(
+            *address,
+            payment.iter().map(|coin| coin.object_ref).collect(),
+            builder.finish(),
+            config.maintenance_gas_budget,
+            gas_price,
+        );
+        let response = client
+            .quorum_driver_api()
+            .execute_transaction_block(
+                signer.sign(data)?,
+                IotaTransactionBlockResponseOptions::new().with_effects(),
+                ExecuteTransactionRequestType::WaitForLocalExecution,
+            )
+            .await?;
+        response
+            .effects
+            .ok_or_else(|| anyhow!("missing effects of the gas pool maintenance transaction"))
+    }
+
+    /// Fetches the latest version of the coins whose recovery time has passed
+    /// and returns them to the pool. Returns the number of recovered coins.
+    async fn recover_due_coins(&self) -> anyhow::Result<usize> {
+        let due = {
+            let mut state = self.inner.lock();
+            let now = now_ms();
+            let due = state
+                .recovering
+                .iter()
+                .filter(|(_, recover_at_ms)| **recover_at_ms <= now)
+                .map(|(object_id, _)| *object_id)
+                .collect::<Vec<_>>();
+            for object_id in &due {
+                state.recovering.remove(object_id);
+            }
+            due
+        };
+        if due.is_empty() {
+            return Ok(0);
+        }
+
+        let coins = match self.fetch_coins(&due).await {
+            Ok(coins) => coins,
+            Err(e) => {
+                let mut state = self.inner.lock();
+                state
+                    .recovering
+                    .extend(due.into_iter().map(|object_id| (object_id, 0)));
+                return Err(e);
+            }
+        };
+        let recovered = coins.len();
+        let snapshot = {
+            let mut state = self.inner.lock();
+            state.idle.extend(coins);
+            self.inner.snapshot_leases(&mut state)
+        };
+        self.inner.save_leases(snapshot);
+        self.inner.released.notify_waiters();
+        Ok(recovered)
+    }
+
+    /// Fetches the latest version of the given coins, skipping those which no
+    /// longer belong to the pool's address.
+    async fn fetch_coins(&self, object_ids: &[ObjectID]) -> anyhow::Result<Vec<PooledCoin>> {
+        let mut coins = Vec::with_capacity(object_ids.len());
+        for chunk in object_ids.chunks(*QUERY_MAX_RESULT_LIMIT) {
+            let responses = self
+                .inner
+                .client
+                .read_api()
+                .multi_get_object_with_options(
+                    chunk.to_vec(),
+                    IotaObjectDataOptions::full_content(),
+                )
+                .await?;
+            for object in responses.into_iter().filter_map(|response| response.data) {
+                if object.owner != Some(Owner::AddressOwner(self.inner.address)) {
+                    continue;
+                }
+                coins.push(PooledCoin {
+                    object_ref: object.object_ref(),
+                    balance: GasCoin::try_from(&object)?.value(),
+                });
+            }
+        }
+        Ok(coins)
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state
+            .lock()
+            .expect("gas pool state lock should not be poisoned")
+    }
+
+    /// Takes the idle coin with the lowest balance covering `budget`. Fails if
+    /// no coin of the pool, leased or not, could ever cover it. The caller
+    /// saves the leases.
+    fn take(&self, state: &mut PoolState, budget: u64) -> anyhow::Result<Option<PooledCoin>> {
+        let best_fit = state
+            .idle
+            .iter()
+            .enumerate()
+            .filter(|(_, coin)| coin.balance >= budget)
+            .min_by_key(|(_, coin)| coin.balance)
+            .map(|(index, _)| index);
+        let Some(index) = best_fit else {
+            ensure!(
+                !state.recovering.is_empty()
+                    || state
+                        .leased
+                        .values()
+                        .any(|(coin, _)| coin.balance >= budget),
+                "no coin of the gas pool holds the budget {budget}"
+            );
+            return Ok(None);
+        };
+
+        let coin = state.idle.swap_remove(index);
+        let recover_at_ms = now_ms() + self.config.lease_timeout.as_millis() as u64;
+        state
+            .leased
+            .insert(coin.object_ref.0, (coin, recover_at_ms));
+        Ok(Some(coin))
+    }
+
+    /// Returns a leased coin to the pool.
+    fn give_back(&self, object_id: ObjectID, coin: PooledCoin) {
+        let snapshot = {
+            let mut state = self.lock();
+            state.leased.remove(&object_id);
+            state.idle.push(coin);
+            self.snapshot_leases(&mut state)
+        };
+        self.save_leases(snapshot);
+        self.released.notify_waiters();
+    }
+
+    /// Recovers a leased coin whose latest version is unknown once its lease
+    /// times out.
+    fn recover_later(&self, object_id: ObjectID) {
+        let snapshot = {
+            let mut state = self.lock();
+            if let Some((_, recover_at_ms)) = state.leased.remove(&object_id) {
+                state.recovering.insert(object_id, recover_at_ms);
+            }
+            self.snapshot_leases(&mut state)
+        };
+        self.save_leases(snapshot);
+    }
+
+    /// Snapshots the leases to be saved with [`Inner::save_leases`] once the
+    /// state is unlocked, so that the file is not written while holding the
+    /// state lock. Returns `None` if the pool has no lease file.
+    fn snapshot_leases(&self, state: &mut PoolState) -> Option<LeaseSnapshot> {
+        if self.config.lease_file.is_none() {
+            return None;
+        }
+        state.lease_generation += 1;
+        let records = state
+            .leased
+            .iter()
+            .map(|(object_id, (_, recover_at_ms))| (object_id, recover_at_ms))
+            .chain(state.recovering.iter())
+            .map(|(object_id, recover_at_ms)| LeaseRecord {
+                object_id: *object_id,
+                recover_at_ms: *recover_at_ms,
+            })
+            .collect();
+        Some(LeaseSnapshot {
+            generation: state.lease_generation,
+            records,
+        })
+    }
+
+    /// Writes a snapshot of the leases to the lease file, unless a more recent
+    /// one was already written by another thread.
+    fn save_leases(&self, snapshot: Option<LeaseSnapshot>) {
+        let (Some(path), Some(snapshot)) = (&self.config.lease_file, snapshot) else {
+            return;
+        };
+        let mut written_generation = self
+            .lease_file
+            .lock()
+            .expect("gas pool lease file lock should not be poisoned");
+        if *written_generation >= snapshot.generation {
+            return;
+        }
+        match write_leases(path, &snapshot.records) {
+            Ok(()) => *written_generation = snapshot.generation,
+            Err(e) => warn!(
+                "Failed to save the gas pool leases to {}: {e}",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// An exclusive lease of a coin of a [`GasPool`], to pay the gas of a single
+/// transaction.
+///
+/// The lease must be given back with [`GasLease::release`] once the
+/// transaction is executed. A lease dropped without being released is assumed
+/// to have paid for a transaction in flight: its coin returns to the pool with
+/// its latest version once the lease times out.
+pub struct GasLease {
+    inner: Arc<Inner>,
+    coin: PooledCoin,
+    released: bool,
+}
+
+impl GasLease {
+    /// The reference of the leased coin, to be used as gas payment.
+    pub fn object_ref(&self) -> ObjectRef {
+        self.coin.object_ref
+    }
+
+    /// The balance of the leased coin.
+    pub fn balance(&self) -> u64 {
+        self.coin.balance
+    }
+
+    /// Returns the coin to the pool with the version and balance it has after
+    /// the transaction of `effects`, which must have been paid with it. The
+    /// coin must only have paid for gas, its balance is lowered by the gas
+    /// used.
+    pub fn release(mut self, effects: &IotaTransactionBlockEffects) -> anyhow::Result<()> {
+        let object_id = self.coin.object_ref.0;
+        let gas_object = effects.gas_object().reference.to_object_ref();
+        ensure!(
+            gas_object.0 == object_id,
+            "the transaction {} was not paid with the leased coin {object_id}",
+            effects.transaction_digest()
+        );
+        let coin = PooledCoin {
+            object_ref: gas_object,
+            balance: subtract_gas(self.coin.balance, effects),
+        };
+        self.released = true;
+        self.inner.give_back(object_id, coin);
+        Ok(())
+    }
+
+    /// Returns the coin to the pool unchanged, when no transaction was sent
+    /// with it.
+    pub fn release_unused(mut self) {
+        self.released = true;
+        self.inner.give_back(self.coin.object_ref.0, self.coin);
+    }
+}
+
+impl Drop for GasLease {
+    fn drop(&mut self) {
+        if !self.released {
+            self.inner.recover_later(self.coin.object_ref.0);
+        }
+    }
+}
+
+fn subtract_gas(balance: u64, effects: &IotaTransactionBlockEffects) -> u64 {
+    let net_gas_usage = effects.gas_cost_summary().net_gas_usage();
+    u64::try_from(i128::from(balance) - i128::from(net_gas_usage)).unwrap_or_default()
+}
+
+fn load_leases(path: &Path) -> anyhow::Result<HashMap<ObjectID, u64>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    let records: Vec<LeaseRecord> = serde_json::from_slice(&std::fs::read(path)?)?;
+    Ok(records
+        .into_iter()
+        .map(|record| (record.object_id, record.recover_at_ms))
+        .collect())
+}
+
+/// Writes the lease records to a temporary file first, so that a crash never
+/// leaves a truncated lease file behind.
+fn write_leases(path: &Path, records: &[LeaseRecord]) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, serde_json::to_vec(records)?)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after the unix epoch")
+        .as_millis() as u64
+}
@@ -0,0 +1,493 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The HTTP transport of an [`IotaClient`](crate::IotaClient), connected
+//! either to a single fullnode or to several ones the requests fail over
+//! between.
+
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use iota_json_rpc_api::ReadApiClient;
+use jsonrpsee::{
+    core::{
+        ClientError,
+        client::{BatchResponse, ClientT},
+        params::BatchRequestBuilder,
+        traits::ToRpcParams,
+    },
+    http_client::HttpClient,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, value::RawValue};
+use tracing::warn;
+
+/// The HTTP client used by the APIs of an [`IotaClient`](crate::IotaClient).
+///
+/// It implements [`ClientT`], so that the RPC client traits of
+/// `iota_json_rpc_api` can be called on it whatever the number of endpoints.
+#[derive(Debug)]
+pub enum RpcHttpClient {
+    /// Client of a single endpoint.
+    Single(HttpClient),
+    /// Client failing over between several endpoints.
+    Failover(FailoverHttpClient),
+}
+
+impl RpcHttpClient {
+    /// Returns the client of the single endpoint, or of the first endpoint
+    /// when failing over between several ones.
+    pub fn primary(&self) -> &HttpClient {
+        match self {
+            Self::Single(client) => client,
+            Self::Failover(client) => &client.endpoints.endpoints[0].client,
+        }
+    }
+
+    /// Returns the client reading from several endpoints and comparing their
+    /// responses, if quorum reads are enabled.
+    pub fn quorum_reads(&self) -> Option<QuorumHttpClient<'_>> {
+        match self {
+            Self::Single(_) => None,
+            Self::Failover(client) => client.quorum_reads(),
+        }
+    }
+}
+
+#[async_trait]
+impl ClientT for RpcHttpClient {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), ClientError>
+    where
+        Params: ToRpcParams + Send,
+    {
+        match self {
+            Self::Single(client) => client.notification(method, params).await,
+            Self::Failover(client) => client.notification(method, params).await,
+        }
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        match self {
+            Self::Single(client) => client.request(method, params).await,
+            Self::Failover(client) => client.request(method, params).await,
+        }
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, ClientError>
+    where
+        R: DeserializeOwned + std::fmt::Debug + 'a,
+    {
+        match self {
+            Self::Single(client) => client.batch_request(batch).await,
+            Self::Failover(client) => client.batch_request(batch).await,
+        }
+    }
+}
+
+struct Endpoint {
+    url: String,
+    client: HttpClient,
+    healthy: AtomicBool,
+}
+
+impl Endpoint {
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    fn set_healthy(&self, healthy: bool) {
+        if self.healthy.swap(healthy, Ordering::Relaxed) && !healthy {
+            warn!("Endpoint {} is unhealthy", self.url);
+        }
+    }
+}
+
+struct Endpoints {
+    endpoints: Vec<Endpoint>,
+    next: AtomicUsize,
+    quorum: Option<usize>,
+}
+
+impl Endpoints {
+    /// Returns the endpoints in the order they are tried for the next request:
+    /// the healthy ones first, starting from the next one in round-robin
+    /// order, then the unhealthy ones as a last resort.
+    fn candidates(&self) -> Vec<&Endpoint> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let count = self.endpoints.len();
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = (0..count)
+            .map(|i| &self.endpoints[(start + i) % count])
+            .partition(|endpoint| endpoint.is_healthy());
+        healthy.into_iter().chain(unhealthy).collect()
+    }
+}
+
+/// An HTTP client sending each request to one of several endpoints in
+/// round-robin order, and failing over to the next endpoint when a request
+/// fails or times out.
+///
+/// Endpoints which fail are skipped until a background health check, or a
+/// request sent to them as a last resort, succeeds again.
+pub struct FailoverHttpClient {
+    endpoints: Arc<Endpoints>,
+}
+
+impl FailoverHttpClient {
+    pub(crate) fn new(
+        endpoints: Vec<(String, HttpClient)>,
+        quorum: Option<usize>,
+        health_check_interval: Duration,
+    ) -> Self {
+        let endpoints = Arc::new(Endpoints {
+            endpoints: endpoints
+                .into_iter()
+                .map(|(url, client)| Endpoint {
+                    url,
+                    client,
+                    healthy: AtomicBool::new(true),
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+            quorum,
+        });
+        tokio::spawn(check_health(
+            Arc::downgrade(&endpoints),
+            health_check_interval,
+        ));
+        Self { endpoints }
+    }
+
+    /// Returns the URLs of the endpoints, along with whether they are
+    /// currently considered healthy.
+    pub fn endpoints(&self) -> Vec<(&str, bool)> {
+        self.endpoints
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint.url.as_str(), endpoint.is_healthy()))
+            .collect()
+    }
+
+    /// Returns the client reading from several endpoints and comparing their
+    /// responses, if quorum reads are enabled.
+    pub fn quorum_reads(&self) -> Option<QuorumHttpClient<'_>> {
+        self.endpoints.quorum.map(|size| QuorumHttpClient {
+            endpoints: &self.endpoints,
+            size,
+        })
+    }
+}
+
+impl Debug for FailoverHttpClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FailoverHttpClient")
+            .field("endpoints", &self.endpoints())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ClientT for FailoverHttpClient {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), ClientError>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams(params.to_rpc_params()?);
+        let mut last_error = None;
+        for endpoint in self.endpoints.candidates() {
+            match endpoint.client.notification(method, params.clone()).await {
+                Ok(()) => {
+                    endpoint.set_healthy(true);
+                    return Ok(());
+                }
+                Err(e) if is_endpoint_failure(&e) => {
+                    endpoint.set_healthy(false);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("a failover client has at least one endpoint"))
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams(params.to_rpc_params()?);
+        let mut last_error = None;
+        for endpoint in self.endpoints.candidates() {
+            match endpoint.client.request(method, params.clone()).await {
+                Ok(response) => {
+                    endpoint.set_healthy(true);
+                    return Ok(response);
+                }
+                Err(e) if is_endpoint_failure(&e) => {
+                    warn!("Request {method} to {} failed: {e}", endpoint.url);
+                    endpoint.set_healthy(false);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("a failover client has at least one endpoint"))
+    }
+
+    /// Batches are sent to a single endpoint, without failing over.
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, ClientError>
+    where
+        R: DeserializeOwned + std::fmt::Debug + 'a,
+    {
+        let endpoint = self.endpoints.candidates()[0];
+        let result = endpoint.client.batch_request(batch).await;
+        if let Err(e) = &result {
+            if is_endpoint_failure(e) {
+                endpoint.set_healthy(false);
+            }
+        }
+        result
+    }
+}
+
+/// A client sending each request to several endpoints, and returning the
+/// response a strict majority of them agree on.
+///
+/// The endpoints disagreeing with the majority are considered unhealthy, as
+/// they are either lagging behind or misbehaving.
+pub struct QuorumHttpClient<'a> {
+    endpoints: &'a Endpoints,
+    size: usize,
+}
+
+#[async_trait]
+impl ClientT for QuorumHttpClient<'_> {
+    async fn notification<Params>(&self, method: &str, _params: Params) -> Result<(), ClientError>
+    where
+        Params: ToRpcParams + Send,
+    {
+        Err(ClientError::Custom(format!(
+            "quorum reads do not support the notification {method}"
+        )))
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams(params.to_rpc_params()?);
+        let mut candidates = self.endpoints.candidates().into_iter();
+        let mut responses = Vec::with_capacity(self.size);
+        let mut last_error = None;
+
+        // replace the endpoints which fail until enough of them responded
+        while responses.len() < self.size {
+            let endpoints = candidates
+                .by_ref()
+                .take(self.size - responses.len())
+                .collect::<Vec<_>>();
+            if endpoints.is_empty() {
+                break;
+            }
+            let results = join_all(
+                endpoints
+                    .iter()
+                    .map(|endpoint| endpoint.client.request::<Value, _>(method, params.clone())),
+            )
+            .await;
+            for (endpoint, result) in endpoints.into_iter().zip(results) {
+                match result {
+                    Ok(response) => {
+                        endpoint.set_healthy(true);
+                        responses.push((endpoint, response));
+                    }
+                    Err(e) if is_endpoint_failure(&e) => {
+                        warn!("Request {method} to {} failed: {e}", endpoint.url);
+                        endpoint.set_healthy(false);
+                        last_error = Some(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        if responses.len() < self.size {
+            return Err(last_error.expect("an endpoint failed to respond"));
+        }
+
+        let majority = responses
+            .iter()
+            .map(|(_, response)| response)
+            .find(|response| {
+                responses.iter().filter(|(_, r)| r == *response).count() * 2 > responses.len()
+            })
+            .cloned();
+        let Some(majority) = majority else {
+            return Err(ClientError::Custom(format!(
+                "no majority among the responses to {method} from {}",
+                responses
+                    .iter()
+                    .map(|(endpoint, _)| endpoint.url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        };
+        for (endpoint, response) in &responses {
+            if *response != majority {
+                warn!(
+                    "Endpoint {} disagrees with the majority on {method}",
+                    endpoint.url
+                );
+                endpoint.set_healthy(false);
+            }
+        }
+        serde_json::from_value(majority).map_err(ClientError::ParseError)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        _batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, ClientError>
+    where
+        R: DeserializeOwned + std::fmt::Debug + 'a,
+    {
+        Err(ClientError::Custom(
+            "quorum reads do not support batch requests".to_string(),
+        ))
+    }
+}
+
+/// Parameters serialized once, to be sent to several endpoints.
+#[derive(Clone)]
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Whether the error is caused by the endpoint rather than by the request, in
+/// which case the request is sent to another endpoint. Errors returned by the
+/// endpoint and responses not matching the expected type are not, as every
+/// endpoint would answer the same.
+fn is_endpoint_failure(error: &ClientError) -> bool {
+    !matches!(error, ClientError::Call(_) | ClientError::ParseError(_))
+}
+
+/// Periodically checks whether the endpoints respond, until the client is
+/// dropped. The endpoints are healthy until the first check.
+async fn check_health(endpoints: Weak<Endpoints>, health_check_interval: Duration) {
+    let mut interval = tokio::time::interval_at(
+        tokio::time::Instant::now() + health_check_interval,
+        health_check_interval,
+    );
+    loop {
+        interval.tick().await;
+        let Some(endpoints) = endpoints.upgrade() else {
+            return;
+        };
+        join_all(endpoints.endpoints.iter().map(|endpoint| async move {
+            let healthy = endpoint
+                .client
+                .get_latest_checkpoint_sequence_number()
+                .await
+                .is_ok();
+            endpoint.set_healthy(healthy);
+        }))
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonrpsee::{
+        RpcModule,
+        http_client::HttpClientBuilder,
+        rpc_params,
+        server::{Server, ServerHandle},
+    };
+
+    use super::*;
+
+    /// Starts a server answering `value` to the `answer` method.
+    async fn start_server(value: u64) -> (String, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        let mut module = RpcModule::new(());
+        module
+            .register_method("answer", move |_, _, _| value)
+            .unwrap();
+        (url, server.start(module))
+    }
+
+    /// Returns a client of servers answering the given values.
+    async fn failover_client(
+        values: &[u64],
+        quorum: Option<usize>,
+    ) -> (FailoverHttpClient, Vec<ServerHandle>) {
+        let mut endpoints = vec![];
+        let mut handles = vec![];
+        for value in values {
+            let (url, handle) = start_server(*value).await;
+            let client = HttpClientBuilder::default().build(&url).unwrap();
+            endpoints.push((url, client));
+            handles.push(handle);
+        }
+        let client = FailoverHttpClient::new(endpoints, quorum, Duration::from_secs(3600));
+        (client, handles)
+    }
+
+    fn healthy_endpoints(client: &FailoverHttpClient) -> Vec<bool> {
+        client
+            .endpoints()
+            .into_iter()
+            .map(|(_, healthy)| healthy)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_quorum_reads_majority() {
+        let (client, _handles) = failover_client(&[1, 2, 1], Some(3)).await;
+        let quorum = client.quorum_reads().unwrap();
+        let answer: u64 = quorum.request("answer", rpc_params![]).await.unwrap();
+        assert_eq!(answer, 1);
+        // the endpoint disagreeing with the majority is considered unhealthy
+        assert_eq!(healthy_endpoints(&client), vec![true, false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_quorum_reads_without_majority() {
+        let (client, _handles) = failover_client(&[1, 2], Some(2)).await;
+        let quorum = client.quorum_reads().unwrap();
+        let result = quorum.request::<u64, _>("answer", rpc_params![]).await;
+        assert!(matches!(result, Err(ClientError::Custom(_))));
+        // no endpoint can be blamed
+        assert_eq!(healthy_endpoints(&client), vec![true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_does_not_fail_over() {
+        let (client, _handles) = failover_client(&[1, 1], None).await;
+        let result = client.request::<String, _>("answer", rpc_params![]).await;
+        assert!(matches!(result, Err(ClientError::ParseError(_))));
+        assert_eq!(healthy_endpoints(&client), vec![true, true]);
+    }
+}
@@ -75,6 +75,8 @@
 
 pub mod apis;
 pub mod error;
+pub mod gas_pool;
+pub mod http_client;
 pub mod iota_client_config;
 pub mod json_rpc_error;
 pub mod wallet_context;
@@ -112,6 +114,7 @@ use serde_json::Value;
 use crate::{
     apis::{CoinReadApi, EventApi, GovernanceApi, QuorumDriverApi, ReadApi},
     error::{Error, IotaRpcResult},
+    http_client::{FailoverHttpClient, RpcHttpClient},
 };
 
 pub const IOTA_COIN_TYPE: &str = "0x2::iota::IOTA";
@@ -150,6 +153,8 @@ pub struct IotaClientBuilder {
     ws_url: Option<String>,
     ws_ping_interval: Option<Duration>,
     basic_auth: Option<(String, String)>,
+    health_check_interval: Duration,
+    quorum_reads: Option<usize>,
 }
 
 impl Default for IotaClientBuilder {
@@ -160,6 +165,8 @@ impl Default for IotaClientBuilder {
             ws_url: None,
             ws_ping_interval: None,
             basic_auth: None,
+            health_check_interval: Duration::from_secs(10),
+            quorum_reads: None,
         }
     }
 }
@@ -195,6 +202,22 @@ impl IotaClientBuilder {
         self
     }
 
+    /// Set the interval at which the endpoints of a client built with
+    /// [`Self::build_with_failover()`] are checked for health.
+    pub fn health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Make the clients built with [`Self::build_with_failover()`] read
+    /// objects from `endpoints` endpoints at once with
+    /// [`ReadApi::get_object_with_options()`], and only return the response a
+    /// strict majority of them agree on.
+    pub fn quorum_reads(mut self, endpoints: usize) -> Self {
+        self.quorum_reads = Some(endpoints);
+        self
+    }
+
     /// Return an [IotaClient] object connected to the Iota network accessible
     /// via the provided URI.
     ///
@@ -214,10 +237,69 @@ impl IotaClientBuilder {
     /// }
     /// ```
     pub async fn build(self, http: impl AsRef<str>) -> IotaRpcResult<IotaClient> {
-        if CryptoProvider::get_default().is_none() {
-            ring::default_provider().install_default().ok();
+        install_crypto_provider();
+        let headers = self.headers();
+        let http = RpcHttpClient::Single(self.http_client(http, &headers)?);
+        self.build_with_http(http, headers).await
+    }
+
+    /// Return an [IotaClient] object connected to several fullnodes of the
+    /// Iota network, accessible via the provided URIs.
+    ///
+    /// Requests are sent to the endpoints in round-robin order, and fail over
+    /// to the next endpoint on errors or timeouts. Failing endpoints are
+    /// skipped until they pass a health check again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use iota_sdk::IotaClientBuilder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anyhow::Error> {
+    ///     let iota = IotaClientBuilder::default()
+    ///         .quorum_reads(2)
+    ///         .build_with_failover(["http://127.0.0.1:9000", "http://127.0.0.1:9001"])
+    ///         .await?;
+    ///
+    ///     println!("Iota local version: {:?}", iota.api_version());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn build_with_failover(
+        self,
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> IotaRpcResult<IotaClient> {
+        install_crypto_provider();
+        let headers = self.headers();
+        let endpoints = urls
+            .into_iter()
+            .map(|url| {
+                let client = self.http_client(&url, &headers)?;
+                Ok((url.as_ref().to_string(), client))
+            })
+            .collect::<IotaRpcResult<Vec<_>>>()?;
+        if endpoints.is_empty() {
+            return Err(Error::Data("At least one endpoint is required.".into()));
+        }
+        if let Some(quorum) = self.quorum_reads {
+            if quorum == 0 || quorum > endpoints.len() {
+                return Err(Error::Data(format!(
+                    "Quorum reads need between 1 and {} endpoints, got {quorum}.",
+                    endpoints.len()
+                )));
+            }
         }
 
+        let http = RpcHttpClient::Failover(FailoverHttpClient::new(
+            endpoints,
+            self.quorum_reads,
+            self.health_check_interval,
+        ));
+        self.build_with_http(http, headers).await
+    }
+
+    fn headers(&self) -> HeaderMap {
         let client_version = env!("CARGO_PKG_VERSION");
         let mut headers = HeaderMap::new();
         headers.insert(
@@ -231,7 +313,7 @@ impl IotaClientBuilder {
         );
         headers.insert(CLIENT_SDK_TYPE_HEADER, HeaderValue::from_static("rust"));
 
-        if let Some((username, password)) = self.basic_auth {
+        if let Some((username, password)) = &self.basic_auth {
             let auth = base64::engine::general_purpose::STANDARD
                 .encode(format!("{}:{}", username, password));
             headers.insert(
@@ -240,7 +322,22 @@ impl IotaClientBuilder {
                 HeaderValue::from_str(&format!("Basic {}", auth)).unwrap(),
             );
         }
+        headers
+    }
+
+    fn http_client(&self, url: impl AsRef<str>, headers: &HeaderMap) -> IotaRpcResult<HttpClient> {
+        Ok(HttpClientBuilder::default()
+            .max_request_size(2 << 30)
+            .set_headers(headers.clone())
+            .request_timeout(self.request_timeout)
+            .build(url)?)
+    }
 
+    async fn build_with_http(
+        self,
+        http: RpcHttpClient,
+        headers: HeaderMap,
+    ) -> IotaRpcResult<IotaClient> {
         let ws = if let Some(url) = self.ws_url {
             let mut builder = WsClientBuilder::default()
                 .max_request_size(2 << 30)
@@ -257,12 +354,6 @@ impl IotaClientBuilder {
             None
         };
 
-        let http = HttpClientBuilder::default()
-            .max_request_size(2 << 30)
-            .set_headers(headers.clone())
-            .request_timeout(self.request_timeout)
-            .build(http)?;
-
         let info = Self::get_server_info(&http, &ws).await?;
 
         let rpc = RpcClient { http, ws, info };
@@ -356,7 +447,7 @@ impl IotaClientBuilder {
     ///
     /// Fails with an error if it cannot call the RPC discover.
     async fn get_server_info(
-        http: &HttpClient,
+        http: &RpcHttpClient,
         ws: &Option<WsClient>,
     ) -> Result<ServerInfo, Error> {
         let rpc_spec: Value = http.request("rpc.discover", rpc_params![]).await?;
@@ -399,6 +490,12 @@ impl IotaClientBuilder {
     }
 }
 
+fn install_crypto_provider() {
+    if CryptoProvider::get_default().is_none() {
+        ring::default_provider().install_default().ok();
+    }
+}
+
 /// Provides all the necessary abstractions for interacting with the Iota
 /// network.
 ///
@@ -446,7 +543,7 @@ pub struct IotaClient {
 }
 
 pub(crate) struct RpcClient {
-    http: HttpClient,
+    http: RpcHttpClient,
     ws: Option<WsClient>,
     info: ServerInfo,
 }
@@ -536,7 +633,17 @@ impl IotaClient {
     }
 
     /// Return a reference to the underlying http client.
+    ///
+    /// If the client was built with several endpoints, this is the client of
+    /// the first one, whose requests do not fail over. See
+    /// [`Self::rpc_http`] for the client used by the APIs.
     pub fn http(&self) -> &HttpClient {
+        self.api.http.primary()
+    }
+
+    /// Return a reference to the http client used by the APIs, which fails
+    /// over between the endpoints if the client was built with several ones.
+    pub fn rpc_http(&self) -> &RpcHttpClient {
         &self.api.http
     }
 
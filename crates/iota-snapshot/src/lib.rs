@@ -65,7 +65,16 @@ use tokio::time::Instant;
 /// references for hash. There is one single ref file per hash bucket. Object
 /// references are written in an append-only manner as well. Finally, the
 /// MANIFEST file contains per file metadata of every file in the snapshot
-/// directory. State Snapshot Directory Layout
+/// directory.
+///
+/// Besides full snapshots, the writer can produce delta snapshots which only
+/// hold the objects created or mutated since a parent snapshot, plus
+/// *.del files listing the object references of the parent's live object set
+/// that are no longer live (i.e. deleted, wrapped or superseded by a newer
+/// version). *.del files use the REFERENCE file disk format. The MANIFEST of a
+/// delta snapshot records its parent epoch and the epoch of the full snapshot
+/// at the root of the chain, so a restore applies the base snapshot followed
+/// by every delta in the chain. State Snapshot Directory Layout
 ///  - snapshot/
 ///     - epoch_0/
 ///        - 1_1.obj
@@ -82,6 +91,11 @@ use tokio::time::Instant;
 ///     - epoch_1/
 ///       - 1_1.obj
 ///       - ...
+///     - epoch_2/ (delta of epoch_1)
+///       - 1_1.obj
+///       - 1_1.ref
+///       - 1_1.del
+///       - MANIFEST
 ///
 /// Object File Disk Format
 /// ┌──────────────────────────────┐
@@ -153,6 +167,7 @@ const FILE_METADATA_BYTES: usize =
 pub enum FileType {
     Object = 0,
     Reference,
+    Deleted,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -174,6 +189,9 @@ impl FileMetadata {
             FileType::Reference => {
                 dir_path.child(&*format!("{}_{}.ref", self.bucket_num, self.part_num))
             }
+            FileType::Deleted => {
+                dir_path.child(&*format!("{}_{}.del", self.bucket_num, self.part_num))
+            }
         }
     }
     pub fn local_file_path(&self, root_path: &std::path::Path, dir_path: &Path) -> Result<PathBuf> {
@@ -189,30 +207,64 @@ pub struct ManifestV1 {
     pub epoch: u64,
 }
 
+/// ManifestV2 describes a delta snapshot, which only holds the changes to the
+/// live object set since the snapshot of `parent_epoch`.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ManifestV2 {
+    pub snapshot_version: u8,
+    pub address_length: u64,
+    pub file_metadata: Vec<FileMetadata>,
+    pub epoch: u64,
+    /// Epoch of the snapshot this delta is applied on top of.
+    pub parent_epoch: u64,
+    /// Epoch of the full snapshot at the root of the delta chain.
+    pub base_epoch: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Manifest {
     V1(ManifestV1),
+    V2(ManifestV2),
 }
 
 impl Manifest {
     pub fn snapshot_version(&self) -> u8 {
         match self {
             Self::V1(manifest) => manifest.snapshot_version,
+            Self::V2(manifest) => manifest.snapshot_version,
         }
     }
     pub fn address_length(&self) -> u64 {
         match self {
             Self::V1(manifest) => manifest.address_length,
+            Self::V2(manifest) => manifest.address_length,
         }
     }
     pub fn file_metadata(&self) -> &Vec<FileMetadata> {
         match self {
             Self::V1(manifest) => &manifest.file_metadata,
+            Self::V2(manifest) => &manifest.file_metadata,
         }
     }
     pub fn epoch(&self) -> u64 {
         match self {
             Self::V1(manifest) => manifest.epoch,
+            Self::V2(manifest) => manifest.epoch,
+        }
+    }
+    /// Returns the epoch of the snapshot this one is a delta of, or `None` for
+    /// a full snapshot.
+    pub fn parent_epoch(&self) -> Option<u64> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(manifest) => Some(manifest.parent_epoch),
+        }
+    }
+    /// Returns the epoch of the full snapshot at the root of the delta chain.
+    pub fn base_epoch(&self) -> u64 {
+        match self {
+            Self::V1(manifest) => manifest.epoch,
+            Self::V2(manifest) => manifest.base_epoch,
         }
     }
 }
@@ -64,6 +64,9 @@ pub struct StateSnapshotReaderV1 {
     local_object_store: Arc<dyn ObjectStorePutExt>,
     ref_files: BTreeMap<u32, BTreeMap<u32, FileMetadata>>,
     object_files: BTreeMap<u32, BTreeMap<u32, FileMetadata>>,
+    deleted_files: BTreeMap<u32, BTreeMap<u32, FileMetadata>>,
+    parent_epoch: Option<u64>,
+    num_deleted_objects: u64,
     indirect_objects_threshold: usize,
     multi_progress_bar: MultiProgress,
     concurrency: usize,
@@ -125,10 +128,11 @@ impl StateSnapshotReaderV1 {
         if manifest.epoch() != epoch {
             return Err(anyhow!("Download manifest is not for epoch: {}", epoch,));
         }
-        // Stores the objects and references FileMetadata in MANIFEST to the local
-        // directory
+        // Stores the objects, references and deleted references FileMetadata in
+        // MANIFEST to the local directory
         let mut object_files = BTreeMap::new();
         let mut ref_files = BTreeMap::new();
+        let mut deleted_files = BTreeMap::new();
         for file_metadata in manifest.file_metadata() {
             match file_metadata.file_type {
                 FileType::Object => {
@@ -149,12 +153,25 @@ impl StateSnapshotReaderV1 {
                     // Inserts the reference FileMetadata with the partition number to the bucket.
                     entry.insert(file_metadata.part_num, file_metadata.clone());
                 }
+                FileType::Deleted => {
+                    let entry = deleted_files
+                        .entry(file_metadata.bucket_num)
+                        .or_insert_with(BTreeMap::new);
+                    entry.insert(file_metadata.part_num, file_metadata.clone());
+                }
             }
         }
+        if manifest.parent_epoch().is_none() && !deleted_files.is_empty() {
+            return Err(anyhow!(
+                "Full snapshot manifest for epoch {} lists deleted objects",
+                epoch
+            ));
+        }
         let epoch_dir_path = Path::from(epoch_dir);
-        // Collects the path of all reference files
+        // Collects the path of all reference and deleted reference files
         let files: Vec<Path> = ref_files
             .values()
+            .chain(deleted_files.values())
             .flat_map(|entry| {
                 let files: Vec<_> = entry
                     .values()
@@ -191,12 +208,53 @@ impl StateSnapshotReaderV1 {
             local_object_store,
             ref_files,
             object_files,
+            deleted_files,
+            parent_epoch: manifest.parent_epoch(),
+            num_deleted_objects: 0,
             indirect_objects_threshold,
             multi_progress_bar,
             concurrency: download_concurrency.get(),
         })
     }
 
+    /// Creates readers for the snapshot of `epoch` and, if it is a delta
+    /// snapshot, for every snapshot of its delta chain. Readers are ordered
+    /// from the full base snapshot to the snapshot of `epoch`, which is the
+    /// order in which they must be read.
+    pub async fn new_chain(
+        epoch: u64,
+        remote_store_config: &ObjectStoreConfig,
+        local_store_config: &ObjectStoreConfig,
+        indirect_objects_threshold: usize,
+        download_concurrency: NonZeroUsize,
+        multi_progress_bar: MultiProgress,
+    ) -> Result<Vec<Self>> {
+        let mut readers = vec![];
+        let mut next_epoch = Some(epoch);
+        while let Some(epoch) = next_epoch {
+            let reader = Self::new(
+                epoch,
+                remote_store_config,
+                local_store_config,
+                indirect_objects_threshold,
+                download_concurrency,
+                multi_progress_bar.clone(),
+            )
+            .await?;
+            next_epoch = reader.parent_epoch();
+            if next_epoch.is_some_and(|parent_epoch| parent_epoch >= epoch) {
+                return Err(anyhow!(
+                    "Snapshot of epoch {} has invalid parent epoch {:?}",
+                    epoch,
+                    next_epoch
+                ));
+            }
+            readers.push(reader);
+        }
+        readers.reverse();
+        Ok(readers)
+    }
+
     pub async fn read(
         &mut self,
         perpetual_db: &AuthorityPerpetualTables,
@@ -266,6 +324,7 @@ impl StateSnapshotReaderV1 {
         }
         checksum_progress_bar.finish_with_message("Checksumming complete");
 
+        let deleted_sender = sender.clone();
         let accum_handle =
             sender.map(|sender| self.spawn_accumulation_tasks(sender, num_part_files));
 
@@ -277,9 +336,56 @@ impl StateSnapshotReaderV1 {
         if let Some(handle) = accum_handle {
             handle.await?;
         }
+
+        // Removes the objects which are no longer live since the parent snapshot
+        let deleted_acc = self.remove_deleted_objects(perpetual_db)?;
+        if let Some(sender) = deleted_sender {
+            sender.send((deleted_acc, 0)).await?;
+        }
         Ok(())
     }
 
+    /// Removes the objects listed in the *.del files of a delta snapshot from
+    /// the AuthorityPerpetualTables, and returns an accumulator from which
+    /// their digests have been removed.
+    fn remove_deleted_objects(
+        &mut self,
+        perpetual_db: &AuthorityPerpetualTables,
+    ) -> Result<Accumulator> {
+        let mut acc = Accumulator::default();
+        for part_files in self.deleted_files.values() {
+            for file_metadata in part_files.values() {
+                let object_refs: Vec<ObjectRef> = ObjectRefIter::new(
+                    file_metadata,
+                    self.local_staging_dir_root.clone(),
+                    self.epoch_dir(),
+                )?
+                .collect();
+                acc.remove_all(object_refs.iter().map(|object_ref| object_ref.2));
+                self.num_deleted_objects += object_refs.len() as u64;
+                AuthorityStore::bulk_remove_live_objects(perpetual_db, object_refs.into_iter())?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Returns the epoch of the snapshot.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the epoch of the snapshot this one is a delta of, or `None` for
+    /// a full snapshot.
+    pub fn parent_epoch(&self) -> Option<u64> {
+        self.parent_epoch
+    }
+
+    /// Returns the number of objects of the parent snapshot which were removed
+    /// while reading this delta snapshot.
+    pub fn num_deleted_objects(&self) -> u64 {
+        self.num_deleted_objects
+    }
+
     /// Spawns accumulation tasks to accumulate the sha3 digests of all objects
     /// then sends the accumulator to the sender.
     fn spawn_accumulation_tasks(
@@ -544,7 +650,7 @@ impl StateSnapshotReaderV1 {
 
     /// Reads the MANIFEST file, verifies it with the checksum, and returns the
     /// Manifest.
-    fn read_manifest(path: PathBuf) -> anyhow::Result<Manifest> {
+    pub(crate) fn read_manifest(path: PathBuf) -> anyhow::Result<Manifest> {
         let manifest_file = File::open(path)?;
         let manifest_file_size = manifest_file.metadata()?.len() as usize;
         let mut manifest_reader = BufReader::new(manifest_file);
@@ -9,11 +9,13 @@ use futures::future::AbortHandle;
 use indicatif::MultiProgress;
 use iota_config::object_storage_config::{ObjectStoreConfig, ObjectStoreType};
 use iota_core::{
-    authority::authority_store_tables::AuthorityPerpetualTables,
+    authority::{AuthorityStore, authority_store_tables::AuthorityPerpetualTables},
     state_accumulator::StateAccumulator,
 };
 use iota_types::{
-    accumulator::Accumulator, base_types::ObjectID, messages_checkpoint::ECMHLiveObjectSetDigest,
+    accumulator::Accumulator,
+    base_types::{IotaAddress, ObjectID, SequenceNumber},
+    messages_checkpoint::ECMHLiveObjectSetDigest,
     object::Object,
 };
 use tempfile::tempdir;
@@ -169,3 +171,132 @@ async fn test_snapshot_empty_db() -> Result<(), anyhow::Error> {
     compare_live_objects(&perpetual_db, &restored_perpetual_db)?;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_snapshot_delta_chain() -> Result<(), anyhow::Error> {
+    let db_path = temp_dir();
+    let restored_db_path = temp_dir();
+    let local = temp_dir().join("local_dir");
+    let remote = temp_dir().join("remote_dir");
+    let restored_local = temp_dir().join("local_dir_restore");
+    let local_store_config = ObjectStoreConfig {
+        object_store: Some(ObjectStoreType::File),
+        directory: Some(local),
+        ..Default::default()
+    };
+    let remote_store_config = ObjectStoreConfig {
+        object_store: Some(ObjectStoreType::File),
+        directory: Some(remote),
+        ..Default::default()
+    };
+    let perpetual_db = Arc::new(AuthorityPerpetualTables::open(&db_path, None));
+    insert_keys(&perpetual_db, 1000)?;
+    let ids = ObjectID::in_range(ObjectID::ZERO, 1200)?;
+
+    // Full base snapshot of epoch 0
+    let root_accumulator =
+        ECMHLiveObjectSetDigest::from(accumulate_live_object_set(&perpetual_db).digest());
+    StateSnapshotWriterV1::new(
+        &local_store_config,
+        &remote_store_config,
+        FileCompression::Zstd,
+        NonZeroUsize::new(1).unwrap(),
+    )
+    .await?
+    .write_internal(0, perpetual_db.clone(), root_accumulator)
+    .await?;
+
+    // Delta snapshot of epoch 1: mutates, deletes and creates objects
+    for id in &ids[0..100] {
+        perpetual_db.insert_object_test_only(Object::with_id_owner_version_for_testing(
+            *id,
+            SequenceNumber::from_u64(2),
+            IotaAddress::ZERO,
+        ))?;
+    }
+    let deleted_refs: Vec<_> = perpetual_db
+        .iter_live_object_set()
+        .filter(|live_object| ids[100..200].contains(&live_object.object_id()))
+        .map(|live_object| live_object.object_reference())
+        .collect();
+    AuthorityStore::bulk_remove_live_objects(&perpetual_db, deleted_refs.into_iter())?;
+    for id in &ids[1000..1100] {
+        perpetual_db.insert_object_test_only(Object::immutable_with_id_for_testing(*id))?;
+    }
+    let root_accumulator =
+        ECMHLiveObjectSetDigest::from(accumulate_live_object_set(&perpetual_db).digest());
+    StateSnapshotWriterV1::new(
+        &local_store_config,
+        &remote_store_config,
+        FileCompression::Zstd,
+        NonZeroUsize::new(1).unwrap(),
+    )
+    .await?
+    .write_delta_internal(1, 0, perpetual_db.clone(), root_accumulator)
+    .await?;
+
+    // Delta snapshot of epoch 2: mutates objects of both previous snapshots again
+    for id in ids[50..150].iter().chain(&ids[1050..1100]) {
+        perpetual_db.insert_object_test_only(Object::with_id_owner_version_for_testing(
+            *id,
+            SequenceNumber::from_u64(3),
+            IotaAddress::ZERO,
+        ))?;
+    }
+    let root_accumulator =
+        ECMHLiveObjectSetDigest::from(accumulate_live_object_set(&perpetual_db).digest());
+    StateSnapshotWriterV1::new(
+        &local_store_config,
+        &remote_store_config,
+        FileCompression::Zstd,
+        NonZeroUsize::new(1).unwrap(),
+    )
+    .await?
+    .write_delta_internal(2, 1, perpetual_db.clone(), root_accumulator)
+    .await?;
+
+    let local_store_restore_config = ObjectStoreConfig {
+        object_store: Some(ObjectStoreType::File),
+        directory: Some(restored_local),
+        ..Default::default()
+    };
+    let readers = StateSnapshotReaderV1::new_chain(
+        2,
+        &remote_store_config,
+        &local_store_restore_config,
+        usize::MAX,
+        NonZeroUsize::new(1).unwrap(),
+        MultiProgress::new(),
+    )
+    .await?;
+    assert_eq!(
+        readers
+            .iter()
+            .map(|reader| reader.epoch())
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    let restored_perpetual_db = AuthorityPerpetualTables::open(&restored_db_path, None);
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(1000);
+    for mut reader in readers {
+        let (_abort_handle, abort_registration) = AbortHandle::new_pair();
+        reader
+            .read(
+                &restored_perpetual_db,
+                abort_registration,
+                Some(sender.clone()),
+            )
+            .await?;
+    }
+    drop(sender);
+    let mut restored_accumulator = Accumulator::default();
+    while let Some((partial_acc, _num_objects)) = receiver.recv().await {
+        restored_accumulator.union(&partial_acc);
+    }
+    compare_live_objects(&perpetual_db, &restored_perpetual_db)?;
+    assert_eq!(
+        ECMHLiveObjectSetDigest::from(restored_accumulator.digest()),
+        root_accumulator
+    );
+    Ok(())
+}
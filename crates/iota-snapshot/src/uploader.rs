@@ -15,12 +15,12 @@ use iota_core::{
 use iota_storage::{
     FileCompression,
     object_store::util::{
-        find_all_dirs_with_epoch_prefix, find_missing_epochs_dirs, path_to_filesystem, put,
+        exists, find_all_dirs_with_epoch_prefix, find_missing_epochs_dirs, path_to_filesystem, put,
         run_manifest_update_loop,
     },
 };
 use iota_types::messages_checkpoint::CheckpointCommitment::ECMHLiveObjectSetDigest;
-use object_store::DynObjectStore;
+use object_store::{DynObjectStore, path::Path};
 use prometheus::{
     IntCounter, IntGauge, Registry, register_int_counter_with_registry,
     register_int_gauge_with_registry,
@@ -73,6 +73,9 @@ pub struct StateSnapshotUploader {
     /// Time interval to check for presence of new db checkpoint (default: 60
     /// secs)
     interval: Duration,
+    /// Number of epochs between two full state snapshots, the snapshots in
+    /// between are written as deltas. Every snapshot is a full one if unset.
+    full_snapshot_interval: Option<u64>,
     metrics: Arc<StateSnapshotUploaderMetrics>,
 }
 
@@ -82,6 +85,7 @@ impl StateSnapshotUploader {
         staging_path: &std::path::Path,
         snapshot_store_config: ObjectStoreConfig,
         interval_s: u64,
+        full_snapshot_interval: Option<u64>,
         registry: &Registry,
         checkpoint_store: Arc<CheckpointStore>,
     ) -> Result<Arc<Self>> {
@@ -103,6 +107,7 @@ impl StateSnapshotUploader {
             staging_store: staging_store_config.make()?,
             snapshot_store: snapshot_store_config.make()?,
             interval: Duration::from_secs(interval_s),
+            full_snapshot_interval,
             metrics: StateSnapshotUploaderMetrics::new(registry),
         }))
     }
@@ -152,9 +157,19 @@ impl StateSnapshotUploader {
                     .last()
                     .expect("Expected at least one commitment")
                     .clone();
-                state_snapshot_writer
-                    .write(*epoch, db, state_hash_commitment)
-                    .await?;
+                match self.delta_parent_epoch(*epoch).await {
+                    Some(parent_epoch) => {
+                        info!("Writing delta state snapshot against epoch: {parent_epoch}");
+                        state_snapshot_writer
+                            .write_delta(*epoch, parent_epoch, db, state_hash_commitment)
+                            .await?;
+                    }
+                    None => {
+                        state_snapshot_writer
+                            .write(*epoch, db, state_hash_commitment)
+                            .await?;
+                    }
+                }
                 info!("State snapshot creation successful for epoch: {}", *epoch);
                 // Drops marker in the output directory that upload completed successfully
                 let bytes = Bytes::from_static(b"success");
@@ -216,6 +231,23 @@ impl StateSnapshotUploader {
         Ok(())
     }
 
+    /// Returns the epoch a delta snapshot of `epoch` should be written against,
+    /// or `None` if a full snapshot is due. A full snapshot is also written if
+    /// the snapshot of the previous epoch is not complete in the remote store.
+    async fn delta_parent_epoch(&self, epoch: u64) -> Option<u64> {
+        let interval = self
+            .full_snapshot_interval
+            .filter(|interval| *interval > 1)?;
+        if epoch % interval == 0 {
+            return None;
+        }
+        let parent_epoch = epoch - 1;
+        let success_marker = Path::from(format!("epoch_{parent_epoch}")).child(SUCCESS_MARKER);
+        exists(&self.snapshot_store, &success_marker)
+            .await
+            .then_some(parent_epoch)
+    }
+
     /// Finds missing epochs in the remote store.
     async fn get_missing_epochs(&self) -> Result<Vec<u64>> {
         let missing_epochs = find_missing_epochs_dirs(&self.snapshot_store, SUCCESS_MARKER).await?;
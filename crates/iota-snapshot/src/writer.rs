@@ -14,7 +14,7 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use byteorder::{BigEndian, ByteOrder};
 use fastcrypto::hash::MultisetHash;
 use futures::StreamExt;
@@ -46,10 +46,33 @@ use tracing::debug;
 
 use crate::{
     FILE_MAX_BYTES, FileCompression, FileMetadata, FileType, MAGIC_BYTES, MANIFEST_FILE_MAGIC,
-    Manifest, ManifestV1, OBJECT_FILE_MAGIC, OBJECT_REF_BYTES, REFERENCE_FILE_MAGIC,
+    Manifest, ManifestV1, ManifestV2, OBJECT_FILE_MAGIC, OBJECT_REF_BYTES, REFERENCE_FILE_MAGIC,
     SEQUENCE_NUM_BYTES, compute_sha3_checksum, create_file_metadata,
+    reader::{ObjectRefIter, StateSnapshotReaderV1},
 };
 
+/// Live object set of the parent snapshot a delta snapshot is computed
+/// against.
+struct SnapshotDeltaBase {
+    parent_epoch: u64,
+    base_epoch: u64,
+    live_object_refs: HashMap<ObjectID, ObjectRef>,
+}
+
+/// Encodes an object reference in the REFERENCE file disk format.
+fn write_object_ref<W: Write>(wbuf: &mut W, object_ref: &ObjectRef) -> Result<()> {
+    let mut buf = [0u8; OBJECT_REF_BYTES];
+    buf[0..ObjectID::LENGTH].copy_from_slice(object_ref.0.as_ref());
+    BigEndian::write_u64(
+        &mut buf[ObjectID::LENGTH..OBJECT_REF_BYTES],
+        object_ref.1.value(),
+    );
+    buf[ObjectID::LENGTH + SEQUENCE_NUM_BYTES..OBJECT_REF_BYTES]
+        .copy_from_slice(object_ref.2.as_ref());
+    wbuf.write_all(&buf)?;
+    Ok(())
+}
+
 /// LiveObjectSetWriterV1 writes live object set. It creates multiple *.obj
 /// files and *.ref file
 struct LiveObjectSetWriterV1 {
@@ -236,15 +259,111 @@ impl LiveObjectSetWriterV1 {
 
     /// Writes an object reference to the reference file.
     fn write_object_ref(&mut self, object_ref: &ObjectRef) -> Result<()> {
-        let mut buf = [0u8; OBJECT_REF_BYTES];
-        buf[0..ObjectID::LENGTH].copy_from_slice(object_ref.0.as_ref());
-        BigEndian::write_u64(
-            &mut buf[ObjectID::LENGTH..OBJECT_REF_BYTES],
-            object_ref.1.value(),
-        );
-        buf[ObjectID::LENGTH + SEQUENCE_NUM_BYTES..OBJECT_REF_BYTES]
-            .copy_from_slice(object_ref.2.as_ref());
-        self.ref_wbuf.write_all(&buf)?;
+        write_object_ref(&mut self.ref_wbuf, object_ref)
+    }
+}
+
+/// DeletedObjectRefWriterV1 writes the references of objects which are no
+/// longer live since the parent snapshot of a delta snapshot. It creates
+/// multiple *.del files.
+struct DeletedObjectRefWriterV1 {
+    dir_path: PathBuf,
+    bucket_num: u32,
+    current_part_num: u32,
+    wbuf: BufWriter<File>,
+    file_size: usize,
+    files: Vec<FileMetadata>,
+    sender: Option<Sender<FileMetadata>>,
+    file_compression: FileCompression,
+}
+
+impl DeletedObjectRefWriterV1 {
+    fn new(
+        dir_path: PathBuf,
+        bucket_num: u32,
+        file_compression: FileCompression,
+        sender: Sender<FileMetadata>,
+    ) -> Result<Self> {
+        let part_num = 1;
+        let (n, f) = Self::deleted_file(dir_path.clone(), bucket_num, part_num)?;
+        Ok(DeletedObjectRefWriterV1 {
+            dir_path,
+            bucket_num,
+            current_part_num: part_num,
+            wbuf: BufWriter::new(f),
+            file_size: n,
+            files: vec![],
+            sender: Some(sender),
+            file_compression,
+        })
+    }
+
+    /// Writes a deleted object reference. Creates a new partition if the
+    /// current file exceeds the maximum size.
+    pub fn write(&mut self, object_ref: &ObjectRef) -> Result<()> {
+        if self.file_size + OBJECT_REF_BYTES > FILE_MAX_BYTES {
+            self.finalize()?;
+            self.current_part_num += 1;
+            let (n, f) = Self::deleted_file(
+                self.dir_path.clone(),
+                self.bucket_num,
+                self.current_part_num,
+            )?;
+            self.file_size = n;
+            self.wbuf = BufWriter::new(f);
+        }
+        write_object_ref(&mut self.wbuf, object_ref)?;
+        self.file_size += OBJECT_REF_BYTES;
+        Ok(())
+    }
+
+    /// Finalizes the current file and returns the FileMetadata of all the
+    /// written files.
+    pub fn done(mut self) -> Result<Vec<FileMetadata>> {
+        self.finalize()?;
+        self.sender = None;
+        Ok(self.files.clone())
+    }
+
+    /// Creates a new deleted references file for the provided bucket number
+    /// and part number, and returns the file and the number of bytes written
+    /// to it.
+    fn deleted_file(dir_path: PathBuf, bucket_num: u32, part_num: u32) -> Result<(usize, File)> {
+        let del_path = dir_path.join(format!("{bucket_num}_{part_num}.del"));
+        let del_tmp_path = dir_path.join(format!("{bucket_num}_{part_num}.del.tmp"));
+        let mut f = File::create(del_tmp_path.clone())?;
+        f.rewind()?;
+        let mut metab = [0u8; MAGIC_BYTES];
+        BigEndian::write_u32(&mut metab, REFERENCE_FILE_MAGIC);
+        let n = f.write(&metab)?;
+        drop(f);
+        fs::rename(del_tmp_path, del_path.clone())?;
+        let mut f = OpenOptions::new().append(true).open(del_path)?;
+        f.seek(SeekFrom::Start(n as u64))?;
+        Ok((n, f))
+    }
+
+    /// Flushes the current file to disk and sends its FileMetadata to the
+    /// channel.
+    fn finalize(&mut self) -> Result<()> {
+        self.wbuf.flush()?;
+        self.wbuf.get_ref().sync_data()?;
+        let off = self.wbuf.get_ref().stream_position()?;
+        self.wbuf.get_ref().set_len(off)?;
+        let file_path = self
+            .dir_path
+            .join(format!("{}_{}.del", self.bucket_num, self.current_part_num));
+        let file_metadata = create_file_metadata(
+            &file_path,
+            self.file_compression,
+            FileType::Deleted,
+            self.bucket_num,
+            self.current_part_num,
+        )?;
+        self.files.push(file_metadata.clone());
+        if let Some(sender) = &self.sender {
+            sender.blocking_send(file_metadata)?;
+        }
         Ok(())
     }
 }
@@ -311,15 +430,68 @@ impl StateSnapshotWriterV1 {
             .await
     }
 
+    /// Writes a delta state snapshot for the specified epoch, which only holds
+    /// the objects created, mutated or deleted since the snapshot of
+    /// `parent_epoch`, and uploads it to the remote store. The snapshot of
+    /// `parent_epoch` (full or delta) must already exist in the remote store.
+    pub async fn write_delta(
+        self,
+        epoch: u64,
+        parent_epoch: u64,
+        perpetual_db: Arc<AuthorityPerpetualTables>,
+        root_state_hash: ECMHLiveObjectSetDigest,
+    ) -> Result<()> {
+        self.write_delta_internal(epoch, parent_epoch, perpetual_db, root_state_hash)
+            .await
+    }
+
     /// Writes the state snapshot for the provided epoch to the local staging
     /// directory and uploads it to the remote store.
     pub(crate) async fn write_internal(
+        self,
+        epoch: u64,
+        perpetual_db: Arc<AuthorityPerpetualTables>,
+        root_state_hash: ECMHLiveObjectSetDigest,
+    ) -> Result<()> {
+        self.write_snapshot(epoch, None, perpetual_db, root_state_hash)
+            .await
+    }
+
+    /// Writes the delta state snapshot against `parent_epoch` for the provided
+    /// epoch to the local staging directory and uploads it to the remote
+    /// store.
+    pub(crate) async fn write_delta_internal(
+        self,
+        epoch: u64,
+        parent_epoch: u64,
+        perpetual_db: Arc<AuthorityPerpetualTables>,
+        root_state_hash: ECMHLiveObjectSetDigest,
+    ) -> Result<()> {
+        if parent_epoch >= epoch {
+            return Err(anyhow!(
+                "Parent epoch {parent_epoch} of delta snapshot must precede epoch {epoch}"
+            ));
+        }
+        self.write_snapshot(epoch, Some(parent_epoch), perpetual_db, root_state_hash)
+            .await
+    }
+
+    /// Writes a full state snapshot, or a delta one if `parent_epoch` is set,
+    /// to the local staging directory and uploads it to the remote store.
+    async fn write_snapshot(
         mut self,
         epoch: u64,
+        parent_epoch: Option<u64>,
         perpetual_db: Arc<AuthorityPerpetualTables>,
         root_state_hash: ECMHLiveObjectSetDigest,
     ) -> Result<()> {
         self.setup_epoch_dir(epoch).await?;
+        let delta_base = match parent_epoch {
+            Some(parent_epoch) => Some(self.load_delta_base(parent_epoch).await.context(
+                format!("Failed to load parent snapshot of epoch: {}", parent_epoch),
+            )?),
+            None => None,
+        };
 
         let manifest_file_path = self.epoch_dir(epoch).child("MANIFEST");
         let local_staging_dir = self.local_staging_dir.clone();
@@ -336,6 +508,7 @@ impl StateSnapshotWriterV1 {
                 sender,
                 Self::bucket_func,
                 root_state_hash,
+                delta_base,
             )
         });
         // Awaits the object and reference files to be written to the local staging
@@ -407,7 +580,10 @@ impl StateSnapshotWriterV1 {
 
     /// Writes the provided live object set in the form of reference files,
     /// object files, and MANIFEST. These files are stored in the local
-    /// staging directory and the FileMetadata is sent to the channel.
+    /// staging directory and the FileMetadata is sent to the channel. If a
+    /// delta base is provided, only the objects which changed since the parent
+    /// snapshot are written, along with *.del files for the objects of the
+    /// parent snapshot which are no longer live.
     fn write_live_object_set<F>(
         &mut self,
         epoch: u64,
@@ -415,16 +591,34 @@ impl StateSnapshotWriterV1 {
         sender: Sender<FileMetadata>,
         bucket_func: F,
         root_state_hash: ECMHLiveObjectSetDigest,
+        mut delta_base: Option<SnapshotDeltaBase>,
     ) -> Result<()>
     where
         F: Fn(&LiveObject) -> u32,
     {
         let mut object_writers: HashMap<u32, LiveObjectSetWriterV1> = HashMap::new();
+        let mut deleted_ref_writers: HashMap<u32, DeletedObjectRefWriterV1> = HashMap::new();
         let local_staging_dir_path =
             path_to_filesystem(self.local_staging_dir.clone(), &self.epoch_dir(epoch))?;
         let mut acc = Accumulator::default();
         for object in perpetual_db.iter_live_object_set() {
             StateAccumulator::accumulate_live_object(&mut acc, &object);
+            if let Some(delta_base) = delta_base.as_mut() {
+                let object_ref = object.object_reference();
+                match delta_base.live_object_refs.remove(&object_ref.0) {
+                    // Unchanged since the parent snapshot
+                    Some(parent_ref) if parent_ref == object_ref => continue,
+                    // Mutated since the parent snapshot, the previous version is no longer live
+                    Some(parent_ref) => self.write_deleted_object_ref(
+                        &mut deleted_ref_writers,
+                        &local_staging_dir_path,
+                        &sender,
+                        &parent_ref,
+                    )?,
+                    // Created since the parent snapshot
+                    None => {}
+                }
+            }
             let bucket_num = bucket_func(&object);
             // Creates a new LiveObjectSetWriterV1 for the bucket if it does not exist
             if let Vacant(entry) = object_writers.entry(bucket_num) {
@@ -445,28 +639,175 @@ impl StateSnapshotWriterV1 {
             root_state_hash,
             "Root state hash mismatch!"
         );
+        // Objects of the parent snapshot which were not visited are no longer live
+        if let Some(delta_base) = delta_base.as_mut() {
+            let mut deleted_refs: Vec<ObjectRef> = delta_base
+                .live_object_refs
+                .drain()
+                .map(|(_, object_ref)| object_ref)
+                .collect();
+            deleted_refs.sort();
+            for object_ref in deleted_refs.iter() {
+                self.write_deleted_object_ref(
+                    &mut deleted_ref_writers,
+                    &local_staging_dir_path,
+                    &sender,
+                    object_ref,
+                )?;
+            }
+        }
         let mut files = vec![];
         // Flushes the object and reference files to disk, informs the file channel of
         // flushed files and get the FileMetadata
         for (_, writer) in object_writers.into_iter() {
             files.extend(writer.done()?);
         }
+        for (_, writer) in deleted_ref_writers.into_iter() {
+            files.extend(writer.done()?);
+        }
         // Write the manifest file for the epoch(bucket)
-        self.write_manifest(epoch, files)?;
+        self.write_manifest(epoch, files, delta_base.as_ref())?;
         Ok(())
     }
 
+    /// Writes the reference of an object of the parent snapshot which is no
+    /// longer live to the *.del file of its bucket.
+    fn write_deleted_object_ref(
+        &self,
+        deleted_ref_writers: &mut HashMap<u32, DeletedObjectRefWriterV1>,
+        local_staging_dir_path: &std::path::Path,
+        sender: &Sender<FileMetadata>,
+        object_ref: &ObjectRef,
+    ) -> Result<()> {
+        let bucket_num = Self::ref_bucket_func(object_ref);
+        // Creates a new DeletedObjectRefWriterV1 for the bucket if it does not exist
+        if let Vacant(entry) = deleted_ref_writers.entry(bucket_num) {
+            entry.insert(DeletedObjectRefWriterV1::new(
+                local_staging_dir_path.to_path_buf(),
+                bucket_num,
+                self.file_compression,
+                sender.clone(),
+            )?);
+        }
+        deleted_ref_writers
+            .get_mut(&bucket_num)
+            .context("Unexpected missing bucket writer")?
+            .write(object_ref)
+    }
+
+    /// Loads the live object set of the snapshot of `parent_epoch` by applying
+    /// every snapshot of its delta chain, starting from the full base
+    /// snapshot, to the object references of the base snapshot.
+    async fn load_delta_base(&self, parent_epoch: u64) -> Result<SnapshotDeltaBase> {
+        // Walks the chain back to the full base snapshot
+        let mut manifests = vec![];
+        let mut next_epoch = Some(parent_epoch);
+        while let Some(epoch) = next_epoch {
+            let manifest_file_path = self.epoch_dir(epoch).child("MANIFEST");
+            copy_file(
+                &manifest_file_path,
+                &manifest_file_path,
+                &self.remote_object_store,
+                &self.local_staging_store,
+            )
+            .await?;
+            let manifest = StateSnapshotReaderV1::read_manifest(path_to_filesystem(
+                self.local_staging_dir.clone(),
+                &manifest_file_path,
+            )?)?;
+            next_epoch = manifest.parent_epoch();
+            if next_epoch.is_some_and(|parent_epoch| parent_epoch >= epoch) {
+                return Err(anyhow!(
+                    "Snapshot of epoch {} has invalid parent epoch {:?}",
+                    epoch,
+                    next_epoch
+                ));
+            }
+            manifests.push(manifest);
+        }
+        let base_epoch = manifests
+            .last()
+            .map(|manifest| manifest.epoch())
+            .context("Missing parent snapshot manifest")?;
+        let mut live_object_refs = HashMap::new();
+        for manifest in manifests.iter().rev() {
+            let epoch_dir = self.epoch_dir(manifest.epoch());
+            for file_metadata in manifest.file_metadata() {
+                if file_metadata.file_type == FileType::Object {
+                    continue;
+                }
+                let file_path = file_metadata.file_path(&epoch_dir);
+                copy_file(
+                    &file_path,
+                    &file_path,
+                    &self.remote_object_store,
+                    &self.local_staging_store,
+                )
+                .await?;
+            }
+            // Removes the references which are no longer live before adding new ones, so
+            // that the new version of a mutated object is kept
+            for file_type in [FileType::Deleted, FileType::Reference] {
+                for file_metadata in manifest
+                    .file_metadata()
+                    .iter()
+                    .filter(|file_metadata| file_metadata.file_type == file_type)
+                {
+                    let iter = ObjectRefIter::new(
+                        file_metadata,
+                        self.local_staging_dir.clone(),
+                        epoch_dir.clone(),
+                    )?;
+                    for object_ref in iter {
+                        if file_type == FileType::Deleted {
+                            live_object_refs.remove(&object_ref.0);
+                        } else {
+                            live_object_refs.insert(object_ref.0, object_ref);
+                        }
+                    }
+                }
+            }
+            // Removes the downloaded files of the parent snapshot from the staging
+            // directory
+            let local_epoch_dir_path =
+                path_to_filesystem(self.local_staging_dir.clone(), &epoch_dir)?;
+            if local_epoch_dir_path.exists() {
+                fs::remove_dir_all(&local_epoch_dir_path)?;
+            }
+        }
+        Ok(SnapshotDeltaBase {
+            parent_epoch,
+            base_epoch,
+            live_object_refs,
+        })
+    }
+
     /// Writes the manifest file for the provided FileMetadata of an epoch and
     /// its sha3 checksum.
-    fn write_manifest(&mut self, epoch: u64, file_metadata: Vec<FileMetadata>) -> Result<()> {
+    fn write_manifest(
+        &mut self,
+        epoch: u64,
+        file_metadata: Vec<FileMetadata>,
+        delta_base: Option<&SnapshotDeltaBase>,
+    ) -> Result<()> {
         let (f, manifest_file_path) = self.manifest_file(epoch)?;
         let mut wbuf = BufWriter::new(f);
-        let manifest: Manifest = Manifest::V1(ManifestV1 {
-            snapshot_version: 1,
-            address_length: ObjectID::LENGTH as u64,
-            file_metadata,
-            epoch,
-        });
+        let manifest: Manifest = match delta_base {
+            Some(delta_base) => Manifest::V2(ManifestV2 {
+                snapshot_version: 1,
+                address_length: ObjectID::LENGTH as u64,
+                file_metadata,
+                epoch,
+                parent_epoch: delta_base.parent_epoch,
+                base_epoch: delta_base.base_epoch,
+            }),
+            None => Manifest::V1(ManifestV1 {
+                snapshot_version: 1,
+                address_length: ObjectID::LENGTH as u64,
+                file_metadata,
+                epoch,
+            }),
+        };
         let serialized_manifest = bcs::to_bytes(&manifest)?;
         wbuf.write_all(&serialized_manifest)?;
         wbuf.flush()?;
@@ -513,6 +854,11 @@ impl StateSnapshotWriterV1 {
         1u32
     }
 
+    fn ref_bucket_func(_object_ref: &ObjectRef) -> u32 {
+        // Deleted references are bucketed the same way as live objects
+        1u32
+    }
+
     fn epoch_dir(&self, epoch: u64) -> Path {
         Path::from(format!("epoch_{}", epoch))
     }
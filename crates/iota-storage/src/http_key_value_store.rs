@@ -44,7 +44,7 @@ pub fn encode_digest<T: AsRef<[u8]>>(digest: &T) -> String {
 }
 
 // for non-digest keys, we need a tag to make sure we don't have collisions
-#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TaggedKey {
     CheckpointSequenceNumber(CheckpointSequenceNumber),
 }
@@ -116,6 +116,44 @@ fn key_to_path_elements(key: &Key) -> IotaResult<(String, &'static str)> {
     }
 }
 
+/// Parses the path elements of a request made by an `HttpKVStore` back into
+/// the requested key, i.e. the inverse of `key_to_path_elements`. Used by
+/// servers implementing the protocol.
+pub fn path_elements_to_key(encoded_key: &str, item_type: &str) -> IotaResult<Key> {
+    let bytes = base64_url::decode(encoded_key).into_iota_result()?;
+    let digest = || <[u8; 32]>::try_from(bytes.as_slice()).into_iota_result();
+    match item_type {
+        "tx" => Ok(Key::Tx(TransactionDigest::new(digest()?))),
+        "fx" => Ok(Key::Fx(TransactionDigest::new(digest()?))),
+        "ev" => Ok(Key::Events(TransactionEventsDigest::new(digest()?))),
+        "tx2c" => Ok(Key::TxToCheckpoint(TransactionDigest::new(digest()?))),
+        // checkpoints are either keyed by digest or by tagged sequence number
+        "cc" | "cs" => {
+            if let Ok(digest) = digest() {
+                return Ok(if item_type == "cc" {
+                    Key::CheckpointContentsByDigest(CheckpointContentsDigest::new(digest))
+                } else {
+                    Key::CheckpointSummaryByDigest(CheckpointDigest::new(digest))
+                });
+            }
+            let TaggedKey::CheckpointSequenceNumber(seq) =
+                bcs::from_bytes(&bytes).into_iota_result()?;
+            Ok(if item_type == "cc" {
+                Key::CheckpointContents(seq)
+            } else {
+                Key::CheckpointSummary(seq)
+            })
+        }
+        "ob" => {
+            let ObjectKey(object_id, version) = bcs::from_bytes(&bytes).into_iota_result()?;
+            Ok(Key::ObjectKey(object_id, version))
+        }
+        _ => Err(IotaError::Storage(format!(
+            "unknown item type: {item_type}"
+        ))),
+    }
+}
+
 impl HttpKVStore {
     pub fn new_kv(
         base_url: &str,
@@ -869,7 +869,6 @@ pub async fn download_formal_snapshot(
         verify != SnapshotVerifyMode::None,
         all_checkpoints,
     );
-    let (_abort_handle, abort_registration) = AbortHandle::new_pair();
     let perpetual_db_clone = perpetual_db.clone();
     let snapshot_dir = path.parent().unwrap().join("snapshot");
     if snapshot_dir.exists() {
@@ -888,21 +887,40 @@ pub async fn download_formal_snapshot(
             directory: Some(snapshot_dir_clone.to_path_buf()),
             ..Default::default()
         };
-        let mut reader = StateSnapshotReaderV1::new(
+        // A delta snapshot is restored by reading its full base snapshot followed by
+        // every delta snapshot of the chain
+        let readers = StateSnapshotReaderV1::new_chain(
             epoch,
             &snapshot_store_config,
             &local_store_config,
             usize::MAX,
             NonZeroUsize::new(num_parallel_downloads).unwrap(),
-            m_clone,
+            m_clone.clone(),
         )
         .await
         .unwrap_or_else(|err| panic!("Failed to create reader: {}", err));
-        reader
-            .read(&perpetual_db_clone, abort_registration, Some(sender))
-            .await
-            .unwrap_or_else(|err| panic!("Failed during read: {}", err));
-        Ok::<(), anyhow::Error>(())
+        if readers.len() > 1 {
+            m_clone.println(format!(
+                "Restoring snapshot of epoch {} as a chain of {} snapshots from base epoch {}",
+                epoch,
+                readers.len(),
+                readers[0].epoch(),
+            ))?;
+        }
+        let mut num_deleted_objects = 0;
+        for mut reader in readers {
+            let (_abort_handle, abort_registration) = AbortHandle::new_pair();
+            reader
+                .read(
+                    &perpetual_db_clone,
+                    abort_registration,
+                    Some(sender.clone()),
+                )
+                .await
+                .unwrap_or_else(|err| panic!("Failed during read: {}", err));
+            num_deleted_objects += reader.num_deleted_objects();
+        }
+        Ok::<u64, anyhow::Error>(num_deleted_objects)
     });
     let mut root_accumulator = Accumulator::default();
     let mut num_live_objects = 0;
@@ -969,10 +987,11 @@ pub async fn download_formal_snapshot(
         )?;
     }
 
-    snapshot_handle
+    let num_deleted_objects = snapshot_handle
         .await
         .expect("Task join failed")
         .expect("Snapshot restore task failed");
+    num_live_objects -= num_deleted_objects;
 
     // TODO we should ensure this map is being updated for all end of epoch
     // checkpoints during summary sync. This happens in
@@ -6,14 +6,14 @@ use anyhow::{Ok, anyhow, ensure};
 use iota_types::{
     IOTA_SYSTEM_PACKAGE_ID,
     base_types::{IotaAddress, ObjectID, ObjectType},
-    governance::{ADD_STAKE_MUL_COIN_FUN_NAME, WITHDRAW_STAKE_FUN_NAME},
+    governance::{ADD_STAKE_FUN_NAME, ADD_STAKE_MUL_COIN_FUN_NAME, WITHDRAW_STAKE_FUN_NAME},
     iota_system_state::IOTA_SYSTEM_MODULE_NAME,
     programmable_transaction_builder::ProgrammableTransactionBuilder,
     timelock::timelocked_staking::{
         ADD_TIMELOCKED_STAKE_FUN_NAME, TIMELOCKED_STAKING_MODULE_NAME,
         WITHDRAW_TIMELOCKED_STAKE_FUN_NAME,
     },
-    transaction::{CallArg, Command, ObjectArg, TransactionData},
+    transaction::{Argument, CallArg, Command, ObjectArg, TransactionData, TransactionKind},
 };
 
 use crate::TransactionBuilder;
@@ -23,7 +23,7 @@ impl TransactionBuilder {
     pub async fn request_add_stake(
         &self,
         signer: IotaAddress,
-        mut coins: Vec<ObjectID>,
+        coins: Vec<ObjectID>,
         amount: impl Into<Option<u64>>,
         validator: IotaAddress,
         gas: impl Into<Option<ObjectID>>,
@@ -34,29 +34,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, coins.clone(), gas_price)
             .await?;
 
-        let mut obj_vec = vec![];
-        let coin = coins
-            .pop()
-            .ok_or_else(|| anyhow!("Coins input should contain at lease one coin object."))?;
-        let (oref, coin_type) = self.get_object_ref_and_type(coin).await?;
-
-        let ObjectType::Struct(type_) = &coin_type else {
-            return Err(anyhow!("Provided object [{coin}] is not a move object."));
-        };
-        ensure!(
-            type_.is_coin(),
-            "Expecting either Coin<T> input coin objects. Received [{type_}]"
-        );
-
-        for coin in coins {
-            let (oref, type_) = self.get_object_ref_and_type(coin).await?;
-            ensure!(
-                type_ == coin_type,
-                "All coins should be the same type, expecting {coin_type}, got {type_}."
-            );
-            obj_vec.push(ObjectArg::ImmOrOwnedObject(oref))
-        }
-        obj_vec.push(ObjectArg::ImmOrOwnedObject(oref));
+        let obj_vec = self.stake_coin_args(coins).await?;
 
         let pt = {
             let mut builder = ProgrammableTransactionBuilder::new();
@@ -88,6 +66,103 @@ impl TransactionBuilder {
         ))
     }
 
+    /// Build a [`TransactionKind::ProgrammableTransaction`] that adds stake to
+    /// a validator's staking pool. If no coins are provided, `amount` is split
+    /// from the gas coin and staked.
+    pub async fn request_add_stake_tx_kind(
+        &self,
+        coins: Vec<ObjectID>,
+        amount: impl Into<Option<u64>>,
+        validator: IotaAddress,
+    ) -> anyhow::Result<TransactionKind> {
+        let amount = amount.into();
+        let mut builder = ProgrammableTransactionBuilder::new();
+        if coins.is_empty() {
+            let amount = amount
+                .ok_or_else(|| anyhow!("An amount is required to stake from the gas coin."))?;
+            let amount = builder.pure(amount)?;
+            let coin = builder.command(Command::SplitCoins(Argument::GasCoin, vec![amount]));
+            let arguments = vec![
+                builder.input(CallArg::IOTA_SYSTEM_MUT)?,
+                coin,
+                builder.pure(validator)?,
+            ];
+            builder.command(Command::move_call(
+                IOTA_SYSTEM_PACKAGE_ID,
+                IOTA_SYSTEM_MODULE_NAME.to_owned(),
+                ADD_STAKE_FUN_NAME.to_owned(),
+                vec![],
+                arguments,
+            ));
+        } else {
+            let obj_vec = self.stake_coin_args(coins).await?;
+            let arguments = vec![
+                builder.input(CallArg::IOTA_SYSTEM_MUT)?,
+                builder.make_obj_vec(obj_vec)?,
+                builder.pure(amount)?,
+                builder.pure(validator)?,
+            ];
+            builder.command(Command::move_call(
+                IOTA_SYSTEM_PACKAGE_ID,
+                IOTA_SYSTEM_MODULE_NAME.to_owned(),
+                ADD_STAKE_MUL_COIN_FUN_NAME.to_owned(),
+                vec![],
+                arguments,
+            ));
+        }
+        Ok(TransactionKind::programmable(builder.finish()))
+    }
+
+    /// Resolve the coins to stake into object arguments, checking that they
+    /// are all coins of the same type.
+    async fn stake_coin_args(&self, mut coins: Vec<ObjectID>) -> anyhow::Result<Vec<ObjectArg>> {
+        let mut obj_vec = vec![];
+        let coin = coins
+            .pop()
+            .ok_or_else(|| anyhow!("Coins input should contain at lease one coin object."))?;
+        let (oref, coin_type) = self.get_object_ref_and_type(coin).await?;
+
+        let ObjectType::Struct(type_) = &coin_type else {
+            return Err(anyhow!("Provided object [{coin}] is not a move object."));
+        };
+        ensure!(
+            type_.is_coin(),
+            "Expecting either Coin<T> input coin objects. Received [{type_}]"
+        );
+
+        for coin in coins {
+            let (oref, type_) = self.get_object_ref_and_type(coin).await?;
+            ensure!(
+                type_ == coin_type,
+                "All coins should be the same type, expecting {coin_type}, got {type_}."
+            );
+            obj_vec.push(ObjectArg::ImmOrOwnedObject(oref))
+        }
+        obj_vec.push(ObjectArg::ImmOrOwnedObject(oref));
+        Ok(obj_vec)
+    }
+
+    /// Build a [`TransactionKind::ProgrammableTransaction`] that withdraws
+    /// stake from a validator's staking pool.
+    pub async fn request_withdraw_stake_tx_kind(
+        &self,
+        staked_iota: ObjectID,
+    ) -> anyhow::Result<TransactionKind> {
+        let staked_iota = self.get_object_ref(staked_iota).await?;
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.move_call(
+            IOTA_SYSTEM_PACKAGE_ID,
+            IOTA_SYSTEM_MODULE_NAME.to_owned(),
+            WITHDRAW_STAKE_FUN_NAME.to_owned(),
+            vec![],
+            vec![
+                CallArg::IOTA_SYSTEM_MUT,
+                CallArg::Object(ObjectArg::ImmOrOwnedObject(staked_iota)),
+            ],
+        )?;
+        Ok(TransactionKind::programmable(builder.finish()))
+    }
+
     /// Withdraw stake from a validator's staking pool.
     pub async fn request_withdraw_stake(
         &self,
@@ -723,6 +723,17 @@ impl RestStateReader for PersistedStoreInnerReadOnlyWrapper {
         todo!()
     }
 
+    fn account_coins_iter(
+        &self,
+        _owner: IotaAddress,
+        _coin_type: Option<&StructTag>,
+        _cursor: Option<(&StructTag, ObjectID)>,
+    ) -> iota_types::storage::error::Result<
+        Box<dyn Iterator<Item = iota_types::storage::AccountCoinInfo> + '_>,
+    > {
+        todo!()
+    }
+
     fn dynamic_field_iter(
         &self,
         _parent: ObjectID,
@@ -163,7 +163,7 @@ impl Event {
 }
 
 // Event emitted in move code `fun advance_epoch`
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct SystemEpochInfoEventV1 {
     pub epoch: u64,
     pub protocol_version: u64,
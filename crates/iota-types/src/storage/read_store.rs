@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{ObjectStore, error::Result};
 use crate::{
-    base_types::{EpochId, IotaAddress, MoveObjectType, ObjectID, SequenceNumber},
+    base_types::{EpochId, IotaAddress, MoveObjectType, ObjectDigest, ObjectID, SequenceNumber},
     committee::Committee,
     digests::{
         ChainIdentifier, CheckpointContentsDigest, CheckpointDigest, TransactionDigest,
@@ -671,6 +671,17 @@ pub trait RestStateReader: ObjectStore + ReadStore + Send + Sync {
         cursor: Option<ObjectID>,
     ) -> Result<Box<dyn Iterator<Item = AccountOwnedObjectInfo> + '_>>;
 
+    /// Iterates over the coins owned by `owner`, ordered by coin type and then
+    /// by object ID. If `coin_type` is provided, only coins of that type are
+    /// returned. Iteration starts at `cursor`, a coin type and object ID pair,
+    /// if provided.
+    fn account_coins_iter(
+        &self,
+        owner: IotaAddress,
+        coin_type: Option<&StructTag>,
+        cursor: Option<(&StructTag, ObjectID)>,
+    ) -> Result<Box<dyn Iterator<Item = AccountCoinInfo> + '_>>;
+
     fn dynamic_field_iter(
         &self,
         parent: ObjectID,
@@ -687,6 +698,15 @@ pub struct AccountOwnedObjectInfo {
     pub type_: MoveObjectType,
 }
 
+pub struct AccountCoinInfo {
+    pub owner: IotaAddress,
+    pub coin_type: StructTag,
+    pub object_id: ObjectID,
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+    pub balance: u64,
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct DynamicFieldKey {
     pub parent: ObjectID,
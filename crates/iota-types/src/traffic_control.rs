@@ -2,11 +2,15 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::path::PathBuf;
+use std::{collections::HashMap, fmt, net::IpAddr, path::PathBuf, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+#[cfg(test)]
+#[path = "unit_tests/traffic_control_tests.rs"]
+mod traffic_control_tests;
+
 // These values set to loosely attempt to limit
 // memory usage for a single sketch to ~20MB
 // For reference, see
@@ -206,6 +210,171 @@ fn default_sketch_tolerance() -> f64 {
     DEFAULT_SKETCH_TOLERANCE
 }
 
+/// Configuration of a token bucket policy. Each client is given a bucket of
+/// `*-capacity` tokens, which bounds the burst of requests it may send at
+/// once, and which is refilled at `*-refill-rate` tokens per second, which
+/// bounds its sustained request rate. Every tallied request consumes tokens
+/// according to the cost of the method it called, and a client whose bucket
+/// does not hold enough tokens to pay for a request is blocked.
+///
+/// Note that requests that are not sampled do not consume tokens, so
+/// `spam-sample-rate` should be set to 1.0 when this is used as a spam policy.
+/// Capacities, refill rates and method costs must be finite and positive.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TokenBucketConfig {
+    #[serde(default = "default_bucket_capacity")]
+    pub client_capacity: f64,
+    #[serde(default = "default_bucket_refill_rate")]
+    pub client_refill_rate: f64,
+    #[serde(default = "default_bucket_capacity")]
+    pub proxied_client_capacity: f64,
+    #[serde(default = "default_bucket_refill_rate")]
+    pub proxied_client_refill_rate: f64,
+    /// Cost of a request to a method that is not listed in `method-costs`.
+    #[serde(default = "default_method_cost")]
+    pub default_method_cost: f64,
+    /// Cost of requests per RPC method, e.g. `iota_executeTransactionBlock`
+    /// for the JSON-RPC API, or `transaction` for the validator API.
+    #[serde(default)]
+    pub method_costs: HashMap<String, f64>,
+    /// Number of clients above which clients whose bucket is full, and is
+    /// thus equivalent to a fresh one, stop being tracked.
+    #[serde(default = "default_max_tracked_clients")]
+    pub max_tracked_clients: usize,
+}
+
+impl Default for TokenBucketConfig {
+    fn default() -> Self {
+        Self {
+            client_capacity: default_bucket_capacity(),
+            client_refill_rate: default_bucket_refill_rate(),
+            proxied_client_capacity: default_bucket_capacity(),
+            proxied_client_refill_rate: default_bucket_refill_rate(),
+            default_method_cost: default_method_cost(),
+            method_costs: HashMap::new(),
+            max_tracked_clients: default_max_tracked_clients(),
+        }
+    }
+}
+
+impl TokenBucketConfig {
+    /// Returns the cost of a request to the given method.
+    pub fn method_cost(&self, method: Option<&str>) -> f64 {
+        method
+            .and_then(|method| self.method_costs.get(method))
+            .copied()
+            .unwrap_or(self.default_method_cost)
+    }
+}
+
+fn default_bucket_capacity() -> f64 {
+    200.0
+}
+
+fn default_bucket_refill_rate() -> f64 {
+    50.0
+}
+
+fn default_method_cost() -> f64 {
+    1.0
+}
+
+fn default_max_tracked_clients() -> usize {
+    100_000
+}
+
+/// A range of IP addresses in CIDR notation, e.g. `10.0.0.0/8` or
+/// `2001:db8::/32`. A single address without a prefix length is also
+/// accepted and matches only that address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IpCidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpCidr {
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self, String> {
+        let max_prefix_len = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix_len > max_prefix_len {
+            return Err(format!(
+                "Invalid prefix length {prefix_len} for {addr}, must be at most {max_prefix_len}"
+            ));
+        }
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// Returns true if `ip` belongs to this range. IPv4 addresses never
+    /// belong to an IPv6 range and vice versa.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(*ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(*ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (
+                addr,
+                Some(
+                    prefix_len
+                        .parse::<u8>()
+                        .map_err(|e| format!("Invalid prefix length in {s}: {e}"))?,
+                ),
+            ),
+            None => (s, None),
+        };
+        let addr = addr
+            .parse::<IpAddr>()
+            .map_err(|e| format!("Invalid IP address in {s}: {e}"))?;
+        let prefix_len = prefix_len.unwrap_or(match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        });
+        Self::new(addr, prefix_len)
+    }
+}
+
+impl TryFrom<String> for IpCidr {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<IpCidr> for String {
+    fn from(value: IpCidr) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
 // Serializable representation of policy types, used in config
 // in order to easily change in tests or to killswitch
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
@@ -219,6 +388,12 @@ pub enum PolicyType {
     /// `window_size_secs` with granularity of `update_interval_secs`
     FreqThreshold(FreqThresholdConfig),
 
+    /// Blocks a client once it runs out of tokens in its bucket, where each
+    /// request consumes tokens according to the cost of its method and
+    /// buckets are refilled at a constant rate, allowing for bursts of up to
+    /// the bucket capacity
+    TokenBucket(TokenBucketConfig),
+
     // Below this point are test policies, and thus should not be used in production
     /// Simple policy that adds connection_ip to blocklist when the same
     /// connection_ip is encountered in tally N times. If used in an error
@@ -255,6 +430,10 @@ pub struct PolicyConfig {
     pub spam_sample_rate: Weight,
     #[serde(default = "default_dry_run")]
     pub dry_run: bool,
+    /// Ranges of trusted client IP addresses, which are never blocked nor
+    /// tallied, e.g. `10.0.0.0/8` for clients in a private network.
+    #[serde(default)]
+    pub allowlist: Vec<IpCidr>,
 }
 
 impl Default for PolicyConfig {
@@ -268,6 +447,7 @@ impl Default for PolicyConfig {
             channel_capacity: 100,
             spam_sample_rate: default_spam_sample_rate(),
             dry_run: default_dry_run(),
+            allowlist: vec![],
         }
     }
 }
@@ -2,8 +2,18 @@
 // Modifications Copyright (c) 2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::quorum_driver_types::{
-    ExecuteTransactionRequestV1, ExecuteTransactionResponseV1, QuorumDriverError,
+use std::collections::BTreeMap;
+
+use crate::{
+    base_types::ObjectID,
+    effects::{TransactionEffects, TransactionEvents},
+    error::IotaError,
+    execution::ExecutionResult,
+    object::Object,
+    quorum_driver_types::{
+        ExecuteTransactionRequestV1, ExecuteTransactionResponseV1, QuorumDriverError,
+    },
+    transaction::TransactionData,
 };
 
 /// Trait to define the interface for how the REST service interacts with a a
@@ -15,4 +25,35 @@ pub trait TransactionExecutor: Send + Sync {
         request: ExecuteTransactionRequestV1,
         client_addr: Option<std::net::SocketAddr>,
     ) -> Result<ExecuteTransactionResponseV1, QuorumDriverError>;
+
+    /// Executes the transaction on top of the latest state without committing
+    /// its results, as a dry run.
+    async fn simulate_transaction(
+        &self,
+        transaction: TransactionData,
+    ) -> Result<SimulateTransactionResult, IotaError>;
+
+    /// Executes the transaction in dev-inspect mode on top of the latest state
+    /// without committing its results. When `skip_checks` is set, only
+    /// lightweight checks are performed on the transaction inputs.
+    async fn dev_inspect_transaction(
+        &self,
+        transaction: TransactionData,
+        skip_checks: bool,
+    ) -> Result<SimulateTransactionResult, IotaError>;
+}
+
+/// The outcome of simulating or dev-inspecting a transaction.
+#[derive(Debug)]
+pub struct SimulateTransactionResult {
+    pub input_objects: BTreeMap<ObjectID, Object>,
+    pub output_objects: BTreeMap<ObjectID, Object>,
+    pub events: Option<TransactionEvents>,
+    pub effects: TransactionEffects,
+    /// The ID of the gas coin that was made up to pay for the transaction, if
+    /// the transaction did not provide a gas payment.
+    pub mock_gas_id: Option<ObjectID>,
+    /// The results of each command of the transaction. Only populated when
+    /// dev-inspecting a transaction that executed successfully.
+    pub execution_results: Option<Vec<ExecutionResult>>,
 }
@@ -0,0 +1,74 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use crate::traffic_control::{IpCidr, PolicyConfig, TokenBucketConfig};
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_ip_cidr_contains() {
+    let cidr: IpCidr = "10.1.0.0/16".parse().unwrap();
+    assert!(cidr.contains(&ip("10.1.0.0")));
+    assert!(cidr.contains(&ip("10.1.255.255")));
+    assert!(!cidr.contains(&ip("10.2.0.1")));
+    assert!(!cidr.contains(&ip("::ffff:10.1.0.1")));
+
+    let single: IpCidr = "192.168.1.7".parse().unwrap();
+    assert_eq!(single.to_string(), "192.168.1.7/32");
+    assert!(single.contains(&ip("192.168.1.7")));
+    assert!(!single.contains(&ip("192.168.1.8")));
+
+    let any: IpCidr = "0.0.0.0/0".parse().unwrap();
+    assert!(any.contains(&ip("1.2.3.4")));
+    assert!(!any.contains(&ip("::1")));
+
+    let v6: IpCidr = "2001:db8::/32".parse().unwrap();
+    assert!(v6.contains(&ip("2001:db8:ffff::1")));
+    assert!(!v6.contains(&ip("2001:db9::1")));
+}
+
+#[test]
+fn test_ip_cidr_parse_errors() {
+    assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+    assert!("2001:db8::/129".parse::<IpCidr>().is_err());
+    assert!("10.0.0/8".parse::<IpCidr>().is_err());
+    assert!("10.0.0.0/x".parse::<IpCidr>().is_err());
+}
+
+#[test]
+fn test_policy_config_allowlist_serde() {
+    let config: PolicyConfig = serde_yaml::from_str(
+        r#"
+        allowlist:
+          - 10.0.0.0/8
+          - "2001:db8::1"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.allowlist, vec![
+        "10.0.0.0/8".parse().unwrap(),
+        "2001:db8::1/128".parse().unwrap(),
+    ]);
+    assert!(
+        serde_yaml::from_str::<PolicyConfig>("allowlist: [10.0.0.0/40]")
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid prefix length")
+    );
+}
+
+#[test]
+fn test_token_bucket_config_defaults() {
+    let config: TokenBucketConfig = serde_yaml::from_str(
+        r#"
+        client-refill-rate: 2.5
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.client_refill_rate, 2.5);
+    assert_eq!(config.proxied_client_refill_rate, 50.0);
+}
@@ -22,11 +22,11 @@ use fastcrypto::{
 };
 use iota_json::IotaJsonValue;
 use iota_json_rpc_types::{
-    Coin, DryRunTransactionBlockResponse, DynamicFieldPage, IotaCoinMetadata, IotaData,
-    IotaExecutionStatus, IotaObjectData, IotaObjectDataOptions, IotaObjectResponse,
+    Coin, DelegatedStake, DryRunTransactionBlockResponse, DynamicFieldPage, IotaCoinMetadata,
+    IotaData, IotaExecutionStatus, IotaObjectData, IotaObjectDataOptions, IotaObjectResponse,
     IotaObjectResponseQuery, IotaParsedData, IotaProtocolConfigValue, IotaRawData,
     IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponse,
-    IotaTransactionBlockResponseOptions,
+    IotaTransactionBlockResponseOptions, StakeStatus,
 };
 use iota_keys::keystore::AccountKeystore;
 use iota_move::manage_package::resolve_lock_file_path;
@@ -403,6 +403,34 @@ pub enum IotaClientCommands {
         opts: OptsWithGas,
     },
 
+    /// Stake IOTA to a validator's staking pool. If no coins are provided, the
+    /// amount is split from the gas coin.
+    #[clap(name = "stake")]
+    Stake {
+        /// Address or name of the validator to stake to
+        #[clap(long)]
+        validator: String,
+        /// The amount to stake (in NANOS). If not specified, the entire
+        /// balance of the input coins is staked.
+        #[clap(long)]
+        amount: Option<u64>,
+        /// The IOTA coins to stake. If not provided, the amount is split from
+        /// the gas coin.
+        #[clap(long, num_args(1..))]
+        coins: Vec<ObjectID>,
+        #[clap(flatten)]
+        opts: OptsWithGas,
+    },
+
+    /// List the stakes of an address, with their estimated rewards
+    #[clap(name = "stakes")]
+    Stakes {
+        /// Address (or its alias) owning the stakes. If no address is provided,
+        /// the stakes of `iota client active-address` are shown.
+        #[arg(value_parser)]
+        address: Option<KeyIdentity>,
+    },
+
     /// Switch active address and network(e.g., devnet, local rpc server).
     #[clap(name = "switch")]
     Switch {
@@ -462,6 +490,17 @@ pub enum IotaClientCommands {
         opts: Opts,
     },
 
+    /// Withdraw a stake from a validator's staking pool. The principal and
+    /// the rewards are returned to the owner of the stake.
+    #[clap(name = "unstake")]
+    Unstake {
+        /// ID of the StakedIota object to withdraw
+        #[clap(long)]
+        staked_iota_id: ObjectID,
+        #[clap(flatten)]
+        opts: OptsWithGas,
+    },
+
     /// Upgrade Move modules
     #[clap(name = "upgrade")]
     Upgrade {
@@ -805,6 +844,7 @@ impl IotaClientCommands {
                     executor_version,
                     protocol_version,
                     config_objects: None,
+                    checkpoints_path: None,
                 };
 
                 let rpc = context.config().get_active_env()?.rpc().clone();
@@ -841,6 +881,7 @@ impl IotaClientCommands {
                     end,
                     terminate_early,
                     max_tasks: 16,
+                    checkpoints_path: None,
                 };
                 let rpc = context.config().get_active_env()?.rpc().clone();
                 let _command_result =
@@ -1347,6 +1388,64 @@ impl IotaClientCommands {
                 .await?
             }
 
+            IotaClientCommands::Stake {
+                validator,
+                amount,
+                coins,
+                opts,
+            } => {
+                if let Some(gas) = opts.gas {
+                    ensure!(
+                        !coins.contains(&gas),
+                        "Gas coin is in the coins to stake, stake from the gas coin by omitting --coins instead"
+                    );
+                }
+                let signer = match coins.first() {
+                    Some(coin) => context.get_object_owner(coin).await?,
+                    None => match context.try_get_object_owner(&opts.gas).await? {
+                        Some(owner) => owner,
+                        None => context.active_address()?,
+                    },
+                };
+                let client = context.get_client().await?;
+                let validator = resolve_validator_address(&client, &validator).await?;
+                let tx_kind = client
+                    .transaction_builder()
+                    .request_add_stake_tx_kind(coins, amount, validator)
+                    .await?;
+                dry_run_or_execute_or_serialize(
+                    signer, tx_kind, context, None, None, opts.gas, opts.rest,
+                )
+                .await?
+            }
+
+            IotaClientCommands::Stakes { address } => {
+                let address = get_identity_address(address, context)?;
+                let stakes = context
+                    .get_client()
+                    .await?
+                    .governance_api()
+                    .get_stakes(address)
+                    .await?;
+                IotaClientCommandResult::Stakes(stakes)
+            }
+
+            IotaClientCommands::Unstake {
+                staked_iota_id,
+                opts,
+            } => {
+                let signer = context.get_object_owner(&staked_iota_id).await?;
+                let client = context.get_client().await?;
+                let tx_kind = client
+                    .transaction_builder()
+                    .request_withdraw_stake_tx_kind(staked_iota_id)
+                    .await?;
+                dry_run_or_execute_or_serialize(
+                    signer, tx_kind, context, None, None, opts.gas, opts.rest,
+                )
+                .await?
+            }
+
             IotaClientCommands::Pay {
                 input_coins,
                 recipients,
@@ -2105,6 +2204,44 @@ impl Display for IotaClientCommandResult {
                 }
                 write!(f, "{}", table)?;
             }
+            IotaClientCommandResult::Stakes(delegated_stakes) => {
+                if delegated_stakes.iter().all(|d| d.stakes.is_empty()) {
+                    write!(f, "No stakes are owned by this address")?;
+                    return Ok(());
+                }
+
+                let mut builder = TableBuilder::default();
+                builder.set_header(vec![
+                    "stakedIotaId",
+                    "validatorAddress",
+                    "principal (NANOS)",
+                    "activeEpoch",
+                    "status",
+                    "estimatedReward (NANOS)",
+                ]);
+                for delegated_stake in delegated_stakes {
+                    for stake in &delegated_stake.stakes {
+                        let (status, estimated_reward) = match stake.status {
+                            StakeStatus::Pending => ("Pending", "-".to_string()),
+                            StakeStatus::Active { estimated_reward } => {
+                                ("Active", estimated_reward.to_string())
+                            }
+                            StakeStatus::Unstaked => ("Unstaked", "-".to_string()),
+                        };
+                        builder.push_record(vec![
+                            stake.staked_iota_id.to_string(),
+                            delegated_stake.validator_address.to_string(),
+                            stake.principal.to_string(),
+                            stake.stake_active_epoch.to_string(),
+                            status.to_string(),
+                            estimated_reward,
+                        ]);
+                    }
+                }
+                let mut table = builder.build();
+                table.with(TableStyle::rounded());
+                write!(f, "{}", table)?;
+            }
             IotaClientCommandResult::NewAddress(new_address) => {
                 let mut builder = TableBuilder::default();
                 builder.push_record(vec!["alias", new_address.alias.as_str()]);
@@ -2442,6 +2579,7 @@ impl IotaClientCommandResult {
             | IotaClientCommandResult::RawObject(_)
             | IotaClientCommandResult::SerializedSignedTransaction(_)
             | IotaClientCommandResult::SerializedUnsignedTransaction(_)
+            | IotaClientCommandResult::Stakes(_)
             | IotaClientCommandResult::Switch(_)
             | IotaClientCommandResult::SyncClientState
             | IotaClientCommandResult::VerifyBytecodeMeter { .. }
@@ -2591,6 +2729,7 @@ pub enum IotaClientCommandResult {
     RawObject(IotaObjectResponse),
     SerializedSignedTransaction(SenderSignedData),
     SerializedUnsignedTransaction(TransactionData),
+    Stakes(Vec<DelegatedStake>),
     Switch(SwitchResponse),
     SyncClientState,
     TransactionBlock(IotaTransactionBlockResponse),
@@ -2891,6 +3030,32 @@ pub async fn max_gas_budget(client: &IotaClient) -> Result<u64, anyhow::Error> {
     })
 }
 
+/// Resolve a validator given either by its address or by the name of an active
+/// validator.
+async fn resolve_validator_address(
+    client: &IotaClient,
+    validator: &str,
+) -> Result<IotaAddress, anyhow::Error> {
+    if let Ok(address) = IotaAddress::from_str(validator) {
+        return Ok(address);
+    }
+    let system_state = client
+        .governance_api()
+        .get_latest_iota_system_state()
+        .await?;
+    let mut matching = system_state
+        .active_validators
+        .iter()
+        .filter(|summary| summary.name.eq_ignore_ascii_case(validator));
+    match (matching.next(), matching.next()) {
+        (Some(summary), None) => Ok(summary.iota_address),
+        (None, _) => bail!("No active validator with address or name [{validator}]"),
+        (Some(_), Some(_)) => bail!(
+            "Multiple active validators are named [{validator}], use the validator address instead"
+        ),
+    }
+}
+
 /// Dry run, execute, or serialize a transaction.
 ///
 /// This basically extracts the logical code for each command that deals with
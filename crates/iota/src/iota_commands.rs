@@ -29,8 +29,9 @@ use iota_genesis_builder::{SnapshotSource, SnapshotUrl};
 use iota_graphql_rpc::{
     config::ConnectionConfig, test_infra::cluster::start_graphql_server_with_fn_rpc,
 };
-#[cfg(feature = "indexer")]
+#[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
 use iota_indexer::test_utils::{ReaderWriterConfig, start_test_indexer};
+use iota_json_rpc_types::{IotaObjectDataOptions, IotaRawData, IotaRawMovePackage};
 use iota_keys::{
     keypair_file::read_key,
     keystore::{AccountKeystore, FileBasedKeystore, Keystore},
@@ -38,6 +39,7 @@ use iota_keys::{
 use iota_move::{self, execute_move_command};
 use iota_move_build::IotaPackageHooks;
 use iota_sdk::{
+    IotaClient,
     iota_client_config::{IotaClientConfig, IotaEnv},
     wallet_context::WalletContext,
 };
@@ -49,10 +51,11 @@ use iota_swarm_config::{
     node_config_builder::FullnodeConfigBuilder,
 };
 use iota_types::{
-    base_types::IotaAddress,
+    base_types::{IotaAddress, ObjectID},
     crypto::{IotaKeyPair, SignatureScheme, ToFromBytes},
 };
 use move_analyzer::analyzer;
+use move_binary_format::CompiledModule;
 use move_package::BuildConfig;
 use rand::rngs::OsRng;
 use tempfile::tempdir;
@@ -63,7 +66,7 @@ use crate::{
     console::start_console,
     fire_drill::{FireDrill, run_fire_drill},
     genesis_ceremony::{Ceremony, run},
-    keytool::KeyToolCommand,
+    keytool::{KeyToolCommand, prompt_for_keystore_passphrase},
     validator_commands::IotaValidatorCommand,
 };
 
@@ -74,10 +77,17 @@ const DEFAULT_FAUCET_NANOS_AMOUNT: u64 = 200_000_000_000; // 200 IOTA
 const DEFAULT_FAUCET_PORT: u16 = 9123;
 #[cfg(feature = "indexer")]
 const DEFAULT_GRAPHQL_PORT: u16 = 9125;
-#[cfg(feature = "indexer")]
+#[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
 const DEFAULT_INDEXER_PORT: u16 = 9124;
 
+/// The database connection the indexer is started with. Postgres is used when
+/// the `indexer` feature is enabled, SQLite otherwise.
 #[cfg(feature = "indexer")]
+type IndexerConnection = diesel::PgConnection;
+#[cfg(all(feature = "indexer-sqlite", not(feature = "indexer")))]
+type IndexerConnection = diesel::SqliteConnection;
+
+#[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
 #[derive(Args)]
 pub struct IndexerFeatureArgs {
     /// Start an indexer with default host and port: 0.0.0.0:9124. This flag
@@ -101,6 +111,7 @@ pub struct IndexerFeatureArgs {
     /// `--with-graphql=0.0.0.0:9125` Note that GraphQL requires a running
     /// indexer, which will be enabled by default if the `--with-indexer`
     /// flag is not set.
+    #[cfg(feature = "indexer")]
     #[clap(
             long,
             default_missing_value = "0.0.0.0:9125",
@@ -111,37 +122,56 @@ pub struct IndexerFeatureArgs {
     with_graphql: Option<String>,
 
     /// Port for the Indexer Postgres DB. Default port is 5432.
+    #[cfg(feature = "indexer")]
     #[clap(long, default_value = "5432")]
     pg_port: u16,
 
     /// Hostname for the Indexer Postgres DB. Default host is localhost.
+    #[cfg(feature = "indexer")]
     #[clap(long, default_value = "localhost")]
     pg_host: String,
 
     /// DB name for the Indexer Postgres DB. Default DB name is iota_indexer.
+    #[cfg(feature = "indexer")]
     #[clap(long, default_value = "iota_indexer")]
     pg_db_name: String,
 
     /// DB username for the Indexer Postgres DB. Default username is postgres.
+    #[cfg(feature = "indexer")]
     #[clap(long, default_value = "postgres")]
     pg_user: String,
 
     /// DB password for the Indexer Postgres DB. Default password is postgrespw.
+    #[cfg(feature = "indexer")]
     #[clap(long, default_value = "postgrespw")]
     pg_password: String,
+
+    /// Path of the SQLite database file for the indexer. By default, a new
+    /// database is created in a temporary directory.
+    #[cfg(all(feature = "indexer-sqlite", not(feature = "indexer")))]
+    #[clap(long, value_name = "SQLITE_DB_PATH")]
+    indexer_db_path: Option<PathBuf>,
 }
 
-#[cfg(feature = "indexer")]
+#[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
 impl IndexerFeatureArgs {
     pub fn for_testing() -> Self {
         Self {
             with_indexer: None,
+            #[cfg(feature = "indexer")]
             with_graphql: None,
+            #[cfg(feature = "indexer")]
             pg_port: 5432,
+            #[cfg(feature = "indexer")]
             pg_host: "localhost".to_string(),
+            #[cfg(feature = "indexer")]
             pg_db_name: "iota_indexer".to_string(),
+            #[cfg(feature = "indexer")]
             pg_user: "postgres".to_string(),
+            #[cfg(feature = "indexer")]
             pg_password: "postgrespw".to_string(),
+            #[cfg(all(feature = "indexer-sqlite", not(feature = "indexer")))]
+            indexer_db_path: None,
         }
     }
 }
@@ -160,7 +190,9 @@ pub enum IotaCommand {
     /// config_dir is not passed, it will generate a new default directory,
     /// generate the genesis blob, and start the network.
     ///
-    /// Note that if you want to start an indexer, Postgres DB is required.
+    /// Note that if you want to start an indexer, Postgres DB is required,
+    /// unless the CLI was built with the `indexer-sqlite` feature, in which
+    /// case the indexer stores its data in a local SQLite database.
     #[clap(name = "start")]
     Start {
         /// Config directory that will be used to store network config, node db,
@@ -202,7 +234,7 @@ pub enum IotaCommand {
         #[clap(long)]
         faucet_amount: Option<u64>,
 
-        #[cfg(feature = "indexer")]
+        #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
         #[clap(flatten)]
         indexer_feature_args: IndexerFeatureArgs,
 
@@ -375,7 +407,7 @@ impl IotaCommand {
                 force_regenesis,
                 with_faucet,
                 faucet_amount,
-                #[cfg(feature = "indexer")]
+                #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
                 indexer_feature_args,
                 fullnode_rpc_port,
                 no_full_node,
@@ -387,7 +419,7 @@ impl IotaCommand {
                     config_dir.clone(),
                     with_faucet,
                     faucet_amount,
-                    #[cfg(feature = "indexer")]
+                    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
                     indexer_feature_args,
                     force_regenesis,
                     epoch_duration_ms,
@@ -434,8 +466,14 @@ impl IotaCommand {
             } => {
                 let keystore_path =
                     keystore_path.unwrap_or(iota_config_dir()?.join(IOTA_KEYSTORE_FILENAME));
-                let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
-                cmd.execute(&mut keystore).await?.print(!json);
+                let mut keystore = Keystore::from_path(&keystore_path)?;
+                prompt_for_keystore_passphrase(&mut keystore);
+                let was_plaintext = matches!(keystore, Keystore::File(_));
+                let output = cmd.execute(&mut keystore).await?;
+                if was_plaintext && matches!(keystore, Keystore::EncryptedFile(_)) {
+                    mark_client_config_keystore_encrypted(&keystore_path)?;
+                }
+                output.print(!json);
                 Ok(())
             }
             IotaCommand::Console { config } => {
@@ -453,6 +491,7 @@ impl IotaCommand {
                 let config_path = config.unwrap_or(iota_config_dir()?.join(IOTA_CLIENT_CONFIG));
                 prompt_if_no_config(&config_path, accept_defaults).await?;
                 let mut context = WalletContext::new(&config_path, None, None)?;
+                prompt_for_keystore_passphrase(context.config_mut().keystore_mut());
                 if let Some(cmd) = cmd {
                     cmd.execute(&mut context).await?.print(!json);
                 } else {
@@ -472,6 +511,7 @@ impl IotaCommand {
                 let config_path = config.unwrap_or(iota_config_dir()?.join(IOTA_CLIENT_CONFIG));
                 prompt_if_no_config(&config_path, accept_defaults).await?;
                 let mut context = WalletContext::new(&config_path, None, None)?;
+                prompt_for_keystore_passphrase(context.config_mut().keystore_mut());
                 if let Some(cmd) = cmd {
                     cmd.execute(&mut context).await?.print(!json);
                 } else {
@@ -503,6 +543,21 @@ impl IotaCommand {
                         let chain_id = client.read_api().get_chain_identifier().await.ok();
                         build.chain_id = chain_id.clone();
                     }
+                    iota_move::Command::Bindgen(bindgen) => {
+                        // The bindings of an on-chain package are generated from its modules and
+                        // the ones of the packages it links to, fetched from the network.
+                        if let Some(package_id) = bindgen.package_id {
+                            let config = client_config
+                                .unwrap_or(iota_config_dir()?.join(IOTA_CLIENT_CONFIG));
+                            prompt_if_no_config(&config, false).await?;
+                            let context = WalletContext::new(&config, None, None)?;
+                            let client = context.get_client().await?;
+                            let (modules, dependency_modules) =
+                                fetch_package_modules(&client, package_id).await?;
+                            bindgen.modules = modules;
+                            bindgen.dependency_modules = dependency_modules;
+                        }
+                    }
                     _ => (),
                 };
                 execute_move_command(package_path.as_deref(), build_config, cmd)
@@ -601,7 +656,8 @@ async fn start(
     config_dir: Option<PathBuf>,
     with_faucet: Option<String>,
     faucet_amount: Option<u64>,
-    #[cfg(feature = "indexer")] indexer_feature_args: IndexerFeatureArgs,
+    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
+    indexer_feature_args: IndexerFeatureArgs,
     force_regenesis: bool,
     epoch_duration_ms: Option<u64>,
     fullnode_rpc_port: u16,
@@ -626,13 +682,18 @@ async fn start(
         pg_user,
         pg_password,
     } = indexer_feature_args;
+    #[cfg(all(feature = "indexer-sqlite", not(feature = "indexer")))]
+    let IndexerFeatureArgs {
+        with_indexer,
+        indexer_db_path,
+    } = indexer_feature_args;
 
     #[cfg(feature = "indexer")]
     if with_graphql.is_some() {
         with_indexer = Some(with_indexer.unwrap_or_default());
     }
 
-    #[cfg(feature = "indexer")]
+    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
     if with_indexer.is_some() {
         ensure!(
             !no_full_node,
@@ -711,13 +772,13 @@ async fn start(
             .with_network_config(network_config);
     }
 
-    #[cfg(feature = "indexer")]
+    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
     let data_ingestion_path = tempdir()?.into_path();
 
     // the indexer requires to set the fullnode's data ingestion directory
     // note that this overrides the default configuration that is set when running
     // the genesis command, which sets data_ingestion_dir to None.
-    #[cfg(feature = "indexer")]
+    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
     if with_indexer.is_some() {
         swarm_builder = swarm_builder.with_data_ingestion_dir(data_ingestion_path.clone());
     }
@@ -744,15 +805,24 @@ async fn start(
     info!("Fullnode URL: {}", fullnode_url);
     #[cfg(feature = "indexer")]
     let pg_address = format!("postgres://{pg_user}:{pg_password}@{pg_host}:{pg_port}/{pg_db_name}");
-
     #[cfg(feature = "indexer")]
+    let indexer_db_url = pg_address.clone();
+    #[cfg(all(feature = "indexer-sqlite", not(feature = "indexer")))]
+    let indexer_db_url = match indexer_db_path {
+        Some(path) => path,
+        None => tempdir()?.into_path().join("indexer.db"),
+    }
+    .to_string_lossy()
+    .into_owned();
+
+    #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
     if let Some(input) = with_indexer {
         let indexer_address = parse_host_port(input, DEFAULT_INDEXER_PORT)
             .map_err(|_| anyhow!("Invalid indexer host and port"))?;
         tracing::info!("Starting the indexer service at {indexer_address}");
         // Start in writer mode
-        start_test_indexer::<diesel::PgConnection>(
-            Some(pg_address.clone()),
+        start_test_indexer::<IndexerConnection>(
+            Some(indexer_db_url.clone()),
             fullnode_url.clone(),
             ReaderWriterConfig::writer_mode(None),
             Some(data_ingestion_path.clone()),
@@ -762,8 +832,8 @@ async fn start(
         info!("Indexer in writer mode started");
 
         // Start in reader mode
-        start_test_indexer::<diesel::PgConnection>(
-            Some(pg_address.clone()),
+        start_test_indexer::<IndexerConnection>(
+            Some(indexer_db_url.clone()),
             fullnode_url.clone(),
             ReaderWriterConfig::reader_mode(indexer_address.to_string()),
             Some(data_ingestion_path),
@@ -916,7 +986,7 @@ async fn genesis(
     if write_config.is_none() && !files.is_empty() {
         if force {
             // check old keystore and client.yaml is compatible
-            let is_compatible = FileBasedKeystore::new(&keystore_path).is_ok()
+            let is_compatible = Keystore::from_path(&keystore_path).is_ok()
                 && PersistedConfig::<IotaClientConfig>::read(&client_path).is_ok();
             // Keep keystore and client.yaml if they are compatible
             if is_compatible {
@@ -973,7 +1043,7 @@ async fn genesis(
                 // Make a new genesis config from the provided ip addresses.
                 GenesisConfig::new_for_benchmarks(&ips)
             } else if keystore_path.exists() {
-                let existing_keys = FileBasedKeystore::new(&keystore_path)?.addresses();
+                let existing_keys = Keystore::from_path(&keystore_path)?.addresses();
                 GenesisConfig::for_local_testing_with_addresses(existing_keys)
             } else {
                 GenesisConfig::for_local_testing()
@@ -1200,7 +1270,8 @@ async fn prompt_if_no_config(
                 .parent()
                 .unwrap_or(&iota_config_dir()?)
                 .join(IOTA_KEYSTORE_FILENAME);
-            let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+            let mut keystore = Keystore::from_path(&keystore_path)?;
+            prompt_for_keystore_passphrase(&mut keystore);
             // Get an existing address or generate a new one
             let active_address = if let Some(existing_address) = keystore.addresses().first() {
                 println!("Using existing address {existing_address} as active address.");
@@ -1246,6 +1317,84 @@ fn read_line() -> Result<String, anyhow::Error> {
     Ok(s.trim_end().to_string())
 }
 
+/// Fetches the modules of an on-chain package, along with the modules of the
+/// packages it links to.
+async fn fetch_package_modules(
+    client: &IotaClient,
+    package_id: ObjectID,
+) -> Result<(Vec<CompiledModule>, Vec<CompiledModule>), anyhow::Error> {
+    let package = fetch_package(client, package_id).await?;
+    let modules = deserialize_package_modules(&package)?;
+    let mut dependency_modules = vec![];
+    for upgrade_info in package.linkage_table.values() {
+        let dependency = fetch_package(client, upgrade_info.upgraded_id).await?;
+        dependency_modules.extend(deserialize_package_modules(&dependency)?);
+    }
+    Ok((modules, dependency_modules))
+}
+
+async fn fetch_package(
+    client: &IotaClient,
+    package_id: ObjectID,
+) -> Result<IotaRawMovePackage, anyhow::Error> {
+    let object = client
+        .read_api()
+        .get_object_with_options(package_id, IotaObjectDataOptions::bcs_lossless())
+        .await?
+        .into_object()?;
+    let Some(IotaRawData::Package(package)) = object.bcs else {
+        bail!("Object {package_id} is not a package");
+    };
+    Ok(package)
+}
+
+fn deserialize_package_modules(
+    package: &IotaRawMovePackage,
+) -> Result<Vec<CompiledModule>, anyhow::Error> {
+    package
+        .module_map
+        .iter()
+        .map(|(name, bytes)| {
+            CompiledModule::deserialize_with_defaults(bytes).map_err(|e| {
+                anyhow!(
+                    "Failed to deserialize module {name} of package {}: {e}",
+                    package.id
+                )
+            })
+        })
+        .collect()
+}
+
+/// Switches the keystore of the client config stored next to `keystore_path`
+/// to `EncryptedFile`, if it references that keystore. The config is edited as
+/// plain YAML because it cannot be loaded while its `File` keystore entry
+/// points to an encrypted keystore.
+fn mark_client_config_keystore_encrypted(keystore_path: &Path) -> Result<(), anyhow::Error> {
+    let config_path = keystore_path.with_file_name(IOTA_CLIENT_CONFIG);
+    if !config_path.exists() {
+        return Ok(());
+    }
+    let mut config: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(&config_path)?)?;
+    let Some(keystore) = config.get_mut("keystore") else {
+        return Ok(());
+    };
+    let Some(path) = keystore.get("File").and_then(|path| path.as_str()) else {
+        return Ok(());
+    };
+    if fs::canonicalize(path).ok() != fs::canonicalize(keystore_path).ok() {
+        return Ok(());
+    }
+    let mut encrypted = serde_yaml::Mapping::new();
+    encrypted.insert("EncryptedFile".into(), path.into());
+    *keystore = serde_yaml::Value::Mapping(encrypted);
+    fs::write(&config_path, serde_yaml::to_string(&config)?)?;
+    info!(
+        "Client config {:?} now uses the encrypted keystore.",
+        config_path
+    );
+    Ok(())
+}
+
 /// Parse the input string into a SocketAddr, with a default port if none is
 /// provided.
 pub fn parse_host_port(
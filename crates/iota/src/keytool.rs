@@ -8,7 +8,7 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use aws_config::BehaviorVersion;
 use aws_sdk_kms::{
     Client as KmsClient,
@@ -30,7 +30,10 @@ use iota_keys::{
         read_authority_keypair_from_file, read_keypair_from_file, write_authority_keypair_to_file,
         write_keypair_to_file,
     },
-    keystore::{AccountKeystore, Keystore},
+    keystore::{
+        AccountKeystore, EncryptedFileKeystore, IOTA_KEYSTORE_PASSPHRASE_ENV, Keystore,
+        ScryptParams,
+    },
 };
 use iota_types::{
     base_types::IotaAddress,
@@ -99,6 +102,11 @@ pub enum KeyToolCommand {
         #[clap(long, default_value = "0")]
         cur_epoch: u64,
     },
+    /// Encrypt the private keys of the keystore in place with a key derived
+    /// from a passphrase (scrypt and AES-256-GCM). The passphrase is read from
+    /// the IOTA_KEYSTORE_PASSPHRASE environment variable, or prompted for if it
+    /// is not set. Afterwards keys are decrypted on demand when they are used.
+    Encrypt,
     /// Generate a new keypair with key scheme flag {ed25519 | secp256k1 |
     /// secp256r1} with optional derivation path, default to
     /// m/44'/4218'/0'/0'/0' for ed25519 or m/54'/4218'/0'/0/0 for secp256k1
@@ -325,6 +333,13 @@ pub struct DecodeOrVerifyTxOutput {
     result: Option<IotaResult>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedKeystore {
+    keystore_path: PathBuf,
+    encrypted_keys: usize,
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Key {
@@ -431,6 +446,7 @@ pub enum CommandOutput {
     Convert(ConvertOutput),
     DecodeMultiSig(DecodedMultiSigOutput),
     DecodeOrVerifyTx(DecodeOrVerifyTxOutput),
+    Encrypt(EncryptedKeystore),
     Error(String),
     Generate(Key),
     Import(Key),
@@ -553,6 +569,23 @@ impl KeyToolCommand {
                     }
                 }
             }
+            KeyToolCommand::Encrypt => {
+                let Keystore::File(file_keystore) = keystore else {
+                    bail!("Only a plaintext file keystore can be encrypted");
+                };
+                let passphrase = read_keystore_passphrase(true)?;
+                let encrypted = EncryptedFileKeystore::from_file_keystore(
+                    file_keystore,
+                    &passphrase,
+                    ScryptParams::default(),
+                )?;
+                let output = EncryptedKeystore {
+                    keystore_path: encrypted.path().to_path_buf(),
+                    encrypted_keys: encrypted.keys().len(),
+                };
+                *keystore = Keystore::EncryptedFile(encrypted);
+                CommandOutput::Encrypt(output)
+            }
             KeyToolCommand::Generate {
                 key_scheme,
                 derivation_path,
@@ -1299,3 +1332,27 @@ fn anemo_styling(pk: &PublicKey) -> Option<String> {
         None
     }
 }
+
+/// Reads the passphrase of an encrypted keystore from the
+/// `IOTA_KEYSTORE_PASSPHRASE` environment variable, or prompts for it. When
+/// `confirm` is set the passphrase has to be entered twice.
+pub fn read_keystore_passphrase(confirm: bool) -> Result<String, anyhow::Error> {
+    if let Ok(passphrase) = std::env::var(IOTA_KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    let prompt = inquire::Password::new("Keystore passphrase:");
+    let prompt = if confirm {
+        prompt
+    } else {
+        prompt.without_confirmation()
+    };
+    Ok(prompt.prompt()?)
+}
+
+/// Makes an encrypted keystore prompt for its passphrase the first time a
+/// private key is needed.
+pub fn prompt_for_keystore_passphrase(keystore: &mut Keystore) {
+    if let Keystore::EncryptedFile(encrypted) = keystore {
+        encrypted.set_passphrase_provider(Arc::new(|| read_keystore_passphrase(false)));
+    }
+}
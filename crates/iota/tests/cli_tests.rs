@@ -21,7 +21,7 @@ use std::{
 };
 
 use expect_test::expect;
-#[cfg(feature = "indexer")]
+#[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
 use iota::iota_commands::IndexerFeatureArgs;
 use iota::{
     client_commands::{
@@ -157,7 +157,7 @@ async fn test_start() -> Result<(), anyhow::Error> {
             faucet_amount: None,
             fullnode_rpc_port: 9000,
             epoch_duration_ms: None,
-            #[cfg(feature = "indexer")]
+            #[cfg(any(feature = "indexer", feature = "indexer-sqlite"))]
             indexer_feature_args: IndexerFeatureArgs::for_testing(),
             local_migration_snapshots: vec![],
             remote_migration_snapshots: vec![],
@@ -4509,6 +4509,7 @@ async fn test_move_new() -> Result<(), anyhow::Error> {
                 list: false,
                 num_threads: 1,
                 report_statistics: None,
+                reports: vec![],
                 verbose_mode: false,
                 seed: None,
                 rand_num_iters: None,
@@ -4525,6 +4526,46 @@ async fn test_move_new() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_move_bindgen() -> Result<(), anyhow::Error> {
+    let current_dir = std::env::current_dir()?;
+    let temp_dir = tempfile::tempdir()?;
+    let output = temp_dir.path().join("bindings.rs");
+    let mut package_path = PathBuf::from(TEST_DATA_DIR);
+    package_path.push("move_call_args_linter");
+    IotaCommand::Move {
+        package_path: Some(package_path),
+        config: None,
+        build_config: BuildConfig::new_for_testing().config,
+        cmd: iota_move::Command::Bindgen(iota_move::bindgen::Bindgen {
+            package_id: None,
+            output: Some(output.clone()),
+            modules: vec![],
+            dependency_modules: vec![],
+        }),
+    }
+    .execute()
+    .await?;
+
+    // iota_move::Command::Bindgen builds the package, which changes the current
+    // dir, so we have to switch back here
+    std::env::set_current_dir(current_dir)?;
+
+    let bindings = std::fs::read_to_string(output)?;
+    assert!(bindings.contains("pub mod object_basics {"));
+    assert!(bindings.contains("pub struct Object {"));
+    assert!(bindings.contains("pub id: ::iota_types::id::UID,"));
+    assert!(bindings.contains("pub struct NewValueEvent {"));
+    // only the emitted struct can be decoded from an event
+    assert_eq!(bindings.matches("pub fn from_event(").count(), 1);
+    // the transaction context is not an argument of the calls
+    assert!(bindings.contains("pub fn create("));
+    assert!(bindings.contains("arg0: u64,"));
+    assert!(bindings.contains("arg1: ::iota_types::base_types::IotaAddress,"));
+    assert!(!bindings.contains("arg2"));
+    Ok(())
+}
+
 #[sim_test]
 async fn test_call_command_emit_args() -> Result<(), anyhow::Error> {
     // Publish the package
@@ -4673,3 +4714,69 @@ async fn test_call_command_emit_args() -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[sim_test]
+async fn test_stake_and_unstake() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await;
+    let rgp = test_cluster.get_reference_gas_price().await;
+    let address = test_cluster.get_address_0();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let validator = client
+        .governance_api()
+        .get_latest_iota_system_state()
+        .await?
+        .active_validators
+        .first()
+        .unwrap()
+        .clone();
+
+    // Stake by validator name, splitting the amount from the gas coin
+    let resp = IotaClientCommands::Stake {
+        validator: validator.name.to_uppercase(),
+        amount: Some(1_000_000_000),
+        coins: vec![],
+        opts: OptsWithGas::for_testing(None, rgp * TEST_ONLY_GAS_UNIT_FOR_GENERIC),
+    }
+    .execute(context)
+    .await?;
+    let effects = resp.tx_block_response().unwrap().effects.as_ref().unwrap();
+    assert!(effects.status().is_ok());
+
+    let resp = IotaClientCommands::Stakes {
+        address: Some(KeyIdentity::Address(address)),
+    }
+    .execute(context)
+    .await?;
+    let IotaClientCommandResult::Stakes(delegated_stakes) = resp else {
+        panic!("Command failed");
+    };
+    assert_eq!(delegated_stakes.len(), 1);
+    assert_eq!(
+        delegated_stakes[0].validator_address,
+        validator.iota_address
+    );
+    let stake = delegated_stakes[0].stakes.first().unwrap();
+    assert_eq!(stake.principal, 1_000_000_000);
+
+    let resp = IotaClientCommands::Unstake {
+        staked_iota_id: stake.staked_iota_id,
+        opts: OptsWithGas::for_testing(None, rgp * TEST_ONLY_GAS_UNIT_FOR_GENERIC),
+    }
+    .execute(context)
+    .await?;
+    let effects = resp.tx_block_response().unwrap().effects.as_ref().unwrap();
+    assert!(effects.status().is_ok());
+
+    let resp = IotaClientCommands::Stakes {
+        address: Some(KeyIdentity::Address(address)),
+    }
+    .execute(context)
+    .await?;
+    let IotaClientCommandResult::Stakes(delegated_stakes) = resp else {
+        panic!("Command failed");
+    };
+    assert!(delegated_stakes.iter().all(|d| d.stakes.is_empty()));
+
+    Ok(())
+}
@@ -584,6 +584,17 @@ impl<T: Send + Sync, V: store::SimulatorStore + Send + Sync> RestStateReader for
         todo!()
     }
 
+    fn account_coins_iter(
+        &self,
+        _owner: IotaAddress,
+        _coin_type: Option<&StructTag>,
+        _cursor: Option<(&StructTag, ObjectID)>,
+    ) -> iota_types::storage::error::Result<
+        Box<dyn Iterator<Item = iota_types::storage::AccountCoinInfo> + '_>,
+    > {
+        todo!()
+    }
+
     fn dynamic_field_iter(
         &self,
         _parent: ObjectID,
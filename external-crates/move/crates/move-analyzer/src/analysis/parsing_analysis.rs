@@ -367,7 +367,7 @@ impl<'a> ParsingAnalysisContext<'a> {
                 {
                     mod_defs.call_infos.insert(
                         last_chain_symbol_loc(chain),
-                        CallInfo::new(/* do_call */ false, &v.value),
+                        CallInfo::new(/* do_call */ false, v),
                     );
                 };
             }
@@ -456,7 +456,7 @@ impl<'a> ParsingAnalysisContext<'a> {
                 {
                     mod_defs
                         .call_infos
-                        .insert(name.loc, CallInfo::new(/* do_call */ true, &v.value));
+                        .insert(name.loc, CallInfo::new(/* do_call */ true, v));
                 };
             }
             E::Index(e, v) => {
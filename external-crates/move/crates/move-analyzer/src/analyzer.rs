@@ -7,8 +7,9 @@ use anyhow::Result;
 use crossbeam::channel::{bounded, select};
 use lsp_server::{Connection, Message, Notification, Request, Response};
 use lsp_types::{
-    notification::Notification as _, request::Request as _, CompletionOptions, Diagnostic,
-    HoverProviderCapability, InlayHintOptions, InlayHintServerCapabilities, OneOf, SaveOptions,
+    notification::Notification as _, request::Request as _, CodeActionKind, CodeActionOptions,
+    CodeActionProviderCapability, CompletionOptions, Diagnostic, HoverProviderCapability,
+    InlayHintOptions, InlayHintServerCapabilities, OneOf, SaveOptions, SignatureHelpOptions,
     TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
     TypeDefinitionProviderCapability, WorkDoneProgressOptions,
 };
@@ -20,8 +21,8 @@ use std::{
 };
 
 use crate::{
-    completion::on_completion_request, context::Context, inlay_hints, symbols,
-    vfs::on_text_document_sync_notification,
+    code_action, completion::on_completion_request, context::Context, formatting, inlay_hints,
+    signature_help, symbols, vfs::on_text_document_sync_notification,
 };
 use url::Url;
 use vfs::{impls::memory::MemoryFS, VfsPath};
@@ -99,7 +100,25 @@ pub fn run() {
         definition_provider: Some(OneOf::Left(true)),
         type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
         references_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Left(true)),
+        // The server shows the signature of the called function as the user is
+        // typing its arguments.
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+            resolve_provider: None,
+        })),
         document_symbol_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
         inlay_hint_provider: Some(OneOf::Right(InlayHintServerCapabilities::Options(
             InlayHintOptions {
                 work_done_progress_options: WorkDoneProgressOptions {
@@ -309,6 +328,18 @@ fn on_request(
         lsp_types::request::References::METHOD => {
             symbols::on_references_request(context, request);
         }
+        lsp_types::request::Rename::METHOD => {
+            symbols::on_rename_request(context, request);
+        }
+        lsp_types::request::SignatureHelpRequest::METHOD => {
+            signature_help::on_signature_help_request(context, request);
+        }
+        lsp_types::request::CodeActionRequest::METHOD => {
+            code_action::on_code_action_request(context, request);
+        }
+        lsp_types::request::Formatting::METHOD => {
+            formatting::on_formatting_request(context, request, ide_files_root.clone());
+        }
         lsp_types::request::HoverRequest::METHOD => {
             symbols::on_hover_request(context, request);
         }
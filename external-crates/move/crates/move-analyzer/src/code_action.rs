@@ -0,0 +1,298 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    context::Context,
+    symbols::{mod_ident_to_ide_string, ModuleDefs, SymbolicatorRunner, Symbols},
+    utils::utf16_line_slice,
+};
+use lsp_server::Request;
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, NumberOrString,
+    Position, Range, TextEdit, WorkspaceEdit,
+};
+use move_compiler::{
+    diagnostics::codes::{
+        custom, DiagnosticInfo, NameResolution, Severity, UnusedItem, WarningFilter,
+    },
+    iota_mode, linters,
+    shared::Identifier,
+};
+use move_symbol_pool::Symbol;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::Path,
+};
+use url::Url;
+
+/// Handles code action request of the language server
+pub fn on_code_action_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<CodeActionParams>(request.params.clone())
+        .expect("could not deserialize code action request");
+
+    let uri = parameters.text_document.uri;
+    let fpath = uri.to_file_path().unwrap();
+    let actions = code_actions(context, &uri, &fpath, &parameters.context.diagnostics)
+        .unwrap_or_default()
+        .into_iter()
+        .map(CodeActionOrCommand::CodeAction)
+        .collect::<Vec<_>>();
+
+    let response = lsp_server::Response::new_ok(request.id.clone(), actions);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send code action response: {:?}", err);
+    }
+}
+
+fn code_actions(
+    context: &Context,
+    uri: &Url,
+    fpath: &Path,
+    diagnostics: &[Diagnostic],
+) -> Option<Vec<CodeAction>> {
+    let symbols_map = &context.symbols.lock().ok()?;
+    let symbols =
+        SymbolicatorRunner::root_dir(fpath).and_then(|pkg_path| symbols_map.get(&pkg_path))?;
+    code_actions_internal(symbols, uri, fpath, diagnostics)
+}
+
+/// Computes quick fixes for the given diagnostics of a file, which are:
+/// - adding a missing `use` for unbound modules and module members
+/// - prefixing unused variables with an underscore
+/// - suppressing lints with an `#[allow(lint(...))]` attribute on the enclosing
+///   module member
+pub fn code_actions_internal(
+    symbols: &Symbols,
+    uri: &Url,
+    fpath: &Path,
+    diagnostics: &[Diagnostic],
+) -> Option<Vec<CodeAction>> {
+    let fhash = symbols.file_hash(fpath)?;
+    let (_, content) = symbols.files.get(&fhash)?;
+    let mod_defs = symbols.file_mods.get(fpath)?;
+
+    let unbound_module = DiagnosticInfo::from(NameResolution::UnboundModule)
+        .render()
+        .0;
+    let unbound_type = DiagnosticInfo::from(NameResolution::UnboundType).render().0;
+    let unbound_name = DiagnosticInfo::from(NameResolution::UnboundUnscopedName)
+        .render()
+        .0;
+    let unused_variable = DiagnosticInfo::from(UnusedItem::Variable).render().0;
+    let lints = lint_filters();
+
+    let mut actions = vec![];
+    for diag in diagnostics {
+        let Some(NumberOrString::String(code)) = &diag.code else {
+            continue;
+        };
+        let Some(module) = enclosing_module(symbols, mod_defs, diag.range.start) else {
+            continue;
+        };
+        let Some(text) = range_text(&content, &diag.range) else {
+            continue;
+        };
+        let quick_fix = |title: String, edit: TextEdit| CodeAction {
+            title,
+            kind: Some(CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diag.clone()]),
+            edit: Some(WorkspaceEdit::new(HashMap::from([(
+                uri.clone(),
+                vec![edit],
+            )]))),
+            ..Default::default()
+        };
+
+        if code == &unbound_module || code == &unbound_type || code == &unbound_name {
+            let name = leading_identifier(text);
+            for path in use_candidates(
+                symbols,
+                name,
+                code == &unbound_module,
+                code == &unbound_name,
+            ) {
+                let edit = use_edit(symbols, &content, module, &path);
+                actions.push(quick_fix(format!("Add 'use {path};'"), edit));
+            }
+        } else if code == &unused_variable {
+            let name = leading_identifier(text);
+            if !name.is_empty() && !name.starts_with('_') {
+                let edit = TextEdit::new(
+                    Range::new(diag.range.start, diag.range.start),
+                    "_".to_string(),
+                );
+                actions.push(quick_fix(
+                    format!("Prefix '{name}' with an underscore"),
+                    edit,
+                ));
+            }
+        } else if let Some((category, name)) = lints.get(code) {
+            let Some(member_line) = enclosing_member_line(symbols, module, diag.range.start) else {
+                continue;
+            };
+            let attribute = format!("#[allow({category}({name}))]");
+            let indent = line_indent(&content, member_line);
+            let edit = TextEdit::new(
+                Range::new(Position::new(member_line, 0), Position::new(member_line, 0)),
+                format!("{indent}{attribute}\n"),
+            );
+            actions.push(quick_fix(format!("Suppress with '{attribute}'"), edit));
+        }
+    }
+    Some(actions)
+}
+
+/// Maps the codes of the known lints to the category and name used to
+/// suppress them in `#[allow(...)]` attributes.
+fn lint_filters() -> BTreeMap<String, (Symbol, &'static str)> {
+    let mut lints = BTreeMap::new();
+    for (category, filters) in [
+        linters::known_filters(),
+        iota_mode::linters::known_filters(),
+    ] {
+        let Some(category) = category else {
+            continue;
+        };
+        for filter in filters {
+            if let WarningFilter::Code {
+                prefix,
+                category: diag_category,
+                code,
+                name: Some(name),
+            } = filter
+            {
+                let info = custom(
+                    prefix.unwrap_or_default(),
+                    Severity::Warning,
+                    diag_category,
+                    code,
+                    "",
+                );
+                lints.insert(info.render().0, (category, name));
+            }
+        }
+    }
+    lints
+}
+
+/// Returns the module (of the file) the position belongs to.
+fn enclosing_module<'a>(
+    symbols: &Symbols,
+    mod_defs: &'a BTreeSet<ModuleDefs>,
+    position: Position,
+) -> Option<&'a ModuleDefs> {
+    mod_defs
+        .iter()
+        .filter(|mod_def| loc_line(symbols, &mod_def.name_loc) <= position.line)
+        .max_by_key(|mod_def| loc_line(symbols, &mod_def.name_loc))
+}
+
+/// Returns the line where the declaration of the module member (function,
+/// struct or enum) the position belongs to starts.
+fn enclosing_member_line(
+    symbols: &Symbols,
+    module: &ModuleDefs,
+    position: Position,
+) -> Option<u32> {
+    module
+        .functions
+        .values()
+        .chain(module.structs.values())
+        .chain(module.enums.values())
+        .map(|member_def| loc_line(symbols, &member_def.name_loc))
+        .filter(|line| *line <= position.line)
+        .max()
+}
+
+/// Returns the paths of the modules (or module members) that can be used to
+/// bind the unbound `name`.
+fn use_candidates(
+    symbols: &Symbols,
+    name: &str,
+    is_module: bool,
+    include_functions: bool,
+) -> BTreeSet<String> {
+    if name.is_empty() {
+        return BTreeSet::new();
+    }
+    let name = Symbol::from(name);
+    symbols
+        .file_mods
+        .values()
+        .flatten()
+        .filter_map(|mod_defs| {
+            let mod_path = mod_ident_to_ide_string(&mod_defs.ident);
+            if is_module {
+                (mod_defs.ident.module.value() == name).then_some(mod_path)
+            } else {
+                (mod_defs.structs.contains_key(&name)
+                    || mod_defs.enums.contains_key(&name)
+                    || (include_functions && mod_defs.functions.contains_key(&name)))
+                .then(|| format!("{mod_path}::{name}"))
+            }
+        })
+        .collect()
+}
+
+/// Computes the edit adding `use {path};` to the module, next to its existing
+/// `use` declarations if any, or right after the module declaration
+/// otherwise.
+fn use_edit(symbols: &Symbols, content: &str, module: &ModuleDefs, path: &str) -> TextEdit {
+    let module_line = loc_line(symbols, &module.name_loc);
+    let existing_use = content
+        .lines()
+        .enumerate()
+        .skip(module_line as usize + 1)
+        .find(|(_, line)| line.trim_start().starts_with("use "));
+    let (line, indent) = match existing_use {
+        Some((line, _)) => (line as u32, line_indent(content, line as u32)),
+        None => {
+            let block_module = content
+                .lines()
+                .nth(module_line as usize)
+                .is_some_and(|line| line.trim_end().ends_with('{'));
+            let indent = if block_module { "    " } else { "" };
+            (module_line + 1, indent.to_string())
+        }
+    };
+    TextEdit::new(
+        Range::new(Position::new(line, 0), Position::new(line, 0)),
+        format!("{indent}use {path};\n"),
+    )
+}
+
+fn loc_line(symbols: &Symbols, loc: &move_ir_types::location::Loc) -> u32 {
+    symbols.files.start_position(loc).line_offset() as u32
+}
+
+fn line_indent(content: &str, line: u32) -> String {
+    content
+        .lines()
+        .nth(line as usize)
+        .map(|line| {
+            line.chars()
+                .take_while(|c| c.is_whitespace())
+                .collect::<String>()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the source text of a single line range.
+fn range_text<'a>(content: &'a str, range: &Range) -> Option<&'a str> {
+    if range.start.line != range.end.line {
+        return None;
+    }
+    let line = content.lines().nth(range.start.line as usize)?;
+    utf16_line_slice(line, range.start.character, range.end.character)
+}
+
+fn leading_identifier(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(text.len());
+    &text[..end]
+}
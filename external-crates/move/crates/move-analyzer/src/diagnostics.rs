@@ -4,31 +4,29 @@
 
 use crate::utils::{loc_end_to_lsp_position_opt, loc_start_to_lsp_position_opt};
 use codespan_reporting::diagnostic::Severity;
-use lsp_types::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Range};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Range,
+};
 use move_command_line_common::files::FileHash;
-use move_compiler::shared::files::MappedFiles;
-use move_ir_types::location::Loc;
+use move_compiler::{diagnostics::Diagnostic as CompilerDiagnostic, shared::files::MappedFiles};
 use std::{collections::BTreeMap, path::PathBuf};
 use url::Url;
 
-/// Converts diagnostics from the codespan format to the format understood by
-/// the language server.
+/// Converts diagnostics from the compiler format to the format understood by
+/// the language server. The code of each diagnostic is preserved so that code
+/// actions can be computed for it.
 pub fn lsp_diagnostics(
-    diagnostics: &Vec<(
-        codespan_reporting::diagnostic::Severity,
-        &'static str,
-        (Loc, String),
-        Vec<(Loc, String)>,
-        Vec<String>,
-    )>,
+    diagnostics: &[CompilerDiagnostic],
     files: &MappedFiles,
 ) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
     let mut lsp_diagnostics = BTreeMap::new();
-    for (s, _, (loc, msg), labels, _) in diagnostics {
+    for diag in diagnostics {
+        let loc = diag.primary_loc();
         let fpath = files.file_path(&loc.file_hash());
-        if let Some(start) = loc_start_to_lsp_position_opt(files, loc) {
-            if let Some(end) = loc_end_to_lsp_position_opt(files, loc) {
+        if let Some(start) = loc_start_to_lsp_position_opt(files, &loc) {
+            if let Some(end) = loc_end_to_lsp_position_opt(files, &loc) {
                 let range = Range::new(start, end);
+                let labels = diag.secondary_labels();
                 let related_info_opt = if labels.is_empty() {
                     None
                 } else {
@@ -51,15 +49,16 @@ pub fn lsp_diagnostics(
                             .collect(),
                     )
                 };
+                let (code, _) = diag.info().clone().render();
                 lsp_diagnostics
                     .entry(fpath.to_path_buf())
                     .or_insert_with(Vec::new)
                     .push(Diagnostic::new(
                         range,
-                        Some(severity(*s)),
+                        Some(severity(diag.info().severity().into_codespan_severity())),
+                        Some(NumberOrString::String(code)),
                         None,
-                        None,
-                        msg.to_string(),
+                        diag.primary_msg().to_string(),
                         related_info_opt,
                         None,
                     ));
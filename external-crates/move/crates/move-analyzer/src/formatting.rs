@@ -0,0 +1,70 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{context::Context, symbols::SymbolicatorRunner};
+use lsp_server::Request;
+use lsp_types::{DocumentFormattingParams, Position, Range, TextEdit};
+use move_compiler::{editions::Edition, parser::format::format_string};
+use move_package::source_package::{
+    layout::SourcePackageLayout, manifest_parser::parse_move_manifest_from_file,
+};
+use std::path::Path;
+use vfs::VfsPath;
+
+/// Handles formatting request of the language server
+pub fn on_formatting_request(context: &Context, request: &Request, ide_files_root: VfsPath) {
+    let parameters = serde_json::from_value::<DocumentFormattingParams>(request.params.clone())
+        .expect("could not deserialize formatting request");
+
+    let fpath = parameters.text_document.uri.to_file_path().unwrap();
+    let edits = formatting_edits(ide_files_root, &fpath);
+
+    let response = lsp_server::Response::new_ok(request.id.clone(), edits);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send formatting response: {:?}", err);
+    }
+}
+
+/// Computes the edit replacing the content of the file with its formatted
+/// version, if it is not formatted already. The content of files opened in the
+/// IDE is read from `ide_files_root`.
+pub fn formatting_edits(ide_files_root: VfsPath, fpath: &Path) -> Option<Vec<TextEdit>> {
+    let content = ide_files_root
+        .join(fpath.to_string_lossy())
+        .and_then(|p| p.read_to_string())
+        .ok()
+        .or_else(|| std::fs::read_to_string(fpath).ok())?;
+
+    let edition = SymbolicatorRunner::root_dir(fpath)
+        .and_then(|pkg_path| {
+            parse_move_manifest_from_file(&pkg_path.join(SourcePackageLayout::Manifest.path())).ok()
+        })
+        .and_then(|manifest| manifest.package.edition)
+        .unwrap_or(Edition::LEGACY);
+
+    let formatted = match format_string(&content, edition) {
+        Ok(formatted) => formatted,
+        Err(_) => {
+            eprintln!("could not format {:?} (it does not parse)", fpath);
+            return None;
+        }
+    };
+    if formatted == content {
+        return Some(vec![]);
+    }
+
+    // the edit spans the whole file, up to the end of its last line
+    let last_line = content.rsplit('\n').next().unwrap_or_default();
+    let end = Position::new(
+        content.matches('\n').count() as u32,
+        last_line.encode_utf16().count() as u32,
+    );
+    Some(vec![TextEdit::new(
+        Range::new(Position::new(0, 0), end),
+        formatted,
+    )])
+}
@@ -8,11 +8,14 @@ extern crate move_ir_types;
 
 pub mod analysis;
 pub mod analyzer;
+pub mod code_action;
 pub mod compiler_info;
 pub mod completion;
 pub mod context;
 pub mod diagnostics;
+pub mod formatting;
 pub mod inlay_hints;
+pub mod signature_help;
 pub mod symbols;
 pub mod utils;
 pub mod vfs;
@@ -0,0 +1,118 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    context::Context,
+    symbols::{ret_type_to_ide_str, type_to_ide_string, DefInfo, SymbolicatorRunner, Symbols},
+    utils::lsp_position_to_loc,
+};
+use lsp_server::Request;
+use lsp_types::{
+    Documentation, ParameterInformation, ParameterLabel, Position, SignatureHelp,
+    SignatureHelpParams, SignatureInformation,
+};
+use std::path::Path;
+
+/// Handles signature help request of the language server
+pub fn on_signature_help_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<SignatureHelpParams>(request.params.clone())
+        .expect("could not deserialize signature help request");
+
+    let fpath = parameters
+        .text_document_position_params
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let position = parameters.text_document_position_params.position;
+    let help = signature_help(context, &fpath, &position);
+
+    let response = lsp_server::Response::new_ok(request.id.clone(), help);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send signature help response: {:?}", err);
+    }
+}
+
+fn signature_help(context: &Context, fpath: &Path, position: &Position) -> Option<SignatureHelp> {
+    let symbols_map = &context.symbols.lock().ok()?;
+    let symbols =
+        SymbolicatorRunner::root_dir(fpath).and_then(|pkg_path| symbols_map.get(&pkg_path))?;
+    signature_help_internal(symbols, fpath, position)
+}
+
+/// Computes the signature of the function whose call arguments surround the
+/// given position, along with the parameter corresponding to the argument the
+/// position is in.
+pub fn signature_help_internal(
+    symbols: &Symbols,
+    fpath: &Path,
+    position: &Position,
+) -> Option<SignatureHelp> {
+    let fhash = symbols.file_hash(fpath)?;
+    let cursor = lsp_position_to_loc(&symbols.files, fhash, position)?.start();
+
+    // in case of nested calls, the innermost one is the one with the shortest
+    // argument list surrounding the cursor
+    let call_info = symbols
+        .file_mods
+        .get(fpath)?
+        .iter()
+        .flat_map(|mod_defs| mod_defs.call_infos.values())
+        .filter(|call_info| {
+            call_info.args_loc.start() < cursor && cursor < call_info.args_loc.end()
+        })
+        .min_by_key(|call_info| call_info.args_loc.end() - call_info.args_loc.start())?;
+
+    let DefInfo::Function(_, _, _, name, _, arg_names, arg_types, ret_type, doc_string) =
+        symbols.def_info(&call_info.def_loc?)?
+    else {
+        return None;
+    };
+
+    let mut label = format!("fun {name}(");
+    let mut parameters = vec![];
+    for (i, (arg_name, arg_type)) in arg_names.iter().zip(arg_types).enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let start = label.len() as u32;
+        label.push_str(&format!(
+            "{}: {}",
+            arg_name.value,
+            type_to_ide_string(arg_type, /* verbose */ false)
+        ));
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, label.len() as u32]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    label.push_str(&ret_type_to_ide_str(ret_type, /* verbose */ false));
+
+    // the receiver of a dot call is the first parameter, which does not appear
+    // in the argument list
+    let receiver = if call_info.dot_call { 1 } else { 0 };
+    let active_parameter = call_info
+        .arg_locs
+        .iter()
+        .filter(|arg_loc| arg_loc.end() < cursor)
+        .count()
+        + receiver;
+    let active_parameter = (active_parameter < parameters.len()).then_some(active_parameter as u32);
+
+    let signature = SignatureInformation {
+        label,
+        documentation: doc_string.clone().map(Documentation::String),
+        parameters: Some(parameters),
+        active_parameter,
+    };
+    Some(SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter,
+    })
+}
@@ -76,7 +76,7 @@ use lsp_server::{Request, RequestId};
 use lsp_types::{
     request::GotoTypeDefinitionParams, Diagnostic, DocumentSymbol, DocumentSymbolParams,
     GotoDefinitionParams, Hover, HoverContents, HoverParams, Location, MarkupContent, MarkupKind,
-    Position, Range, ReferenceParams, SymbolKind,
+    Position, Range, ReferenceParams, RenameParams, SymbolKind, TextEdit, WorkspaceEdit,
 };
 use move_command_line_common::files::FileHash;
 use move_compiler::{
@@ -85,7 +85,7 @@ use move_compiler::{
     expansion::ast::{self as E, AbilitySet, ModuleIdent, ModuleIdent_, Value, Value_, Visibility},
     linters::LintLevel,
     naming::ast::{DatatypeTypeParameter, StructFields, Type, TypeName_, Type_, VariantFields},
-    parser::ast as P,
+    parser::{ast as P, keywords::KEYWORDS},
     shared::{
         files::{FileId, MappedFiles},
         unique_map::UniqueMap,
@@ -121,11 +121,14 @@ use crate::{
     compiler_info::CompilerInfo,
     context::Context,
     diagnostics::{lsp_diagnostics, lsp_empty_diagnostics},
-    utils::{loc_start_to_lsp_position_opt, lsp_position_to_loc},
+    utils::{loc_start_to_lsp_position_opt, lsp_position_to_loc, utf16_line_slice},
 };
 
 const MANIFEST_FILE_NAME: &str = "Move.toml";
 
+/// Keywords reserved in Move 2024 in addition to the ones in `KEYWORDS`
+const MOVE_2024_KEYWORDS: &[&str] = &["enum", "for", "match", "mut", "type"];
+
 #[derive(Clone)]
 pub struct PrecompiledPkgDeps {
     /// Hash of the manifest file for a given package
@@ -344,6 +347,8 @@ pub struct LocalDef {
 pub struct CallInfo {
     /// Is it a dot call?
     pub dot_call: bool,
+    /// Location of the parenthesized argument list
+    pub args_loc: Loc,
     /// Locations of arguments
     pub arg_locs: Vec<Loc>,
     /// Definition of function being called (as an Option as its computed after
@@ -352,10 +357,11 @@ pub struct CallInfo {
 }
 
 impl CallInfo {
-    pub fn new(dot_call: bool, args: &[P::Exp]) -> Self {
+    pub fn new(dot_call: bool, args: &Spanned<Vec<P::Exp>>) -> Self {
         Self {
             dot_call,
-            arg_locs: args.iter().map(|e| e.loc).collect(),
+            args_loc: args.loc,
+            arg_locs: args.value.iter().map(|e| e.loc).collect(),
             def_loc: None,
         }
     }
@@ -1742,8 +1748,7 @@ pub fn get_symbols(
 
     let mut ide_diagnostics = lsp_empty_diagnostics(mapped_files.file_name_mapping());
     if let Some((compiler_diagnostics, failure)) = diagnostics {
-        let lsp_diagnostics =
-            lsp_diagnostics(&compiler_diagnostics.into_codespan_format(), &mapped_files);
+        let lsp_diagnostics = lsp_diagnostics(&compiler_diagnostics.into_vec(), &mapped_files);
         // start with empty diagnostics for all files and replace them with actual
         // diagnostics only for files that have failures/warnings so that
         // diagnostics for all other files (that no longer have
@@ -2657,6 +2662,113 @@ pub fn on_references_request(context: &Context, request: &Request) {
     );
 }
 
+/// Handles rename request of the language server
+pub fn on_rename_request(context: &Context, request: &Request) {
+    let symbols_map = &context.symbols.lock().unwrap();
+    let parameters = serde_json::from_value::<RenameParams>(request.params.clone())
+        .expect("could not deserialize rename request");
+
+    let fpath = parameters
+        .text_document_position
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let loc = parameters.text_document_position.position;
+    let line = loc.line;
+    let col = loc.character;
+    let new_name = parameters.new_name;
+
+    if !is_valid_identifier(&new_name) {
+        let response = lsp_server::Response::new_err(
+            request.id.clone(),
+            lsp_server::ErrorCode::InvalidParams as i32,
+            format!("'{new_name}' is not a valid identifier"),
+        );
+        if let Err(err) = context
+            .connection
+            .sender
+            .send(lsp_server::Message::Response(response))
+        {
+            eprintln!("could not send rename response: {:?}", err);
+        }
+        return;
+    }
+
+    on_use_request(
+        context,
+        symbols_map,
+        &fpath,
+        line,
+        col,
+        request.id.clone(),
+        |u, symbols| {
+            let pkg_path = SymbolicatorRunner::root_dir(&fpath)?;
+            rename_edits(symbols, &pkg_path, &u.def_loc, &new_name)
+                .map(|edit| serde_json::to_value(edit).unwrap())
+        },
+    );
+}
+
+/// Computes the edits renaming the identifier defined at `def_loc` and all its
+/// uses to `new_name`. Only identifiers defined in the package itself (and not
+/// in its dependencies) can be renamed. Uses whose source text differs from the
+/// defined identifier (e.g. module aliases) are left untouched.
+pub fn rename_edits(
+    symbols: &Symbols,
+    pkg_path: &Path,
+    def_loc: &Loc,
+    new_name: &str,
+) -> Option<WorkspaceEdit> {
+    let def_path = symbols.files.file_path(&def_loc.file_hash());
+    if !def_path.starts_with(pkg_path) || def_path.starts_with(pkg_path.join("build")) {
+        return None;
+    }
+    let def_posn = symbols.files.file_start_position_opt(def_loc)?;
+    let (_, def_file_content) = symbols.files.get(&def_loc.file_hash())?;
+    let old_name = def_ident(
+        &def_file_content,
+        def_posn.position.line_offset() as u32,
+        def_posn.position.column_offset() as u32,
+    );
+
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for ref_loc in symbols.references.get(def_loc)? {
+        let (_, file_content) = symbols.files.get(&ref_loc.fhash)?;
+        let ref_ident = file_content
+            .lines()
+            .nth(ref_loc.start.line as usize)
+            .and_then(|line| utf16_line_slice(line, ref_loc.start.character, ref_loc.col_end));
+        if ref_ident != Some(old_name.as_str()) {
+            continue;
+        }
+        let range = Range {
+            start: ref_loc.start,
+            end: Position {
+                line: ref_loc.start.line,
+                character: ref_loc.col_end,
+            },
+        };
+        let path = symbols.files.file_path(&ref_loc.fhash);
+        changes
+            .entry(Url::from_file_path(path).unwrap())
+            .or_default()
+            .push(TextEdit::new(range, new_name.to_string()));
+    }
+    Some(WorkspaceEdit::new(changes))
+}
+
+/// Checks if a string can be used as a (non-reserved) Move identifier.
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let valid_chars = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => true,
+        Some('_') => name.len() > 1,
+        _ => false,
+    } && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid_chars && !KEYWORDS.contains(&name) && !MOVE_2024_KEYWORDS.contains(&name)
+}
+
 /// Helper function that take a DefInfo, checks if it represents
 /// a enum arm variable defintion, and if need be converts it
 /// to the one that represents an enum guard variable (which
@@ -57,6 +57,29 @@ pub fn lsp_position_to_loc(
     files.line_char_offset_to_loc_opt(file_hash, line_offset, char_offset)
 }
 
+/// Converts a column of a line expressed in UTF-16 code units (which is how
+/// LSP positions are encoded) to a byte offset within the line. Returns `None`
+/// if the column is past the end of the line or in the middle of a character.
+pub fn utf16_col_to_byte_offset(line: &str, col: u32) -> Option<usize> {
+    let col = col as usize;
+    let mut utf16_col = 0;
+    for (byte_offset, c) in line.char_indices() {
+        if utf16_col >= col {
+            return (utf16_col == col).then_some(byte_offset);
+        }
+        utf16_col += c.len_utf16();
+    }
+    (utf16_col == col).then_some(line.len())
+}
+
+/// Returns the part of a line between two columns expressed in UTF-16 code
+/// units.
+pub fn utf16_line_slice(line: &str, col_start: u32, col_end: u32) -> Option<&str> {
+    let start = utf16_col_to_byte_offset(line, col_start)?;
+    let end = utf16_col_to_byte_offset(line, col_end)?;
+    line.get(start..end)
+}
+
 /// Some functions defined in a module need to be ignored.
 pub fn ignored_function(name: Symbol) -> bool {
     // In test mode (that's how IDE compiles Move source files),
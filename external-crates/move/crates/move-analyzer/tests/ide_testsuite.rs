@@ -11,13 +11,18 @@ use std::{
 };
 
 use json_comments::StripComments;
-use lsp_types::{InlayHintKind, InlayHintLabel, InlayHintTooltip, Position};
+use lsp_types::{
+    Diagnostic, InlayHintKind, InlayHintLabel, InlayHintTooltip, NumberOrString, ParameterLabel,
+    Position, Range, TextEdit,
+};
 use move_analyzer::{
+    code_action::code_actions_internal,
     completion::completion_items,
     inlay_hints::inlay_hints_internal,
+    signature_help::signature_help_internal,
     symbols::{
-        def_info_doc_string, get_symbols, maybe_convert_for_guard, PrecompiledPkgDeps, Symbols,
-        UseDefMap,
+        def_info_doc_string, get_symbols, is_valid_identifier, maybe_convert_for_guard,
+        rename_edits, PrecompiledPkgDeps, Symbols, UseDefMap,
     },
 };
 use move_command_line_common::testing::{
@@ -25,6 +30,7 @@ use move_command_line_common::testing::{
 };
 use move_compiler::linters::LintLevel;
 use serde::{Deserialize, Serialize};
+use url::Url;
 use vfs::{MemoryFS, VfsPath};
 
 //**************************************************************************************************
@@ -49,6 +55,18 @@ enum TestSuite {
         project: String,
         file_tests: BTreeMap<String, Vec<HintTest>>,
     },
+    Rename {
+        project: String,
+        file_tests: BTreeMap<String, Vec<RenameTest>>,
+    },
+    SignatureHelp {
+        project: String,
+        file_tests: BTreeMap<String, Vec<SignatureHelpTest>>,
+    },
+    CodeAction {
+        project: String,
+        file_tests: BTreeMap<String, Vec<CodeActionTest>>,
+    },
 }
 
 #[derive(Serialize, Deserialize)]
@@ -76,6 +94,29 @@ struct HintTest {
     use_col: u32,
 }
 
+#[derive(Serialize, Deserialize)]
+struct RenameTest {
+    use_line: u32,
+    use_col: u32,
+    new_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignatureHelpTest {
+    use_line: u32,
+    use_col: u32,
+}
+
+/// A diagnostic to compute code actions for, whose columns are expressed in
+/// UTF-16 code units like the ones sent by LSP clients.
+#[derive(Serialize, Deserialize)]
+struct CodeActionTest {
+    line: u32,
+    col_start: u32,
+    col_end: u32,
+    code: String,
+}
+
 //**************************************************************************************************
 // Test Impls
 //**************************************************************************************************
@@ -311,6 +352,181 @@ impl HintTest {
     }
 }
 
+impl RenameTest {
+    fn test(
+        &self,
+        test_idx: usize,
+        pkg_path: &Path,
+        symbols: &Symbols,
+        mod_symbols: &UseDefMap,
+        output: &mut dyn std::io::Write,
+    ) -> anyhow::Result<()> {
+        let RenameTest {
+            use_line,
+            use_col,
+            new_name,
+        } = self;
+        writeln!(output, "-- test {test_idx} -------------------")?;
+        writeln!(
+            output,
+            "use line: {use_line}, use_col: {use_col}, new name: {new_name}"
+        )?;
+        if !is_valid_identifier(new_name) {
+            writeln!(output, "INVALID IDENTIFIER")?;
+            return Ok(());
+        }
+        let lsp_use_line = use_line - 1; // 0th-based
+        let lsp_use_col = use_col - 1; // 0th-based
+        let Some(use_def) = mod_symbols.get(lsp_use_line).and_then(|uses| {
+            uses.into_iter()
+                .find(|u| lsp_use_col >= u.col_start() && lsp_use_col <= u.col_end())
+        }) else {
+            writeln!(output, "NO USE FOUND")?;
+            return Ok(());
+        };
+        let Some(edit) = rename_edits(symbols, pkg_path, &use_def.def_loc(), new_name) else {
+            writeln!(output, "NO EDITS")?;
+            return Ok(());
+        };
+        let mut changes = edit
+            .changes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(uri, edits)| (file_name(&uri), edits))
+            .collect::<Vec<_>>();
+        changes.sort_by(|(file1, _), (file2, _)| file1.cmp(file2));
+        for (file, mut edits) in changes {
+            edits.sort_by_key(|edit| (edit.range.start.line, edit.range.start.character));
+            for edit in edits {
+                writeln!(output, "EDIT {file}:{}", render_edit(&edit))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SignatureHelpTest {
+    fn test(
+        &self,
+        test_idx: usize,
+        symbols: &Symbols,
+        output: &mut dyn std::io::Write,
+        use_file_path: &Path,
+    ) -> anyhow::Result<()> {
+        let lsp_use_line = self.use_line - 1; // 0th-based
+        let lsp_use_col = self.use_col - 1; // 0th-based
+        writeln!(output, "-- test {test_idx} -------------------")?;
+        writeln!(
+            output,
+            "use line: {}, use_col: {}",
+            self.use_line, self.use_col
+        )?;
+        let Some(help) = signature_help_internal(
+            symbols,
+            use_file_path,
+            &Position::new(lsp_use_line, lsp_use_col),
+        ) else {
+            writeln!(output, "NO SIGNATURE HELP")?;
+            return Ok(());
+        };
+        for signature in help.signatures {
+            writeln!(output, "SIGNATURE : {}", signature.label)?;
+            let parameters = signature
+                .parameters
+                .unwrap_or_default()
+                .into_iter()
+                .map(|parameter| match parameter.label {
+                    ParameterLabel::Simple(label) => label,
+                    ParameterLabel::LabelOffsets([start, end]) => {
+                        signature.label[start as usize..end as usize].to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            for parameter in &parameters {
+                writeln!(output, "PARAMETER : '{parameter}'")?;
+            }
+            match signature
+                .active_parameter
+                .and_then(|active| parameters.get(active as usize))
+            {
+                Some(parameter) => writeln!(output, "ACTIVE    : '{parameter}'")?,
+                None => writeln!(output, "ACTIVE    : NONE")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CodeActionTest {
+    fn test(
+        &self,
+        test_idx: usize,
+        symbols: &Symbols,
+        output: &mut dyn std::io::Write,
+        use_file_path: &Path,
+    ) -> anyhow::Result<()> {
+        let CodeActionTest {
+            line,
+            col_start,
+            col_end,
+            code,
+        } = self;
+        writeln!(output, "-- test {test_idx} -------------------")?;
+        writeln!(
+            output,
+            "diagnostic: {code} @ line: {line}, cols: {col_start}-{col_end}"
+        )?;
+        let lsp_line = line - 1; // 0th-based
+        let diagnostic = Diagnostic {
+            range: Range::new(
+                Position::new(lsp_line, col_start - 1),
+                Position::new(lsp_line, col_end - 1),
+            ),
+            code: Some(NumberOrString::String(code.clone())),
+            ..Default::default()
+        };
+        let uri = Url::from_file_path(use_file_path).unwrap();
+        let actions =
+            code_actions_internal(symbols, &uri, use_file_path, &[diagnostic]).unwrap_or_default();
+        if actions.is_empty() {
+            writeln!(output, "NO CODE ACTIONS")?;
+        }
+        for action in actions {
+            writeln!(output, "ACTION: {}", action.title)?;
+            let changes = action
+                .edit
+                .and_then(|edit| edit.changes)
+                .unwrap_or_default();
+            for edit in changes.into_values().flatten() {
+                writeln!(output, "    EDIT {}", render_edit(&edit))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn file_name(uri: &Url) -> String {
+    uri.to_file_path()
+        .unwrap()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Renders an edit with 1-based lines and columns.
+fn render_edit(edit: &TextEdit) -> String {
+    let Range { start, end } = edit.range;
+    format!(
+        "{}:{}-{}:{} {:?}",
+        start.line + 1,
+        start.character + 1,
+        end.line + 1,
+        end.character + 1,
+        edit.new_text
+    )
+}
+
 //**************************************************************************************************
 // Test Suite Runner Code
 //**************************************************************************************************
@@ -498,6 +714,98 @@ fn hint_test_suite(
     Ok(result)
 }
 
+fn rename_test_suite(
+    project: String,
+    file_tests: BTreeMap<String, Vec<RenameTest>>,
+) -> datatest_stable::Result<String> {
+    let (project_path, _, _, symbols) = initial_symbols(project)?;
+    let pkg_path = dunce::canonicalize(&project_path).unwrap();
+
+    let mut output: BufWriter<_> = BufWriter::new(Vec::new());
+    let writer: &mut dyn io::Write = output.get_mut();
+
+    for (file, tests) in file_tests {
+        writeln!(
+            writer,
+            "== {file} ========================================================"
+        )?;
+
+        let mut fpath = project_path.clone();
+
+        fpath.push(format!("sources/{file}"));
+        let cpath = dunce::canonicalize(&fpath).unwrap();
+        let mod_symbols = symbols
+            .file_use_defs
+            .get(&cpath)
+            .ok_or(format!("NO SYMBOLS FOR {}", cpath.to_str().unwrap()))?;
+
+        for (idx, test) in tests.iter().enumerate() {
+            test.test(idx, &pkg_path, &symbols, mod_symbols, writer)?;
+        }
+    }
+
+    let result: String = String::from_utf8(output.into_inner().unwrap()).unwrap();
+    Ok(result)
+}
+
+fn signature_help_test_suite(
+    project: String,
+    file_tests: BTreeMap<String, Vec<SignatureHelpTest>>,
+) -> datatest_stable::Result<String> {
+    let (project_path, _, _, symbols) = initial_symbols(project)?;
+
+    let mut output: BufWriter<_> = BufWriter::new(Vec::new());
+    let writer: &mut dyn io::Write = output.get_mut();
+
+    for (file, tests) in file_tests {
+        writeln!(
+            writer,
+            "== {file} ========================================================"
+        )?;
+
+        let mut fpath = project_path.clone();
+
+        fpath.push(format!("sources/{file}"));
+        let cpath = dunce::canonicalize(&fpath).unwrap();
+
+        for (idx, test) in tests.iter().enumerate() {
+            test.test(idx, &symbols, writer, &cpath)?;
+        }
+    }
+
+    let result: String = String::from_utf8(output.into_inner().unwrap()).unwrap();
+    Ok(result)
+}
+
+fn code_action_test_suite(
+    project: String,
+    file_tests: BTreeMap<String, Vec<CodeActionTest>>,
+) -> datatest_stable::Result<String> {
+    let (project_path, _, _, symbols) = initial_symbols(project)?;
+
+    let mut output: BufWriter<_> = BufWriter::new(Vec::new());
+    let writer: &mut dyn io::Write = output.get_mut();
+
+    for (file, tests) in file_tests {
+        writeln!(
+            writer,
+            "== {file} ========================================================"
+        )?;
+
+        let mut fpath = project_path.clone();
+
+        fpath.push(format!("sources/{file}"));
+        let cpath = dunce::canonicalize(&fpath).unwrap();
+
+        for (idx, test) in tests.iter().enumerate() {
+            test.test(idx, &symbols, writer, &cpath)?;
+        }
+    }
+
+    let result: String = String::from_utf8(output.into_inner().unwrap()).unwrap();
+    Ok(result)
+}
+
 fn move_ide_testsuite(test_path: &Path) -> datatest_stable::Result<()> {
     let suite_file = io::BufReader::new(File::open(test_path)?);
     let stripped = StripComments::new(suite_file);
@@ -520,6 +828,18 @@ fn move_ide_testsuite(test_path: &Path) -> datatest_stable::Result<()> {
             project,
             file_tests,
         } => hint_test_suite(project, file_tests),
+        TestSuite::Rename {
+            project,
+            file_tests,
+        } => rename_test_suite(project, file_tests),
+        TestSuite::SignatureHelp {
+            project,
+            file_tests,
+        } => signature_help_test_suite(project, file_tests),
+        TestSuite::CodeAction {
+            project,
+            file_tests,
+        } => code_action_test_suite(project, file_tests),
     }?;
 
     let exp_string = test_path
@@ -0,0 +1,23 @@
+module LspActions::actions {
+    use LspActions::uses;
+
+    public fun unused(): u64 {
+        /* 😀 */ let x = 1;
+        0
+    }
+
+    public fun spin() {
+        while (true) {}
+    }
+
+    public fun counter(): u64 {
+        LspActions::rename::value(&uses::twice(1))
+    }
+}
+
+module LspActions::plain {
+    // Counter double twice
+    public fun make(): u64 {
+        1
+    }
+}
@@ -0,0 +1,25 @@
+module LspActions::rename {
+    public struct Counter has drop {
+        value: u64,
+    }
+
+    public fun new(start: u64): Counter {
+        Counter { value: start }
+    }
+
+    public fun increment(counter: &mut Counter) {
+        counter.value = counter.value + 1;
+    }
+
+    public fun add(counter: &mut Counter, amount: u64) {
+        counter.value = counter.value + amount;
+    }
+
+    public fun double(n: u64): u64 {
+        n * 2
+    }
+
+    public fun value(counter: &Counter): u64 {
+        counter.value
+    }
+}
@@ -0,0 +1,12 @@
+module LspActions::uses {
+    use LspActions::rename::{Self, Counter};
+
+    public fun twice(start: u64): Counter {
+        let mut counter = rename::new(start);
+        rename::increment(&mut counter);
+        counter.increment();
+        rename::add(&mut counter, rename::double(start));
+        counter.add(5);
+        counter
+    }
+}
@@ -6,12 +6,19 @@ use super::reroot_path;
 use clap::*;
 use move_compiler::compiled_unit::NamedCompiledModule;
 use move_coverage::{
-    coverage_map::CoverageMap, format_csv_summary, format_human_summary,
-    source_coverage::SourceCoverageBuilder, summary::summarize_inst_cov,
+    coverage_map::CoverageMap,
+    export::{format_cobertura, format_lcov, CoverageExportFormat, ModuleLineCoverage},
+    format_csv_summary, format_human_summary,
+    source_coverage::SourceCoverageBuilder,
+    summary::summarize_inst_cov,
 };
 use move_disassembler::disassembler::Disassembler;
 use move_package::BuildConfig;
-use std::path::Path;
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 #[derive(Parser)]
 pub enum CoverageSummaryOptions {
@@ -38,6 +45,17 @@ pub enum CoverageSummaryOptions {
         #[clap(long = "module")]
         module_name: String,
     },
+    /// Export the line and branch coverage of all modules in this package, to
+    /// be consumed by coverage dashboards and other tools
+    #[clap(name = "export")]
+    Export {
+        #[clap(long = "format", value_enum)]
+        format: CoverageExportFormat,
+        /// The file to write the coverage to. Printed to stdout if not
+        /// provided
+        #[clap(long = "output", short = 'o')]
+        output_path: Option<PathBuf>,
+    },
 }
 
 /// Inspect test coverage for this package. A previous test run with the
@@ -97,6 +115,36 @@ impl Coverage {
                 disassembler.add_coverage_map(coverage_map.to_unified_exec_map());
                 println!("{}", disassembler.disassemble()?);
             }
+            CoverageSummaryOptions::Export {
+                format,
+                output_path,
+            } => {
+                let coverage_map = coverage_map.to_unified_exec_map();
+                let module_coverages = package
+                    .root_modules()
+                    .map(|unit| {
+                        ModuleLineCoverage::new(
+                            &unit.unit.module,
+                            &coverage_map,
+                            &unit.unit.source_map,
+                            &unit.source_path,
+                        )
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let mut writer: Box<dyn Write> = match output_path {
+                    Some(output_path) => Box::new(File::create(output_path)?),
+                    None => Box::new(std::io::stdout()),
+                };
+                match format {
+                    CoverageExportFormat::Lcov => format_lcov(&module_coverages, &mut writer)?,
+                    CoverageExportFormat::Cobertura => format_cobertura(
+                        &module_coverages,
+                        package.compiled_package_info.package_name.as_str(),
+                        &std::env::current_dir()?,
+                        &mut writer,
+                    )?,
+                }
+            }
         }
         Ok(())
     }
@@ -5,7 +5,10 @@
 use super::reroot_path;
 use clap::*;
 use move_docgen::DocgenOptions;
-use move_package::{BuildConfig, ModelConfig};
+use move_package::{
+    BuildConfig, ModelConfig,
+    source_package::{layout::SourcePackageLayout, manifest_parser::parse_move_manifest_from_file},
+};
 use std::{
     fs,
     path::{Path, PathBuf},
@@ -64,15 +67,27 @@ pub struct Docgen {
 impl Docgen {
     /// Calling the Docgen
     pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        let manifest = parse_move_manifest_from_file(
+            &rerooted_path.join(SourcePackageLayout::Manifest.path()),
+        )?;
+        let flavor = manifest
+            .package
+            .flavor
+            .or(config.default_flavor)
+            .unwrap_or_default();
         let model = config.move_model_for_package(
-            &reroot_path(path).unwrap(),
+            &rerooted_path,
             ModelConfig {
                 all_files_as_targets: false,
                 target_filter: None,
             },
         )?;
 
-        let mut options = DocgenOptions::default();
+        let mut options = DocgenOptions {
+            flavor,
+            ..DocgenOptions::default()
+        };
 
         if !self.template.is_empty() {
             options.root_doc_templates = self.template;
@@ -0,0 +1,90 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::Path, sync::Arc};
+
+use clap::*;
+use move_command_line_common::files::{find_move_filenames, FileHash};
+use move_compiler::{
+    diagnostics::report_diagnostics_to_buffer_with_env_color, editions::Edition,
+    parser::format::format_string, shared::files::MappedFiles,
+};
+use move_package::{
+    source_package::{layout::SourcePackageLayout, manifest_parser::parse_move_manifest_from_file},
+    BuildConfig,
+};
+
+use super::reroot_path;
+
+/// Format the Move source files of the package at `path`. If no path is
+/// provided defaults to current directory.
+#[derive(Parser)]
+#[clap(name = "fmt")]
+pub struct Fmt {
+    /// Only check whether the source files are formatted, without modifying
+    /// them. Fails if any of them is not.
+    #[clap(long = "check")]
+    pub check: bool,
+}
+
+impl Fmt {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        let manifest = parse_move_manifest_from_file(
+            &rerooted_path.join(SourcePackageLayout::Manifest.path()),
+        )?;
+        let edition = manifest
+            .package
+            .edition
+            .or(config.default_edition)
+            .unwrap_or(Edition::LEGACY);
+
+        let source_dirs = [
+            SourcePackageLayout::Sources,
+            SourcePackageLayout::Scripts,
+            SourcePackageLayout::Tests,
+            SourcePackageLayout::Examples,
+        ]
+        .into_iter()
+        .map(|layout| rerooted_path.join(layout.path()))
+        .filter(|dir| dir.is_dir())
+        .collect::<Vec<_>>();
+
+        let mut unformatted = vec![];
+        for file in find_move_filenames(&source_dirs, false)? {
+            let source = fs::read_to_string(&file)?;
+            let formatted = match format_string(&source, edition) {
+                Ok(formatted) => formatted,
+                Err(diags) => {
+                    let mut files = MappedFiles::empty();
+                    files.add(
+                        FileHash::new(&source),
+                        file.as_str().into(),
+                        Arc::from(source),
+                    );
+                    let report = report_diagnostics_to_buffer_with_env_color(&files, diags);
+                    anyhow::bail!("{}", String::from_utf8_lossy(&report));
+                }
+            };
+            if formatted == source {
+                continue;
+            }
+            if !self.check {
+                fs::write(&file, formatted)?;
+            }
+            unformatted.push(file);
+        }
+
+        if self.check && !unformatted.is_empty() {
+            anyhow::bail!(
+                "The following files are not formatted:\n{}",
+                unformatted
+                    .iter()
+                    .map(|file| format!("    {file}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,10 @@ use move_compiler::{
 };
 use move_coverage::coverage_map::{output_map_to_file, CoverageMap};
 use move_package::{compilation::build_plan::BuildPlan, BuildConfig};
-use move_unit_test::UnitTestingConfig;
+use move_unit_test::{
+    test_reporter::{parse_test_report, TestReport},
+    UnitTestingConfig,
+};
 use move_vm_test_utils::gas_schedule::CostTable;
 use std::{io::Write, path::Path, process::ExitStatus};
 // if windows
@@ -55,6 +58,10 @@ pub struct Test {
     /// 'csv' passed
     #[clap(name = "report-statistics", short = 's', long = "statistics")]
     pub report_statistics: Option<Option<String>>,
+    /// Write a machine readable report of the test results, given as
+    /// `junit=<path>` (JUnit XML) or `json=<path>`. Can be repeated.
+    #[clap(name = "report", long = "report", value_parser = parse_test_report)]
+    pub reports: Vec<TestReport>,
 
     /// Verbose mode
     #[clap(long = "verbose")]
@@ -108,6 +115,7 @@ impl Test {
             list,
             num_threads,
             report_statistics,
+            reports,
             verbose_mode,
             compute_coverage: _,
             seed,
@@ -119,6 +127,7 @@ impl Test {
             list,
             num_threads,
             report_statistics,
+            reports,
             verbose: verbose_mode,
             seed,
             rand_num_iters,
@@ -43,7 +43,9 @@ pub struct Context<'a> {
     env: &'a mut CompilationEnv,
 }
 
-static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r".*Cap(?:[A-Z0-9_]+|ability|$).*").unwrap());
+/// Matches the names of capability-like types.
+pub(super) static CAPABILITY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r".*Cap(?:[A-Z0-9_]+|ability|$).*").unwrap());
 
 impl TypingVisitorConstructor for WarnFreezeCapability {
     type Context<'a> = Context<'a>;
@@ -101,7 +103,7 @@ fn check_type_arguments(context: &mut Context, fun: &T::ModuleCall, loc: Loc) {
         let Some(sp!(_, TypeName_::ModuleType(_, struct_name))) = type_arg.type_name() else {
             continue;
         };
-        if REGEX.is_match(struct_name.value().as_str()) {
+        if CAPABILITY_REGEX.is_match(struct_name.value().as_str()) {
             let msg = format!(
                 "The type {} is potentially a capability based on its name",
                 core::error_format_(type_arg, &core::Subst::empty()),
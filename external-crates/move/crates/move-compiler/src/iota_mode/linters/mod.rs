@@ -13,7 +13,7 @@ use crate::{
     hlir::ast::{BaseType_, SingleType, SingleType_},
     linters::{LintLevel, LinterDiagnosticCategory, ALLOW_ATTR_CATEGORY, LINT_WARNING_PREFIX},
     naming::ast as N,
-    typing::visitor::TypingVisitor,
+    typing::{ast as T, visitor::TypingVisitor},
 };
 
 pub mod coin_field;
@@ -23,9 +23,13 @@ pub mod freeze_wrapped;
 pub mod freezing_capability;
 pub mod missing_key;
 pub mod public_mut_tx_context;
+pub mod public_mut_uid;
 pub mod public_random;
 pub mod self_transfer;
 pub mod share_owned;
+pub mod unbounded_shared_vector;
+pub mod unchecked_destroy_zero;
+pub mod unprotected_capability;
 
 pub const IOTA_PKG_NAME: &str = "iota";
 
@@ -42,6 +46,23 @@ pub const PUBLIC_RECEIVE_FUN: &str = "public_receive";
 pub const COIN_MOD_NAME: &str = "coin";
 pub const COIN_STRUCT_NAME: &str = "Coin";
 
+pub const BALANCE_MOD_NAME: &str = "balance";
+pub const DESTROY_ZERO_FUN: &str = "destroy_zero";
+pub const VALUE_FUN: &str = "value";
+
+pub const OBJECT_MOD_NAME: &str = "object";
+pub const UID_STRUCT_NAME: &str = "UID";
+
+pub const TX_CONTEXT_MOD_NAME: &str = "tx_context";
+pub const SENDER_FUN: &str = "sender";
+
+pub const PACKAGE_MOD_NAME: &str = "package";
+pub const PUBLISHER_STRUCT_NAME: &str = "Publisher";
+
+pub const STD_PKG_NAME: &str = "std";
+pub const VECTOR_MOD_NAME: &str = "vector";
+pub const PUSH_BACK_FUN: &str = "push_back";
+
 pub const BAG_MOD_NAME: &str = "bag";
 pub const BAG_STRUCT_NAME: &str = "Bag";
 
@@ -76,6 +97,10 @@ pub const PUBLIC_RANDOM_FILTER_NAME: &str = "public_random";
 pub const MISSING_KEY_FILTER_NAME: &str = "missing_key";
 pub const FREEZING_CAPABILITY_FILTER_NAME: &str = "freezing_capability";
 pub const PREFER_MUTABLE_TX_CONTEXT_FILTER_NAME: &str = "prefer_mut_tx_context";
+pub const UNCHECKED_DESTROY_ZERO_FILTER_NAME: &str = "unchecked_destroy_zero";
+pub const PUBLIC_MUT_UID_FILTER_NAME: &str = "public_mut_uid";
+pub const UNBOUNDED_SHARED_VECTOR_FILTER_NAME: &str = "unbounded_shared_vector";
+pub const UNPROTECTED_CAPABILITY_FILTER_NAME: &str = "unprotected_capability";
 
 pub const RANDOM_MOD_NAME: &str = "random";
 pub const RANDOM_STRUCT_NAME: &str = "Random";
@@ -95,6 +120,10 @@ pub enum LinterDiagnosticCode {
     MissingKey,
     FreezingCapability,
     PreferMutableTxContext,
+    UncheckedDestroyZero,
+    PublicMutUid,
+    UnboundedSharedVector,
+    UnprotectedCapability,
 }
 
 pub fn known_filters() -> (Option<Symbol>, Vec<WarningFilter>) {
@@ -160,6 +189,30 @@ pub fn known_filters() -> (Option<Symbol>, Vec<WarningFilter>) {
             LinterDiagnosticCode::PreferMutableTxContext as u8,
             Some(PREFER_MUTABLE_TX_CONTEXT_FILTER_NAME),
         ),
+        WarningFilter::code(
+            Some(LINT_WARNING_PREFIX),
+            LinterDiagnosticCategory::Iota as u8,
+            LinterDiagnosticCode::UncheckedDestroyZero as u8,
+            Some(UNCHECKED_DESTROY_ZERO_FILTER_NAME),
+        ),
+        WarningFilter::code(
+            Some(LINT_WARNING_PREFIX),
+            LinterDiagnosticCategory::Iota as u8,
+            LinterDiagnosticCode::PublicMutUid as u8,
+            Some(PUBLIC_MUT_UID_FILTER_NAME),
+        ),
+        WarningFilter::code(
+            Some(LINT_WARNING_PREFIX),
+            LinterDiagnosticCategory::Iota as u8,
+            LinterDiagnosticCode::UnboundedSharedVector as u8,
+            Some(UNBOUNDED_SHARED_VECTOR_FILTER_NAME),
+        ),
+        WarningFilter::code(
+            Some(LINT_WARNING_PREFIX),
+            LinterDiagnosticCategory::Iota as u8,
+            LinterDiagnosticCode::UnprotectedCapability as u8,
+            Some(UNPROTECTED_CAPABILITY_FILTER_NAME),
+        ),
     ];
 
     (Some(ALLOW_ATTR_CATEGORY.into()), filters)
@@ -183,6 +236,10 @@ pub fn linter_visitors(level: LintLevel) -> Vec<Visitor> {
             visitors.extend([
                 freezing_capability::WarnFreezeCapability.visitor(),
                 public_mut_tx_context::PreferMutableTxContext.visitor(),
+                public_mut_uid::PublicMutUidVisitor.visitor(),
+                unchecked_destroy_zero::UncheckedDestroyZeroVisitor.visitor(),
+                unbounded_shared_vector::UnboundedSharedVectorVisitor.visitor(),
+                unprotected_capability::UnprotectedCapabilityVisitor.visitor(),
             ]);
             visitors
         }
@@ -198,6 +255,30 @@ pub fn base_type(t: &N::Type) -> Option<&N::Type> {
     }
 }
 
+/// Returns the first argument of a call.
+pub fn first_argument(args: &T::Exp) -> Option<&T::Exp> {
+    match &args.exp.value {
+        T::UnannotatedExp_::ExpList(items) => match items.first() {
+            Some(T::ExpListItem::Single(e, _)) => Some(e),
+            _ => None,
+        },
+        _ => Some(args),
+    }
+}
+
+/// Returns the local variable a reference is derived from, through field
+/// borrows.
+pub fn root_var(exp: &T::Exp) -> Option<&N::Var> {
+    use T::UnannotatedExp_ as E;
+    match &exp.exp.value {
+        E::Borrow(_, base, _) | E::TempBorrow(_, base) | E::Dereference(base) => root_var(base),
+        E::Copy { var, .. } | E::Move { var, .. } | E::Use(var) | E::BorrowLocal(_, var) => {
+            Some(var)
+        }
+        _ => None,
+    }
+}
+
 /// Returns abilities of a given type, if any.
 pub fn type_abilities(sp!(_, st_): &SingleType) -> Option<E::AbilitySet> {
     let sp!(_, bt_) = match st_ {
@@ -0,0 +1,109 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! This analysis flags 'public' functions returning a mutable reference to
+//! an 'iota::object::UID'. Such a reference gives callers full control over the
+//! dynamic fields of the object it belongs to.
+
+use super::{
+    LinterDiagnosticCategory, LinterDiagnosticCode, IOTA_PKG_NAME, LINT_WARNING_PREFIX,
+    OBJECT_MOD_NAME, UID_STRUCT_NAME,
+};
+use crate::{
+    diag,
+    diagnostics::{
+        codes::{custom, DiagnosticInfo, Severity},
+        WarningFilters,
+    },
+    expansion::ast::{ModuleIdent, Visibility},
+    iota_mode::IOTA_ADDR_NAME,
+    naming::ast as N,
+    parser::ast::FunctionName,
+    shared::CompilationEnv,
+    typing::{
+        ast as T,
+        visitor::{TypingVisitorConstructor, TypingVisitorContext},
+    },
+};
+
+const PUBLIC_MUT_UID_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    LinterDiagnosticCategory::Iota as u8,
+    LinterDiagnosticCode::PublicMutUid as u8,
+    "'public' function returning '&mut UID'",
+);
+
+pub struct PublicMutUidVisitor;
+pub struct Context<'a> {
+    env: &'a mut CompilationEnv,
+}
+
+impl TypingVisitorConstructor for PublicMutUidVisitor {
+    type Context<'a> = Context<'a>;
+
+    fn context<'a>(env: &'a mut CompilationEnv, _program: &T::Program) -> Self::Context<'a> {
+        Context { env }
+    }
+}
+
+impl TypingVisitorContext for Context<'_> {
+    fn add_warning_filter_scope(&mut self, filter: WarningFilters) {
+        self.env.add_warning_filter_scope(filter)
+    }
+
+    fn pop_warning_filter_scope(&mut self) {
+        self.env.pop_warning_filter_scope()
+    }
+
+    fn visit_module_custom(&mut self, ident: ModuleIdent, mdef: &mut T::ModuleDefinition) -> bool {
+        // skips if true
+        mdef.attributes.is_test_or_test_only() || ident.value.address.is(IOTA_ADDR_NAME)
+    }
+
+    fn visit_function_custom(
+        &mut self,
+        _module: ModuleIdent,
+        fname: FunctionName,
+        fdef: &mut T::Function,
+    ) -> bool {
+        if fdef.attributes.is_test_or_test_only()
+            || !matches!(fdef.visibility, Visibility::Public(_))
+        {
+            return true;
+        }
+        for t in returned_types(&fdef.signature.return_type) {
+            if is_mut_uid(t) {
+                let msg = format!("'public' function '{fname}' returns '&mut {UID_STRUCT_NAME}'");
+                let mut d = diag!(PUBLIC_MUT_UID_DIAG, (t.loc, msg));
+                d.add_note(format!(
+                    "Any caller can use a mutable reference to '{}::{}::{}' to add, modify or \
+                     remove the dynamic fields of the object it belongs to",
+                    IOTA_PKG_NAME, OBJECT_MOD_NAME, UID_STRUCT_NAME
+                ));
+                d.add_note(
+                    "Consider returning an immutable reference, or exposing the required \
+                     operations as functions of this module instead",
+                );
+                self.env.add_diag(d);
+            }
+        }
+        true
+    }
+}
+
+/// Returns the types of the returned values, unpacking tuples.
+fn returned_types(t: &N::Type) -> Vec<&N::Type> {
+    match &t.value {
+        N::Type_::Apply(_, sp!(_, N::TypeName_::Multiple(_)), tys) => tys.iter().collect(),
+        N::Type_::Unit => vec![],
+        _ => vec![t],
+    }
+}
+
+fn is_mut_uid(sp!(_, t): &N::Type) -> bool {
+    matches!(
+        t,
+        N::Type_::Ref(true, inner) if inner.value.is(IOTA_PKG_NAME, OBJECT_MOD_NAME, UID_STRUCT_NAME)
+    )
+}
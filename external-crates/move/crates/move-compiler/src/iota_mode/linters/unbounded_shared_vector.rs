@@ -0,0 +1,197 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! This analysis flags loops pushing elements to a vector of a shared object.
+//! A type is considered shared if the module defining the function shares it
+//! with 'iota::transfer::share_object' or 'iota::transfer::public_share_object'.
+//! As anyone can call functions taking a shared object, such vectors can be
+//! grown until every transaction using the object runs out of gas.
+
+use std::collections::BTreeSet;
+
+use super::{
+    first_argument, root_var, LinterDiagnosticCategory, LinterDiagnosticCode, IOTA_PKG_NAME,
+    LINT_WARNING_PREFIX, PUBLIC_SHARE_FUN, PUSH_BACK_FUN, SHARE_FUN, STD_PKG_NAME,
+    TRANSFER_MOD_NAME, VECTOR_MOD_NAME,
+};
+use crate::{
+    diag,
+    diagnostics::{
+        codes::{custom, DiagnosticInfo, Severity},
+        WarningFilters,
+    },
+    expansion::ast::ModuleIdent,
+    iota_mode::IOTA_ADDR_NAME,
+    naming::ast as N,
+    parser::ast::FunctionName,
+    shared::{CompilationEnv, Identifier},
+    typing::{
+        ast as T,
+        visitor::{TypingVisitorConstructor, TypingVisitorContext},
+    },
+};
+
+const UNBOUNDED_SHARED_VECTOR_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    LinterDiagnosticCategory::Iota as u8,
+    LinterDiagnosticCode::UnboundedSharedVector as u8,
+    "unbounded vector growth in a shared object",
+);
+
+const SHARE_FUNCTIONS: &[&str] = &[SHARE_FUN, PUBLIC_SHARE_FUN];
+
+pub struct UnboundedSharedVectorVisitor;
+pub struct Context<'a> {
+    env: &'a mut CompilationEnv,
+    /// Whether the functions of the current module are only visited to collect
+    /// the types it shares.
+    collecting: bool,
+    /// The types shared in the current module.
+    shared_types: BTreeSet<N::TypeName_>,
+    /// The parameters of the current function which are mutable references to
+    /// shared objects.
+    shared_params: BTreeSet<N::Var_>,
+    /// The number of loops around the visited expression.
+    loop_depth: usize,
+}
+
+impl TypingVisitorConstructor for UnboundedSharedVectorVisitor {
+    type Context<'a> = Context<'a>;
+
+    fn context<'a>(env: &'a mut CompilationEnv, _program: &T::Program) -> Self::Context<'a> {
+        Context {
+            env,
+            collecting: false,
+            shared_types: BTreeSet::new(),
+            shared_params: BTreeSet::new(),
+            loop_depth: 0,
+        }
+    }
+}
+
+impl TypingVisitorContext for Context<'_> {
+    fn add_warning_filter_scope(&mut self, filter: WarningFilters) {
+        self.env.add_warning_filter_scope(filter)
+    }
+
+    fn pop_warning_filter_scope(&mut self) {
+        self.env.pop_warning_filter_scope()
+    }
+
+    fn visit_module_custom(&mut self, ident: ModuleIdent, mdef: &mut T::ModuleDefinition) -> bool {
+        // skips if true
+        if mdef.attributes.is_test_or_test_only() || ident.value.address.is(IOTA_ADDR_NAME) {
+            return true;
+        }
+        // collect the shared types before checking the functions
+        self.shared_types.clear();
+        self.collecting = true;
+        for (fname, fdef) in mdef.functions.key_cloned_iter_mut() {
+            self.visit_function(ident, fname, fdef);
+        }
+        self.collecting = false;
+        self.shared_types.is_empty()
+    }
+
+    fn visit_function_custom(
+        &mut self,
+        _module: ModuleIdent,
+        _function_name: FunctionName,
+        fdef: &mut T::Function,
+    ) -> bool {
+        if fdef.attributes.is_test_or_test_only() {
+            return true;
+        }
+        if self.collecting {
+            return false;
+        }
+        self.shared_params = fdef
+            .signature
+            .parameters
+            .iter()
+            .filter(|(_, _, t)| self.is_mut_shared_object(t))
+            .map(|(_, var, _)| var.value)
+            .collect();
+        self.loop_depth = 0;
+        // skips if true
+        self.shared_params.is_empty()
+    }
+
+    fn visit_exp_custom(&mut self, exp: &mut T::Exp) -> bool {
+        use T::UnannotatedExp_ as E;
+        if self.collecting {
+            if let E::ModuleCall(fun) = &exp.exp.value {
+                if is_share_function(fun) {
+                    self.shared_types.extend(
+                        fun.type_arguments
+                            .iter()
+                            .filter_map(|t| t.value.type_name())
+                            .map(|tn| tn.value.clone()),
+                    );
+                }
+            }
+            return false;
+        }
+        match &mut exp.exp.value {
+            E::While(_, cond, body) => {
+                self.visit_exp(cond);
+                self.loop_depth += 1;
+                self.visit_exp(body);
+                self.loop_depth -= 1;
+                true
+            }
+            E::Loop { body, .. } => {
+                self.loop_depth += 1;
+                self.visit_exp(body);
+                self.loop_depth -= 1;
+                true
+            }
+            E::ModuleCall(fun) if self.loop_depth > 0 && is_push_back(fun) => {
+                if let Some(var) = first_argument(&fun.arguments)
+                    .and_then(|arg| root_var(arg))
+                    .filter(|var| self.shared_params.contains(&var.value))
+                {
+                    let msg = format!(
+                        "Elements are pushed in a loop to a vector of the shared object '{}'",
+                        var.value.name
+                    );
+                    let mut d = diag!(UNBOUNDED_SHARED_VECTOR_DIAG, (exp.exp.loc, msg));
+                    d.add_note(
+                        "Anyone can call functions taking a shared object. A vector in a shared \
+                         object which grows without bound makes every transaction using the \
+                         object more expensive, until it exceeds the gas or object size limits",
+                    );
+                    d.add_note(
+                        "Consider bounding the length of the vector, or storing the elements in \
+                         an 'iota::table::Table' or 'iota::table_vec::TableVec' instead",
+                    );
+                    self.env.add_diag(d);
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Context<'_> {
+    fn is_mut_shared_object(&self, sp!(_, t): &N::Type) -> bool {
+        matches!(
+            t,
+            N::Type_::Ref(true, inner) if inner
+                .value
+                .type_name()
+                .is_some_and(|tn| self.shared_types.contains(&tn.value))
+        )
+    }
+}
+
+fn is_share_function(fun: &T::ModuleCall) -> bool {
+    fun.module.value.is(IOTA_PKG_NAME, TRANSFER_MOD_NAME)
+        && SHARE_FUNCTIONS.contains(&fun.name.value().as_str())
+}
+
+fn is_push_back(fun: &T::ModuleCall) -> bool {
+    fun.module.value.is(STD_PKG_NAME, VECTOR_MOD_NAME) && fun.name.value().as_str() == PUSH_BACK_FUN
+}
@@ -0,0 +1,120 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! This analysis flags calls to 'iota::balance::destroy_zero' and
+//! 'iota::coin::destroy_zero' on a local whose value is not read beforehand
+//! in the same function. As these functions abort on a non-zero value, a
+//! missing check usually means either a funds-locking abort or a wrong
+//! assumption about the value.
+
+use std::collections::BTreeSet;
+
+use super::{
+    first_argument, root_var, LinterDiagnosticCategory, LinterDiagnosticCode, BALANCE_MOD_NAME,
+    COIN_MOD_NAME, DESTROY_ZERO_FUN, IOTA_PKG_NAME, LINT_WARNING_PREFIX, VALUE_FUN,
+};
+use crate::{
+    diag,
+    diagnostics::{
+        codes::{custom, DiagnosticInfo, Severity},
+        WarningFilters,
+    },
+    expansion::ast::ModuleIdent,
+    iota_mode::IOTA_ADDR_NAME,
+    naming::ast as N,
+    parser::ast::FunctionName,
+    shared::{CompilationEnv, Identifier},
+    typing::{
+        ast as T,
+        visitor::{TypingVisitorConstructor, TypingVisitorContext},
+    },
+};
+
+const UNCHECKED_DESTROY_ZERO_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    LinterDiagnosticCategory::Iota as u8,
+    LinterDiagnosticCode::UncheckedDestroyZero as u8,
+    "value destroyed without a zero check",
+);
+
+const VALUE_MODULES: &[&str] = &[BALANCE_MOD_NAME, COIN_MOD_NAME];
+
+pub struct UncheckedDestroyZeroVisitor;
+pub struct Context<'a> {
+    env: &'a mut CompilationEnv,
+    /// The locals holding a balance or coin whose value was read in the
+    /// current function so far.
+    checked_vars: BTreeSet<N::Var_>,
+}
+
+impl TypingVisitorConstructor for UncheckedDestroyZeroVisitor {
+    type Context<'a> = Context<'a>;
+
+    fn context<'a>(env: &'a mut CompilationEnv, _program: &T::Program) -> Self::Context<'a> {
+        Context {
+            env,
+            checked_vars: BTreeSet::new(),
+        }
+    }
+}
+
+impl TypingVisitorContext for Context<'_> {
+    fn add_warning_filter_scope(&mut self, filter: WarningFilters) {
+        self.env.add_warning_filter_scope(filter)
+    }
+
+    fn pop_warning_filter_scope(&mut self) {
+        self.env.pop_warning_filter_scope()
+    }
+
+    fn visit_module_custom(&mut self, ident: ModuleIdent, mdef: &mut T::ModuleDefinition) -> bool {
+        // skips if true
+        mdef.attributes.is_test_or_test_only() || ident.value.address.is(IOTA_ADDR_NAME)
+    }
+
+    fn visit_function_custom(
+        &mut self,
+        _module: ModuleIdent,
+        _function_name: FunctionName,
+        fdef: &mut T::Function,
+    ) -> bool {
+        self.checked_vars.clear();
+        // skips if true
+        fdef.attributes.is_test_or_test_only()
+    }
+
+    fn visit_exp_custom(&mut self, exp: &mut T::Exp) -> bool {
+        let T::UnannotatedExp_::ModuleCall(fun) = &exp.exp.value else {
+            return false;
+        };
+        let Some(module) = VALUE_MODULES
+            .iter()
+            .find(|module| fun.module.value.is(IOTA_PKG_NAME, module))
+        else {
+            return false;
+        };
+        let name = fun.name.value();
+        let var = first_argument(&fun.arguments).and_then(root_var);
+        if name.as_str() == VALUE_FUN {
+            if let Some(var) = var {
+                self.checked_vars.insert(var.value);
+            }
+        } else if name.as_str() == DESTROY_ZERO_FUN
+            && !var.is_some_and(|var| self.checked_vars.contains(&var.value))
+        {
+            let msg = format!(
+                "'{}::{}::{}' is called without checking that the value is zero",
+                IOTA_PKG_NAME, module, DESTROY_ZERO_FUN
+            );
+            let mut d = diag!(UNCHECKED_DESTROY_ZERO_DIAG, (exp.exp.loc, msg));
+            d.add_note(format!(
+                "'{DESTROY_ZERO_FUN}' aborts if the value is not zero. Check it with \
+                 '{IOTA_PKG_NAME}::{module}::{VALUE_FUN}' first, or join or transfer a possibly \
+                 non-zero value instead of destroying it"
+            ));
+            self.env.add_diag(d);
+        }
+        false
+    }
+}
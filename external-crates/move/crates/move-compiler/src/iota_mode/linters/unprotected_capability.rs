@@ -0,0 +1,180 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! This analysis flags 'public' and 'entry' functions creating a capability
+//! with the 'store' ability without any access control, i.e. without taking
+//! another capability or 'iota::package::Publisher' as a parameter, and
+//! without checking the sender of the transaction. Anyone can call such a
+//! function to obtain the capability and, as it has 'store', hand it over to
+//! others.
+
+use move_ir_types::location::Loc;
+use move_symbol_pool::Symbol;
+
+use super::{
+    freezing_capability::CAPABILITY_REGEX, LinterDiagnosticCategory, LinterDiagnosticCode,
+    IOTA_PKG_NAME, LINT_WARNING_PREFIX, PACKAGE_MOD_NAME, PUBLISHER_STRUCT_NAME, SENDER_FUN,
+    TX_CONTEXT_MOD_NAME,
+};
+use crate::{
+    diag,
+    diagnostics::{
+        codes::{custom, DiagnosticInfo, Severity},
+        WarningFilters,
+    },
+    expansion::ast::{ModuleIdent, Visibility},
+    iota_mode::IOTA_ADDR_NAME,
+    naming::ast as N,
+    parser::ast::{Ability_, DatatypeName, FunctionName},
+    shared::{unique_map::UniqueMap, CompilationEnv, Identifier},
+    typing::{
+        ast as T,
+        visitor::{TypingVisitorConstructor, TypingVisitorContext},
+    },
+};
+
+const UNPROTECTED_CAPABILITY_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    LinterDiagnosticCategory::Iota as u8,
+    LinterDiagnosticCode::UnprotectedCapability as u8,
+    "capability created without access control",
+);
+
+pub struct UnprotectedCapabilityVisitor;
+pub struct Context<'a> {
+    env: &'a mut CompilationEnv,
+    /// The current module.
+    module: Option<ModuleIdent>,
+    /// The capability-like types with 'store' defined in the current module.
+    capabilities: Vec<Symbol>,
+    /// Whether the sender of the transaction was read in the current function
+    /// so far.
+    sender_checked: bool,
+    /// The capabilities created in the current function.
+    created: Vec<(Loc, Symbol)>,
+}
+
+impl TypingVisitorConstructor for UnprotectedCapabilityVisitor {
+    type Context<'a> = Context<'a>;
+
+    fn context<'a>(env: &'a mut CompilationEnv, _program: &T::Program) -> Self::Context<'a> {
+        Context {
+            env,
+            module: None,
+            capabilities: vec![],
+            sender_checked: false,
+            created: vec![],
+        }
+    }
+}
+
+impl TypingVisitorContext for Context<'_> {
+    fn add_warning_filter_scope(&mut self, filter: WarningFilters) {
+        self.env.add_warning_filter_scope(filter)
+    }
+
+    fn pop_warning_filter_scope(&mut self) {
+        self.env.pop_warning_filter_scope()
+    }
+
+    fn visit_module_custom(&mut self, ident: ModuleIdent, mdef: &mut T::ModuleDefinition) -> bool {
+        // skips if true
+        if mdef.attributes.is_test_or_test_only() || ident.value.address.is(IOTA_ADDR_NAME) {
+            return true;
+        }
+        self.module = Some(ident);
+        self.capabilities = stored_capabilities(&mdef.structs);
+        self.capabilities.is_empty()
+    }
+
+    fn visit_function_custom(
+        &mut self,
+        _module: ModuleIdent,
+        fname: FunctionName,
+        fdef: &mut T::Function,
+    ) -> bool {
+        if fdef.attributes.is_test_or_test_only()
+            || (!matches!(fdef.visibility, Visibility::Public(_)) && fdef.entry.is_none())
+            || fdef
+                .signature
+                .parameters
+                .iter()
+                .any(|(_, _, t)| is_access_control(t))
+        {
+            return true;
+        }
+        self.sender_checked = false;
+        self.created.clear();
+        if let T::FunctionBody_::Defined(seq) = &mut fdef.body.value {
+            self.visit_seq(seq);
+        }
+        if !self.sender_checked {
+            for (loc, cap) in std::mem::take(&mut self.created) {
+                let msg = format!(
+                    "'{fname}' creates '{cap}', which has the 'store' ability, without any \
+                     access control"
+                );
+                let mut d = diag!(UNPROTECTED_CAPABILITY_DIAG, (loc, msg));
+                d.add_note(
+                    "Anyone can call this function to obtain the capability and, as it has \
+                     'store', freely transfer it or wrap it into other objects",
+                );
+                d.add_note(format!(
+                    "Consider requiring another capability or '{}::{}::{}' as a parameter, \
+                     checking the sender of the transaction, or only creating the capability \
+                     in the 'init' function",
+                    IOTA_PKG_NAME, PACKAGE_MOD_NAME, PUBLISHER_STRUCT_NAME
+                ));
+                self.env.add_diag(d);
+            }
+        }
+        true
+    }
+
+    fn visit_exp_custom(&mut self, exp: &mut T::Exp) -> bool {
+        match &exp.exp.value {
+            T::UnannotatedExp_::ModuleCall(fun)
+                if fun.module.value.is(IOTA_PKG_NAME, TX_CONTEXT_MOD_NAME)
+                    && fun.name.value().as_str() == SENDER_FUN =>
+            {
+                self.sender_checked = true;
+            }
+            T::UnannotatedExp_::Pack(mident, sname, _, _)
+                if self.module.is_some_and(|m| m.value == mident.value)
+                    && self.capabilities.contains(&sname.value()) =>
+            {
+                self.created.push((exp.exp.loc, sname.value()));
+            }
+            _ => (),
+        }
+        false
+    }
+}
+
+/// Returns the names of the capability-like structs with 'store'.
+fn stored_capabilities(structs: &UniqueMap<DatatypeName, N::StructDefinition>) -> Vec<Symbol> {
+    structs
+        .key_cloned_iter()
+        .filter(|(name, sdef)| {
+            sdef.abilities.has_ability_(Ability_::Store)
+                && CAPABILITY_REGEX.is_match(name.value().as_str())
+        })
+        .map(|(name, _)| name.value())
+        .collect()
+}
+
+/// Returns whether holding a value of this type, or a reference to it, grants
+/// some authority.
+fn is_access_control(t: &N::Type) -> bool {
+    let Some(sp!(_, tn)) = super::base_type(t).and_then(|t| t.value.type_name()) else {
+        return false;
+    };
+    match tn {
+        N::TypeName_::ModuleType(_, name) => {
+            CAPABILITY_REGEX.is_match(name.value().as_str())
+                || tn.is(IOTA_PKG_NAME, PACKAGE_MOD_NAME, PUBLISHER_STRUCT_NAME)
+        }
+        N::TypeName_::Builtin(_) | N::TypeName_::Multiple(_) => false,
+    }
+}
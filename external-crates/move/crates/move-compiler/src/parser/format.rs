@@ -0,0 +1,767 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Formatting of Move source files.
+//!
+//! The formatter works on the tokens produced by the lexer and only rewrites
+//! the whitespace in between them: tokens and comments are kept in their
+//! original order, so formatting never changes the meaning of a program. The
+//! line breaks of the source are kept, except for runs of blank lines which are
+//! collapsed, while the indentation and the spacing within lines are
+//! normalized. Lines are not re-wrapped.
+
+use std::collections::BTreeMap;
+
+use move_command_line_common::files::FileHash;
+
+use crate::{
+    diag,
+    diagnostics::{codes::Severity, Diagnostics},
+    editions::Edition,
+    ice,
+    parser::{
+        comments::verify_string,
+        lexer::{Lexer, Tok},
+        syntax::{make_loc, parse_file_string},
+    },
+    shared::{CompilationEnv, PackageConfig},
+    Flags,
+};
+
+const INDENT: &str = "    ";
+
+/// Formats the Move source `input`, written in the given edition. Sources
+/// which do not parse are not formatted, and the parsing errors are returned
+/// instead.
+pub fn format_string(input: &str, edition: Edition) -> Result<String, Diagnostics> {
+    let file_hash = FileHash::new(input);
+    verify_string(file_hash, input)?;
+    let mut env = CompilationEnv::new(
+        Flags::empty(),
+        vec![],
+        vec![],
+        BTreeMap::new(),
+        Some(PackageConfig {
+            edition,
+            ..PackageConfig::default()
+        }),
+    );
+    parse_file_string(&mut env, file_hash, input, None)?;
+    env.check_diags_at_or_above_severity(Severity::NonblockingError)?;
+
+    let tokens = lex(input, file_hash, edition)?;
+    let formatted = Formatter::new(input, file_hash).format(&tokens)?;
+
+    // only whitespace is rewritten, so the formatted source must be made of the
+    // same tokens and comments as the original one
+    let formatted_tokens = lex(&formatted, FileHash::new(&formatted), edition)?;
+    let same_tokens = tokens.len() == formatted_tokens.len()
+        && tokens
+            .iter()
+            .zip(&formatted_tokens)
+            .all(|(t1, t2)| t1.tok == t2.tok && t1.content == t2.content);
+    if !same_tokens
+        || count_comments(input, &tokens) != count_comments(&formatted, &formatted_tokens)
+    {
+        return Err(Diagnostics::from(vec![ice!((
+            make_loc(file_hash, 0, 0),
+            "Formatting changed the tokens of the source"
+        ))]));
+    }
+    Ok(formatted)
+}
+
+struct Token<'a> {
+    tok: Tok,
+    content: &'a str,
+    start: usize,
+    end: usize,
+}
+
+fn lex(input: &str, file_hash: FileHash, edition: Edition) -> Result<Vec<Token<'_>>, Diagnostics> {
+    let mut lexer = Lexer::new(input, file_hash, edition);
+    let mut tokens = vec![];
+    loop {
+        lexer
+            .advance()
+            .map_err(|diag| Diagnostics::from(vec![*diag]))?;
+        if lexer.peek() == Tok::EOF {
+            break;
+        }
+        tokens.push(Token {
+            tok: lexer.peek(),
+            content: lexer.content(),
+            start: lexer.start_loc(),
+            end: lexer.start_loc() + lexer.content().len(),
+        });
+    }
+    Ok(tokens)
+}
+
+fn count_comments(input: &str, tokens: &[Token]) -> usize {
+    let mut prev_end = 0;
+    let mut count = 0;
+    for token in tokens {
+        count += Gap::parse(input, prev_end, token.start).comments.len();
+        prev_end = token.end;
+    }
+    count + Gap::parse(input, prev_end, input.len()).comments.len()
+}
+
+/// The whitespace and comments in between two tokens.
+struct Gap<'a> {
+    comments: Vec<Comment<'a>>,
+    /// The number of line breaks after the last comment (or in the whole gap
+    /// if there are no comments).
+    newlines_after: usize,
+    /// Whether the gap ends with whitespace.
+    space_after: bool,
+}
+
+struct Comment<'a> {
+    text: &'a str,
+    /// The number of line breaks between the comment and the previous comment
+    /// or token.
+    newlines_before: usize,
+    /// Whether the comment is preceded by whitespace.
+    space_before: bool,
+    /// The column of the comment in the source.
+    column: usize,
+}
+
+impl<'a> Gap<'a> {
+    fn has_line_break(&self) -> bool {
+        self.newlines_after > 0
+            || self
+                .comments
+                .iter()
+                .any(|comment| comment.newlines_before > 0 || comment.text.starts_with("//"))
+    }
+
+    fn parse(input: &'a str, start: usize, end: usize) -> Self {
+        let mut comments = vec![];
+        let mut newlines = 0;
+        let mut space = false;
+        let mut offset = start;
+        while offset < end {
+            let rest = &input[offset..end];
+            let c = rest.chars().next().unwrap();
+            if c.is_whitespace() {
+                newlines += (c == '\n') as usize;
+                space = true;
+                offset += c.len_utf8();
+                continue;
+            }
+            // the lexer only leaves whitespace and comments in between tokens
+            let len = if rest.starts_with("//") {
+                rest.find('\n').unwrap_or(rest.len())
+            } else {
+                block_comment_len(rest)
+            };
+            let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+            comments.push(Comment {
+                text: rest[..len].trim_end(),
+                newlines_before: newlines,
+                space_before: space,
+                column: offset - line_start,
+            });
+            newlines = 0;
+            space = false;
+            offset += len;
+        }
+        Gap {
+            comments,
+            newlines_after: newlines,
+            space_after: space,
+        }
+    }
+}
+
+/// Returns the length of the (possibly nested) block comment `text` starts
+/// with.
+fn block_comment_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    text.len()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bracket {
+    Paren,
+    Square,
+    /// The brackets of an attribute, as in `#[test]`
+    Attribute,
+    Brace,
+    /// The braces of a list of imported members, as in `use a::m::{Self, f}`
+    UseBrace,
+    /// The angle brackets of type parameters or arguments spanning multiple
+    /// lines. As `<` and `>` can also be comparisons, they are only guessed
+    /// from their layout: a `<` directly following a name and ending its line,
+    /// closed by a `>` starting a line.
+    Angle,
+}
+
+struct OpenBracket {
+    bracket: Bracket,
+    /// The indentation level of the line the bracket was opened on.
+    indent: usize,
+    start: usize,
+}
+
+struct Formatter<'a> {
+    input: &'a str,
+    file_hash: FileHash,
+    out: String,
+    open_brackets: Vec<OpenBracket>,
+    /// The indentation level of the current line
+    indent: usize,
+    /// Whether nothing has been written on the current line yet
+    line_start: bool,
+    /// Whether a blank line should precede the next line
+    blank_line: bool,
+    /// The `if`s which may still be followed by an `else`, along with the
+    /// number of brackets open and the indentation level of their line
+    ifs: Vec<(usize, usize)>,
+    /// The last token written, along with the bracket it opened or closed
+    prev: Option<(Tok, Option<Bracket>)>,
+    /// Whether a comment was written after the last token
+    after_comment: bool,
+    /// Whether the last comment written is a line comment
+    after_line_comment: bool,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(input: &'a str, file_hash: FileHash) -> Self {
+        Self {
+            input,
+            file_hash,
+            out: String::new(),
+            open_brackets: vec![],
+            indent: 0,
+            line_start: true,
+            blank_line: false,
+            ifs: vec![],
+            prev: None,
+            after_comment: false,
+            after_line_comment: false,
+        }
+    }
+
+    fn format(mut self, tokens: &[Token]) -> Result<String, Diagnostics> {
+        // the gaps before each token, and at the end of the file
+        let mut gaps = vec![];
+        let mut prev_end = 0;
+        for token in tokens {
+            gaps.push(Gap::parse(self.input, prev_end, token.start));
+            prev_end = token.end;
+        }
+        gaps.push(Gap::parse(self.input, prev_end, self.input.len()));
+
+        for (i, token) in tokens.iter().enumerate() {
+            self.comments(&gaps[i]);
+            self.token(token, &gaps[i], gaps[i + 1].has_line_break())?;
+        }
+        self.comments(&gaps[tokens.len()]);
+
+        // angle brackets are only guessed, see `Bracket::Angle`
+        self.open_brackets
+            .retain(|open| open.bracket != Bracket::Angle);
+        if let Some(open) = self.open_brackets.last() {
+            let loc = make_loc(self.file_hash, open.start, open.start + 1);
+            return Err(Diagnostics::from(vec![diag!(
+                Syntax::UnexpectedToken,
+                (loc, "Unclosed delimiter")
+            )]));
+        }
+        let len = self.out.trim_end().len();
+        self.out.truncate(len);
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        Ok(self.out)
+    }
+
+    fn comments(&mut self, gap: &Gap) {
+        for comment in &gap.comments {
+            if comment.newlines_before > 0 || self.out.is_empty() {
+                self.new_line(comment.newlines_before);
+                let indent = self.content_indent(None);
+                self.start_line(indent, /* closing */ false);
+                self.write_comment(comment, /* reindent */ true);
+            } else {
+                // trailing line comments are always separated by a single space
+                if comment.space_before || comment.text.starts_with("//") {
+                    self.out.push(' ');
+                }
+                self.write_comment(comment, /* reindent */ false);
+            }
+            self.after_comment = true;
+            self.after_line_comment = comment.text.starts_with("//");
+        }
+    }
+
+    fn token(
+        &mut self,
+        token: &Token,
+        gap: &Gap,
+        line_break_after: bool,
+    ) -> Result<(), Diagnostics> {
+        if gap.newlines_after > 0 || self.after_line_comment {
+            self.new_line(gap.newlines_after);
+        }
+
+        let closed = match token.tok {
+            Tok::RParen | Tok::RBracket | Tok::RBrace => Some(self.close_bracket(token)?),
+            Tok::Greater if self.line_start && self.at_bracket(Bracket::Angle) => {
+                self.open_brackets.pop()
+            }
+            Tok::Semicolon => {
+                self.close_angle_brackets();
+                None
+            }
+            _ => None,
+        };
+
+        // the `if` an `else` belongs to is the last one within the same brackets
+        self.ifs
+            .retain(|(depth, _)| *depth <= self.open_brackets.len());
+        let if_indent = if token.tok == Tok::Else
+            && self
+                .ifs
+                .last()
+                .is_some_and(|(depth, _)| *depth == self.open_brackets.len())
+        {
+            self.ifs.pop().map(|(_, indent)| indent)
+        } else {
+            None
+        };
+
+        if self.line_start {
+            // closing brackets are indented like the line which opened them, and
+            // `else`s like the line of their `if`
+            let indent = match (&closed, if_indent) {
+                (Some(open), _) => open.indent,
+                (None, Some(indent)) => indent,
+                (None, None) => self.content_indent(Some(token.tok)),
+            };
+            self.start_line(indent, closed.is_some());
+        } else {
+            if self.after_comment {
+                if gap.space_after {
+                    self.out.push(' ');
+                }
+            } else if let Some((prev, prev_bracket)) = self.prev {
+                let next_bracket = closed.as_ref().map(|open| open.bracket);
+                if space_between(prev, prev_bracket, token.tok, next_bracket, gap.space_after) {
+                    self.out.push(' ');
+                }
+            }
+            // the brackets opened after a bracket closing in the middle of a line
+            // are indented like the line which opened it, as the `{` in
+            // `fun f(a: u64,\n    b: u64) {`
+            if let Some(open) = &closed {
+                self.indent = self.indent.min(open.indent);
+            }
+        }
+        // the content of `&mut` includes the whitespace following it
+        self.out.push_str(token.content.trim_end());
+
+        let bracket = match token.tok {
+            Tok::LParen => Some(Bracket::Paren),
+            Tok::LBracket if self.prev.is_some_and(|(prev, _)| prev == Tok::NumSign) => {
+                Some(Bracket::Attribute)
+            }
+            Tok::LBracket => Some(Bracket::Square),
+            Tok::LBrace if self.prev.is_some_and(|(prev, _)| prev == Tok::ColonColon) => {
+                Some(Bracket::UseBrace)
+            }
+            Tok::LBrace => Some(Bracket::Brace),
+            Tok::Less
+                if line_break_after
+                    && !gap.space_after
+                    && !self.after_comment
+                    && self.prev.is_some_and(|(prev, _)| {
+                        matches!(
+                            prev,
+                            Tok::Identifier | Tok::RestrictedIdentifier | Tok::SyntaxIdentifier
+                        )
+                    }) =>
+            {
+                Some(Bracket::Angle)
+            }
+            _ => None,
+        };
+        if token.tok == Tok::If {
+            self.ifs.push((self.open_brackets.len(), self.indent));
+        }
+        if let Some(bracket) = bracket {
+            self.open_brackets.push(OpenBracket {
+                bracket,
+                indent: self.indent,
+                start: token.start,
+            });
+        }
+        self.prev = Some((token.tok, bracket.or(closed.map(|open| open.bracket))));
+        self.after_comment = false;
+        self.after_line_comment = false;
+        Ok(())
+    }
+
+    fn close_bracket(&mut self, token: &Token) -> Result<OpenBracket, Diagnostics> {
+        let matching = |bracket| match bracket {
+            Bracket::Paren => Tok::RParen,
+            Bracket::Square | Bracket::Attribute => Tok::RBracket,
+            Bracket::Brace | Bracket::UseBrace => Tok::RBrace,
+            Bracket::Angle => Tok::Greater,
+        };
+        self.close_angle_brackets();
+        match self.open_brackets.pop() {
+            Some(open) if matching(open.bracket) == token.tok => Ok(open),
+            _ => {
+                let loc = make_loc(self.file_hash, token.start, token.end);
+                Err(Diagnostics::from(vec![diag!(
+                    Syntax::UnexpectedToken,
+                    (loc, format!("Unexpected '{}'", token.content))
+                )]))
+            }
+        }
+    }
+
+    fn at_bracket(&self, bracket: Bracket) -> bool {
+        self.open_brackets
+            .last()
+            .is_some_and(|open| open.bracket == bracket)
+    }
+
+    /// Closes the angle brackets left open, in case they were not closed at
+    /// the start of a line.
+    fn close_angle_brackets(&mut self) {
+        while self.at_bracket(Bracket::Angle) {
+            self.open_brackets.pop();
+        }
+    }
+
+    /// Ends the current line, if anything was written on it. A blank line is
+    /// kept if the source had one.
+    fn new_line(&mut self, newlines: usize) {
+        if self.out.is_empty() {
+            return;
+        }
+        if !self.line_start {
+            let len = self.out.trim_end_matches([' ', '\t']).len();
+            self.out.truncate(len);
+            self.out.push('\n');
+            self.line_start = true;
+        }
+        self.blank_line |= newlines > 1;
+    }
+
+    fn start_line(&mut self, indent: usize, closing: bool) {
+        // blank lines are dropped at the start and at the end of a block
+        let after_open = !self.after_comment
+            && self.prev.is_some_and(|(prev, bracket)| {
+                bracket.is_some() && matches!(prev, Tok::LParen | Tok::LBracket | Tok::LBrace)
+            });
+        if self.blank_line && !closing && !after_open && !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.blank_line = false;
+        self.indent = indent;
+        self.out.push_str(&INDENT.repeat(indent));
+        self.line_start = false;
+    }
+
+    /// Returns the indentation level of a line starting with `next` (or with a
+    /// comment), which does not close a bracket.
+    fn content_indent(&self, next: Option<Tok>) -> usize {
+        let indent = self.open_brackets.last().map_or(0, |open| open.indent + 1);
+        // lines which do not start a new statement, member or list element
+        // continue the previous line and are indented further
+        let continuation = next != Some(Tok::LBrace)
+            && self.prev.is_some_and(|(prev, bracket)| {
+                !matches!(
+                    prev,
+                    Tok::Semicolon
+                        | Tok::Comma
+                        | Tok::LParen
+                        | Tok::LBracket
+                        | Tok::LBrace
+                        | Tok::RBrace
+                ) && !matches!(bracket, Some(Bracket::Attribute | Bracket::Angle))
+            });
+        indent + continuation as usize
+    }
+
+    fn write_comment(&mut self, comment: &Comment, reindent: bool) {
+        let mut lines = comment.text.split('\n');
+        self.out.push_str(lines.next().unwrap().trim_end());
+        // the lines of a block comment keep their position relative to its start
+        let shift = self.indent * INDENT.len();
+        for line in lines {
+            self.out.push('\n');
+            let line = line.trim_end();
+            let content = line.trim_start();
+            if !reindent || content.is_empty() || shift == comment.column {
+                self.out.push_str(line);
+                continue;
+            }
+            let column = (line.len() - content.len() + shift).saturating_sub(comment.column);
+            self.out.push_str(&" ".repeat(column));
+            self.out.push_str(content);
+        }
+    }
+}
+
+/// Returns whether a space separates the tokens `prev` and `next` written on
+/// the same line. `spaced` tells whether they were separated in the source,
+/// which is kept when the tokens are ambiguous, e.g. `<` can be either a
+/// comparison or the start of type arguments.
+fn space_between(
+    prev: Tok,
+    prev_bracket: Option<Bracket>,
+    next: Tok,
+    next_bracket: Option<Bracket>,
+    spaced: bool,
+) -> bool {
+    use Tok::*;
+    match (prev, next) {
+        (ColonColon, _) | (_, ColonColon) => false,
+        (_, Comma | Semicolon) => false,
+        (LParen | LBracket, _) | (_, RParen | RBracket) => false,
+        (Comma | Semicolon, _) => true,
+        (Period, _) | (_, Period) => false,
+        (LBrace, RBrace) => false,
+        (LBrace, _) => prev_bracket != Some(Bracket::UseBrace),
+        (_, RBrace) => next_bracket != Some(Bracket::UseBrace),
+        (_, LBrace) => true,
+        (_, Colon) => false,
+        (Colon, _) => true,
+        (NumSign | AtSign | Exclaim, _) => false,
+        (Identifier | RestrictedIdentifier | SyntaxIdentifier, Exclaim) => false,
+        (AmpMut, _) => true,
+        (Public, LParen) => false,
+        (If | While | Match, LParen) => true,
+        (Identifier | RestrictedIdentifier | SyntaxIdentifier, LParen | LBracket) => false,
+        (RParen | RBracket, LBracket) => false,
+        (_, LParen | LBracket) => spaced,
+        _ if is_binary_operator(prev) || is_binary_operator(next) => true,
+        _ if is_keyword(prev) || is_keyword(next) => true,
+        _ => spaced,
+    }
+}
+
+/// Returns whether the token is always a binary operator. Tokens which can
+/// also be unary operators or delimiters, such as `*`, `&`, `|` or `<`, are
+/// not.
+fn is_binary_operator(tok: Tok) -> bool {
+    use Tok::*;
+    matches!(
+        tok,
+        Equal
+            | EqualEqual
+            | ExclaimEqual
+            | LessEqual
+            | GreaterEqual
+            | AmpAmp
+            | PipePipe
+            | Plus
+            | Minus
+            | Slash
+            | Percent
+            | Caret
+            | LessLess
+            | EqualGreater
+            | EqualEqualGreater
+            | LessEqualEqualGreater
+            | MinusGreater
+    )
+}
+
+fn is_keyword(tok: Tok) -> bool {
+    use Tok::*;
+    matches!(
+        tok,
+        Abort
+            | Acquires
+            | As
+            | Break
+            | Continue
+            | Copy
+            | Else
+            | If
+            | Invariant
+            | Let
+            | Loop
+            | Module
+            | Move
+            | Native
+            | Public
+            | Return
+            | Spec
+            | Struct
+            | Use
+            | While
+            | Fun
+            | Const
+            | Friend
+            | Mut
+            | Enum
+            | Type
+            | Match
+            | For
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_string;
+    use crate::editions::Edition;
+
+    fn format(input: &str) -> String {
+        let formatted = format_string(input, Edition::E2024_BETA).unwrap();
+        assert_eq!(
+            format_string(&formatted, Edition::E2024_BETA).unwrap(),
+            formatted,
+            "formatting is not idempotent"
+        );
+        formatted
+    }
+
+    #[test]
+    fn test_format_layout() {
+        let input = r#"module 0x1::m {
+use 0x1::vector::{ Self,length };
+  // a comment
+public fun f(x:u64,y :u64):u64{
+let z=x+y;   // trailing
+
+
+      if(z > 0){ z }else{
+
+
+      0
+  }
+}
+}"#;
+        let expected = r#"module 0x1::m {
+    use 0x1::vector::{Self, length};
+    // a comment
+    public fun f(x: u64, y: u64): u64 {
+        let z = x + y; // trailing
+
+        if (z > 0) { z } else {
+            0
+        }
+    }
+}
+"#;
+        assert_eq!(format(input), expected);
+    }
+
+    #[test]
+    fn test_format_continuation() {
+        let input = r#"module 0x1::m;
+
+#[test, expected_failure(abort_code=1)]
+fun f(v: vector<u64>): vector<u64> {
+let s =
+v.length();
+v
+.map!(|x| x+s)
+.filter!(|x| *x > 1)
+}
+
+fun g(x: u64): u64 {
+if (x > 1) x
+else if (x == 1)
+1
+  else
+0
+}
+
+public macro fun m<
+$T: copy,
+  >($x: $T): $T {
+assert!(
+$x
+== $x,
+);
+$x
+}
+"#;
+        let expected = r#"module 0x1::m;
+
+#[test, expected_failure(abort_code = 1)]
+fun f(v: vector<u64>): vector<u64> {
+    let s =
+        v.length();
+    v
+        .map!(|x| x + s)
+        .filter!(|x| *x > 1)
+}
+
+fun g(x: u64): u64 {
+    if (x > 1) x
+    else if (x == 1)
+        1
+    else
+        0
+}
+
+public macro fun m<
+    $T: copy,
+>($x: $T): $T {
+    assert!(
+        $x
+            == $x,
+    );
+    $x
+}
+"#;
+        assert_eq!(format(input), expected);
+    }
+
+    #[test]
+    fn test_format_comments() {
+        let input = r#"// Copyright
+module 0x1::m {
+/// Documentation
+  /* a block
+     comment */
+    public(package) fun f(/* no args */) {
+      abort 0 // never returns
+    }
+}"#;
+        let expected = r#"// Copyright
+module 0x1::m {
+    /// Documentation
+    /* a block
+       comment */
+    public(package) fun f(/* no args */) {
+        abort 0 // never returns
+    }
+}
+"#;
+        assert_eq!(format(input), expected);
+    }
+
+    #[test]
+    fn test_format_syntax_error() {
+        assert!(format_string("module 0x1::m { fun f( }", Edition::E2024_BETA).is_err());
+    }
+}
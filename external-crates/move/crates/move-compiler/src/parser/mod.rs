@@ -6,6 +6,7 @@
 pub mod ast;
 pub mod comments;
 pub(crate) mod filter;
+pub mod format;
 pub mod keywords;
 pub mod lexer;
 pub(crate) mod syntax;
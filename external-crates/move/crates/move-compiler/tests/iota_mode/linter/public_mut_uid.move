@@ -0,0 +1,44 @@
+// tests the lint for 'public' functions returning '&mut UID'
+module a::public_mut_uid {
+    use iota::object::UID;
+
+    struct Obj has key {
+        id: UID,
+    }
+
+    // should warn
+    public fun uid_mut(obj: &mut Obj): &mut UID {
+        &mut obj.id
+    }
+
+    // should warn
+    public fun uid_mut_and_value(obj: &mut Obj): (u64, &mut UID) {
+        (0, &mut obj.id)
+    }
+
+    // should not warn
+    public fun uid(obj: &Obj): &UID {
+        &obj.id
+    }
+
+    // should not warn
+    fun private_uid_mut(obj: &mut Obj): &mut UID {
+        &mut obj.id
+    }
+
+    // should not warn
+    public(friend) fun friend_uid_mut(obj: &mut Obj): &mut UID {
+        &mut obj.id
+    }
+
+    #[allow(lint(public_mut_uid))]
+    public fun suppressed_uid_mut(obj: &mut Obj): &mut UID {
+        &mut obj.id
+    }
+}
+
+module iota::object {
+    struct UID has store {
+        id: address,
+    }
+}
@@ -0,0 +1,90 @@
+// tests the lint for vectors of shared objects growing in loops
+module a::unbounded_shared_vector {
+    use std::vector;
+    use iota::object::{Self, UID};
+    use iota::transfer;
+    use iota::tx_context::TxContext;
+
+    struct Registry has key {
+        id: UID,
+        entries: vector<u64>,
+    }
+
+    struct Owned has key {
+        id: UID,
+        entries: vector<u64>,
+    }
+
+    public fun share(ctx: &mut TxContext) {
+        transfer::share_object(Registry { id: object::new(ctx), entries: vector[] })
+    }
+
+    // should warn
+    public fun register_all(registry: &mut Registry, entries: vector<u64>) {
+        while (!vector::is_empty(&entries)) {
+            vector::push_back(&mut registry.entries, vector::pop_back(&mut entries));
+        }
+    }
+
+    // should warn
+    public fun register_n(registry: &mut Registry, n: u64) {
+        let i = 0;
+        loop {
+            if (i == n) break;
+            vector::push_back(&mut registry.entries, i);
+            i = i + 1;
+        }
+    }
+
+    // should not warn
+    public fun register(registry: &mut Registry, entry: u64) {
+        vector::push_back(&mut registry.entries, entry);
+    }
+
+    // should not warn
+    public fun collect(registry: &Registry, n: u64): vector<u64> {
+        let entries = vector[];
+        let i = 0;
+        while (i < n) {
+            vector::push_back(&mut entries, *vector::borrow(&registry.entries, i));
+            i = i + 1;
+        };
+        entries
+    }
+
+    // should not warn
+    public fun register_owned(owned: &mut Owned, n: u64) {
+        let i = 0;
+        while (i < n) {
+            vector::push_back(&mut owned.entries, i);
+            i = i + 1;
+        }
+    }
+
+    #[allow(lint(unbounded_shared_vector))]
+    public fun suppressed_register_all(registry: &mut Registry, entries: vector<u64>) {
+        while (!vector::is_empty(&entries)) {
+            vector::push_back(&mut registry.entries, vector::pop_back(&mut entries));
+        }
+    }
+}
+
+module iota::object {
+    struct UID has store {
+        id: address,
+    }
+
+    public fun new(_: &mut iota::tx_context::TxContext): UID {
+        abort 0
+    }
+}
+
+module iota::transfer {
+    public fun share_object<T: key>(_: T) {
+        abort 0
+    }
+}
+
+module iota::tx_context {
+    struct TxContext has drop {}
+}
@@ -0,0 +1,78 @@
+// tests the lint for destroying balances and coins without checking their value
+module a::unchecked_destroy_zero {
+    use iota::balance::{Self, Balance};
+    use iota::coin::{Self, Coin};
+
+    struct S {}
+
+    // should warn
+    public fun destroy_balance(b: Balance<S>) {
+        balance::destroy_zero(b);
+    }
+
+    // should warn
+    public fun destroy_coin(c: Coin<S>) {
+        coin::destroy_zero(c);
+    }
+
+    // should not warn
+    public fun destroy_checked_balance(b: Balance<S>) {
+        assert!(balance::value(&b) == 0, 0);
+        balance::destroy_zero(b);
+    }
+
+    // should not warn
+    public fun destroy_checked_coin(c: Coin<S>, keep: &mut Coin<S>) {
+        if (coin::value(&c) == 0) {
+            coin::destroy_zero(c);
+        } else {
+            coin::join(keep, c);
+        }
+    }
+
+    // should warn, the value of another balance is checked
+    public fun destroy_other_balance(b: Balance<S>, other: &Balance<S>) {
+        assert!(balance::value(other) == 0, 0);
+        balance::destroy_zero(b);
+    }
+
+    #[allow(lint(unchecked_destroy_zero))]
+    public fun suppressed_destroy_balance(b: Balance<S>) {
+        balance::destroy_zero(b);
+    }
+}
+
+module iota::balance {
+    struct Balance<phantom T> has store {
+        value: u64,
+    }
+
+    public fun value<T>(self: &Balance<T>): u64 {
+        self.value
+    }
+
+    public fun destroy_zero<T>(balance: Balance<T>) {
+        let Balance { value } = balance;
+        assert!(value == 0, 0);
+    }
+}
+
+module iota::coin {
+    struct Coin<phantom T> has store {
+        value: u64,
+    }
+
+    public fun value<T>(self: &Coin<T>): u64 {
+        self.value
+    }
+
+    public fun join<T>(self: &mut Coin<T>, c: Coin<T>) {
+        let Coin { value } = c;
+        self.value = self.value + value;
+    }
+
+    public fun destroy_zero<T>(c: Coin<T>) {
+        let Coin { value } = c;
+        assert!(value == 0, 0);
+    }
+}
@@ -0,0 +1,88 @@
+// tests the lint for capabilities with 'store' created without access control
+module a::unprotected_capability {
+    use iota::object::{Self, UID};
+    use iota::package::Publisher;
+    use iota::transfer;
+    use iota::tx_context::{Self, TxContext};
+
+    struct AdminCap has key, store {
+        id: UID,
+    }
+
+    struct OwnerCap has key {
+        id: UID,
+    }
+
+    const ADMIN: address = @0x42;
+
+    // should warn
+    public fun new_admin_cap(ctx: &mut TxContext): AdminCap {
+        AdminCap { id: object::new(ctx) }
+    }
+
+    // should warn
+    entry fun mint_admin_cap(recipient: address, ctx: &mut TxContext) {
+        transfer::public_transfer(AdminCap { id: object::new(ctx) }, recipient)
+    }
+
+    // should not warn
+    public fun new_admin_cap_with_cap(_: &AdminCap, ctx: &mut TxContext): AdminCap {
+        AdminCap { id: object::new(ctx) }
+    }
+
+    // should not warn
+    public fun new_admin_cap_with_publisher(_: &Publisher, ctx: &mut TxContext): AdminCap {
+        AdminCap { id: object::new(ctx) }
+    }
+
+    // should not warn
+    public fun new_admin_cap_for_admin(ctx: &mut TxContext): AdminCap {
+        assert!(tx_context::sender(ctx) == ADMIN, 0);
+        AdminCap { id: object::new(ctx) }
+    }
+
+    // should not warn
+    public fun new_owner_cap(ctx: &mut TxContext): OwnerCap {
+        OwnerCap { id: object::new(ctx) }
+    }
+
+    // should not warn
+    fun private_new_admin_cap(ctx: &mut TxContext): AdminCap {
+        AdminCap { id: object::new(ctx) }
+    }
+
+    #[allow(lint(unprotected_capability))]
+    public fun suppressed_new_admin_cap(ctx: &mut TxContext): AdminCap {
+        AdminCap { id: object::new(ctx) }
+    }
+}
+
+module iota::object {
+    struct UID has store {
+        id: address,
+    }
+
+    public fun new(_: &mut iota::tx_context::TxContext): UID {
+        abort 0
+    }
+}
+
+module iota::package {
+    struct Publisher has key, store {
+        id: iota::object::UID,
+    }
+}
+
+module iota::transfer {
+    public fun public_transfer<T: key + store>(_: T, _: address) {
+        abort 0
+    }
+}
+
+module iota::tx_context {
+    struct TxContext has drop {}
+
+    public fun sender(_: &TxContext): address {
+        @0
+    }
+}
@@ -0,0 +1,720 @@
+// Copyright (c) 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use move_binary_format::{
+    file_format::{Bytecode, CodeOffset, FunctionDefinitionIndex},
+    CompiledModule,
+};
+use move_bytecode_source_map::source_map::SourceMap;
+
+use crate::coverage_map::ExecCoverageMap;
+
+/// The formats the coverage information can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CoverageExportFormat {
+    /// LCOV tracefile, as read by `genhtml` and most coverage services
+    Lcov,
+    /// Cobertura XML
+    Cobertura,
+}
+
+/// The coverage of a source line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineCoverage {
+    /// The number of times the line was executed
+    pub hits: u64,
+    /// The number of times each outcome of the branches on the line was taken,
+    /// `None` if the branch itself was never executed
+    pub branches: Vec<Option<u64>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionLineCoverage {
+    pub name: String,
+    /// The line the function is declared at
+    pub line: u32,
+    /// The number of times the function was called, i.e. its first
+    /// instruction was executed other than by jumping back to it
+    pub hits: u64,
+    pub lines: BTreeMap<u32, LineCoverage>,
+}
+
+/// The line and branch coverage of a module, computed from the coverage map
+/// and the source map of the module. Lines are 1-indexed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleLineCoverage {
+    /// The module name, qualified by its address
+    pub name: String,
+    pub source_path: PathBuf,
+    pub functions: Vec<FunctionLineCoverage>,
+}
+
+impl LineCoverage {
+    fn merge(&mut self, other: &LineCoverage) {
+        self.hits = self.hits.max(other.hits);
+        self.branches.extend(other.branches.iter().copied());
+    }
+
+    fn covered_branches(&self) -> usize {
+        self.branches
+            .iter()
+            .filter(|taken| taken.is_some_and(|taken| taken > 0))
+            .count()
+    }
+}
+
+impl ModuleLineCoverage {
+    /// A line is executed as many times as its most executed instruction. The
+    /// outcomes of conditional branches and variant switches are counted as
+    /// taken when the instruction they lead to was executed, which is exact
+    /// unless that instruction can also be reached from elsewhere.
+    pub fn new(
+        module: &CompiledModule,
+        coverage_map: &ExecCoverageMap,
+        source_map: &SourceMap,
+        source_path: &Path,
+    ) -> Result<Self> {
+        let source = fs::read_to_string(source_path)?;
+        if !source_map.check(&source) {
+            bail!(
+                "File contents of {} out of sync with source map",
+                source_path.display()
+            );
+        }
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect::<Vec<_>>();
+        let line_of =
+            |offset: u32| line_starts.partition_point(|start| *start <= offset as usize) as u32;
+        let file_hash = source_map.definition_location.file_hash();
+
+        let module_id = module.self_id();
+        let module_map = coverage_map
+            .module_maps
+            .get(&(*module_id.address(), module_id.name().to_owned()));
+
+        let mut functions = vec![];
+        for (function_def_idx, function_def) in module.function_defs().iter().enumerate() {
+            // native functions have no code to cover
+            let Some(code_unit) = &function_def.code else {
+                continue;
+            };
+            let fn_handle = module.function_handle_at(function_def.function);
+            let fn_name = module.identifier_at(fn_handle.name);
+            let function_coverage =
+                module_map.and_then(|module_map| module_map.function_maps.get(fn_name));
+            let hits = |code_offset: CodeOffset| {
+                function_coverage
+                    .and_then(|coverage| coverage.get(&(code_offset as u64)))
+                    .copied()
+                    .unwrap_or(0)
+            };
+            let function_map = source_map
+                .get_function_source_map(FunctionDefinitionIndex(function_def_idx as u16))?;
+
+            let mut lines = BTreeMap::<u32, LineCoverage>::new();
+            for (code_offset, instr) in code_unit.code.iter().enumerate() {
+                let code_offset = code_offset as CodeOffset;
+                // code inlined from macros defined in other files is not attributed
+                // to this one
+                let Some(loc) = function_map
+                    .get_code_location(code_offset)
+                    .filter(|loc| loc.file_hash() == file_hash)
+                else {
+                    continue;
+                };
+                let line = lines.entry(line_of(loc.start())).or_default();
+                let executed = hits(code_offset);
+                line.hits = line.hits.max(executed);
+
+                let mut outcomes = instr.offsets(&code_unit.jump_tables);
+                if instr.is_conditional_branch() {
+                    outcomes.push(code_offset + 1);
+                } else if !matches!(instr, Bytecode::VariantSwitch(_)) {
+                    continue;
+                }
+                outcomes.sort();
+                outcomes.dedup();
+                line.branches.extend(
+                    outcomes
+                        .into_iter()
+                        .map(|outcome| (executed > 0).then(|| hits(outcome).min(executed))),
+                );
+            }
+
+            // The first instruction is also executed whenever a loop starting
+            // the function jumps back to it, which is not a call
+            let back_edges = code_unit
+                .code
+                .iter()
+                .enumerate()
+                .map(|(code_offset, instr)| {
+                    let code_offset = code_offset as CodeOffset;
+                    let mut targets = instr.offsets(&code_unit.jump_tables);
+                    targets.sort();
+                    targets.dedup();
+                    if !targets.contains(&0) {
+                        return 0;
+                    }
+                    // the jumps to other targets, including falling through to the
+                    // next instruction, are the ones not taken to the start
+                    if instr.is_conditional_branch() {
+                        targets.push(code_offset + 1);
+                    }
+                    let to_others = targets
+                        .into_iter()
+                        .filter(|target| *target != 0)
+                        .map(hits)
+                        .sum::<u64>();
+                    hits(code_offset).saturating_sub(to_others)
+                })
+                .sum::<u64>();
+
+            functions.push(FunctionLineCoverage {
+                name: fn_name.to_string(),
+                line: line_of(function_map.definition_location.start()),
+                hits: hits(0).saturating_sub(back_edges),
+                lines,
+            });
+        }
+
+        Ok(Self {
+            name: module_id.short_str_lossless(),
+            source_path: source_path.to_path_buf(),
+            functions,
+        })
+    }
+
+    /// Returns the coverage of the lines of all functions of the module.
+    pub fn lines(&self) -> BTreeMap<u32, LineCoverage> {
+        let mut lines = BTreeMap::<u32, LineCoverage>::new();
+        for function in &self.functions {
+            for (line, coverage) in &function.lines {
+                lines.entry(*line).or_default().merge(coverage);
+            }
+        }
+        lines
+    }
+}
+
+/// Writes the coverage of the modules as an LCOV tracefile, with one record
+/// per source file.
+pub fn format_lcov<W: Write>(modules: &[ModuleLineCoverage], writer: &mut W) -> io::Result<()> {
+    let mut files = BTreeMap::<&Path, Vec<&ModuleLineCoverage>>::new();
+    for module in modules {
+        files.entry(&module.source_path).or_default().push(module);
+    }
+
+    for (source_path, modules) in files {
+        writeln!(writer, "TN:")?;
+        writeln!(writer, "SF:{}", display_path(source_path))?;
+
+        let functions = modules
+            .iter()
+            .flat_map(|module| {
+                module
+                    .functions
+                    .iter()
+                    .map(move |function| (format!("{}::{}", module.name, function.name), function))
+            })
+            .collect::<Vec<_>>();
+        for (name, function) in &functions {
+            writeln!(writer, "FN:{},{}", function.line, name)?;
+        }
+        for (name, function) in &functions {
+            writeln!(writer, "FNDA:{},{}", function.hits, name)?;
+        }
+        writeln!(writer, "FNF:{}", functions.len())?;
+        writeln!(
+            writer,
+            "FNH:{}",
+            functions.iter().filter(|(_, f)| f.hits > 0).count()
+        )?;
+
+        let mut lines = BTreeMap::<u32, LineCoverage>::new();
+        for module in modules {
+            for (line, coverage) in module.lines() {
+                lines.entry(line).or_default().merge(&coverage);
+            }
+        }
+        let (mut branches_found, mut branches_hit) = (0, 0);
+        for (line, coverage) in &lines {
+            for (idx, taken) in coverage.branches.iter().enumerate() {
+                match taken {
+                    Some(taken) => writeln!(writer, "BRDA:{line},0,{idx},{taken}")?,
+                    None => writeln!(writer, "BRDA:{line},0,{idx},-")?,
+                }
+            }
+            branches_found += coverage.branches.len();
+            branches_hit += coverage.covered_branches();
+        }
+        writeln!(writer, "BRF:{branches_found}")?;
+        writeln!(writer, "BRH:{branches_hit}")?;
+        for (line, coverage) in &lines {
+            writeln!(writer, "DA:{line},{}", coverage.hits)?;
+        }
+        writeln!(writer, "LF:{}", lines.len())?;
+        writeln!(
+            writer,
+            "LH:{}",
+            lines.values().filter(|coverage| coverage.hits > 0).count()
+        )?;
+        writeln!(writer, "end_of_record")?;
+    }
+    Ok(())
+}
+
+/// Writes the coverage of the modules as a Cobertura XML report, with the
+/// modules as the classes of a single package. Source paths are relative to
+/// `source_root`.
+pub fn format_cobertura<W: Write>(
+    modules: &[ModuleLineCoverage],
+    package_name: &str,
+    source_root: &Path,
+    writer: &mut W,
+) -> io::Result<()> {
+    let module_lines = modules
+        .iter()
+        .map(|module| module.lines())
+        .collect::<Vec<_>>();
+    let all_lines = module_lines.iter().flat_map(|lines| lines.values());
+    let (lines_valid, lines_covered, branches_valid, branches_covered) = counts(all_lines.clone());
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    writeln!(writer, r#"<?xml version="1.0" ?>"#)?;
+    writeln!(
+        writer,
+        r#"<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">"#
+    )?;
+    writeln!(
+        writer,
+        r#"<coverage line-rate="{}" branch-rate="{}" lines-covered="{lines_covered}" lines-valid="{lines_valid}" branches-covered="{branches_covered}" branches-valid="{branches_valid}" complexity="0" version="{}" timestamp="{timestamp}">"#,
+        rate(lines_covered, lines_valid),
+        rate(branches_covered, branches_valid),
+        env!("CARGO_PKG_VERSION"),
+    )?;
+    writeln!(writer, "  <sources>")?;
+    writeln!(
+        writer,
+        "    <source>{}</source>",
+        xml_escape(&display_path(source_root))
+    )?;
+    writeln!(writer, "  </sources>")?;
+    writeln!(writer, "  <packages>")?;
+    writeln!(
+        writer,
+        r#"    <package name="{}" line-rate="{}" branch-rate="{}" complexity="0">"#,
+        xml_escape(package_name),
+        rate(lines_covered, lines_valid),
+        rate(branches_covered, branches_valid),
+    )?;
+    writeln!(writer, "      <classes>")?;
+    for (module, lines) in modules.iter().zip(&module_lines) {
+        let (lines_valid, lines_covered, branches_valid, branches_covered) = counts(lines.values());
+        writeln!(
+            writer,
+            r#"        <class name="{}" filename="{}" line-rate="{}" branch-rate="{}" complexity="0">"#,
+            xml_escape(&module.name),
+            xml_escape(&display_path(
+                module
+                    .source_path
+                    .strip_prefix(source_root)
+                    .unwrap_or(&module.source_path)
+            )),
+            rate(lines_covered, lines_valid),
+            rate(branches_covered, branches_valid),
+        )?;
+        writeln!(writer, "          <methods>")?;
+        for function in &module.functions {
+            let (lines_valid, lines_covered, branches_valid, branches_covered) =
+                counts(function.lines.values());
+            writeln!(
+                writer,
+                r#"            <method name="{}" signature="" line-rate="{}" branch-rate="{}" complexity="0">"#,
+                xml_escape(&function.name),
+                rate(lines_covered, lines_valid),
+                rate(branches_covered, branches_valid),
+            )?;
+            writeln!(writer, "              <lines>")?;
+            for (line, coverage) in &function.lines {
+                write_cobertura_line(writer, "                ", *line, coverage)?;
+            }
+            writeln!(writer, "              </lines>")?;
+            writeln!(writer, "            </method>")?;
+        }
+        writeln!(writer, "          </methods>")?;
+        writeln!(writer, "          <lines>")?;
+        for (line, coverage) in lines {
+            write_cobertura_line(writer, "            ", *line, coverage)?;
+        }
+        writeln!(writer, "          </lines>")?;
+        writeln!(writer, "        </class>")?;
+    }
+    writeln!(writer, "      </classes>")?;
+    writeln!(writer, "    </package>")?;
+    writeln!(writer, "  </packages>")?;
+    writeln!(writer, "</coverage>")
+}
+
+fn write_cobertura_line<W: Write>(
+    writer: &mut W,
+    indent: &str,
+    line: u32,
+    coverage: &LineCoverage,
+) -> io::Result<()> {
+    if coverage.branches.is_empty() {
+        writeln!(
+            writer,
+            r#"{indent}<line number="{line}" hits="{}" branch="false"/>"#,
+            coverage.hits
+        )
+    } else {
+        let covered = coverage.covered_branches();
+        let total = coverage.branches.len();
+        writeln!(
+            writer,
+            r#"{indent}<line number="{line}" hits="{}" branch="true" condition-coverage="{}% ({covered}/{total})"/>"#,
+            coverage.hits,
+            covered * 100 / total,
+        )
+    }
+}
+
+/// Returns the number of lines, covered lines, branches and covered branches.
+fn counts<'a>(lines: impl Iterator<Item = &'a LineCoverage>) -> (usize, usize, usize, usize) {
+    lines.fold(
+        (0, 0, 0, 0),
+        |(lines, covered, branches, covered_branches), line| {
+            (
+                lines + 1,
+                covered + usize::from(line.hits > 0),
+                branches + line.branches.len(),
+                covered_branches + line.covered_branches(),
+            )
+        },
+    )
+}
+
+fn rate(covered: usize, total: usize) -> String {
+    let rate = if total == 0 {
+        1.0
+    } else {
+        covered as f64 / total as f64
+    };
+    format!("{rate:.4}")
+}
+
+fn display_path(path: &Path) -> String {
+    path.strip_prefix(".")
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::{
+        empty_module, CodeUnit, FunctionDefinition, FunctionHandle, FunctionHandleIndex,
+        IdentifierIndex, ModuleHandleIndex, Signature, SignatureIndex, SignatureToken, Visibility,
+    };
+    use move_command_line_common::files::FileHash;
+    use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+    use move_ir_types::{
+        ast::{ModuleIdent, ModuleName},
+        location::Loc,
+    };
+
+    const SOURCE: &str = "module 0x42::m {
+    fun count_down(n: u64) {
+        while (n > 0) {
+            n = n - 1;
+        }
+    }
+
+    fun never(b: bool): u64 {
+        if (b) 1 else 2
+    }
+}
+";
+
+    fn loc(snippet: &str) -> Loc {
+        let start = SOURCE.find(snippet).unwrap() as u32;
+        Loc::new(FileHash::new(SOURCE), start, start + snippet.len() as u32)
+    }
+
+    /// The bytecode of `SOURCE` along with its source map, with each function
+    /// given as its name, parameter type, return type and code with the
+    /// snippet of source each segment of the code starts at.
+    fn compiled_module() -> (CompiledModule, SourceMap) {
+        let address = AccountAddress::from_hex_literal("0x42").unwrap();
+        let functions = [
+            (
+                "count_down",
+                SignatureToken::U64,
+                None,
+                vec![
+                    (Bytecode::CopyLoc(0), Some("while (n > 0)")),
+                    (Bytecode::LdU64(0), None),
+                    (Bytecode::Gt, None),
+                    (Bytecode::BrFalse(9), None),
+                    (Bytecode::MoveLoc(0), Some("n = n - 1;")),
+                    (Bytecode::LdU64(1), None),
+                    (Bytecode::Sub, None),
+                    (Bytecode::StLoc(0), None),
+                    (Bytecode::Branch(0), Some("while (n > 0)")),
+                    (Bytecode::Ret, Some("    }\n\n")),
+                ],
+            ),
+            (
+                "never",
+                SignatureToken::Bool,
+                Some(SignatureToken::U64),
+                vec![
+                    (Bytecode::MoveLoc(0), Some("if (b) 1 else 2")),
+                    (Bytecode::BrFalse(4), None),
+                    (Bytecode::LdU64(1), None),
+                    (Bytecode::Ret, None),
+                    (Bytecode::LdU64(2), None),
+                    (Bytecode::Ret, None),
+                ],
+            ),
+        ];
+
+        let mut module = empty_module();
+        module.identifiers[0] = Identifier::new("m").unwrap();
+        module.address_identifiers[0] = address;
+        let mut source_map = SourceMap::new(
+            loc("0x42::m"),
+            ModuleIdent::new(ModuleName("m".into()), address),
+        );
+        for (fdef_idx, (name, parameter, return_, code)) in functions.into_iter().enumerate() {
+            let fdef_idx = FunctionDefinitionIndex(fdef_idx as u16);
+            module.signatures.push(Signature(vec![parameter]));
+            let parameters = SignatureIndex(module.signatures.len() as u16 - 1);
+            module
+                .signatures
+                .push(Signature(return_.into_iter().collect()));
+            let return_ = SignatureIndex(module.signatures.len() as u16 - 1);
+            module.identifiers.push(Identifier::new(name).unwrap());
+            module.function_handles.push(FunctionHandle {
+                module: ModuleHandleIndex(0),
+                name: IdentifierIndex(module.identifiers.len() as u16 - 1),
+                parameters,
+                return_,
+                type_parameters: vec![],
+            });
+            source_map
+                .add_top_level_function_mapping(fdef_idx, loc(name), false)
+                .unwrap();
+            for (code_offset, (_, snippet)) in code.iter().enumerate() {
+                if let Some(snippet) = snippet {
+                    source_map
+                        .add_code_mapping(fdef_idx, code_offset as CodeOffset, loc(snippet))
+                        .unwrap();
+                }
+            }
+            module.function_defs.push(FunctionDefinition {
+                function: FunctionHandleIndex(module.function_handles.len() as u16 - 1),
+                visibility: Visibility::Private,
+                is_entry: false,
+                acquires_global_resources: vec![],
+                code: Some(CodeUnit {
+                    locals: SignatureIndex(0),
+                    code: code.into_iter().map(|(instr, _)| instr).collect(),
+                    jump_tables: vec![],
+                }),
+            });
+        }
+        (module, source_map)
+    }
+
+    /// `count_down` called with 2 and then with 0, `never` not called
+    fn coverage_map() -> ExecCoverageMap {
+        let mut coverage_map = ExecCoverageMap::new(String::new());
+        let counts = [(0..=3, 4), (4..=8, 2), (9..=9, 2)];
+        for (code_offsets, count) in counts {
+            for code_offset in code_offsets {
+                coverage_map.insert_multi(
+                    AccountAddress::from_hex_literal("0x42").unwrap(),
+                    Identifier::new("m").unwrap(),
+                    Identifier::new("count_down").unwrap(),
+                    code_offset,
+                    count,
+                );
+            }
+        }
+        coverage_map
+    }
+
+    fn line(hits: u64, branches: Vec<Option<u64>>) -> LineCoverage {
+        LineCoverage { hits, branches }
+    }
+
+    fn expected_coverage(source_path: &Path) -> ModuleLineCoverage {
+        ModuleLineCoverage {
+            name: "0x42::m".to_string(),
+            source_path: source_path.to_path_buf(),
+            functions: vec![
+                FunctionLineCoverage {
+                    name: "count_down".to_string(),
+                    line: 2,
+                    // offset 0 ran 4 times, twice after jumping back to the loop
+                    hits: 2,
+                    lines: BTreeMap::from([
+                        (3, line(4, vec![Some(2), Some(2)])),
+                        (4, line(2, vec![])),
+                        (6, line(2, vec![])),
+                    ]),
+                },
+                FunctionLineCoverage {
+                    name: "never".to_string(),
+                    line: 8,
+                    hits: 0,
+                    lines: BTreeMap::from([(9, line(0, vec![None, None]))]),
+                },
+            ],
+        }
+    }
+
+    const EXPECTED_LCOV: &str = "TN:
+SF:/project/sources/m.move
+FN:2,0x42::m::count_down
+FN:8,0x42::m::never
+FNDA:2,0x42::m::count_down
+FNDA:0,0x42::m::never
+FNF:2
+FNH:1
+BRDA:3,0,0,2
+BRDA:3,0,1,2
+BRDA:9,0,0,-
+BRDA:9,0,1,-
+BRF:4
+BRH:2
+DA:3,4
+DA:4,2
+DA:6,2
+DA:9,0
+LF:4
+LH:3
+end_of_record
+";
+
+    const EXPECTED_COBERTURA: &str = r#"<?xml version="1.0" ?>
+<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">
+<coverage line-rate="0.7500" branch-rate="0.5000" lines-covered="3" lines-valid="4" branches-covered="2" branches-valid="4" complexity="0" version="{version}" timestamp="0">
+  <sources>
+    <source>/project</source>
+  </sources>
+  <packages>
+    <package name="test&amp;co" line-rate="0.7500" branch-rate="0.5000" complexity="0">
+      <classes>
+        <class name="0x42::m" filename="sources/m.move" line-rate="0.7500" branch-rate="0.5000" complexity="0">
+          <methods>
+            <method name="count_down" signature="" line-rate="1.0000" branch-rate="1.0000" complexity="0">
+              <lines>
+                <line number="3" hits="4" branch="true" condition-coverage="100% (2/2)"/>
+                <line number="4" hits="2" branch="false"/>
+                <line number="6" hits="2" branch="false"/>
+              </lines>
+            </method>
+            <method name="never" signature="" line-rate="0.0000" branch-rate="0.0000" complexity="0">
+              <lines>
+                <line number="9" hits="0" branch="true" condition-coverage="0% (0/2)"/>
+              </lines>
+            </method>
+          </methods>
+          <lines>
+            <line number="3" hits="4" branch="true" condition-coverage="100% (2/2)"/>
+            <line number="4" hits="2" branch="false"/>
+            <line number="6" hits="2" branch="false"/>
+            <line number="9" hits="0" branch="true" condition-coverage="0% (0/2)"/>
+          </lines>
+        </class>
+      </classes>
+    </package>
+  </packages>
+</coverage>
+"#;
+
+    #[test]
+    fn test_module_line_coverage() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_path = dir.path().join("m.move");
+        fs::write(&source_path, SOURCE).unwrap();
+        let (module, source_map) = compiled_module();
+
+        let coverage =
+            ModuleLineCoverage::new(&module, &coverage_map(), &source_map, &source_path).unwrap();
+        assert_eq!(coverage, expected_coverage(&source_path));
+
+        // Without coverage information nothing is executed
+        let coverage = ModuleLineCoverage::new(
+            &module,
+            &ExecCoverageMap::new(String::new()),
+            &source_map,
+            &source_path,
+        )
+        .unwrap();
+        assert!(coverage.functions.iter().all(
+            |function| function.hits == 0 && function.lines.values().all(|line| line.hits == 0)
+        ));
+
+        fs::write(&source_path, format!("{SOURCE}\n")).unwrap();
+        assert!(
+            ModuleLineCoverage::new(&module, &coverage_map(), &source_map, &source_path).is_err()
+        );
+    }
+
+    #[test]
+    fn test_format_lcov() {
+        let coverage = expected_coverage(Path::new("/project/sources/m.move"));
+        let mut lcov = vec![];
+        format_lcov(&[coverage], &mut lcov).unwrap();
+        assert_eq!(String::from_utf8(lcov).unwrap(), EXPECTED_LCOV);
+    }
+
+    #[test]
+    fn test_format_cobertura() {
+        let coverage = expected_coverage(Path::new("/project/sources/m.move"));
+        let mut cobertura = vec![];
+        format_cobertura(
+            &[coverage],
+            "test&co",
+            Path::new("/project"),
+            &mut cobertura,
+        )
+        .unwrap();
+
+        // The timestamp is the only part of the report which is not reproducible
+        let cobertura = String::from_utf8(cobertura).unwrap();
+        let (head, tail) = cobertura.split_once(" timestamp=\"").unwrap();
+        let (_, tail) = tail.split_once('"').unwrap();
+        assert_eq!(
+            format!("{head} timestamp=\"0\"{tail}"),
+            EXPECTED_COBERTURA.replace("{version}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+}
@@ -10,6 +10,7 @@ use move_binary_format::CompiledModule;
 use crate::summary::ModuleSummary;
 
 pub mod coverage_map;
+pub mod export;
 pub mod source_coverage;
 pub mod summary;
 
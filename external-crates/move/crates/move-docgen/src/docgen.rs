@@ -18,17 +18,21 @@ use codespan::{ByteIndex, Span};
 use itertools::Itertools;
 #[allow(unused_imports)]
 use log::{debug, info, warn};
-use move_compiler::parser::keywords::{BUILTINS, CONTEXTUAL_KEYWORDS, KEYWORDS};
+use move_binary_format::file_format::Bytecode;
+use move_compiler::{
+    editions::Flavor,
+    parser::keywords::{BUILTINS, CONTEXTUAL_KEYWORDS, KEYWORDS},
+};
 use move_model::{
     ast::ModuleName,
     code_writer::{CodeWriter, CodeWriterLabel},
     emit, emitln,
     model::{
-        AbilitySet, EnumEnv, FunId, FunctionEnv, GlobalEnv, Loc, ModuleEnv, ModuleId,
+        AbilitySet, DatatypeId, EnumEnv, FunId, FunctionEnv, GlobalEnv, Loc, ModuleEnv, ModuleId,
         NamedConstantEnv, Parameter, QualifiedId, StructEnv, TypeParameter,
     },
     symbol::Symbol,
-    ty::TypeDisplayContext,
+    ty::{Type, TypeDisplayContext},
 };
 use num::BigUint;
 use once_cell::sync::Lazy;
@@ -38,6 +42,14 @@ use serde::{Deserialize, Serialize};
 /// The maximum number of subheadings that are allowed
 const MAX_SUBSECTIONS: usize = 6;
 
+/// The IOTA framework modules and functions whose type arguments are
+/// documented on the datatypes they are instantiated with.
+const IOTA_EVENT_EMIT: (&str, &str) = ("0x2::event", "emit");
+const IOTA_DYNAMIC_FIELD_ADDS: &[(&str, &str)] = &[
+    ("0x2::dynamic_field", "add"),
+    ("0x2::dynamic_object_field", "add"),
+];
+
 /// Options passed into the documentation generator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -97,6 +109,9 @@ pub struct DocgenOptions {
     /// If this is being compiled relative to a different place where it will be
     /// stored (output directory).
     pub compile_relative_to_output_dir: bool,
+    /// The flavor of the documented packages. With the IOTA flavor, objects,
+    /// entry functions, events and dynamic fields are annotated.
+    pub flavor: Flavor,
 }
 
 impl Default for DocgenOptions {
@@ -116,6 +131,7 @@ impl Default for DocgenOptions {
             references_file: None,
             include_dep_diagrams: false,
             include_call_diagrams: false,
+            flavor: Flavor::default(),
         }
     }
 }
@@ -143,6 +159,12 @@ pub struct Docgen<'env> {
     section_nest: RefCell<usize>,
     /// The last user provided (via an explicit # header) section nest.
     last_root_section_nest: RefCell<usize>,
+    /// Map from datatypes to the functions emitting them as events. Only
+    /// computed for the IOTA flavor.
+    event_emitters: BTreeMap<QualifiedId<DatatypeId>, BTreeSet<QualifiedId<FunId>>>,
+    /// Map from datatypes to the functions adding them as dynamic field names or
+    /// values. Only computed for the IOTA flavor.
+    dynamic_field_users: BTreeMap<QualifiedId<DatatypeId>, BTreeSet<QualifiedId<FunId>>>,
 }
 
 /// Information about the generated documentation for a specific script or
@@ -178,6 +200,14 @@ enum TemplateElement {
 impl<'env> Docgen<'env> {
     /// Creates a new documentation generator.
     pub fn new(env: &'env GlobalEnv, options: &'env DocgenOptions) -> Self {
+        let (event_emitters, dynamic_field_users) = if options.flavor == Flavor::Iota {
+            (
+                Self::compute_generic_call_users(env, &[IOTA_EVENT_EMIT]),
+                Self::compute_generic_call_users(env, IOTA_DYNAMIC_FIELD_ADDS),
+            )
+        } else {
+            Default::default()
+        };
         Self {
             options,
             env,
@@ -190,7 +220,47 @@ impl<'env> Docgen<'env> {
             toc: RefCell::new(Default::default()),
             section_nest: RefCell::new(0),
             last_root_section_nest: RefCell::new(0),
+            event_emitters,
+            dynamic_field_users,
+        }
+    }
+
+    /// Computes a map from datatypes to the functions which call one of the
+    /// given generic functions instantiated with them.
+    fn compute_generic_call_users(
+        env: &GlobalEnv,
+        callees: &[(&str, &str)],
+    ) -> BTreeMap<QualifiedId<DatatypeId>, BTreeSet<QualifiedId<FunId>>> {
+        let mut users: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for module_env in env.get_modules() {
+            let module = module_env.get_verified_module();
+            for func_env in module_env.get_functions() {
+                for code in func_env.get_bytecode() {
+                    let Bytecode::CallGeneric(idx) = code else {
+                        continue;
+                    };
+                    let inst = module.function_instantiation_at(*idx);
+                    let callee = module_env.get_used_function(inst.handle);
+                    let callee_name =
+                        (callee.module_env.get_full_name_str(), callee.get_name_str());
+                    if !callees
+                        .iter()
+                        .any(|(m, f)| callee_name.0 == *m && callee_name.1 == *f)
+                    {
+                        continue;
+                    }
+                    for token in &module.signature_at(inst.type_parameters).0 {
+                        if let Type::Datatype(mid, did, _) = module_env.globalize_signature(token) {
+                            users
+                                .entry(mid.qualified(did))
+                                .or_default()
+                                .insert(func_env.get_qualified_id());
+                        }
+                    }
+                }
+            }
         }
+        users
     }
 
     /// Generate document contents, returning pairs of output file names and
@@ -898,6 +968,7 @@ impl<'env> Docgen<'env> {
         self.increment_section_nest();
         self.doc_text(struct_env.get_doc());
         self.code_block(&self.struct_header_display(struct_env));
+        self.gen_iota_datatype_notes(struct_env.get_qualified_id(), struct_env.get_abilities());
 
         if self.options.include_impl || (self.options.include_specs && self.options.specs_inlined) {
             // Include field documentation if either impls or specs are present and inlined,
@@ -920,6 +991,7 @@ impl<'env> Docgen<'env> {
         self.increment_section_nest();
         self.doc_text(enum_env.get_doc());
         self.code_block(&self.enum_header_display(enum_env));
+        self.gen_iota_datatype_notes(enum_env.get_qualified_id(), enum_env.get_abilities());
 
         if self.options.include_impl || (self.options.include_specs && self.options.specs_inlined) {
             // Include field documentation if either impls or specs are present and inlined,
@@ -1064,6 +1136,7 @@ impl<'env> Docgen<'env> {
         self.doc_text(func_env.get_doc());
         let sig = self.function_header_display(func_env);
         self.code_block(&sig);
+        self.gen_iota_function_notes(func_env);
         if self.options.include_impl {
             self.begin_collapsed("Implementation");
             self.code_block(&self.get_source_with_indent(&func_env.get_loc()));
@@ -1098,6 +1171,105 @@ impl<'env> Docgen<'env> {
         }
     }
 
+    /// Generates the IOTA specific notes of a datatype: whether it is an
+    /// object, and which functions emit it as an event or add it to dynamic
+    /// fields.
+    fn gen_iota_datatype_notes(&self, id: QualifiedId<DatatypeId>, abilities: AbilitySet) {
+        if self.options.flavor != Flavor::Iota {
+            return;
+        }
+        let mut notes = vec![];
+        if abilities.has_key() {
+            notes.push(if abilities.has_store() {
+                "This type is an IOTA object. It can be owned, shared or frozen and, as it has \
+                 <code>store</code>, freely transferred or wrapped into other objects."
+                    .to_string()
+            } else {
+                "This type is an IOTA object. It can be owned, shared or frozen and, as it has \
+                 no <code>store</code>, only be transferred by functions of its own module."
+                    .to_string()
+            });
+        }
+        if let Some(funs) = self.event_emitters.get(&id) {
+            notes.push(format!(
+                "Emitted as an event by {}.",
+                self.functions_display(funs)
+            ));
+        }
+        if let Some(funs) = self.dynamic_field_users.get(&id) {
+            notes.push(format!(
+                "Used in dynamic fields by {}.",
+                self.functions_display(funs)
+            ));
+        }
+        self.notes(&notes);
+    }
+
+    /// Generates the IOTA specific notes of a function: whether it can be
+    /// called from a transaction, and which events it emits.
+    fn gen_iota_function_notes(&self, func_env: &FunctionEnv<'_>) {
+        if self.options.flavor != Flavor::Iota {
+            return;
+        }
+        let mut notes = vec![];
+        if func_env.is_entry() {
+            notes.push(
+                "This is an entry function: it can be called directly from a transaction."
+                    .to_string(),
+            );
+        }
+        let id = func_env.get_qualified_id();
+        let events = self
+            .event_emitters
+            .iter()
+            .filter(|(_, funs)| funs.contains(&id))
+            .map(|(ty, _)| self.module_item_ref_display(ty.module_id, ty.id.symbol()))
+            .collect_vec();
+        if !events.is_empty() {
+            notes.push(format!("Emits the events {}.", events.join(", ")));
+        }
+        self.notes(&notes);
+    }
+
+    /// Displays a list of functions, linked to their documentation.
+    fn functions_display(&self, funs: &BTreeSet<QualifiedId<FunId>>) -> String {
+        funs.iter()
+            .map(|id| {
+                let func_env = self.env.get_function(*id);
+                self.module_item_ref_display(id.module_id, func_env.get_name())
+            })
+            .join(", ")
+    }
+
+    /// Displays a module item as code, qualified with its module if it is not
+    /// declared in the current module, and linked to its documentation if the
+    /// module is documented.
+    fn module_item_ref_display(&self, module_id: ModuleId, item: Symbol) -> String {
+        let module_env = self.env.get_module(module_id);
+        let name = if self
+            .current_module
+            .as_ref()
+            .is_some_and(|m| m.get_id() == module_id)
+        {
+            self.name_string(item).to_string()
+        } else {
+            format!(
+                "{}::{}",
+                module_env.get_name().display(self.env.symbol_pool()),
+                self.name_string(item)
+            )
+        };
+        if self.infos.contains_key(&module_id) {
+            format!(
+                "<a href=\"{}\"><code>{}</code></a>",
+                self.ref_for_module_item(&module_env, item),
+                name
+            )
+        } else {
+            format!("<code>{}</code>", name)
+        }
+    }
+
     /// Generates documentation for a function signature.
     fn function_header_display(&self, func_env: &FunctionEnv<'_>) -> String {
         let name = self.name_string(func_env.get_name());
@@ -1344,6 +1516,14 @@ impl<'env> Docgen<'env> {
         decorated_text
     }
 
+    /// Emits notes as separate paragraphs.
+    fn notes(&self, notes: &[String]) {
+        for note in notes {
+            emitln!(self.writer, "{}", note);
+            emitln!(self.writer);
+        }
+    }
+
     /// Begins a code block. This uses html, not markdown code blocks, so we are
     /// able to insert style and links into the code.
     fn begin_code(&self) {
@@ -0,0 +1,8 @@
+/// A stub of the IOTA framework module managing dynamic fields.
+module 0x2::dynamic_field {
+    public native fun add<Name: copy + drop + store, Value: store>(
+        object: address,
+        name: Name,
+        value: Value,
+    );
+}
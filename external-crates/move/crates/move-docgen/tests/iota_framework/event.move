@@ -0,0 +1,4 @@
+/// A stub of the IOTA framework module emitting events.
+module 0x2::event {
+    public native fun emit<T: copy + drop>(event: T);
+}
@@ -0,0 +1,32 @@
+module 0x42::iota_notes {
+    use 0x2::dynamic_field;
+    use 0x2::event;
+
+    /// A coin which can be transferred freely.
+    public struct Coin has key, store {
+        value: u64,
+    }
+
+    /// A vault which only this module can transfer.
+    public struct Vault has key {
+        balance: u64,
+    }
+
+    /// Emitted when a vault is created.
+    public struct VaultCreated has copy, drop {
+        balance: u64,
+    }
+
+    /// The name of the dynamic field holding the coin of a vault.
+    public struct CoinKey has copy, drop, store {}
+
+    /// Creates a vault.
+    public entry fun create_vault(balance: u64) {
+        event::emit(VaultCreated { balance });
+    }
+
+    /// Stores a coin in a vault.
+    public fun store_coin(vault: address, coin: Coin) {
+        dynamic_field::add(vault, CoinKey {}, coin);
+    }
+}
@@ -13,6 +13,7 @@ use codespan_reporting::term::termcolor::Buffer;
 use itertools::Itertools;
 #[allow(unused_imports)]
 use log::debug;
+use move_compiler::editions::Flavor;
 use move_prover::{cli::Options, run_move_prover};
 use move_prover_test_utils::baseline_test::verify_or_update_baseline;
 use tempfile::TempDir;
@@ -24,6 +25,10 @@ const FLAGS: &[&str] = &[
     "--docgen",
 ];
 
+/// Stubs of the IOTA framework modules whose calls are annotated by the IOTA
+/// flavor.
+const IOTA_FRAMEWORK_FLAG: &str = "--dependency=tests/iota_framework";
+
 fn test_runner(path: &Path) -> datatest_stable::Result<()> {
     let mut args = vec!["mvp_test".to_string()];
     args.extend(FLAGS.iter().map(|s| (*s).to_string()).collect_vec());
@@ -73,6 +78,25 @@ fn test_runner(path: &Path) -> datatest_stable::Result<()> {
     Ok(())
 }
 
+fn iota_test_runner(path: &Path) -> datatest_stable::Result<()> {
+    let mut args = vec!["mvp_test".to_string()];
+    args.extend(FLAGS.iter().map(|s| (*s).to_string()).collect_vec());
+    args.push(IOTA_FRAMEWORK_FLAG.to_string());
+    args.push(path.to_string_lossy().to_string());
+
+    let mut options = Options::create_from_args(&args)?;
+    options.setup_logging_for_test();
+
+    // Leave out implementations and specs, the baseline is about the notes.
+    options.docgen.include_specs = false;
+    options.docgen.include_impl = false;
+    options.docgen.include_private_fun = true;
+    options.docgen.flavor = Flavor::Iota;
+    test_docgen(path, options, "iota.md")?;
+
+    Ok(())
+}
+
 fn test_docgen(path: &Path, mut options: Options, suffix: &str) -> anyhow::Result<()> {
     let mut temp_path = PathBuf::from(TempDir::new()?.path());
     options.docgen.output_directory = temp_path.to_string_lossy().to_string();
@@ -103,4 +127,11 @@ fn test_docgen(path: &Path, mut options: Options, suffix: &str) -> anyhow::Resul
     Ok(())
 }
 
-datatest_stable::harness!(test_runner, "tests/sources", r".*\.move|.*_template\.md",);
+datatest_stable::harness!(
+    test_runner,
+    "tests/sources",
+    r".*\.move|.*_template\.md",
+    iota_test_runner,
+    "tests/iota_sources",
+    r".*\.move",
+);
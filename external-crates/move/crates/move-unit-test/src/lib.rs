@@ -25,7 +25,10 @@ use move_core_types::language_storage::ModuleId;
 use move_vm_runtime::native_functions::NativeFunctionTable;
 use move_vm_test_utils::gas_schedule::CostTable;
 
-use crate::test_runner::TestRunner;
+use crate::{
+    test_reporter::{parse_test_report, TestReport},
+    test_runner::TestRunner,
+};
 
 /// The default value bounding the amount of gas consumed in a test.
 const DEFAULT_EXECUTION_BOUND: u64 = 1_000_000;
@@ -76,6 +79,11 @@ pub struct UnitTestingConfig {
     #[clap(name = "report-statistics", short = 's', long = "statistics")]
     pub report_statistics: Option<Option<String>>,
 
+    /// Write a machine readable report of the test results, given as
+    /// `junit=<path>` (JUnit XML) or `json=<path>`. Can be repeated.
+    #[clap(name = "report", long = "report", value_parser = parse_test_report)]
+    pub reports: Vec<TestReport>,
+
     #[clap(
         name = "report_stacktrace_on_abort",
         short = 'r',
@@ -137,6 +145,7 @@ impl UnitTestingConfig {
             filter: None,
             num_threads: 8,
             report_statistics: None,
+            reports: vec![],
             report_stacktrace_on_abort: false,
             source_files: vec![],
             dep_files: vec![],
@@ -263,6 +272,9 @@ impl UnitTestingConfig {
         if let Some(report_type) = &self.report_statistics {
             test_results.report_statistics(&shared_writer, report_type)?;
         }
+        for report in &self.reports {
+            test_results.write_report(report)?;
+        }
 
         let ok = test_results.summarize(&shared_writer)?;
 
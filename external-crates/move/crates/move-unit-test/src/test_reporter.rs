@@ -16,9 +16,11 @@ use move_core_types::{
     vm_status::{StatusCode, StatusType},
 };
 use move_ir_types::location::Loc;
+use serde::Serialize;
 use std::{
     collections::{BTreeMap, BTreeSet},
     io::{Result, Write},
+    path::PathBuf,
     sync::Mutex,
     time::Duration,
 };
@@ -69,6 +71,108 @@ pub struct TestResults {
     test_plan: TestPlan,
 }
 
+/// The formats of the machine readable reports of the test results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestReportFormat {
+    /// JUnit XML, as understood by most CI systems
+    Junit,
+    Json,
+}
+
+/// A machine readable report of the test results, to be written to `path`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestReport {
+    pub format: TestReportFormat,
+    pub path: PathBuf,
+}
+
+/// The outcome of a test function, over all of its runs
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCaseReport {
+    pub name: String,
+    pub module: String,
+    /// The total time taken by the runs of the test, in seconds
+    pub duration: f64,
+    pub gas_used: u64,
+    pub failure: Option<TestFailureReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestFailureReport {
+    /// The kind of the failure, e.g. `unexpected_error` or `timeout`
+    pub kind: &'static str,
+    pub message: String,
+    /// The status the VM errored with, if any
+    pub status: Option<String>,
+    pub abort_code: Option<u64>,
+    /// Where the VM errored, if known
+    pub location: Option<TestErrorLocation>,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestErrorLocation {
+    pub module: String,
+    pub function: String,
+    pub file: String,
+    pub line: usize,
+}
+
+/// The content of a JSON test report
+#[derive(Serialize)]
+struct JsonTestReport<'a> {
+    total: usize,
+    passed: usize,
+    failed: usize,
+    tests: &'a [TestCaseReport],
+}
+
+impl TestFailureReport {
+    /// A single line description of the failure
+    pub fn summary(&self) -> String {
+        let Some(status) = &self.status else {
+            return self.message.lines().next().unwrap_or_default().to_string();
+        };
+        let mut summary = status.clone();
+        if let Some(abort_code) = self.abort_code {
+            summary.push_str(&format!(" with code {abort_code}"));
+        }
+        if let Some(location) = &self.location {
+            summary.push_str(&format!(" in {location}"));
+        }
+        summary
+    }
+}
+
+impl std::fmt::Display for TestErrorLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}::{} ({}:{})",
+            self.module, self.function, self.file, self.line
+        )
+    }
+}
+
+/// Parses a report given as `<format>=<path>`, e.g. `junit=report.xml`.
+pub fn parse_test_report(s: &str) -> anyhow::Result<TestReport> {
+    let Some((format, path)) = s.split_once('=') else {
+        anyhow::bail!("Invalid report '{s}', expected '<format>=<path>'");
+    };
+    if path.is_empty() {
+        anyhow::bail!("Missing path of the '{format}' report");
+    }
+    let format = match format {
+        "junit" => TestReportFormat::Junit,
+        "json" => TestReportFormat::Json,
+        _ => anyhow::bail!("Unknown report format '{format}', expected 'junit' or 'json'"),
+    };
+    Ok(TestReport {
+        format,
+        path: PathBuf::from(path),
+    })
+}
+
 impl TestRunInfo {
     pub fn new(elapsed_time: Duration, instructions_executed: u64) -> Self {
         Self {
@@ -110,6 +214,19 @@ impl FailureReason {
     pub fn property(details: String) -> Self {
         FailureReason::Property(details)
     }
+
+    /// A short identifier of the kind of failure, used in the test reports
+    pub fn kind(&self) -> &'static str {
+        match self {
+            FailureReason::NoError(_) => "no_error",
+            FailureReason::WrongError(..) | FailureReason::WrongAbortDEPRECATED(..) => {
+                "wrong_error"
+            }
+            FailureReason::UnexpectedError(..) => "unexpected_error",
+            FailureReason::Timeout(_) => "timeout",
+            FailureReason::Property(_) => "property",
+        }
+    }
 }
 
 fn clever_error_line_number_to_loc(test_plan: &TestPlan, vm_error: &VMError) -> Option<Loc> {
@@ -153,6 +270,10 @@ impl TestFailure {
     }
 
     pub fn render_error(&self, test_plan: &TestPlan) -> String {
+        self.render_error_with_color(test_plan, control::SHOULD_COLORIZE.should_colorize())
+    }
+
+    fn render_error_with_color(&self, test_plan: &TestPlan, color: bool) -> String {
         match &self.failure_reason {
             FailureReason::NoError(message) => message.to_string(),
             FailureReason::Timeout(message) => message.to_string(),
@@ -164,7 +285,7 @@ impl TestFailure {
                         .present_tense(),
                     actual.with_context(&test_plan.module_info).past_tense(),
                 );
-                Self::report_error_with_location(test_plan, base_message, &self.vm_error)
+                Self::report_error_with_location(test_plan, base_message, &self.vm_error, color)
             }
             FailureReason::WrongAbortDEPRECATED(message, expected_code, actual) => {
                 let base_message = format!(
@@ -174,7 +295,7 @@ impl TestFailure {
                     expected_code,
                     actual.with_context(&test_plan.module_info).past_tense(),
                 );
-                Self::report_error_with_location(test_plan, base_message, &self.vm_error)
+                Self::report_error_with_location(test_plan, base_message, &self.vm_error, color)
             }
             FailureReason::UnexpectedError(message, error) => {
                 let prefix = match error.0.status_type() {
@@ -198,7 +319,7 @@ impl TestFailure {
                     message,
                     error.with_context(&test_plan.module_info).past_tense(),
                 );
-                Self::report_error_with_location(test_plan, base_message, &self.vm_error)
+                Self::report_error_with_location(test_plan, base_message, &self.vm_error, color)
             }
             FailureReason::Property(message) => message.clone(),
         }
@@ -256,13 +377,10 @@ impl TestFailure {
         test_plan: &TestPlan,
         base_message: String,
         vm_error: &Option<VMError>,
+        color: bool,
     ) -> String {
         let report_diagnostics = |mapped_files, diags| {
-            diagnostics::report_diagnostics_to_buffer_with_mapped_files(
-                mapped_files,
-                diags,
-                control::SHOULD_COLORIZE.should_colorize(),
-            )
+            diagnostics::report_diagnostics_to_buffer_with_mapped_files(mapped_files, diags, color)
         };
 
         let vm_error = match vm_error {
@@ -327,6 +445,141 @@ impl TestFailure {
     }
 }
 
+impl TestFailure {
+    fn report(&self, test_plan: &TestPlan) -> TestFailureReport {
+        let vm_error = self.vm_error.as_ref();
+        TestFailureReport {
+            kind: self.failure_reason.kind(),
+            message: self.render_error_with_color(test_plan, false),
+            status: vm_error.map(|err| format!("{:?}", err.major_status())),
+            abort_code: vm_error
+                .filter(|err| err.major_status() == StatusCode::ABORTED)
+                .and_then(|err| err.sub_status()),
+            location: vm_error.and_then(|err| error_location(test_plan, err)),
+            seed: self.prng_seed,
+        }
+    }
+}
+
+/// Returns the source location of the instruction the VM errored at.
+fn error_location(test_plan: &TestPlan, vm_error: &VMError) -> Option<TestErrorLocation> {
+    let Location::Module(module_id) = vm_error.location() else {
+        return None;
+    };
+    let (fdef_idx, offset) = vm_error.offsets().first()?;
+    let named_module = test_plan.module_info.get(module_id)?;
+    let loc = named_module
+        .source_map
+        .get_function_source_map(*fdef_idx)
+        .ok()?
+        .get_code_location(*offset)?;
+    let loc = match clever_error_line_number_to_loc(test_plan, vm_error) {
+        Some(alt_loc) if !loc.overlaps(&alt_loc) => alt_loc,
+        _ => loc,
+    };
+    let fn_handle_idx = named_module.module.function_def_at(*fdef_idx).function;
+    let fn_id_idx = named_module.module.function_handle_at(fn_handle_idx).name;
+    Some(TestErrorLocation {
+        module: format_module_id(&test_plan.module_info, module_id),
+        function: named_module.module.identifier_at(fn_id_idx).to_string(),
+        file: test_plan
+            .mapped_files
+            .filename(&loc.file_hash())
+            .to_string(),
+        line: test_plan.mapped_files.position(&loc).start.user_line(),
+    })
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // other control characters are not allowed in XML 1.0
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => (),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders the test cases as JUnit XML, with one test suite per module.
+fn junit_report(test_cases: &[TestCaseReport]) -> String {
+    let mut suites: BTreeMap<&str, Vec<&TestCaseReport>> = BTreeMap::new();
+    for test_case in test_cases {
+        suites.entry(&test_case.module).or_default().push(test_case);
+    }
+    let failures = |cases: &[&TestCaseReport]| cases.iter().filter(|c| c.failure.is_some()).count();
+    let duration = |cases: &[&TestCaseReport]| cases.iter().map(|c| c.duration).sum::<f64>();
+
+    let all_cases = test_cases.iter().collect::<Vec<_>>();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"Move unit tests\" tests=\"{}\" failures=\"{}\" time=\"{:.6}\">\n",
+        all_cases.len(),
+        failures(&all_cases),
+        duration(&all_cases),
+    ));
+    for (module, cases) in suites {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.6}\">\n",
+            xml_escape(module),
+            cases.len(),
+            failures(&cases),
+            duration(&cases),
+        ));
+        for case in cases {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\">\n",
+                xml_escape(&case.name),
+                xml_escape(module),
+                case.duration,
+            ));
+            let mut properties = vec![("gas_used", case.gas_used.to_string())];
+            if let Some(failure) = &case.failure {
+                properties.extend(
+                    failure
+                        .abort_code
+                        .map(|code| ("abort_code", code.to_string())),
+                );
+                properties.extend(
+                    failure
+                        .location
+                        .as_ref()
+                        .map(|location| ("error_location", location.to_string())),
+                );
+                properties.extend(failure.seed.map(|seed| ("seed", seed.to_string())));
+            }
+            xml.push_str("      <properties>\n");
+            for (name, value) in properties {
+                xml.push_str(&format!(
+                    "        <property name=\"{name}\" value=\"{}\"/>\n",
+                    xml_escape(&value)
+                ));
+            }
+            xml.push_str("      </properties>\n");
+            if let Some(failure) = &case.failure {
+                let summary = failure.summary();
+                xml.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    failure.kind,
+                    xml_escape(&summary),
+                    xml_escape(&failure.message),
+                ));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
 impl Default for TestStatistics {
     fn default() -> Self {
         Self::new()
@@ -410,6 +663,66 @@ impl TestResults {
         }
     }
 
+    /// Returns the outcome of each test function. A test with several runs
+    /// (e.g. a `#[random_test]`) fails if any of its runs failed, and its
+    /// duration and gas used are summed over all runs.
+    pub fn test_case_reports(&self) -> Vec<TestCaseReport> {
+        let mut test_cases = BTreeMap::new();
+        for (module_id, test_results) in &self.final_statistics.passed {
+            for (function_name, test_results) in test_results {
+                let (time, instrs_executed) = calculate_run_statistics(test_results);
+                let test_case = test_cases
+                    .entry((module_id, function_name))
+                    .or_insert_with(|| self.empty_test_case_report(module_id, function_name));
+                test_case.duration += time.as_secs_f64();
+                test_case.gas_used += instrs_executed;
+            }
+        }
+        for (module_id, test_failures) in &self.final_statistics.failed {
+            for (function_name, test_failures) in test_failures {
+                let (time, instrs_executed) =
+                    calculate_run_statistics(test_failures.iter().map(|f| &f.test_run_info));
+                let test_case = test_cases
+                    .entry((module_id, function_name))
+                    .or_insert_with(|| self.empty_test_case_report(module_id, function_name));
+                test_case.duration += time.as_secs_f64();
+                test_case.gas_used += instrs_executed;
+                test_case.failure = test_failures
+                    .first()
+                    .map(|failure| failure.report(&self.test_plan));
+            }
+        }
+        test_cases.into_values().collect()
+    }
+
+    fn empty_test_case_report(&self, module_id: &ModuleId, function_name: &str) -> TestCaseReport {
+        TestCaseReport {
+            name: function_name.to_string(),
+            module: format_module_id(&self.test_plan.module_info, module_id),
+            duration: 0.0,
+            gas_used: 0,
+            failure: None,
+        }
+    }
+
+    /// Writes a machine readable report of the test results.
+    pub fn write_report(&self, report: &TestReport) -> Result<()> {
+        let test_cases = self.test_case_reports();
+        let content = match report.format {
+            TestReportFormat::Junit => junit_report(&test_cases),
+            TestReportFormat::Json => {
+                let failed = test_cases.iter().filter(|c| c.failure.is_some()).count();
+                serde_json::to_string_pretty(&JsonTestReport {
+                    total: test_cases.len(),
+                    passed: test_cases.len() - failed,
+                    failed,
+                    tests: &test_cases,
+                })?
+            }
+        };
+        std::fs::write(&report.path, content)
+    }
+
     pub fn report_statistics<W: Write>(
         &self,
         writer: &Mutex<W>,
@@ -615,3 +928,175 @@ impl TestResults {
         Ok(num_failed_tests == 0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_compiler::shared::files::MappedFiles;
+    use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+
+    fn module_test_plan(address: &str, name: &str) -> ModuleTestPlan {
+        ModuleTestPlan {
+            module_id: ModuleId::new(
+                AccountAddress::from_hex_literal(address).unwrap(),
+                Identifier::new(name).unwrap(),
+            ),
+            tests: BTreeMap::new(),
+        }
+    }
+
+    fn run_info(millis: u64, instructions_executed: u64) -> TestRunInfo {
+        TestRunInfo::new(Duration::from_millis(millis), instructions_executed)
+    }
+
+    /// A passing test in `0x1::a` and a failing one in `0x2::b`, whose
+    /// failure message needs escaping in XML
+    fn test_results() -> TestResults {
+        let module_a = module_test_plan("0x1", "a");
+        let module_b = module_test_plan("0x2", "b");
+        let mut statistics = TestStatistics::new();
+        statistics.test_success("ok".to_string(), run_info(1500, 10), &module_a);
+        statistics.test_failure(
+            "fails".to_string(),
+            TestFailure::new(
+                FailureReason::property("<x> & \"y\" aren't equal\nx = 1\u{7}".to_string()),
+                run_info(250, 3),
+                None,
+                Some(42),
+            ),
+            &module_b,
+        );
+        TestResults::new(
+            statistics,
+            TestPlan::new(vec![module_a, module_b], MappedFiles::empty(), vec![]),
+        )
+    }
+
+    const EXPECTED_JUNIT_REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="Move unit tests" tests="2" failures="1" time="1.750000">
+  <testsuite name="0x1::a" tests="1" failures="0" time="1.500000">
+    <testcase name="ok" classname="0x1::a" time="1.500000">
+      <properties>
+        <property name="gas_used" value="10"/>
+      </properties>
+    </testcase>
+  </testsuite>
+  <testsuite name="0x2::b" tests="1" failures="1" time="0.250000">
+    <testcase name="fails" classname="0x2::b" time="0.250000">
+      <properties>
+        <property name="gas_used" value="3"/>
+        <property name="seed" value="42"/>
+      </properties>
+      <failure type="property" message="&lt;x&gt; &amp; &quot;y&quot; aren&apos;t equal">&lt;x&gt; &amp; &quot;y&quot; aren&apos;t equal
+x = 1</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+
+    #[test]
+    fn test_parse_test_report() {
+        assert_eq!(
+            parse_test_report("junit=out/report.xml").unwrap(),
+            TestReport {
+                format: TestReportFormat::Junit,
+                path: PathBuf::from("out/report.xml"),
+            }
+        );
+        // Only the first `=` separates the format from the path
+        assert_eq!(
+            parse_test_report("json=a=b.json").unwrap(),
+            TestReport {
+                format: TestReportFormat::Json,
+                path: PathBuf::from("a=b.json"),
+            }
+        );
+
+        for (report, error) in [
+            ("report.xml", "Invalid report 'report.xml'"),
+            ("junit=", "Missing path of the 'junit' report"),
+            ("csv=report.csv", "Unknown report format 'csv'"),
+        ] {
+            let err = parse_test_report(report).unwrap_err().to_string();
+            assert!(err.starts_with(error), "unexpected error: {err}");
+        }
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("plain text"), "plain text");
+        assert_eq!(
+            xml_escape(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;"
+        );
+        // Whitespace is kept while other control characters are dropped
+        assert_eq!(xml_escape("a\tb\r\nc\u{0}\u{1b}[31md"), "a\tb\r\nc[31md");
+    }
+
+    #[test]
+    fn test_junit_report() {
+        let test_cases = test_results().test_case_reports();
+        assert_eq!(junit_report(&test_cases), EXPECTED_JUNIT_REPORT);
+        assert_eq!(
+            junit_report(&[]),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"Move unit tests\" tests=\"0\" failures=\"0\" time=\"0.000000\">\n\
+             </testsuites>\n"
+        );
+    }
+
+    #[test]
+    fn test_write_report() {
+        let results = test_results();
+        let dir = tempfile::tempdir().unwrap();
+
+        let junit = TestReport {
+            format: TestReportFormat::Junit,
+            path: dir.path().join("report.xml"),
+        };
+        results.write_report(&junit).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&junit.path).unwrap(),
+            EXPECTED_JUNIT_REPORT
+        );
+
+        let json = TestReport {
+            format: TestReportFormat::Json,
+            path: dir.path().join("report.json"),
+        };
+        results.write_report(&json).unwrap();
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json.path).unwrap()).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!({
+                "total": 2,
+                "passed": 1,
+                "failed": 1,
+                "tests": [
+                    {
+                        "name": "ok",
+                        "module": "0x1::a",
+                        "duration": 1.5,
+                        "gas_used": 10,
+                        "failure": null,
+                    },
+                    {
+                        "name": "fails",
+                        "module": "0x2::b",
+                        "duration": 0.25,
+                        "gas_used": 3,
+                        "failure": {
+                            "kind": "property",
+                            "message": "<x> & \"y\" aren't equal\nx = 1\u{7}",
+                            "status": null,
+                            "abort_code": null,
+                            "location": null,
+                            "seed": 42,
+                        },
+                    },
+                ],
+            })
+        );
+    }
+}
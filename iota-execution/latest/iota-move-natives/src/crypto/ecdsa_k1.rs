@@ -14,8 +14,8 @@ use fastcrypto::{
     traits::{RecoverableSignature, RecoverableSigner, ToFromBytes},
 };
 use iota_types::crypto::KeypairTraits;
-use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{gas_algebra::InternalGas, vm_status::StatusCode};
 use move_vm_runtime::{native_charge_gas_early_exit, native_functions::NativeContext};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -24,9 +24,10 @@ use move_vm_types::{
     values::{self, Value, VectorRef},
 };
 use rand::{SeedableRng, rngs::StdRng};
+use secp256k1::{Message, SECP256K1, XOnlyPublicKey, schnorr};
 use smallvec::smallvec;
 
-use crate::NativesCostTable;
+use crate::{NativesCostTable, object_runtime::ObjectRuntime};
 
 pub const FAIL_TO_RECOVER_PUBKEY: u64 = 0;
 pub const INVALID_SIGNATURE: u64 = 1;
@@ -34,6 +35,7 @@ pub const INVALID_PUBKEY: u64 = 2;
 pub const INVALID_PRIVKEY: u64 = 3;
 pub const INVALID_HASH_FUNCTION: u64 = 4;
 pub const INVALID_SEED: u64 = 5;
+pub const NOT_SUPPORTED_ERROR: u64 = 6;
 
 pub const KECCAK256: u8 = 0;
 pub const SHA256: u8 = 1;
@@ -42,6 +44,14 @@ const KECCAK256_BLOCK_SIZE: usize = 136;
 const SHA256_BLOCK_SIZE: usize = 64;
 const SEED_LENGTH: usize = 32;
 
+fn is_schnorr_supported(context: &NativeContext) -> bool {
+    context
+        .extensions()
+        .get::<ObjectRuntime>()
+        .protocol_config
+        .enable_schnorr_verify()
+}
+
 #[derive(Clone)]
 pub struct EcdsaK1EcrecoverCostParams {
     /// Base cost for invoking the `ecrecover` function with `hash=0` implying
@@ -319,6 +329,79 @@ pub fn secp256k1_verify(
     Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
 }
 
+#[derive(Clone)]
+pub struct EcdsaK1SchnorrVerifyCostParams {
+    /// Base cost for invoking the `schnorr_verify` function
+    pub ecdsa_k1_schnorr_verify_cost_base: Option<InternalGas>,
+}
+/// ****************************************************************************
+/// ********************* native fun schnorr_verify
+/// Implementation of the Move native function `schnorr_verify(signature:
+/// &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool`
+///   gas cost: ecdsa_k1_schnorr_verify_cost_base | covers various fixed costs
+/// in the oper
+/// Note: `signature`, `public_key` and `msg` are all fixed size as defined in
+/// BIP-340, so their costs are included in the base cost.
+/// ****************************************************************************
+/// *******************
+pub fn schnorr_verify(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let cost = context.gas_used();
+    if !is_schnorr_supported(context) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
+    // Load the cost parameters from the protocol config
+    let ecdsa_k1_schnorr_verify_cost_params = &context
+        .extensions()
+        .get::<NativesCostTable>()
+        .ecdsa_k1_schnorr_verify_cost_params
+        .clone();
+
+    // Charge the base cost for this oper
+    native_charge_gas_early_exit!(
+        context,
+        ecdsa_k1_schnorr_verify_cost_params
+            .ecdsa_k1_schnorr_verify_cost_base
+            .ok_or_else(
+                || PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Gas cost for schnorr_verify not available".to_string())
+            )?
+    );
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = context.gas_used();
+
+    let Ok(sig) = schnorr::Signature::from_slice(&signature_bytes_ref) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let Ok(pk) = XOnlyPublicKey::from_slice(&public_key_bytes_ref) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let Ok(msg) = Message::from_slice(&msg_ref) else {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    };
+
+    let result = SECP256K1.verify_schnorr(&sig, &msg, &pk).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
 /// ****************************************************************************
 /// ********************* native fun secp256k1_sign (TEST ONLY)
 /// Implementation of the Move native function `secp256k1_sign(private_key:
@@ -4,6 +4,12 @@
 
 use std::collections::VecDeque;
 
+use ark_bn254::{Bn254, Fr as Bn254Fr, G1Affine as Bn254G1, G2Affine as Bn254G2};
+use ark_ec::{
+    CurveGroup,
+    pairing::{Pairing as _, PairingOutput},
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use fastcrypto::{
     error::{FastCryptoError, FastCryptoResult},
     groups::{
@@ -37,6 +43,17 @@ fn is_msm_supported(context: &NativeContext) -> bool {
         .enable_group_ops_native_function_msm()
 }
 
+// BN254 operations are only available if enabled by the protocol config, all
+// other groups are always supported.
+fn is_group_supported(context: &NativeContext, group_type: u8) -> bool {
+    !Groups::from_u8(group_type).is_some_and(|group| group.is_bn254())
+        || context
+            .extensions()
+            .get::<ObjectRuntime>()
+            .protocol_config
+            .enable_group_ops_bn254()
+}
+
 // Gas related structs and functions.
 
 #[derive(Clone)]
@@ -81,6 +98,28 @@ pub struct GroupOpsCostParams {
     pub bls12381_msm_max_len: Option<u32>,
     // costs for decode, pairing, and encode output
     pub bls12381_pairing_cost: Option<InternalGas>,
+
+    // costs for decode and validate
+    pub bn254_decode_scalar_cost: Option<InternalGas>,
+    pub bn254_decode_g1_cost: Option<InternalGas>,
+    pub bn254_decode_g2_cost: Option<InternalGas>,
+    // costs for decode, add, and encode output
+    pub bn254_scalar_add_cost: Option<InternalGas>,
+    pub bn254_g1_add_cost: Option<InternalGas>,
+    pub bn254_g2_add_cost: Option<InternalGas>,
+    pub bn254_gt_add_cost: Option<InternalGas>,
+    // costs for decode, sub, and encode output
+    pub bn254_scalar_sub_cost: Option<InternalGas>,
+    pub bn254_g1_sub_cost: Option<InternalGas>,
+    pub bn254_g2_sub_cost: Option<InternalGas>,
+    pub bn254_gt_sub_cost: Option<InternalGas>,
+    // costs for decode, mul, and encode output
+    pub bn254_scalar_mul_cost: Option<InternalGas>,
+    pub bn254_g1_mul_cost: Option<InternalGas>,
+    pub bn254_g2_mul_cost: Option<InternalGas>,
+    pub bn254_gt_mul_cost: Option<InternalGas>,
+    // costs for decode, pairing, and encode output
+    pub bn254_pairing_cost: Option<InternalGas>,
 }
 
 macro_rules! native_charge_gas_early_exit_option {
@@ -104,6 +143,10 @@ enum Groups {
     BLS12381G1 = 1,
     BLS12381G2 = 2,
     BLS12381GT = 3,
+    BN254Scalar = 4,
+    BN254G1 = 5,
+    BN254G2 = 6,
+    BN254GT = 7,
 }
 
 impl Groups {
@@ -113,9 +156,20 @@ impl Groups {
             1 => Some(Groups::BLS12381G1),
             2 => Some(Groups::BLS12381G2),
             3 => Some(Groups::BLS12381GT),
+            4 => Some(Groups::BN254Scalar),
+            5 => Some(Groups::BN254G1),
+            6 => Some(Groups::BN254G2),
+            7 => Some(Groups::BN254GT),
             _ => None,
         }
     }
+
+    fn is_bn254(&self) -> bool {
+        matches!(
+            self,
+            Groups::BN254Scalar | Groups::BN254G1 | Groups::BN254G2 | Groups::BN254GT
+        )
+    }
 }
 
 fn parse_untrusted<G: ToFromByteArray<S> + FromTrustedByteArray<S>, const S: usize>(
@@ -156,6 +210,47 @@ fn binary_op<G: ToFromByteArray<S> + FromTrustedByteArray<S>, const S: usize>(
     binary_op_diff::<G, G, S, S>(op, a1, a2)
 }
 
+// BN254 elements use the compressed arkworks encoding, i.e., scalars and
+// coordinates are encoded using little-endian byte order.
+const BN254_SCALAR_LENGTH: usize = 32;
+const BN254_G1_LENGTH: usize = 32;
+const BN254_G2_LENGTH: usize = 64;
+const BN254_GT_LENGTH: usize = 384;
+
+type Bn254GT = PairingOutput<Bn254>;
+
+fn parse_bn254<G: CanonicalDeserialize>(
+    e: &[u8],
+    length: usize,
+    validate: Validate,
+) -> FastCryptoResult<G> {
+    if e.len() != length {
+        return Err(FastCryptoError::InvalidInput);
+    }
+    G::deserialize_with_mode(e, Compress::Yes, validate).map_err(|_| FastCryptoError::InvalidInput)
+}
+
+fn serialize_bn254<G: CanonicalSerialize>(e: G) -> FastCryptoResult<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(e.compressed_size());
+    e.serialize_compressed(&mut bytes)
+        .map_err(|_| FastCryptoError::InvalidInput)?;
+    Ok(bytes)
+}
+
+// Binary operations on BN254 elements. As with the other groups, the inputs are
+// trusted since all Element<G> are validated on construction.
+fn bn254_binary_op<G1: CanonicalDeserialize, G2: CanonicalDeserialize, R: CanonicalSerialize>(
+    op: impl Fn(G1, G2) -> R,
+    a1: &[u8],
+    length1: usize,
+    a2: &[u8],
+    length2: usize,
+) -> FastCryptoResult<Vec<u8>> {
+    let e1 = parse_bn254::<G1>(a1, length1, Validate::No)?;
+    let e2 = parse_bn254::<G2>(a2, length2, Validate::No)?;
+    serialize_bn254(op(e1, e2))
+}
+
 // TODO: Since in many cases more than one group operation will be performed in
 // a single transaction, it might be worth caching the affine representation of
 // the group elements and use them to save conversions.
@@ -181,6 +276,10 @@ pub fn internal_validate(
     let bytes = bytes_ref.as_bytes_ref();
     let group_type = pop_arg!(args, u8);
 
+    if !is_group_supported(context, group_type) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
     let cost_params = &context
         .extensions()
         .get::<NativesCostTable>()
@@ -200,6 +299,18 @@ pub fn internal_validate(
             native_charge_gas_early_exit_option!(context, cost_params.bls12381_decode_g2_cost);
             parse_untrusted::<bls::G2Element, { bls::G2Element::BYTE_LENGTH }>(&bytes).is_ok()
         }
+        Some(Groups::BN254Scalar) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_decode_scalar_cost);
+            parse_bn254::<Bn254Fr>(&bytes, BN254_SCALAR_LENGTH, Validate::Yes).is_ok()
+        }
+        Some(Groups::BN254G1) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_decode_g1_cost);
+            parse_bn254::<Bn254G1>(&bytes, BN254_G1_LENGTH, Validate::Yes).is_ok()
+        }
+        Some(Groups::BN254G2) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_decode_g2_cost);
+            parse_bn254::<Bn254G2>(&bytes, BN254_G2_LENGTH, Validate::Yes).is_ok()
+        }
         _ => false,
     };
 
@@ -229,6 +340,10 @@ pub fn internal_add(
     let e1 = e1_ref.as_bytes_ref();
     let group_type = pop_arg!(args, u8);
 
+    if !is_group_supported(context, group_type) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
     let cost_params = &context
         .extensions()
         .get::<NativesCostTable>()
@@ -252,6 +367,46 @@ pub fn internal_add(
             native_charge_gas_early_exit_option!(context, cost_params.bls12381_gt_add_cost);
             binary_op::<bls::GTElement, { bls::GTElement::BYTE_LENGTH }>(|a, b| Ok(a + b), &e1, &e2)
         }
+        Some(Groups::BN254Scalar) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_scalar_add_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254Fr| a + b,
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_SCALAR_LENGTH,
+            )
+        }
+        Some(Groups::BN254G1) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g1_add_cost);
+            bn254_binary_op(
+                |a: Bn254G1, b: Bn254G1| (a + b).into_affine(),
+                &e1,
+                BN254_G1_LENGTH,
+                &e2,
+                BN254_G1_LENGTH,
+            )
+        }
+        Some(Groups::BN254G2) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g2_add_cost);
+            bn254_binary_op(
+                |a: Bn254G2, b: Bn254G2| (a + b).into_affine(),
+                &e1,
+                BN254_G2_LENGTH,
+                &e2,
+                BN254_G2_LENGTH,
+            )
+        }
+        Some(Groups::BN254GT) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_gt_add_cost);
+            bn254_binary_op(
+                |a: Bn254GT, b: Bn254GT| a + b,
+                &e1,
+                BN254_GT_LENGTH,
+                &e2,
+                BN254_GT_LENGTH,
+            )
+        }
         _ => Err(FastCryptoError::InvalidInput),
     };
 
@@ -286,6 +441,10 @@ pub fn internal_sub(
     let e1 = e1_ref.as_bytes_ref();
     let group_type = pop_arg!(args, u8);
 
+    if !is_group_supported(context, group_type) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
     let cost_params = &context
         .extensions()
         .get::<NativesCostTable>()
@@ -309,6 +468,46 @@ pub fn internal_sub(
             native_charge_gas_early_exit_option!(context, cost_params.bls12381_gt_sub_cost);
             binary_op::<bls::GTElement, { bls::GTElement::BYTE_LENGTH }>(|a, b| Ok(a - b), &e1, &e2)
         }
+        Some(Groups::BN254Scalar) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_scalar_sub_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254Fr| a - b,
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_SCALAR_LENGTH,
+            )
+        }
+        Some(Groups::BN254G1) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g1_sub_cost);
+            bn254_binary_op(
+                |a: Bn254G1, b: Bn254G1| (a - b).into_affine(),
+                &e1,
+                BN254_G1_LENGTH,
+                &e2,
+                BN254_G1_LENGTH,
+            )
+        }
+        Some(Groups::BN254G2) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g2_sub_cost);
+            bn254_binary_op(
+                |a: Bn254G2, b: Bn254G2| (a - b).into_affine(),
+                &e1,
+                BN254_G2_LENGTH,
+                &e2,
+                BN254_G2_LENGTH,
+            )
+        }
+        Some(Groups::BN254GT) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_gt_sub_cost);
+            bn254_binary_op(
+                |a: Bn254GT, b: Bn254GT| a - b,
+                &e1,
+                BN254_GT_LENGTH,
+                &e2,
+                BN254_GT_LENGTH,
+            )
+        }
         _ => Err(FastCryptoError::InvalidInput),
     };
 
@@ -343,6 +542,10 @@ pub fn internal_mul(
     let e1 = e1_ref.as_bytes_ref();
     let group_type = pop_arg!(args, u8);
 
+    if !is_group_supported(context, group_type) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
     let cost_params = &context
         .extensions()
         .get::<NativesCostTable>()
@@ -381,6 +584,46 @@ pub fn internal_mul(
                 { bls::GTElement::BYTE_LENGTH },
             >(|a, b| Ok(b * a), &e1, &e2)
         }
+        Some(Groups::BN254Scalar) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_scalar_mul_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254Fr| b * a,
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_SCALAR_LENGTH,
+            )
+        }
+        Some(Groups::BN254G1) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g1_mul_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254G1| (b * a).into_affine(),
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_G1_LENGTH,
+            )
+        }
+        Some(Groups::BN254G2) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_g2_mul_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254G2| (b * a).into_affine(),
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_G2_LENGTH,
+            )
+        }
+        Some(Groups::BN254GT) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_gt_mul_cost);
+            bn254_binary_op(
+                |a: Bn254Fr, b: Bn254GT| b * a,
+                &e1,
+                BN254_SCALAR_LENGTH,
+                &e2,
+                BN254_GT_LENGTH,
+            )
+        }
         _ => Err(FastCryptoError::InvalidInput),
     };
 
@@ -716,6 +959,10 @@ pub fn internal_pairing(
     let e1 = e1_ref.as_bytes_ref();
     let group_type = pop_arg!(args, u8);
 
+    if !is_group_supported(context, group_type) {
+        return Ok(NativeResult::err(cost, NOT_SUPPORTED_ERROR));
+    }
+
     let cost_params = &context
         .extensions()
         .get::<NativesCostTable>()
@@ -732,6 +979,16 @@ pub fn internal_pairing(
                 })
             })
         }
+        Some(Groups::BN254G1) => {
+            native_charge_gas_early_exit_option!(context, cost_params.bn254_pairing_cost);
+            bn254_binary_op(
+                |a: Bn254G1, b: Bn254G2| Bn254::pairing(a, b),
+                &e1,
+                BN254_G1_LENGTH,
+                &e2,
+                BN254_G2_LENGTH,
+            )
+        }
         _ => Err(FastCryptoError::InvalidInput),
     };
 
@@ -35,7 +35,7 @@ use self::{
         ecdsa_k1,
         ecdsa_k1::{
             EcdsaK1DecompressPubkeyCostParams, EcdsaK1EcrecoverCostParams,
-            EcdsaK1Secp256k1VerifyCostParams,
+            EcdsaK1SchnorrVerifyCostParams, EcdsaK1Secp256k1VerifyCostParams,
         },
         ecdsa_r1,
         ecdsa_r1::{EcdsaR1EcrecoverCostParams, EcdsaR1Secp256R1VerifyCostParams},
@@ -141,6 +141,7 @@ pub struct NativesCostTable {
     pub ecdsa_k1_ecrecover_cost_params: EcdsaK1EcrecoverCostParams,
     pub ecdsa_k1_decompress_pubkey_cost_params: EcdsaK1DecompressPubkeyCostParams,
     pub ecdsa_k1_secp256k1_verify_cost_params: EcdsaK1Secp256k1VerifyCostParams,
+    pub ecdsa_k1_schnorr_verify_cost_params: EcdsaK1SchnorrVerifyCostParams,
 
     // ecdsar1
     pub ecdsa_r1_ecrecover_cost_params: EcdsaR1EcrecoverCostParams,
@@ -437,6 +438,11 @@ impl NativesCostTable {
                     .ecdsa_k1_secp256k1_verify_sha256_msg_cost_per_block()
                     .into(),
             },
+            ecdsa_k1_schnorr_verify_cost_params: EcdsaK1SchnorrVerifyCostParams {
+                ecdsa_k1_schnorr_verify_cost_base: protocol_config
+                    .ecdsa_k1_schnorr_verify_cost_base_as_option()
+                    .map(Into::into),
+            },
             ecdsa_r1_ecrecover_cost_params: EcdsaR1EcrecoverCostParams {
                 ecdsa_r1_ecrecover_keccak256_cost_base: protocol_config
                     .ecdsa_r1_ecrecover_keccak256_cost_base()
@@ -639,6 +645,54 @@ impl NativesCostTable {
                 bls12381_pairing_cost: protocol_config
                     .group_ops_bls12381_pairing_cost_as_option()
                     .map(Into::into),
+                bn254_decode_scalar_cost: protocol_config
+                    .group_ops_bn254_decode_scalar_cost_as_option()
+                    .map(Into::into),
+                bn254_decode_g1_cost: protocol_config
+                    .group_ops_bn254_decode_g1_cost_as_option()
+                    .map(Into::into),
+                bn254_decode_g2_cost: protocol_config
+                    .group_ops_bn254_decode_g2_cost_as_option()
+                    .map(Into::into),
+                bn254_scalar_add_cost: protocol_config
+                    .group_ops_bn254_scalar_add_cost_as_option()
+                    .map(Into::into),
+                bn254_g1_add_cost: protocol_config
+                    .group_ops_bn254_g1_add_cost_as_option()
+                    .map(Into::into),
+                bn254_g2_add_cost: protocol_config
+                    .group_ops_bn254_g2_add_cost_as_option()
+                    .map(Into::into),
+                bn254_gt_add_cost: protocol_config
+                    .group_ops_bn254_gt_add_cost_as_option()
+                    .map(Into::into),
+                bn254_scalar_sub_cost: protocol_config
+                    .group_ops_bn254_scalar_sub_cost_as_option()
+                    .map(Into::into),
+                bn254_g1_sub_cost: protocol_config
+                    .group_ops_bn254_g1_sub_cost_as_option()
+                    .map(Into::into),
+                bn254_g2_sub_cost: protocol_config
+                    .group_ops_bn254_g2_sub_cost_as_option()
+                    .map(Into::into),
+                bn254_gt_sub_cost: protocol_config
+                    .group_ops_bn254_gt_sub_cost_as_option()
+                    .map(Into::into),
+                bn254_scalar_mul_cost: protocol_config
+                    .group_ops_bn254_scalar_mul_cost_as_option()
+                    .map(Into::into),
+                bn254_g1_mul_cost: protocol_config
+                    .group_ops_bn254_g1_mul_cost_as_option()
+                    .map(Into::into),
+                bn254_g2_mul_cost: protocol_config
+                    .group_ops_bn254_g2_mul_cost_as_option()
+                    .map(Into::into),
+                bn254_gt_mul_cost: protocol_config
+                    .group_ops_bn254_gt_mul_cost_as_option()
+                    .map(Into::into),
+                bn254_pairing_cost: protocol_config
+                    .group_ops_bn254_pairing_cost_as_option()
+                    .map(Into::into),
             },
             vdf_cost_params: VDFCostParams {
                 vdf_verify_cost: protocol_config
@@ -827,6 +881,11 @@ pub fn all_natives(silent: bool, protocol_config: &ProtocolConfig) -> NativeFunc
             "secp256k1_verify",
             make_native!(ecdsa_k1::secp256k1_verify),
         ),
+        (
+            "ecdsa_k1",
+            "schnorr_verify",
+            make_native!(ecdsa_k1::schnorr_verify),
+        ),
         ("ecvrf", "ecvrf_verify", make_native!(ecvrf::ecvrf_verify)),
         (
             "ecdsa_r1",